@@ -1,4 +1,4 @@
-use baml_types::{BamlMap, BamlMediaType, BamlValue, FieldType, TypeValue};
+use baml_types::{BamlMap, BamlMediaType, BamlValue, FieldType, LiteralValue, TypeValue};
 use core::result::Result;
 use std::path::PathBuf;
 
@@ -170,6 +170,15 @@ impl ArgCoercer {
                     Err(())
                 }
             },
+            FieldType::Literal(literal) => match (literal, value) {
+                (LiteralValue::String(l), BamlValue::String(s)) if l == s => Ok(value.clone()),
+                (LiteralValue::Int(l), BamlValue::Int(i)) if l == i => Ok(value.clone()),
+                (LiteralValue::Bool(l), BamlValue::Bool(b)) if l == b => Ok(value.clone()),
+                _ => {
+                    scope.push_error(format!("Expected literal {}, got `{}`", literal, value));
+                    Err(())
+                }
+            },
             FieldType::Enum(name) => match value {
                 BamlValue::String(s) => {
                     if let Ok(e) = ir.find_enum(name) {
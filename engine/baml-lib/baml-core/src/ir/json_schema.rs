@@ -1,6 +1,11 @@
 // JSON Schema
 
-use baml_types::TypeValue;
+use std::convert::Infallible;
+
+use anyhow::Result;
+use baml_types::{LiteralValue, TypeValue};
+use indexmap::IndexSet;
+use internal_baml_jinja::types::{self as output_format, OutputFormatContent};
 use serde_json::json;
 
 use super::{
@@ -153,29 +158,15 @@ impl WithJsonSchema for Walker<'_, &Class> {
     }
 }
 
-impl<'db> WithJsonSchema for FieldType {
+impl WithJsonSchema for FieldType {
     fn json_schema(&self) -> serde_json::Value {
-        match self {
-            FieldType::Class(name) | FieldType::Enum(name) => json!({
-                "$ref": format!("#/definitions/{}", name),
-            }),
-            FieldType::Primitive(t) => match t {
-                TypeValue::String => json!({
-                    "type": "string",
-                }),
-                TypeValue::Int => json!({
-                    "type": "integer",
-                }),
-                TypeValue::Float => json!({
-                    "type": "number",
+        // Classes and enums are references into `definitions`.
+        type_json_schema(self, &mut |t| {
+            Ok::<_, Infallible>(match t {
+                FieldType::Class(name) | FieldType::Enum(name) => json!({
+                    "$ref": format!("#/definitions/{}", name),
                 }),
-                TypeValue::Bool => json!({
-                    "type": "boolean",
-                }),
-                TypeValue::Null => json!({
-                    "type": "null",
-                }),
-                TypeValue::Media(_) => json!({
+                _ => json!({
                     // anyOf either an object that has a uri, or it has a base64 string
                     "type": "object",
                     "properties": {
@@ -185,54 +176,156 @@ impl<'db> WithJsonSchema for FieldType {
                     },
                     "required": ["url"],
                 }),
-            },
-            FieldType::List(item) => json!({
-                "type": "array",
-                "items": (*item).json_schema()
-            }),
-            FieldType::Map(_k, v) => json!({
-                "type": "object",
-                "additionalProperties": {
-                    "type": v.json_schema(),
-                }
-            }),
-            FieldType::Union(options) => json!({
-                "anyOf": options.iter().map(|t| {
-                    let mut res = t.json_schema();
-                    // if res is a map, add a "title" field
-                    if let serde_json::Value::Object(r) = &mut res {
-                        r.insert("title".to_string(), json!(t.to_string()));
-                    }
-                    res
+            })
+        })
+        .unwrap_or_else(|e| match e {})
+    }
+}
+
+/// A JSON Schema for the target of `output_format`, for providers that accept
+/// one (e.g. as tool parameters). Field and value names use their aliases,
+/// matching what the parser expects back. Recursive classes are emitted once
+/// under `$defs` and referenced from there.
+pub fn output_json_schema(output_format: &OutputFormatContent) -> Result<serde_json::Value> {
+    let mut defs = IndexSet::new();
+    let mut schema = output_type_json_schema(output_format, output_format.target(), &mut defs)?;
+
+    let mut definitions = serde_json::Map::new();
+    while let Some(cls) = defs.get_index(definitions.len()).cloned() {
+        let class = output_format.find_class(&cls)?;
+        let definition = output_class_json_schema(output_format, class, &mut defs)?;
+        definitions.insert(class.name.rendered_name().to_string(), definition);
+    }
+    if !definitions.is_empty() {
+        if let Some(obj) = schema.as_object_mut() {
+            obj.insert("$defs".into(), definitions.into());
+        }
+    }
+    Ok(schema)
+}
+
+fn output_class_json_schema(
+    output_format: &OutputFormatContent,
+    class: &output_format::Class,
+    defs: &mut IndexSet<String>,
+) -> Result<serde_json::Value> {
+    let mut properties = serde_json::Map::new();
+    let mut required = vec![];
+    for (name, t, description) in &class.fields {
+        let mut field = output_type_json_schema(output_format, t, defs)?;
+        if let (Some(description), Some(obj)) = (description, field.as_object_mut()) {
+            obj.insert("description".into(), description.clone().into());
+        }
+        properties.insert(name.rendered_name().to_string(), field);
+        if !t.is_optional() {
+            required.push(name.rendered_name().to_string());
+        }
+    }
+    Ok(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    }))
+}
+
+fn output_type_json_schema(
+    output_format: &OutputFormatContent,
+    field_type: &FieldType,
+    defs: &mut IndexSet<String>,
+) -> Result<serde_json::Value> {
+    type_json_schema(field_type, &mut |t| {
+        Ok(match t {
+            FieldType::Enum(name) => {
+                let enm = output_format.find_enum(name)?;
+                let mut schema = json!({
+                    "type": "string",
+                    "enum": enm
+                        .values
+                        .iter()
+                        .map(|(n, _)| n.rendered_name())
+                        .collect::<Vec<_>>(),
+                });
+                let descriptions = enm
+                    .values
+                    .iter()
+                    .filter_map(|(n, d)| {
+                        d.as_ref().map(|d| format!("{}: {}", n.rendered_name(), d))
+                    })
+                    .collect::<Vec<_>>();
+                if !descriptions.is_empty() {
+                    schema["description"] = descriptions.join("\n").into();
                 }
-            ).collect::<Vec<_>>(),
-            }),
-            FieldType::Tuple(options) => json!({
-                "type": "array",
-                "prefixItems": options.iter().map(|t| t.json_schema()).collect::<Vec<_>>(),
-            }),
-            // The caller object is responsible for adding the "null" type
-            FieldType::Optional(inner) => {
-                match **inner {
-                    FieldType::Primitive(_) => {
-                        let mut res = inner.json_schema();
-                        res["type"] = json!([res["type"], "null"]);
-                        res["default"] = serde_json::Value::Null;
-                        res
-                    }
-                    _ => {
-                        let mut res = inner.json_schema();
-                        // if res is a map, add a "title" field
-                        if let serde_json::Value::Object(r) = &mut res {
-                            r.insert("title".to_string(), json!(inner.to_string()));
-                        }
-                        json!({
-                            "anyOf": [res, json!({"type": "null", "title": "null"})],
-                            "default": serde_json::Value::Null,
-                        })
-                    }
+                schema
+            }
+            FieldType::Class(name) => {
+                let class = output_format.find_class(name)?;
+                if output_format.is_recursive_class(name) {
+                    defs.insert(name.clone());
+                    json!({
+                        "$ref": format!("#/$defs/{}", class.name.rendered_name())
+                    })
+                } else {
+                    output_class_json_schema(output_format, class, defs)?
                 }
             }
+            _ => anyhow::bail!("type '{t}' is not supported in outputs"),
+        })
+    })
+}
+
+/// The schema of `field_type`, leaving classes, enums and media to
+/// `named_type`, as how those are spelled depends on where the schema is used.
+fn type_json_schema<E>(
+    field_type: &FieldType,
+    named_type: &mut impl FnMut(&FieldType) -> Result<serde_json::Value, E>,
+) -> Result<serde_json::Value, E> {
+    Ok(match field_type {
+        FieldType::Primitive(t) => match t {
+            TypeValue::String => json!({ "type": "string" }),
+            TypeValue::Int => json!({ "type": "integer" }),
+            TypeValue::Float => json!({ "type": "number" }),
+            TypeValue::Bool => json!({ "type": "boolean" }),
+            TypeValue::Null => json!({ "type": "null" }),
+            TypeValue::Media(_) => named_type(field_type)?,
+        },
+        FieldType::Class(_) | FieldType::Enum(_) => named_type(field_type)?,
+        FieldType::Literal(v) => match v {
+            LiteralValue::String(s) => json!({ "type": "string", "enum": [s] }),
+            LiteralValue::Int(i) => json!({ "type": "integer", "enum": [i] }),
+            LiteralValue::Bool(b) => json!({ "type": "boolean", "enum": [b] }),
+        },
+        FieldType::List(item) => json!({
+            "type": "array",
+            "items": type_json_schema(item, named_type)?,
+        }),
+        FieldType::Map(_, value) => json!({
+            "type": "object",
+            "additionalProperties": type_json_schema(value, named_type)?,
+        }),
+        FieldType::Union(options) => json!({
+            "anyOf": options
+                .iter()
+                .map(|t| type_json_schema(t, named_type))
+                .collect::<Result<Vec<_>, E>>()?,
+        }),
+        FieldType::Tuple(options) => json!({
+            "type": "array",
+            "prefixItems": options
+                .iter()
+                .map(|t| type_json_schema(t, named_type))
+                .collect::<Result<Vec<_>, E>>()?,
+        }),
+        FieldType::Optional(inner) => {
+            let inner = type_json_schema(inner, named_type)?;
+            if inner.get("anyOf").is_some_and(|items| {
+                items
+                    .as_array()
+                    .is_some_and(|items| items.iter().any(|t| t["type"] == "null"))
+            }) {
+                inner
+            } else {
+                json!({ "anyOf": [inner, { "type": "null" }] })
+            }
         }
-    }
+    })
 }
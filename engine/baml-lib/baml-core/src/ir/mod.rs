@@ -7,6 +7,7 @@ pub use ir_helpers::{
     scope_diagnostics, ArgCoercer, ClassFieldWalker, ClassWalker, ClientWalker, EnumValueWalker,
     EnumWalker, FunctionWalker, IRHelper, RetryPolicyWalker, TemplateStringWalker, TestCaseWalker,
 };
+pub use json_schema::output_json_schema;
 
 pub(super) use repr::IntermediateRepr;
 
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use anyhow::{anyhow, Context, Result};
-use baml_types::FieldType;
+use baml_types::{Constraint, FieldType, StreamingBehavior};
 use either::Either;
 use indexmap::IndexMap;
 use internal_baml_parser_database::{
@@ -9,7 +9,7 @@ use internal_baml_parser_database::{
         ClassWalker, ClientSpec as AstClientSpec, ClientWalker, ConfigurationWalker,
         EnumValueWalker, EnumWalker, FieldWalker, FunctionWalker, TemplateStringWalker,
     },
-    Attributes, ParserDatabase, PromptAst, RetryPolicyStrategy,
+    Attributes, OutputMode as AstOutputMode, ParserDatabase, PromptAst, RetryPolicyStrategy,
 };
 use internal_baml_schema_ast::ast::SubType;
 
@@ -194,9 +194,13 @@ pub struct NodeAttributes {
     ///
     ///   - @skip becomes ("skip", bool)
     ///   - @alias(...) becomes ("alias", ...)
+    ///   - @stream.done becomes ("stream.done", bool), and likewise @stream.not_null
     #[serde(with = "indexmap::map::serde_seq")]
     meta: IndexMap<String, Expression>,
 
+    /// @check and @assert constraints on the node.
+    pub constraints: Vec<Constraint>,
+
     // Spans
     #[serde(skip)]
     pub span: Option<ast::Span>,
@@ -206,6 +210,14 @@ impl NodeAttributes {
     pub fn get(&self, key: &str) -> Option<&Expression> {
         self.meta.get(key)
     }
+
+    /// @stream.done and @stream.not_null on a class field.
+    pub fn streaming(&self) -> StreamingBehavior {
+        StreamingBehavior {
+            done: self.meta.contains_key("stream.done"),
+            not_null: self.meta.contains_key("stream.not_null"),
+        }
+    }
 }
 
 fn to_ir_attributes(
@@ -219,6 +231,8 @@ fn to_ir_attributes(
         alias,
         dynamic_type,
         skip,
+        constraints: _,
+        streaming,
     }) = maybe_ast_attributes
     {
         if let Some(true) = dynamic_type {
@@ -238,6 +252,12 @@ fn to_ir_attributes(
         if let Some(true) = skip {
             attributes.insert("skip".to_string(), Expression::Bool(true));
         }
+        if streaming.done {
+            attributes.insert("stream.done".to_string(), Expression::Bool(true));
+        }
+        if streaming.not_null {
+            attributes.insert("stream.not_null".to_string(), Expression::Bool(true));
+        }
     }
 
     attributes
@@ -256,6 +276,7 @@ pub trait WithRepr<T> {
     fn attributes(&self, _: &ParserDatabase) -> NodeAttributes {
         NodeAttributes {
             meta: IndexMap::new(),
+            constraints: Vec::new(),
             span: None,
         }
     }
@@ -288,6 +309,9 @@ impl WithRepr<FieldType> for ast::FieldType {
                     repr
                 }
             }
+            ast::FieldType::Literal(arity, literal_value, ..) => {
+                type_with_arity(FieldType::Literal(literal_value.clone()), arity)
+            }
             ast::FieldType::Symbol(arity, idn, ..) => type_with_arity(
                 match db.find_type(idn) {
                     Some(Either::Left(class_walker)) => {
@@ -434,6 +458,9 @@ impl WithRepr<Expression> for ast::Expression {
                     .map(|(k, v)| Ok((k.repr(db)?, v.repr(db)?)))
                     .collect::<Result<Vec<_>>>()?,
             ),
+            ast::Expression::JinjaExpressionValue(expr, _) => {
+                anyhow::bail!("Jinja expressions are only allowed in @assert and @check: {expr}")
+            }
         })
     }
 }
@@ -451,6 +478,7 @@ impl WithRepr<TemplateString> for TemplateStringWalker<'_> {
     fn attributes(&self, _: &ParserDatabase) -> NodeAttributes {
         NodeAttributes {
             meta: Default::default(),
+            constraints: Vec::new(),
 
             span: Some(self.span().clone()),
         }
@@ -493,6 +521,7 @@ impl WithRepr<EnumValue> for EnumValueWalker<'_> {
     fn attributes(&self, db: &ParserDatabase) -> NodeAttributes {
         let attributes = NodeAttributes {
             meta: to_ir_attributes(db, self.get_default_attributes()),
+            constraints: Vec::new(),
             span: Some(self.span().clone()),
         };
 
@@ -508,6 +537,7 @@ impl WithRepr<Enum> for EnumWalker<'_> {
     fn attributes(&self, db: &ParserDatabase) -> NodeAttributes {
         let attributes = NodeAttributes {
             meta: to_ir_attributes(db, self.get_default_attributes(SubType::Enum)),
+            constraints: Vec::new(),
             span: Some(self.span().clone()),
         };
 
@@ -535,6 +565,10 @@ impl WithRepr<Field> for FieldWalker<'_> {
     fn attributes(&self, db: &ParserDatabase) -> NodeAttributes {
         let attributes = NodeAttributes {
             meta: to_ir_attributes(db, self.get_default_attributes()),
+            constraints: self
+                .get_default_attributes()
+                .map(|attributes| attributes.constraints.clone())
+                .unwrap_or_default(),
             span: Some(self.span().clone()),
         };
 
@@ -574,6 +608,7 @@ impl WithRepr<Class> for ClassWalker<'_> {
         let default_attributes = self.get_default_attributes(SubType::Class);
         let attributes = NodeAttributes {
             meta: to_ir_attributes(db, default_attributes),
+            constraints: Vec::new(),
             span: Some(self.span().clone()),
         };
 
@@ -689,6 +724,8 @@ pub struct Function {
     pub name: FunctionId,
     pub inputs: Vec<(String, FieldType)>,
     pub output: FieldType,
+    /// @check and @assert constraints on the return value.
+    pub output_constraints: Vec<Constraint>,
     pub tests: Vec<Node<TestCase>>,
     pub configs: Vec<FunctionConfig>,
     pub default_config: String,
@@ -701,6 +738,26 @@ pub struct FunctionConfig {
     #[serde(skip)]
     pub prompt_span: ast::Span,
     pub client: ClientSpec,
+    pub output_mode: OutputMode,
+}
+
+/// How a function asks the model for its return type.
+#[derive(serde::Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// The schema is rendered into the prompt via `ctx.output_format`.
+    #[default]
+    Prompt,
+    /// The schema is sent as a tool the model is forced to call.
+    Tool,
+}
+
+impl From<AstOutputMode> for OutputMode {
+    fn from(mode: AstOutputMode) -> Self {
+        match mode {
+            AstOutputMode::Prompt => OutputMode::Prompt,
+            AstOutputMode::Tool => OutputMode::Tool,
+        }
+    }
 }
 
 // NB(sam): we used to use this to bridge the wasm layer, but
@@ -791,6 +848,7 @@ impl WithRepr<Function> for FunctionWalker<'_> {
     fn attributes(&self, _: &ParserDatabase) -> NodeAttributes {
         NodeAttributes {
             meta: Default::default(),
+            constraints: Vec::new(),
             span: Some(self.span().clone()),
         }
     }
@@ -815,6 +873,7 @@ impl WithRepr<Function> for FunctionWalker<'_> {
                 .expect("need block arg")
                 .field_type
                 .repr(db)?,
+            output_constraints: self.metadata().output_constraints.clone(),
             configs: vec![FunctionConfig {
                 name: "default_config".to_string(),
                 prompt_template: self.jinja_prompt().to_string(),
@@ -823,6 +882,12 @@ impl WithRepr<Function> for FunctionWalker<'_> {
                     Ok(spec) => ClientSpec::from(spec),
                     Err(e) => anyhow::bail!("{}", e.message()),
                 },
+                output_mode: self
+                    .metadata()
+                    .output_mode
+                    .as_ref()
+                    .map(|(mode, _)| OutputMode::from(*mode))
+                    .unwrap_or_default(),
             }],
             default_config: "default_config".to_string(),
             tests: self
@@ -847,6 +912,7 @@ impl WithRepr<Client> for ClientWalker<'_> {
     fn attributes(&self, _: &ParserDatabase) -> NodeAttributes {
         NodeAttributes {
             meta: IndexMap::new(),
+            constraints: Vec::new(),
             span: Some(self.span().clone()),
         }
     }
@@ -878,6 +944,7 @@ pub struct RetryPolicy {
     pub name: RetryPolicyId,
     pub max_retries: u32,
     pub strategy: RetryPolicyStrategy,
+    pub retry_on: Option<Vec<u16>>,
     // NB: the parser DB has a notion of "empty options" vs "no options"; we collapse
     // those here into an empty vec
     options: Vec<(String, Expression)>,
@@ -887,6 +954,7 @@ impl WithRepr<RetryPolicy> for ConfigurationWalker<'_> {
     fn attributes(&self, _db: &ParserDatabase) -> NodeAttributes {
         NodeAttributes {
             meta: IndexMap::new(),
+            constraints: Vec::new(),
             span: Some(self.span().clone()),
         }
     }
@@ -896,6 +964,7 @@ impl WithRepr<RetryPolicy> for ConfigurationWalker<'_> {
             name: RetryPolicyId(self.name().to_string()),
             max_retries: self.retry_policy().max_retries,
             strategy: self.retry_policy().strategy,
+            retry_on: self.retry_policy().retry_on.clone(),
             options: match &self.retry_policy().options {
                 Some(o) => o
                     .iter()
@@ -928,6 +997,7 @@ impl WithRepr<TestCaseFunction> for (&ConfigurationWalker<'_>, usize) {
         let span = self.0.test_case().functions[self.1].1.clone();
         NodeAttributes {
             meta: IndexMap::new(),
+            constraints: Vec::new(),
 
             span: Some(span),
         }
@@ -944,6 +1014,7 @@ impl WithRepr<TestCase> for ConfigurationWalker<'_> {
     fn attributes(&self, _db: &ParserDatabase) -> NodeAttributes {
         NodeAttributes {
             meta: IndexMap::new(),
+            constraints: self.test_case().constraints.clone(),
             span: Some(self.span().clone()),
         }
     }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use baml_types::BamlValue;
+use baml_types::{BamlValue, Constraint, StreamingBehavior};
 use indexmap::IndexMap;
 
 use internal_baml_parser_database::RetryPolicyStrategy;
@@ -64,6 +64,10 @@ impl<'a> Walker<'a, &'a FunctionNode> {
         self.elem().output()
     }
 
+    pub fn output_constraints(&self) -> &'a [Constraint] {
+        &self.elem().output_constraints
+    }
+
     pub fn inputs(&self) -> &'a Vec<(String, baml_types::FieldType)> {
         self.elem().inputs()
     }
@@ -253,6 +257,11 @@ impl<'a> Walker<'a, (&'a FunctionNode, &'a TestCase)> {
         self.item.1.attributes.span.as_ref()
     }
 
+    /// The `@@assert` and `@@check` constraints on the result of the test.
+    pub fn constraints(&self) -> &'a [Constraint] {
+        &self.item.1.attributes.constraints
+    }
+
     pub fn test_case_params(
         &self,
         env_values: &HashMap<String, String>,
@@ -355,6 +364,11 @@ impl<'a> Walker<'a, &'a RetryPolicy> {
         &self.elem().strategy
     }
 
+    /// The error codes to retry, or `None` to retry every error.
+    pub fn retry_on(&self) -> Option<&[u16]> {
+        self.elem().retry_on.as_deref()
+    }
+
     pub fn span(&self) -> Option<&crate::Span> {
         self.item.attributes.span.as_ref()
     }
@@ -411,6 +425,14 @@ impl<'a> Walker<'a, &'a Field> {
             .transpose()
     }
 
+    pub fn constraints(&self) -> &'a [Constraint] {
+        &self.item.attributes.constraints
+    }
+
+    pub fn streaming(&self) -> StreamingBehavior {
+        self.item.attributes.streaming()
+    }
+
     pub fn span(&self) -> Option<&crate::Span> {
         self.item.attributes.span.as_ref()
     }
@@ -24,6 +24,7 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             "google-ai",
             "vertex-ai",
             "aws-bedrock",
+            "mock",
         ];
 
         let suggestions: Vec<String> = allowed_providers
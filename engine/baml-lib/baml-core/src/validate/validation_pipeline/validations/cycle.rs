@@ -1,30 +1,27 @@
 use std::collections::HashSet;
 
 use internal_baml_diagnostics::DatamodelError;
-use internal_baml_schema_ast::ast::{TypeExpId, WithIdentifier, WithName, WithSpan};
+use internal_baml_schema_ast::ast::{FieldType, TypeExpId, WithIdentifier, WithName, WithSpan};
 
 use crate::validate::validation_pipeline::context::Context;
 
 pub(super) fn validate(ctx: &mut Context<'_>) {
     // Validates if there's a cycle in any dependency graph.
+    //
+    // Recursive classes are fine as long as the recursion can terminate, so
+    // only the dependencies that every value of a class must contain are
+    // considered here (see `insert_required_deps`).
     let mut deps_list = ctx
         .db
         .walk_classes()
-        .map(|f| {
-            (
-                f.id,
-                f.dependencies()
-                    .into_iter()
-                    .filter(|f| match ctx.db.find_type_by_str(f) {
-                        Some(either::Either::Left(_cls)) => true,
-                        // Don't worry about enum dependencies, they can't form cycles.
-                        Some(either::Either::Right(_enm)) => false,
-                        None => {
-                            panic!("Unknown class `{}`", f);
-                        }
-                    })
-                    .collect::<HashSet<_>>(),
-            )
+        .map(|cls| {
+            let mut deps = HashSet::new();
+            for field in cls.static_fields() {
+                if let Some(field_type) = field.r#type() {
+                    insert_required_deps(ctx, field_type, &mut deps);
+                }
+            }
+            (cls.id, deps)
         })
         .collect::<Vec<_>>();
 
@@ -97,3 +94,47 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
         }
     }
 }
+
+/// Collects the classes that any value of `field_type` must contain.
+///
+/// Optional fields, lists and maps can always be empty, so they never force a
+/// nested value and are allowed to point back at the class. A union only
+/// requires what all of its variants require.
+fn insert_required_deps(ctx: &Context<'_>, field_type: &FieldType, deps: &mut HashSet<String>) {
+    match field_type {
+        FieldType::Symbol(arity, idn, ..) if arity.is_required() => {
+            match ctx.db.find_type_by_str(idn.name()) {
+                Some(either::Either::Left(_cls)) => {
+                    deps.insert(idn.name().to_string());
+                }
+                // Don't worry about enum dependencies, they can't form cycles.
+                Some(either::Either::Right(_enm)) => {}
+                None => {
+                    panic!("Unknown class `{}`", idn.name());
+                }
+            }
+        }
+        FieldType::Tuple(arity, items, ..) if arity.is_required() => {
+            for item in items {
+                insert_required_deps(ctx, item, deps);
+            }
+        }
+        FieldType::Union(arity, variants, ..) if arity.is_required() => {
+            let mut required = variants.iter().map(|variant| {
+                let mut variant_deps = HashSet::new();
+                insert_required_deps(ctx, variant, &mut variant_deps);
+                variant_deps
+            });
+            if let Some(first) = required.next() {
+                deps.extend(required.fold(first, |acc, next| &acc & &next));
+            }
+        }
+        FieldType::Symbol(..)
+        | FieldType::Tuple(..)
+        | FieldType::Primitive(..)
+        | FieldType::Literal(..)
+        | FieldType::List(..)
+        | FieldType::Map(..)
+        | FieldType::Union(..) => {}
+    }
+}
@@ -2,7 +2,8 @@ use crate::validate::validation_pipeline::context::Context;
 
 use internal_baml_diagnostics::{DatamodelError, DatamodelWarning, Span};
 
-use internal_baml_schema_ast::ast::{WithIdentifier, WithName, WithSpan};
+use internal_baml_parser_database::OutputMode;
+use internal_baml_schema_ast::ast::{self, WithIdentifier, WithName, WithSpan};
 
 use super::types::validate_type;
 
@@ -100,6 +101,25 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             }
         }
 
+        // Tool arguments must be a JSON object, so only classes can be
+        // returned through a tool call.
+        if let Some((OutputMode::Tool, span)) = func.metadata().output_mode.as_ref() {
+            let returns_class =
+                func.walk_output_args()
+                    .all(|arg| match &arg.ast_arg().1.field_type {
+                        ast::FieldType::Symbol(arity, idn, _) => {
+                            !arity.is_optional() && ctx.db.find_class(idn).is_some()
+                        }
+                        _ => false,
+                    });
+            if !returns_class {
+                ctx.push_error(DatamodelError::new_validation_error(
+                    "`output_mode tool` requires the function to return a class",
+                    span.clone(),
+                ));
+            }
+        }
+
         let prompt = match func.metadata().prompt.as_ref() {
             Some(prompt) => prompt,
             None => {
@@ -0,0 +1,70 @@
+use std::fmt;
+
+/// The source of a Jinja expression, without the surrounding `{{ }}`.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct JinjaExpression(pub String);
+
+impl fmt::Display for JinjaExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{{ {} }}}}", self.0.trim())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, strum::Display)]
+pub enum ConstraintLevel {
+    /// A failed check is reported next to the value, but does not fail parsing.
+    #[strum(serialize = "check")]
+    Check,
+    /// A failed assert fails parsing.
+    #[strum(serialize = "assert")]
+    Assert,
+}
+
+/// A user-defined predicate over a value, declared with `@check` or `@assert`.
+///
+/// Inside the expression, `this` refers to the value being constrained.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct Constraint {
+    pub level: ConstraintLevel,
+    pub expression: JinjaExpression,
+    pub label: Option<String>,
+}
+
+impl Constraint {
+    /// The label if one was given, otherwise the expression itself.
+    pub fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self.expression.0.trim().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "@{}({}, {})", self.level, label, self.expression),
+            None => write!(f, "@{}({})", self.level, self.expression),
+        }
+    }
+}
+
+/// The outcome of evaluating a single `@check` against a parsed value.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub struct ResponseCheck {
+    pub name: String,
+    pub expression: String,
+    pub status: CheckStatus,
+    /// Why the expression couldn't be evaluated, in which case the check
+    /// failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckStatus {
+    Succeeded,
+    Failed,
+}
@@ -1,4 +1,4 @@
-use super::{BamlMediaType, FieldType, TypeValue};
+use super::{BamlMediaType, FieldType, LiteralValue, TypeValue};
 
 impl FieldType {
     pub fn string() -> Self {
@@ -25,6 +25,10 @@ impl FieldType {
         FieldType::Primitive(TypeValue::Media(BamlMediaType::Image))
     }
 
+    pub fn literal(value: LiteralValue) -> Self {
+        FieldType::Literal(value)
+    }
+
     pub fn r#enum(name: &str) -> Self {
         FieldType::Enum(name.to_string())
     }
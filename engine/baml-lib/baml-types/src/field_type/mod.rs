@@ -40,10 +40,40 @@ impl std::fmt::Display for TypeValue {
     }
 }
 
+/// A single value that a field is pinned to, e.g. `"success"`, `1` or `true`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub enum LiteralValue {
+    String(String),
+    Int(i64),
+    Bool(bool),
+}
+
+impl LiteralValue {
+    /// The primitive type that every value of this literal belongs to.
+    pub fn literal_base_type(&self) -> TypeValue {
+        match self {
+            LiteralValue::String(_) => TypeValue::String,
+            LiteralValue::Int(_) => TypeValue::Int,
+            LiteralValue::Bool(_) => TypeValue::Bool,
+        }
+    }
+}
+
+impl std::fmt::Display for LiteralValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LiteralValue::String(s) => write!(f, "\"{}\"", s),
+            LiteralValue::Int(i) => write!(f, "{}", i),
+            LiteralValue::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
 /// FieldType represents the type of either a class field or a function arg.
 #[derive(serde::Serialize, Debug, Clone)]
 pub enum FieldType {
     Primitive(TypeValue),
+    Literal(LiteralValue),
     Enum(String),
     Class(String),
     List(Box<FieldType>),
@@ -61,6 +91,7 @@ impl std::fmt::Display for FieldType {
                 write!(f, "{}", name)
             }
             FieldType::Primitive(t) => write!(f, "{}", t),
+            FieldType::Literal(v) => write!(f, "{}", v),
             FieldType::Union(choices) => {
                 write!(
                     f,
@@ -93,7 +124,7 @@ impl std::fmt::Display for FieldType {
 impl FieldType {
     pub fn is_primitive(&self) -> bool {
         match self {
-            FieldType::Primitive(_) => true,
+            FieldType::Primitive(_) | FieldType::Literal(_) => true,
             FieldType::Optional(t) => t.is_primitive(),
             FieldType::List(t) => t.is_primitive(),
             _ => false,
@@ -4,11 +4,15 @@ mod media;
 mod minijinja;
 
 mod baml_value;
+mod constraint;
 mod field_type;
 mod generator;
+mod streaming;
 
 pub use baml_value::BamlValue;
-pub use field_type::{FieldType, TypeValue};
+pub use constraint::{CheckStatus, Constraint, ConstraintLevel, JinjaExpression, ResponseCheck};
+pub use field_type::{FieldType, LiteralValue, TypeValue};
 pub use generator::{GeneratorDefaultClientMode, GeneratorOutputType};
 pub use map::Map as BamlMap;
 pub use media::{BamlMedia, BamlMediaContent, BamlMediaType, MediaBase64, MediaUrl};
+pub use streaming::StreamingBehavior;
@@ -0,0 +1,18 @@
+/// How a class field behaves in partial results while a function streams,
+/// declared with `@stream.done` and `@stream.not_null`.
+///
+/// Neither has any effect on the final result.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct StreamingBehavior {
+    /// The field is left out until its value is complete, so it is never
+    /// seen half-written.
+    pub done: bool,
+    /// The object holding the field is left out until the field has a value.
+    pub not_null: bool,
+}
+
+impl StreamingBehavior {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
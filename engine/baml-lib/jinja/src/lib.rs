@@ -4,7 +4,9 @@ mod chat_message_part;
 mod evaluate_type;
 mod get_vars;
 mod output_format;
+mod predicate;
 pub use output_format::types;
+pub use predicate::{evaluate_predicate, validate_expression};
 
 use evaluate_type::get_variable_types;
 pub use evaluate_type::{PredefinedTypes, Type, TypeError};
@@ -487,6 +489,20 @@ pub fn render_prompt(
     }
 }
 
+/// Renders a plain string template, with the entries of `args` as variables.
+///
+/// Unlike `render_prompt`, the output is not split into chat messages.
+pub fn render_string(template: &str, args: &BamlValue) -> anyhow::Result<String> {
+    if !matches!(args, BamlValue::Map(_)) {
+        anyhow::bail!("args must be a map");
+    }
+
+    let env = get_env();
+    let args: Value = args.clone().into();
+    env.render_str(template, args)
+        .map_err(|err| anyhow::anyhow!("Error occurred while rendering template: {err:#}"))
+}
+
 #[cfg(test)]
 mod render_tests {
 
@@ -1107,4 +1123,20 @@ mod render_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn render_string_template() -> anyhow::Result<()> {
+        let args = BamlValue::Map(BamlMap::from([
+            ("prompt".to_string(), BamlValue::String("Hi".to_string())),
+            ("call_count".to_string(), BamlValue::Int(2)),
+        ]));
+
+        let rendered = render_string("{{ prompt | upper }} #{{ call_count }}", &args)?;
+        assert_eq!(rendered, "HI #2");
+
+        assert!(render_string("{{ prompt }", &args).is_err());
+        assert!(render_string("{{ prompt }}", &BamlValue::Int(1)).is_err());
+
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use baml_types::{FieldType, TypeValue};
+use baml_types::{Constraint, FieldType, StreamingBehavior, TypeValue};
 use indexmap::{IndexMap, IndexSet};
 
 #[derive(Debug)]
@@ -46,13 +46,22 @@ pub struct Class {
     pub name: Name,
     // type and description
     pub fields: Vec<(Name, FieldType, Option<String>)>,
+    // @check and @assert constraints, keyed by the real field name
+    pub field_constraints: IndexMap<String, Vec<Constraint>>,
+    // @stream.done and @stream.not_null, keyed by the real field name
+    pub field_streaming: IndexMap<String, StreamingBehavior>,
 }
 
 #[derive(Debug, Clone)]
 pub struct OutputFormatContent {
     enums: Arc<IndexMap<String, Enum>>,
     classes: Arc<IndexMap<String, Class>>,
+    // Classes that (directly or indirectly) contain themselves. These are
+    // rendered once by name instead of being inlined.
+    recursive_classes: Arc<IndexSet<String>>,
     target: FieldType,
+    // @check and @assert constraints on the target itself
+    target_constraints: Arc<Vec<Constraint>>,
 }
 
 enum RenderSetting<T> {
@@ -205,10 +214,17 @@ impl<'s> std::fmt::Display for MapRender<'s> {
 
 struct RenderState {
     hoisted_enums: IndexSet<String>,
+    hoisted_classes: IndexSet<String>,
 }
 
 impl OutputFormatContent {
     pub fn new(enums: Vec<Enum>, classes: Vec<Class>, target: FieldType) -> Self {
+        let classes = classes
+            .into_iter()
+            .map(|c| (c.name.name.clone(), c))
+            .collect::<IndexMap<_, _>>();
+        let recursive_classes = find_recursive_classes(&classes);
+
         Self {
             enums: Arc::new(
                 enums
@@ -216,16 +232,30 @@ impl OutputFormatContent {
                     .map(|e| (e.name.name.clone(), e))
                     .collect(),
             ),
-            classes: Arc::new(
-                classes
-                    .into_iter()
-                    .map(|c| (c.name.name.clone(), c))
-                    .collect(),
-            ),
+            classes: Arc::new(classes),
+            recursive_classes: Arc::new(recursive_classes),
             target,
+            target_constraints: Arc::new(Vec::new()),
         }
     }
 
+    pub fn with_target_constraints(mut self, constraints: Vec<Constraint>) -> Self {
+        self.target_constraints = Arc::new(constraints);
+        self
+    }
+
+    pub fn target_constraints(&self) -> &[Constraint] {
+        &self.target_constraints
+    }
+
+    pub fn target(&self) -> &FieldType {
+        &self.target
+    }
+
+    pub fn is_recursive_class(&self, name: &str) -> bool {
+        self.recursive_classes.contains(name)
+    }
+
     fn prefix<'a>(&self, options: &'a RenderOptions) -> Option<&'a str> {
         match &options.prefix {
             RenderSetting::Always(prefix) => Some(prefix.as_str()),
@@ -233,6 +263,7 @@ impl OutputFormatContent {
             RenderSetting::Auto => match &self.target {
                 FieldType::Primitive(TypeValue::String) => None,
                 FieldType::Primitive(_) => Some("Answer as a: "),
+                FieldType::Literal(_) => Some("Answer using this specific value:\n"),
                 FieldType::Enum(_) => Some("Answer with any of the categories:\n"),
                 FieldType::Class(_) => Some("Answer in JSON using this schema:\n"),
                 FieldType::List(_) => Some("Answer with a JSON Array using this schema:\n"),
@@ -260,6 +291,29 @@ impl OutputFormatContent {
         .to_string(options)
     }
 
+    fn class_to_string(
+        &self,
+        class: &Class,
+        options: &RenderOptions,
+        render_state: &mut RenderState,
+    ) -> Result<String, minijinja::Error> {
+        Ok(ClassRender {
+            name: class.name.rendered_name().to_string(),
+            values: class
+                .fields
+                .iter()
+                .map(|(n, t, d)| {
+                    Ok(ClassFieldRender {
+                        name: n.rendered_name().to_string(),
+                        r#type: self.inner_type_render(options, t, render_state, false)?,
+                        description: d.clone(),
+                    })
+                })
+                .collect::<Result<_, minijinja::Error>>()?,
+        }
+        .to_string())
+    }
+
     fn inner_type_render(
         &self,
         options: &RenderOptions,
@@ -281,6 +335,7 @@ impl OutputFormatContent {
                     ))
                 }
             },
+            FieldType::Literal(v) => v.to_string(),
             FieldType::Enum(e) => {
                 let Some(enm) = self.enums.get(e) else {
                     return Err(minijinja::Error::new(
@@ -315,29 +370,21 @@ impl OutputFormatContent {
                     ));
                 };
 
-                ClassRender {
-                    name: class.name.rendered_name().to_string(),
-                    values: class
-                        .fields
-                        .iter()
-                        .map(|(n, t, d)| {
-                            Ok(ClassFieldRender {
-                                name: n.rendered_name().to_string(),
-                                r#type: self.inner_type_render(options, t, render_state, false)?,
-                                description: d.clone(),
-                            })
-                        })
-                        .collect::<Result<_, minijinja::Error>>()?,
+                if self.recursive_classes.contains(cls) {
+                    render_state.hoisted_classes.insert(class.name.name.clone());
+                    class.name.rendered_name().to_string()
+                } else {
+                    self.class_to_string(class, options, render_state)?
                 }
-                .to_string()
             }
             FieldType::List(inner) => {
                 let inner_str = self.inner_type_render(options, inner, render_state, false)?;
 
                 if match inner.as_ref() {
-                    FieldType::Primitive(_) => false,
+                    FieldType::Primitive(_) | FieldType::Literal(_) => false,
                     FieldType::Optional(t) => !t.is_primitive(),
                     FieldType::Enum(e) => inner_str.len() > 15,
+                    FieldType::Class(c) => !self.recursive_classes.contains(c),
                     _ => true,
                 } {
                     format!("[\n  {}\n]", inner_str.replace('\n', "\n  "))
@@ -385,6 +432,7 @@ impl OutputFormatContent {
 
         let mut render_state = RenderState {
             hoisted_enums: IndexSet::new(),
+            hoisted_classes: IndexSet::new(),
         };
 
         let message = match &self.target {
@@ -402,21 +450,35 @@ impl OutputFormatContent {
             _ => Some(self.inner_type_render(&options, &self.target, &mut render_state, false)?),
         };
 
-        let enum_definitions = render_state
+        // Rendering a recursive class may hoist further classes (and enums),
+        // so keep going until every hoisted class has a definition.
+        let mut class_definitions = Vec::new();
+        while let Some(cls) = render_state
+            .hoisted_classes
+            .get_index(class_definitions.len())
+            .cloned()
+        {
+            let class = self.classes.get(&cls).expect("Class not found");
+            let definition = self.class_to_string(class, &options, &mut render_state)?;
+            class_definitions.push(format!("{} {}", class.name.rendered_name(), definition));
+        }
+
+        let definitions = render_state
             .hoisted_enums
             .iter()
             .map(|e| {
                 let enm = self.enums.get(e).expect("Enum not found");
                 self.enum_to_string(enm, &options)
             })
+            .chain(class_definitions)
             .collect::<Vec<_>>();
 
         match (prefix, message) {
             (Some(prefix), Some(message)) => {
-                if enum_definitions.len() > 0 {
+                if definitions.len() > 0 {
                     Ok(Some(format!(
                         "{}\n\n{}{}",
-                        enum_definitions.join("\n\n"),
+                        definitions.join("\n\n"),
                         prefix,
                         message,
                     )))
@@ -425,30 +487,22 @@ impl OutputFormatContent {
                 }
             }
             (None, Some(message)) => {
-                if enum_definitions.len() > 0 {
-                    Ok(Some(format!(
-                        "{}\n\n{}",
-                        enum_definitions.join("\n\n"),
-                        message
-                    )))
+                if definitions.len() > 0 {
+                    Ok(Some(format!("{}\n\n{}", definitions.join("\n\n"), message)))
                 } else {
                     Ok(Some(message))
                 }
             }
             (Some(prefix), None) => {
-                if enum_definitions.len() > 0 {
-                    Ok(Some(format!(
-                        "{}\n\n{}",
-                        prefix,
-                        enum_definitions.join("\n\n")
-                    )))
+                if definitions.len() > 0 {
+                    Ok(Some(format!("{}\n\n{}", prefix, definitions.join("\n\n"))))
                 } else {
                     Ok(Some(prefix.to_string()))
                 }
             }
             (None, None) => {
-                if enum_definitions.len() > 0 {
-                    Ok(Some(enum_definitions.join("\n\n")))
+                if definitions.len() > 0 {
+                    Ok(Some(definitions.join("\n\n")))
                 } else {
                     Ok(None)
                 }
@@ -457,6 +511,52 @@ impl OutputFormatContent {
     }
 }
 
+/// Finds every class that can reach itself through its fields.
+fn find_recursive_classes(classes: &IndexMap<String, Class>) -> IndexSet<String> {
+    fn class_refs<'a>(t: &'a FieldType, refs: &mut Vec<&'a str>) {
+        match t {
+            FieldType::Class(name) => refs.push(name),
+            FieldType::List(inner) | FieldType::Optional(inner) => class_refs(inner, refs),
+            FieldType::Map(k, v) => {
+                class_refs(k, refs);
+                class_refs(v, refs);
+            }
+            FieldType::Union(items) | FieldType::Tuple(items) => {
+                items.iter().for_each(|t| class_refs(t, refs))
+            }
+            FieldType::Primitive(_) | FieldType::Literal(_) | FieldType::Enum(_) => {}
+        }
+    }
+
+    classes
+        .keys()
+        .filter(|start| {
+            let mut seen = IndexSet::new();
+            let mut stack = vec![start.as_str()];
+            while let Some(name) = stack.pop() {
+                let Some(class) = classes.get(name) else {
+                    continue;
+                };
+                let mut refs = vec![];
+                class
+                    .fields
+                    .iter()
+                    .for_each(|(_, t, _)| class_refs(t, &mut refs));
+                for r in refs {
+                    if r == start.as_str() {
+                        return true;
+                    }
+                    if seen.insert(r) {
+                        stack.push(r);
+                    }
+                }
+            }
+            false
+        })
+        .cloned()
+        .collect()
+}
+
 #[cfg(test)]
 impl OutputFormatContent {
     pub fn new_array() -> Self {
@@ -489,6 +589,7 @@ impl OutputFormatContent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use baml_types::LiteralValue;
 
     #[test]
     fn test_render_string() {
@@ -547,6 +648,8 @@ mod tests {
                     Some("The person's age".to_string()),
                 ),
             ],
+            field_constraints: IndexMap::new(),
+            field_streaming: IndexMap::new(),
         });
 
         let content =
@@ -583,6 +686,8 @@ mod tests {
                     None,
                 ),
             ],
+            field_constraints: IndexMap::new(),
+            field_streaming: IndexMap::new(),
         });
 
         let content =
@@ -596,4 +701,72 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_render_recursive_class() {
+        let mut classes = vec![];
+        classes.push(Class {
+            name: Name::new("Node".to_string()),
+            fields: vec![
+                (
+                    Name::new("value".to_string()),
+                    FieldType::Primitive(TypeValue::Int),
+                    None,
+                ),
+                (
+                    Name::new("children".to_string()),
+                    FieldType::List(Box::new(FieldType::Class("Node".to_string()))),
+                    None,
+                ),
+            ],
+            field_constraints: IndexMap::new(),
+            field_streaming: IndexMap::new(),
+        });
+
+        let content =
+            OutputFormatContent::new(vec![], classes, FieldType::Class("Node".to_string()));
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(
+                "Node {\n  value: int,\n  children: Node[],\n}\n\nAnswer in JSON using this schema:\nNode"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_literal_union() {
+        let classes = vec![Class {
+            name: Name::new("Result".to_string()),
+            fields: vec![
+                (
+                    Name::new("status".to_string()),
+                    FieldType::Union(vec![
+                        FieldType::Literal(LiteralValue::String("success".to_string())),
+                        FieldType::Literal(LiteralValue::String("error".to_string())),
+                    ]),
+                    None,
+                ),
+                (
+                    Name::new("code".to_string()),
+                    FieldType::Literal(LiteralValue::Int(200)),
+                    None,
+                ),
+            ],
+            field_constraints: IndexMap::new(),
+            field_streaming: IndexMap::new(),
+        }];
+
+        let content =
+            OutputFormatContent::new(vec![], classes, FieldType::Class("Result".to_string()));
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(
+                "Answer in JSON using this schema:\n{\n  status: \"success\" or \"error\",\n  code: 200,\n}"
+                    .to_string()
+            )
+        );
+    }
 }
@@ -0,0 +1,90 @@
+use baml_types::{BamlValue, JinjaExpression};
+use minijinja::context;
+
+use crate::get_env;
+
+/// The environment for predicates: the usual one, plus a `regex_match` test.
+///
+/// ```ignore
+/// {{ this is regex_match("^[0-9]+$") }}
+/// ```
+fn predicate_env<'a>() -> minijinja::Environment<'a> {
+    let mut env = get_env();
+    env.add_test("regex_match", regex_match);
+    env
+}
+
+fn regex_match(value: String, pattern: String) -> Result<bool, minijinja::Error> {
+    let re = regex::Regex::new(&pattern).map_err(|e| {
+        minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            format!("Invalid regex {pattern:?}: {e}"),
+        )
+    })?;
+    Ok(re.is_match(&value))
+}
+
+/// Checks that a Jinja expression compiles, without evaluating it.
+pub fn validate_expression(expression: &JinjaExpression) -> Result<(), minijinja::Error> {
+    predicate_env()
+        .compile_expression(&expression.0)
+        .map(|_| ())
+}
+
+/// Evaluates a constraint expression against a value, available as `this`.
+///
+/// The result is the truthiness of the expression, following Jinja semantics.
+pub fn evaluate_predicate(this: &BamlValue, predicate: &JinjaExpression) -> anyhow::Result<bool> {
+    let env = predicate_env();
+    let expression = env.compile_expression(&predicate.0)?;
+    let this: minijinja::Value = this.clone().into();
+    let result = expression.eval(context! { this => this })?;
+    Ok(result.is_true())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use baml_types::BamlMap;
+
+    fn expr(s: &str) -> JinjaExpression {
+        JinjaExpression(s.to_string())
+    }
+
+    #[test]
+    fn test_evaluate_primitive_predicates() {
+        assert!(evaluate_predicate(&BamlValue::Int(5), &expr("this > 0")).unwrap());
+        assert!(!evaluate_predicate(&BamlValue::Int(-1), &expr("this > 0")).unwrap());
+        assert!(
+            evaluate_predicate(&BamlValue::String("hi".into()), &expr("this|length == 2")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_class_predicate() {
+        let person = BamlValue::Class(
+            "Person".to_string(),
+            BamlMap::from([
+                ("name".to_string(), BamlValue::String("Ada".into())),
+                ("age".to_string(), BamlValue::Int(36)),
+            ]),
+        );
+        assert!(evaluate_predicate(&person, &expr("this.age >= 18 and this.name")).unwrap());
+    }
+
+    #[test]
+    fn test_regex_match() {
+        let this = BamlValue::String("order-1234".into());
+        assert!(
+            evaluate_predicate(&this, &expr(r#"this is regex_match("^order-[0-9]+$")"#)).unwrap()
+        );
+        assert!(!evaluate_predicate(&this, &expr(r#"this is regex_match("^[0-9]+$")"#)).unwrap());
+        assert!(evaluate_predicate(&this, &expr(r#"this is regex_match("(")"#)).is_err());
+    }
+
+    #[test]
+    fn test_invalid_expression() {
+        assert!(validate_expression(&expr("this >")).is_err());
+        assert!(evaluate_predicate(&BamlValue::Null, &expr("this >")).is_err());
+    }
+}
@@ -31,7 +31,7 @@ pub(super) fn coerce_array(
     let mut flags = DeserializerConditions::new();
 
     match &value {
-        Some(crate::jsonish::Value::Array(arr)) => {
+        Some(crate::jsonish::Value::Array(arr, _)) => {
             for (i, item) in arr.iter().enumerate() {
                 match inner.coerce(&ctx.enter_scope(&format!("{i}")), inner, Some(item)) {
                     Ok(v) => items.push(v),
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use baml_types::{BamlValue, LiteralValue};
+use internal_baml_core::ir::FieldType;
+use internal_baml_jinja::types::{Enum, Name};
+
+use crate::deserializer::types::BamlValueWithFlags;
+
+use super::{ParsingContext, ParsingError, TypeCoercer};
+
+pub(super) fn coerce_literal(
+    ctx: &ParsingContext,
+    literal_target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    let FieldType::Literal(literal) = literal_target else {
+        unreachable!("coerce_literal called with non-literal type");
+    };
+    log::debug!(
+        "scope: {scope} :: coercing to: {name} (current: {current})",
+        name = literal_target.to_string(),
+        scope = ctx.display_scope(),
+        current = value.map(|v| v.r#type()).unwrap_or("<null>".into())
+    );
+
+    let value = match value {
+        None | Some(crate::jsonish::Value::Null) => {
+            return Err(ctx.error_unexpected_null(literal_target));
+        }
+        Some(v) => v,
+    };
+
+    match literal {
+        LiteralValue::Int(expected) => {
+            let parsed = literal
+                .literal_base_type()
+                .coerce(ctx, literal_target, Some(value))?;
+            match BamlValue::from(&parsed) {
+                BamlValue::Int(i) if i == *expected => Ok(parsed),
+                _ => Err(ctx.error_unexpected_type(literal_target, value)),
+            }
+        }
+        LiteralValue::Bool(expected) => {
+            let parsed = literal
+                .literal_base_type()
+                .coerce(ctx, literal_target, Some(value))?;
+            match BamlValue::from(&parsed) {
+                BamlValue::Bool(b) if b == *expected => Ok(parsed),
+                _ => Err(ctx.error_unexpected_type(literal_target, value)),
+            }
+        }
+        LiteralValue::String(expected) => {
+            // A string literal behaves like an enum with a single value, so
+            // reuse the enum matcher (exact, case-insensitive, punctuation).
+            let as_enum = Enum {
+                name: Name::new(expected.clone()),
+                values: vec![(Name::new(expected.clone()), None)],
+            };
+            match as_enum.coerce(ctx, literal_target, Some(value))? {
+                BamlValueWithFlags::Enum(_, v) => Ok(BamlValueWithFlags::String(v)),
+                _ => Err(ctx.error_unexpected_type(literal_target, value)),
+            }
+        }
+    }
+}
@@ -36,7 +36,7 @@ pub(super) fn coerce_map(
     flags.add_flag(Flag::ObjectToMap(value.clone()));
 
     match &value {
-        crate::jsonish::Value::Object(obj) => {
+        crate::jsonish::Value::Object(obj, _) => {
             let mut items = BamlMap::new();
             for (key, value) in obj.iter() {
                 match value_type.coerce(&ctx.enter_scope(key), value_type, Some(value)) {
@@ -66,7 +66,7 @@ fn coerce_string(
 ) -> Result<BamlValueWithFlags, ParsingError> {
     if let Some(value) = value {
         match value {
-            crate::jsonish::Value::String(s) => {
+            crate::jsonish::Value::String(s, _) => {
                 Ok(BamlValueWithFlags::String(s.to_string().into()))
             }
             crate::jsonish::Value::Null => Err(ctx.error_unexpected_null(target)),
@@ -86,7 +86,7 @@ fn coerce_int(
 ) -> Result<BamlValueWithFlags, ParsingError> {
     if let Some(value) = value {
         match value {
-            crate::jsonish::Value::Number(n) => {
+            crate::jsonish::Value::Number(n, _) => {
                 if let Some(n) = n.as_i64() {
                     Ok(BamlValueWithFlags::Int(n.into()))
                 } else if let Some(n) = n.as_u64() {
@@ -99,7 +99,7 @@ fn coerce_int(
                     Err(ctx.error_unexpected_type(target, value))
                 }
             }
-            crate::jsonish::Value::String(s) => {
+            crate::jsonish::Value::String(s, _) => {
                 let s = s.trim();
                 // Trim trailing commas
                 let s = s.trim_end_matches(',');
@@ -123,7 +123,7 @@ fn coerce_int(
                     Err(ctx.error_unexpected_type(target, value))
                 }
             }
-            crate::jsonish::Value::Array(items) => {
+            crate::jsonish::Value::Array(items, _) => {
                 coerce_array_to_singular(ctx, target, &items.iter().collect::<Vec<_>>(), &|value| {
                     coerce_int(ctx, target, Some(value))
                 })
@@ -174,7 +174,7 @@ fn coerce_float(
 ) -> Result<BamlValueWithFlags, ParsingError> {
     if let Some(value) = value {
         match value {
-            crate::jsonish::Value::Number(n) => {
+            crate::jsonish::Value::Number(n, _) => {
                 if let Some(n) = n.as_f64() {
                     Ok(BamlValueWithFlags::Float(n.into()))
                 } else if let Some(n) = n.as_i64() {
@@ -185,7 +185,7 @@ fn coerce_float(
                     Err(ctx.error_unexpected_type(target, value))
                 }
             }
-            crate::jsonish::Value::String(s) => {
+            crate::jsonish::Value::String(s, _) => {
                 let s = s.trim();
                 // Trim trailing commas
                 let s = s.trim_end_matches(',');
@@ -203,7 +203,7 @@ fn coerce_float(
                     Err(ctx.error_unexpected_type(target, value))
                 }
             }
-            crate::jsonish::Value::Array(items) => {
+            crate::jsonish::Value::Array(items, _) => {
                 coerce_array_to_singular(ctx, target, &items.iter().collect::<Vec<_>>(), &|value| {
                     coerce_float(ctx, target, Some(value))
                 })
@@ -223,12 +223,12 @@ fn coerce_bool(
     if let Some(value) = value {
         match value {
             crate::jsonish::Value::Boolean(b) => Ok(BamlValueWithFlags::Bool((*b).into())),
-            crate::jsonish::Value::String(s) => match s.as_str() {
+            crate::jsonish::Value::String(s, _) => match s.as_str() {
                 "true" => Ok(BamlValueWithFlags::Bool(
                     (true, Flag::StringToBool(s.clone())).into(),
                 )),
                 "false" => Ok(BamlValueWithFlags::Bool(
-                    (true, Flag::StringToBool(s.clone())).into(),
+                    (false, Flag::StringToBool(s.clone())).into(),
                 )),
                 _ => match s.to_ascii_lowercase().trim() {
                     "true" => Ok(BamlValueWithFlags::Bool(
@@ -240,7 +240,7 @@ fn coerce_bool(
                     _ => Err(ctx.error_unexpected_type(target, value)),
                 },
             },
-            crate::jsonish::Value::Array(items) => {
+            crate::jsonish::Value::Array(items, _) => {
                 coerce_array_to_singular(ctx, target, &items.iter().collect::<Vec<_>>(), &|value| {
                     coerce_float(ctx, target, Some(value))
                 })
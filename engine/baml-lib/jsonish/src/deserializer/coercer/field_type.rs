@@ -2,16 +2,19 @@ use anyhow::Result;
 use baml_types::BamlMap;
 use internal_baml_core::{ir::FieldType, ir::TypeValue};
 
-use crate::deserializer::{
-    coercer::{DefaultValue, TypeCoercer},
-    deserialize_flags::{DeserializerConditions, Flag},
-    types::BamlValueWithFlags,
+use crate::{
+    deserializer::{
+        coercer::{DefaultValue, TypeCoercer},
+        deserialize_flags::{DeserializerConditions, Flag},
+        types::BamlValueWithFlags,
+    },
+    jsonish::CompletionState,
 };
 
 use super::{
-    array_helper, coerce_array::coerce_array, coerce_map::coerce_map,
-    coerce_optional::coerce_optional, coerce_union::coerce_union, ir_ref::IrRef, ParsingContext,
-    ParsingError,
+    array_helper, coerce_array::coerce_array, coerce_literal::coerce_literal,
+    coerce_map::coerce_map, coerce_optional::coerce_optional, coerce_union::coerce_union,
+    ir_ref::IrRef, ParsingContext, ParsingError,
 };
 
 impl TypeCoercer for FieldType {
@@ -33,7 +36,10 @@ impl TypeCoercer for FieldType {
                     self.coerce(
                         ctx,
                         target,
-                        Some(&crate::jsonish::Value::String(primitive.clone())),
+                        Some(&crate::jsonish::Value::String(
+                            primitive.clone(),
+                            value.map_or(CompletionState::Complete, |v| v.completion_state()),
+                        )),
                     )
                 } else {
                     array_helper::coerce_array_to_singular(
@@ -74,16 +80,25 @@ impl TypeCoercer for FieldType {
                 v.add_flag(Flag::ObjectFromFixedJson(fixes.to_vec()));
                 Ok(v)
             }
-            _ => match self {
-                FieldType::Primitive(p) => p.coerce(ctx, target, value),
-                FieldType::Enum(e) => IrRef::Enum(e).coerce(ctx, target, value),
-                FieldType::Class(c) => IrRef::Class(c).coerce(ctx, target, value),
-                FieldType::List(_) => coerce_array(ctx, self, value),
-                FieldType::Union(_) => coerce_union(ctx, self, value),
-                FieldType::Optional(_) => coerce_optional(ctx, self, value),
-                FieldType::Map(_, _) => coerce_map(ctx, self, value),
-                FieldType::Tuple(_) => Err(ctx.error_internal("Tuple not supported")),
-            },
+            _ => {
+                let mut coerced = match self {
+                    FieldType::Primitive(p) => p.coerce(ctx, target, value),
+                    FieldType::Literal(_) => coerce_literal(ctx, self, value),
+                    FieldType::Enum(e) => IrRef::Enum(e).coerce(ctx, target, value),
+                    FieldType::Class(c) => IrRef::Class(c).coerce(ctx, target, value),
+                    FieldType::List(_) => coerce_array(ctx, self, value),
+                    FieldType::Union(_) => coerce_union(ctx, self, value),
+                    FieldType::Optional(_) => coerce_optional(ctx, self, value),
+                    FieldType::Map(_, _) => coerce_map(ctx, self, value),
+                    FieldType::Tuple(_) => Err(ctx.error_internal("Tuple not supported")),
+                }?;
+                if ctx.allow_partials
+                    && value.is_some_and(|v| v.completion_state() == CompletionState::Incomplete)
+                {
+                    coerced.mark_pending();
+                }
+                Ok(coerced)
+            }
         }
     }
 }
@@ -116,7 +131,7 @@ impl DefaultValue for FieldType {
                     None
                 }
             }
-            FieldType::Primitive(_) => None,
+            FieldType::Primitive(_) | FieldType::Literal(_) => None,
         }
     }
 }
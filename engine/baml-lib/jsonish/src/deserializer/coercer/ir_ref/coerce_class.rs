@@ -1,12 +1,15 @@
 use anyhow::Result;
-use baml_types::BamlMap;
+use baml_types::{BamlMap, StreamingBehavior};
 use internal_baml_core::ir::FieldType;
 use internal_baml_jinja::types::{Class, Name};
 
-use crate::deserializer::{
-    coercer::{array_helper, DefaultValue, ParsingError, TypeCoercer},
-    deserialize_flags::{DeserializerConditions, Flag},
-    types::BamlValueWithFlags,
+use crate::{
+    deserializer::{
+        coercer::{array_helper, DefaultValue, ParsingError, TypeCoercer},
+        deserialize_flags::{DeserializerConditions, Flag},
+        types::BamlValueWithFlags,
+    },
+    jsonish::CompletionState,
 };
 
 use super::ParsingContext;
@@ -40,6 +43,14 @@ impl TypeCoercer for Class {
             .collect::<BamlMap<_, _>>();
         let mut flags = DeserializerConditions::new();
 
+        // While streaming, a @stream.done field is left out until its value
+        // is complete.
+        let held_back = |field: &FieldValue, v: &crate::jsonish::Value| {
+            ctx.allow_partials
+                && streaming(self, field).done
+                && v.completion_state() == CompletionState::Incomplete
+        };
+
         let mut completed_cls = Vec::new();
 
         // There are a few possible approaches here:
@@ -47,7 +58,7 @@ impl TypeCoercer for Class {
             None => {
                 // Do nothing
             }
-            Some(crate::jsonish::Value::Object(obj)) => {
+            Some(object @ crate::jsonish::Value::Object(obj, completion_state)) => {
                 // match keys, if that fails, then do something fancy later.
                 let mut extra_keys = vec![];
                 let mut found_keys = false;
@@ -57,26 +68,30 @@ impl TypeCoercer for Class {
                         .iter()
                         .find(|(name, ..)| name.rendered_name().trim() == key)
                     {
+                        found_keys = true;
+                        if held_back(field, v) {
+                            return;
+                        }
                         let scope = ctx.enter_scope(field.0.real_name());
                         let parsed = field.1.coerce(&scope, &field.1, Some(v));
                         update_map(&mut required_values, &mut optional_values, field, parsed);
-                        found_keys = true;
                     } else {
                         extra_keys.push((key, v));
                     }
                 });
 
-                if !found_keys && !extra_keys.is_empty() && self.fields.len() == 1 {
+                if !found_keys
+                    && !extra_keys.is_empty()
+                    && self.fields.len() == 1
+                    && !held_back(&self.fields[0], object)
+                {
                     // Try to coerce the object into the single field
                     let field = &self.fields[0];
                     let scope = ctx.enter_scope(&format!("<implied:{}>", field.0.real_name()));
-                    let parsed = field
-                        .1
-                        .coerce(
-                            &scope,
-                            &field.1,
-                            Some(&crate::jsonish::Value::Object(obj.clone())),
-                        )
+                    let value = crate::jsonish::Value::Object(obj.clone(), *completion_state);
+                    let parsed = scope
+                        .visit_class_value_pair(self.name.real_name(), &value)
+                        .and_then(|scope| field.1.coerce(&scope, &field.1, Some(&value)))
                         .map(|mut v| {
                             v.add_flag(Flag::ImpliedKey(field.0.real_name().into()));
                             v
@@ -100,11 +115,14 @@ impl TypeCoercer for Class {
                     });
                 }
             }
-            Some(crate::jsonish::Value::Array(items)) => {
-                if self.fields.len() == 1 {
+            Some(array @ crate::jsonish::Value::Array(items, _)) => {
+                if self.fields.len() == 1 && !held_back(&self.fields[0], array) {
                     let field = &self.fields[0];
                     let scope = ctx.enter_scope(&format!("<implied:{}>", field.0.real_name()));
-                    let parsed = match field.1.coerce(&scope, &field.1, value) {
+                    let parsed = match scope
+                        .visit_class_value_pair(self.name.real_name(), array)
+                        .and_then(|scope| field.1.coerce(&scope, &field.1, value))
+                    {
                         Ok(mut v) => {
                             v.add_flag(Flag::ImpliedKey(field.0.real_name().into()));
                             Ok(v)
@@ -126,10 +144,13 @@ impl TypeCoercer for Class {
             }
             Some(x) => {
                 // If the class has a single field, then we can try to coerce it directly
-                if self.fields.len() == 1 {
+                if self.fields.len() == 1 && !held_back(&self.fields[0], x) {
                     let field = &self.fields[0];
                     let scope = ctx.enter_scope(&format!("<implied:{}>", field.0.real_name()));
-                    let parsed = match field.1.coerce(&scope, &field.1, Some(x)) {
+                    let parsed = match scope
+                        .visit_class_value_pair(self.name.real_name(), x)
+                        .and_then(|scope| field.1.coerce(&scope, &field.1, Some(x)))
+                    {
                         Ok(mut v) => {
                             v.add_flag(Flag::ImpliedKey(field.0.real_name().into()));
                             flags.add_flag(Flag::InferedObject(x.clone()));
@@ -144,6 +165,16 @@ impl TypeCoercer for Class {
 
         // Check what we have / what we need
         {
+            // While streaming, fields the object has not reached yet are pending.
+            let missing = |mut default: BamlValueWithFlags| {
+                if ctx.allow_partials
+                    && value.is_some_and(|v| v.completion_state() == CompletionState::Incomplete)
+                {
+                    default.mark_pending();
+                }
+                default
+            };
+
             self.fields.iter().for_each(|(field_name, t, ..)| {
                 if t.is_optional() {
                     if let Some(v) = optional_values.get(field_name.real_name()) {
@@ -158,10 +189,10 @@ impl TypeCoercer for Class {
                                 t.default_value(Some(e))
                             }
                             // If we're missing a field, thats ok!
-                            None => Some(BamlValueWithFlags::Null(
+                            None => Some(missing(BamlValueWithFlags::Null(
                                 DeserializerConditions::new()
                                     .with_flag(Flag::OptionalDefaultFromNoValue),
-                            )),
+                            ))),
                         };
 
                         if let Some(next) = next {
@@ -183,16 +214,19 @@ impl TypeCoercer for Class {
                                     None
                                 }
                             }),
-                            None => t.default_value(None).or_else(|| {
-                                if ctx.allow_partials {
-                                    Some(BamlValueWithFlags::Null(
-                                        DeserializerConditions::new()
-                                            .with_flag(Flag::OptionalDefaultFromNoValue),
-                                    ))
-                                } else {
-                                    None
-                                }
-                            }),
+                            None => t
+                                .default_value(None)
+                                .or_else(|| {
+                                    if ctx.allow_partials {
+                                        Some(BamlValueWithFlags::Null(
+                                            DeserializerConditions::new()
+                                                .with_flag(Flag::OptionalDefaultFromNoValue),
+                                        ))
+                                    } else {
+                                        None
+                                    }
+                                })
+                                .map(missing),
                         };
 
                         if let Some(next) = next {
@@ -238,12 +272,33 @@ impl TypeCoercer for Class {
                     None => Some(k.clone()),
                 })
                 .collect::<Vec<_>>();
+            // While streaming, a @stream.not_null field without a value holds
+            // back the whole object.
+            let pending_not_null_fields = self
+                .fields
+                .iter()
+                .filter(|field| ctx.allow_partials && streaming(self, field).not_null)
+                .map(|(name, ..)| name.real_name())
+                .filter(|name| {
+                    !matches!(
+                        required_values.get(*name).or_else(|| optional_values.get(*name)),
+                        Some(Some(Ok(v))) if !matches!(v, BamlValueWithFlags::Null(_))
+                    )
+                })
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>();
 
-            if !missing_required_fields.is_empty() || !unparsed_required_fields.is_empty() {
+            if !missing_required_fields.is_empty()
+                || !unparsed_required_fields.is_empty()
+                || !pending_not_null_fields.is_empty()
+            {
                 if completed_cls.is_empty() {
                     return Err(ctx.error_missing_required_field(
                         unparsed_required_fields,
-                        missing_required_fields,
+                        missing_required_fields
+                            .into_iter()
+                            .chain(pending_not_null_fields)
+                            .collect(),
                         value,
                     ));
                 }
@@ -308,6 +363,13 @@ impl TypeCoercer for Class {
     }
 }
 
+fn streaming(cls: &Class, (name, ..): &FieldValue) -> StreamingBehavior {
+    cls.field_streaming
+        .get(name.real_name())
+        .copied()
+        .unwrap_or_default()
+}
+
 fn update_map<'a>(
     required_values: &'a mut BamlMap<String, Option<Result<BamlValueWithFlags, ParsingError>>>,
     optional_values: &'a mut BamlMap<String, Option<Result<BamlValueWithFlags, ParsingError>>>,
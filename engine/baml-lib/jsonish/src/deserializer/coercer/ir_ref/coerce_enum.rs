@@ -53,7 +53,7 @@ impl TypeCoercer for Enum {
         let mut flags = DeserializerConditions::new();
 
         let context = match value {
-            crate::jsonish::Value::String(s) => s.clone(),
+            crate::jsonish::Value::String(s, _) => s.clone(),
             crate::jsonish::Value::AnyOf(_, s) => {
                 flags.add_flag(Flag::ObjectToString(value.clone()));
                 s.clone()
@@ -1,5 +1,6 @@
 mod array_helper;
 mod coerce_array;
+mod coerce_literal;
 mod coerce_map;
 mod coerce_optional;
 mod coerce_primitive;
@@ -15,6 +16,10 @@ use super::types::BamlValueWithFlags;
 
 pub struct ParsingContext<'a> {
     scope: Vec<String>,
+    // Classes currently being coerced from a value without consuming any of
+    // it (e.g. an implied single field). Used to stop recursive classes from
+    // wrapping the same value forever.
+    visited: Vec<(String, crate::jsonish::Value)>,
     of: &'a OutputFormatContent,
     allow_partials: bool,
 }
@@ -30,6 +35,7 @@ impl ParsingContext<'_> {
     pub(crate) fn new<'a>(of: &'a OutputFormatContent, allow_partials: bool) -> ParsingContext<'a> {
         ParsingContext {
             scope: Vec::new(),
+            visited: Vec::new(),
             of,
             allow_partials,
         }
@@ -40,11 +46,39 @@ impl ParsingContext<'_> {
         new_scope.push(scope.to_string());
         ParsingContext {
             scope: new_scope,
+            visited: self.visited.clone(),
             of: self.of,
             allow_partials: self.allow_partials,
         }
     }
 
+    pub(crate) fn visit_class_value_pair(
+        &self,
+        cls: &str,
+        value: &crate::jsonish::Value,
+    ) -> Result<ParsingContext, ParsingError> {
+        if self
+            .visited
+            .iter()
+            .any(|(name, visited)| name == cls && visited == value)
+        {
+            return Err(ParsingError {
+                reason: format!("Circular reference detected for class {}", cls),
+                scope: self.scope.clone(),
+                causes: vec![],
+            });
+        }
+
+        let mut visited = self.visited.clone();
+        visited.push((cls.to_string(), value.clone()));
+        Ok(ParsingContext {
+            scope: self.scope.clone(),
+            visited,
+            of: self.of,
+            allow_partials: self.allow_partials,
+        })
+    }
+
     pub(crate) fn error_too_many_matches<T: std::fmt::Display>(
         &self,
         target: &FieldType,
@@ -0,0 +1,162 @@
+use anyhow::Result;
+use baml_types::{BamlMap, BamlValue, CheckStatus, Constraint, ConstraintLevel, ResponseCheck};
+use internal_baml_jinja::{evaluate_predicate, types::OutputFormatContent};
+
+use super::{
+    deserialize_flags::{DeserializerConditions, Flag},
+    types::BamlValueWithFlags,
+};
+
+/// Evaluates every `@assert` and `@check` that applies to a fully parsed value.
+///
+/// A failed assert is an error, and so is an assert whose expression fails
+/// to evaluate. Checks never fail parsing: a value with checks is wrapped
+/// into a `Checked` class holding the original `value` and the outcome of
+/// each check under `checks`.
+pub fn apply_constraints(
+    of: &OutputFormatContent,
+    value: BamlValueWithFlags,
+) -> Result<BamlValueWithFlags> {
+    apply(of, value, of.target_constraints(), "<root>")
+}
+
+fn apply(
+    of: &OutputFormatContent,
+    value: BamlValueWithFlags,
+    constraints: &[Constraint],
+    path: &str,
+) -> Result<BamlValueWithFlags> {
+    // Constraints see the value as the user declared it, before any of its
+    // children are wrapped by their own checks.
+    let checks = evaluate(&value, constraints, path)?;
+    let value = apply_children(of, value, path)?;
+
+    if checks.is_empty() {
+        return Ok(value);
+    }
+    Ok(wrap_checked(value, checks))
+}
+
+fn apply_children(
+    of: &OutputFormatContent,
+    value: BamlValueWithFlags,
+    path: &str,
+) -> Result<BamlValueWithFlags> {
+    Ok(match value {
+        BamlValueWithFlags::Class(name, conds, fields) => {
+            let class = of.find_class(&name).ok();
+            let fields = fields
+                .into_iter()
+                .map(|(field, v)| {
+                    let constraints = class
+                        .and_then(|c| c.field_constraints.get(&field))
+                        .map(|c| c.as_slice())
+                        .unwrap_or_default();
+                    let v = apply(of, v, constraints, &format!("{path}.{field}"))?;
+                    Ok((field, v))
+                })
+                .collect::<Result<BamlMap<_, _>>>()?;
+            BamlValueWithFlags::Class(name, conds, fields)
+        }
+        BamlValueWithFlags::List(conds, items) => {
+            let items = items
+                .into_iter()
+                .enumerate()
+                .map(|(idx, v)| apply_children(of, v, &format!("{path}[{idx}]")))
+                .collect::<Result<Vec<_>>>()?;
+            BamlValueWithFlags::List(conds, items)
+        }
+        BamlValueWithFlags::Map(conds, entries) => {
+            let entries = entries
+                .into_iter()
+                .map(|(k, (kconds, v))| {
+                    let v = apply_children(of, v, &format!("{path}[{k:?}]"))?;
+                    Ok((k, (kconds, v)))
+                })
+                .collect::<Result<BamlMap<_, _>>>()?;
+            BamlValueWithFlags::Map(conds, entries)
+        }
+        other => other,
+    })
+}
+
+fn evaluate(
+    value: &BamlValueWithFlags,
+    constraints: &[Constraint],
+    path: &str,
+) -> Result<Vec<ResponseCheck>> {
+    if constraints.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let this: BamlValue = value.into();
+    let mut checks = Vec::new();
+    for constraint in constraints {
+        // An expression that can't be evaluated for this value, e.g. taking
+        // the length of a null optional field, fails an assert with that
+        // error, and fails a check with the error recorded next to it.
+        let result = evaluate_predicate(&this, &constraint.expression);
+        match constraint.level {
+            ConstraintLevel::Assert => match result {
+                Ok(true) => {}
+                Ok(false) => anyhow::bail!("Assertion failed at {path}: {constraint}"),
+                Err(e) => anyhow::bail!("Failed to evaluate {constraint} at {path}: {e:#}"),
+            },
+            ConstraintLevel::Check => checks.push(ResponseCheck {
+                name: constraint.name(),
+                expression: constraint.expression.0.clone(),
+                status: match result {
+                    Ok(true) => CheckStatus::Succeeded,
+                    Ok(false) | Err(_) => CheckStatus::Failed,
+                },
+                error: result.err().map(|e| format!("{e:#}")),
+            }),
+        }
+    }
+    Ok(checks)
+}
+
+fn wrap_checked(value: BamlValueWithFlags, checks: Vec<ResponseCheck>) -> BamlValueWithFlags {
+    let check_values = checks
+        .iter()
+        .map(|check| {
+            let fields = BamlMap::from([
+                (
+                    "name".to_string(),
+                    BamlValueWithFlags::String(check.name.clone().into()),
+                ),
+                (
+                    "expression".to_string(),
+                    BamlValueWithFlags::String(check.expression.clone().into()),
+                ),
+                (
+                    "status".to_string(),
+                    BamlValueWithFlags::String(check.status.to_string().into()),
+                ),
+            ]);
+            (
+                check.name.clone(),
+                (
+                    DeserializerConditions::new(),
+                    BamlValueWithFlags::Class(
+                        "Check".to_string(),
+                        DeserializerConditions::new(),
+                        fields,
+                    ),
+                ),
+            )
+        })
+        .collect();
+
+    BamlValueWithFlags::Class(
+        "Checked".to_string(),
+        DeserializerConditions::new().with_flag(Flag::ConstraintResults(checks)),
+        BamlMap::from([
+            ("value".to_string(), value),
+            (
+                "checks".to_string(),
+                BamlValueWithFlags::Map(DeserializerConditions::new(), check_values),
+            ),
+        ]),
+    )
+}
@@ -1,3 +1,5 @@
+use baml_types::ResponseCheck;
+
 use super::{coercer::ParsingError, types::BamlValueWithFlags};
 
 #[derive(Debug, Clone)]
@@ -42,6 +44,12 @@ pub enum Flag {
 
     // X -> Object convertions.
     NoFields(Option<crate::jsonish::Value>),
+
+    // Results of @check constraints on this value.
+    ConstraintResults(Vec<ResponseCheck>),
+
+    // While streaming, the value was not finished yet.
+    Pending,
 }
 
 #[derive(Clone)]
@@ -90,6 +98,8 @@ impl DeserializerConditions {
                 Flag::NoFields(_) => None,
                 Flag::UnionMatch(_idx, _) => None,
                 Flag::DefaultButHadUnparseableValue(e) => Some(e.clone()),
+                Flag::ConstraintResults(_) => None,
+                Flag::Pending => None,
             })
             .collect::<Vec<_>>()
     }
@@ -229,6 +239,23 @@ impl std::fmt::Display for Flag {
                     writeln!(f, "<empty>")?;
                 }
             }
+            Flag::ConstraintResults(checks) => {
+                writeln!(f, "Constraint results:")?;
+                for check in checks {
+                    write!(
+                        f,
+                        "  {}: {} ({})",
+                        check.name, check.expression, check.status
+                    )?;
+                    match &check.error {
+                        Some(error) => writeln!(f, ": {}", error)?,
+                        None => writeln!(f)?,
+                    }
+                }
+            }
+            Flag::Pending => {
+                write!(f, "Pending")?;
+            }
         }
         Ok(())
     }
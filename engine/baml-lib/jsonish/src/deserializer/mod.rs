@@ -1,4 +1,5 @@
 pub mod coercer;
+pub mod constraints;
 pub mod deserialize_flags;
 // pub mod schema;
 mod score;
@@ -62,6 +62,9 @@ impl WithScore for Flag {
             Flag::StringToChar(_) => 1,
             Flag::FloatToInt(_) => 1,
             Flag::NoFields(_) => 1,
+            // Checks are evaluated after parsing, so they never affect which value is picked.
+            Flag::ConstraintResults(_) => 0,
+            Flag::Pending => 0,
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use baml_types::{BamlMap, BamlMedia, BamlValue};
+use baml_types::{BamlMap, BamlMedia, BamlValue, CheckStatus, ResponseCheck};
 use serde_json::json;
 use strsim::jaro;
 
@@ -9,6 +9,7 @@ use super::{
     deserialize_flags::{DeserializerConditions, Flag},
     score::WithScore,
 };
+use crate::jsonish::CompletionState;
 
 // Recursive parity
 #[derive(Clone, Debug)]
@@ -71,6 +72,99 @@ impl BamlValueWithFlags {
             BamlValueWithFlags::Media(v) => &v.flags,
         }
     }
+
+    /// Every `@check` that failed anywhere in this value.
+    pub fn failed_checks(&self) -> Vec<ResponseCheck> {
+        let mut failed = Vec::new();
+        self.collect_failed_checks(&mut failed);
+        failed
+    }
+
+    fn collect_failed_checks(&self, failed: &mut Vec<ResponseCheck>) {
+        for flag in self.conditions().flags() {
+            if let Flag::ConstraintResults(checks) = flag {
+                failed.extend(
+                    checks
+                        .iter()
+                        .filter(|c| c.status == CheckStatus::Failed)
+                        .cloned(),
+                );
+            }
+        }
+        match self {
+            BamlValueWithFlags::List(_, items) => {
+                items.iter().for_each(|i| i.collect_failed_checks(failed))
+            }
+            BamlValueWithFlags::Map(_, kv) => kv
+                .values()
+                .for_each(|(_, v)| v.collect_failed_checks(failed)),
+            BamlValueWithFlags::Class(_, _, fields) => fields
+                .values()
+                .for_each(|v| v.collect_failed_checks(failed)),
+            _ => {}
+        }
+    }
+
+    /// Whether this value was still being written when it was parsed. Only
+    /// partial results have pending values.
+    pub fn completion_state(&self) -> CompletionState {
+        if self
+            .conditions()
+            .flags()
+            .iter()
+            .any(|f| matches!(f, Flag::Pending))
+        {
+            CompletionState::Incomplete
+        } else {
+            CompletionState::Complete
+        }
+    }
+
+    pub(crate) fn mark_pending(&mut self) {
+        if self.completion_state() == CompletionState::Complete {
+            self.add_flag(Flag::Pending);
+        }
+    }
+
+    /// The completion state of this value and of everything in it, shaped
+    /// like the value itself:
+    ///
+    /// ```json
+    /// {
+    ///   "state": "pending",
+    ///   "fields": {
+    ///     "title": "complete",
+    ///     "tags": { "state": "pending", "items": ["complete", "pending"] }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// Lists have `items`, maps have `entries` and classes have `fields`.
+    /// Any other value is just its state.
+    pub fn completion_states(&self) -> serde_json::Value {
+        let state = self.completion_state();
+        match self {
+            BamlValueWithFlags::List(_, items) => json!({
+                "state": state,
+                "items": items.iter().map(|i| i.completion_states()).collect::<Vec<_>>(),
+            }),
+            BamlValueWithFlags::Map(_, kv) => json!({
+                "state": state,
+                "entries": kv
+                    .iter()
+                    .map(|(k, (_, v))| (k.clone(), v.completion_states()))
+                    .collect::<serde_json::Map<_, _>>(),
+            }),
+            BamlValueWithFlags::Class(_, _, fields) => json!({
+                "state": state,
+                "fields": fields
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.completion_states()))
+                    .collect::<serde_json::Map<_, _>>(),
+            }),
+            _ => json!(state),
+        }
+    }
 }
 
 trait ParsingErrorToUiJson {
@@ -5,7 +5,7 @@ mod parser;
 // mod test_iterative_parser;
 mod value;
 
-pub use value::{Fixes, Value};
+pub use value::{CompletionState, Fixes, Value};
 
 // pub use iterative_parser::{parse_jsonish_value, JSONishOptions};
 pub use parser::{parse, ParseOptions};
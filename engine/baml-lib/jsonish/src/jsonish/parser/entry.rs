@@ -6,7 +6,7 @@ use crate::jsonish::{
         markdown_parser::{self, MarkdownResult},
         multi_json_parser,
     },
-    value::Fixes,
+    value::{CompletionState, Fixes},
     Value,
 };
 
@@ -59,7 +59,9 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
                     let others = items
                         .iter()
                         .filter_map(|res| match res {
-                            MarkdownResult::String(s) => Some(Value::String(s.to_string())),
+                            MarkdownResult::String(s) => {
+                                Some(Value::String(s.to_string(), CompletionState::Complete))
+                            }
                             _ => None,
                         })
                         .map(|v| {
@@ -87,7 +89,7 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
                         })
                         .map(|(s, v)| Value::Markdown(s.to_string(), Box::new(v)))
                         .collect::<Vec<_>>();
-                    let array = Value::Array(items.clone());
+                    let array = Value::Array(items.clone(), CompletionState::Complete);
                     let items = items
                         .into_iter()
                         .chain(std::iter::once(array))
@@ -120,7 +122,7 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
                     ))
                 }
                 _ => {
-                    let items_clone = Value::Array(items.clone());
+                    let items_clone = Value::Array(items.clone(), CompletionState::Complete);
                     let items = items
                         .into_iter()
                         .chain(std::iter::once(items_clone))
@@ -163,7 +165,7 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
                             .map(|(v, fixes)| Value::FixedJson(v.into(), fixes))
                             .collect::<Vec<_>>();
 
-                        let items_clone = Value::Array(items.clone());
+                        let items_clone = Value::Array(items.clone(), CompletionState::Complete);
 
                         let items = items
                             .into_iter()
@@ -179,8 +181,9 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
         }
     }
 
+    // Nothing closes plain text, so it may still be streaming.
     if options.allow_as_string {
-        return Ok(Value::String(str.to_string()));
+        return Ok(Value::String(str.to_string(), CompletionState::Incomplete));
     }
 
     Err(anyhow::anyhow!("Failed to parse JSON"))
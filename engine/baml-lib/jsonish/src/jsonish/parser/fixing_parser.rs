@@ -1,7 +1,10 @@
 mod json_collection;
 mod json_parse_state;
 
-use crate::jsonish::{value::Fixes, Value};
+use crate::jsonish::{
+    value::{CompletionState, Fixes},
+    Value,
+};
 
 use self::json_parse_state::JsonParseState;
 
@@ -46,7 +49,7 @@ pub fn parse<'a>(str: &'a str, _options: &ParseOptions) -> Result<Vec<(Value, Ve
 
     // If we still have a collection open, close it
     while !state.collection_stack.is_empty() {
-        state.complete_collection();
+        state.complete_collection(CompletionState::Incomplete);
     }
 
     // Determine what to return.
@@ -68,6 +71,7 @@ pub fn parse<'a>(str: &'a str, _options: &ParseOptions) -> Result<Vec<(Value, Ve
                             .into_iter()
                             .map(|f| Value::FixedJson(f.1.into(), f.2))
                             .collect(),
+                        CompletionState::Complete,
                     ),
                     vec![Fixes::InferredArray],
                 )])
@@ -1,6 +1,6 @@
 use baml_types::BamlMap;
 
-use crate::jsonish::Value;
+use crate::jsonish::{value::CompletionState, Value};
 
 #[derive(Debug)]
 pub enum JsonCollection {
@@ -31,11 +31,9 @@ impl JsonCollection {
             JsonCollection::BlockComment(_) => "Comment",
         }
     }
-}
 
-impl From<JsonCollection> for Option<Value> {
-    fn from(collection: JsonCollection) -> Option<Value> {
-        Some(match collection {
+    pub fn into_value(self, completion_state: CompletionState) -> Option<Value> {
+        Some(match self {
             JsonCollection::TrailingComment(_) | JsonCollection::BlockComment(_) => return None,
             JsonCollection::Object(keys, values) => {
                 // log::debug!("keys: {:?}", keys);
@@ -43,12 +41,12 @@ impl From<JsonCollection> for Option<Value> {
                 for (key, value) in keys.into_iter().zip(values.into_iter()) {
                     object.insert(key, value);
                 }
-                Value::Object(object)
+                Value::Object(object, completion_state)
             }
-            JsonCollection::Array(values) => Value::Array(values),
-            JsonCollection::QuotedString(s) => Value::String(s),
-            JsonCollection::TripleQuotedString(s) => Value::String(s),
-            JsonCollection::SingleQuotedString(s) => Value::String(s),
+            JsonCollection::Array(values) => Value::Array(values, completion_state),
+            JsonCollection::QuotedString(s) => Value::String(s, completion_state),
+            JsonCollection::TripleQuotedString(s) => Value::String(s, completion_state),
+            JsonCollection::SingleQuotedString(s) => Value::String(s, completion_state),
             JsonCollection::UnquotedString(s) => {
                 let s = s.trim();
                 if s == "true" {
@@ -58,16 +56,16 @@ impl From<JsonCollection> for Option<Value> {
                 } else if s == "null" {
                     Value::Null
                 } else if let Ok(n) = s.parse::<i64>() {
-                    Value::Number(n.into())
+                    Value::Number(n.into(), completion_state)
                 } else if let Ok(n) = s.parse::<u64>() {
-                    Value::Number(n.into())
+                    Value::Number(n.into(), completion_state)
                 } else if let Ok(n) = s.parse::<f64>() {
                     match serde_json::Number::from_f64(n) {
-                        Some(n) => Value::Number(n),
-                        None => Value::String(s.into()),
+                        Some(n) => Value::Number(n, completion_state),
+                        None => Value::String(s.into(), completion_state),
                     }
                 } else {
-                    Value::String(s.into())
+                    Value::String(s.into(), completion_state)
                 }
             }
         })
@@ -1,6 +1,9 @@
 use std::iter::Peekable;
 
-use crate::jsonish::{value::Fixes, Value};
+use crate::jsonish::{
+    value::{CompletionState, Fixes},
+    Value,
+};
 use anyhow::Result;
 
 use super::json_collection::JsonCollection;
@@ -20,7 +23,9 @@ impl JsonParseState {
         }
     }
 
-    pub fn complete_collection(&mut self) {
+    /// Pops the innermost collection into its parent. `completion_state` is
+    /// whether it was closed in the text, or only because the text ended.
+    pub fn complete_collection(&mut self, completion_state: CompletionState) {
         let (collection, fixes) = match self.collection_stack.pop() {
             Some(collection) => collection,
             None => return,
@@ -28,7 +33,7 @@ impl JsonParseState {
 
         let name = collection.name();
 
-        let value: Value = match collection.into() {
+        let value: Value = match collection.into_value(completion_state) {
             Some(value) => value,
             None => return,
         };
@@ -38,7 +43,7 @@ impl JsonParseState {
                 JsonCollection::Object(keys, values) => {
                     if keys.len() == values.len() {
                         match value {
-                            Value::String(s) => keys.push(s),
+                            Value::String(s, _) => keys.push(s),
                             Value::AnyOf(_, s) => keys.push(s),
                             _ => keys.push(value.to_string()),
                         }
@@ -111,10 +116,11 @@ impl JsonParseState {
         }
     }
 
+    // The string is incomplete if the text ends before anything closes it.
     fn should_close_unescaped_string(
         &mut self,
         mut next: Peekable<impl Iterator<Item = (usize, char)>>,
-    ) -> Option<usize> {
+    ) -> Option<(usize, CompletionState)> {
         let pos = if self.collection_stack.len() >= 2 {
             self.collection_stack
                 .get(self.collection_stack.len() - 2)
@@ -141,13 +147,13 @@ impl JsonParseState {
                     counter = idx;
                     match c {
                         // If at some point we find a valid json character, we'll close the string
-                        '{' | '[' => return Some(idx),
+                        '{' | '[' => return Some((idx, CompletionState::Complete)),
                         x => {
                             let _ = self.consume(x);
                         }
                     }
                 }
-                Some(counter)
+                Some((counter, CompletionState::Incomplete))
             }
             1 => None,
             2 => {
@@ -156,13 +162,13 @@ impl JsonParseState {
                 while let Some((idx, c)) = next.next() {
                     counter = idx;
                     match c {
-                        ':' => return Some(idx),
+                        ':' => return Some((idx, CompletionState::Complete)),
                         x => {
                             let _ = self.consume(x);
                         }
                     }
                 }
-                Some(counter)
+                Some((counter, CompletionState::Incomplete))
             }
             3 => {
                 // in object value
@@ -175,7 +181,7 @@ impl JsonParseState {
                                 match next_c {
                                     '\n' => {
                                         log::debug!("Closing due to: newline after comma");
-                                        return Some(idx);
+                                        return Some((idx, CompletionState::Complete));
                                     }
                                     ' ' => {
                                         log::debug!("Testing for comment after space + comma");
@@ -194,17 +200,26 @@ impl JsonParseState {
                                                         // Likely end of the key as the LLM generated a (', ' token by mistake)
                                                         // so drop the comma
                                                         log::debug!("Closing due to: newline after comma + space");
-                                                        return Some(idx);
+                                                        return Some((
+                                                            idx,
+                                                            CompletionState::Complete,
+                                                        ));
                                                     }
                                                 }
                                                 '/' => match next.peek() {
                                                     Some((_, '/')) => {
                                                         // This is likely a comment
-                                                        return Some(idx);
+                                                        return Some((
+                                                            idx,
+                                                            CompletionState::Complete,
+                                                        ));
                                                     }
                                                     Some((_, '*')) => {
                                                         // This is likely a comment
-                                                        return Some(idx);
+                                                        return Some((
+                                                            idx,
+                                                            CompletionState::Complete,
+                                                        ));
                                                     }
                                                     _ => {
                                                         // let _ = self.consume(c);
@@ -213,7 +228,7 @@ impl JsonParseState {
                                                 '"' => {
                                                     // This is likely a new key
                                                     log::debug!("Closing due to: new key after space + comma");
-                                                    return Some(idx);
+                                                    return Some((idx, CompletionState::Complete));
                                                 }
                                                 x => {
                                                     break;
@@ -230,16 +245,16 @@ impl JsonParseState {
                                 }
                             } else {
                                 // Don't include the comma
-                                return Some(idx);
+                                return Some((idx, CompletionState::Complete));
                             }
                         }
-                        '}' => return Some(idx),
+                        '}' => return Some((idx, CompletionState::Complete)),
                         x => {
                             let _ = self.consume(x);
                         }
                     }
                 }
-                Some(counter)
+                Some((counter, CompletionState::Incomplete))
             }
             4 => {
                 // in array
@@ -247,14 +262,14 @@ impl JsonParseState {
                 while let Some((idx, c)) = next.next() {
                     counter = idx;
                     match c {
-                        ',' => return Some(idx),
-                        ']' => return Some(idx),
+                        ',' => return Some((idx, CompletionState::Complete)),
+                        ']' => return Some((idx, CompletionState::Complete)),
                         x => {
                             let _ = self.consume(x);
                         }
                     }
                 }
-                Some(counter)
+                Some((counter, CompletionState::Incomplete))
             }
             _ => unreachable!("Invalid position"),
         }
@@ -367,7 +382,7 @@ impl JsonParseState {
                     match token {
                         '}' => {
                             // We're ready to close the object
-                            self.complete_collection();
+                            self.complete_collection(CompletionState::Complete);
                             Ok(0)
                         }
                         // We can safely ignore these tokens
@@ -384,7 +399,7 @@ impl JsonParseState {
                     match token {
                         ']' => {
                             // We're ready to close the array
-                            self.complete_collection();
+                            self.complete_collection(CompletionState::Complete);
                             Ok(0)
                         }
                         // Skip these tokens
@@ -406,7 +421,7 @@ impl JsonParseState {
                         };
 
                         if is_triple_quoted {
-                            self.complete_collection();
+                            self.complete_collection(CompletionState::Complete);
                             Ok(3)
                         } else {
                             self.consume(token)
@@ -424,7 +439,7 @@ impl JsonParseState {
                             // It's possible that the LLM messed up the escaping
                             // We'll try to fix it.
                             if self.should_close_string(next, '"') {
-                                self.complete_collection();
+                                self.complete_collection(CompletionState::Complete);
                                 Ok(0)
                             } else {
                                 self.consume(token)
@@ -493,7 +508,7 @@ impl JsonParseState {
                             // It's possible that the LLM messed up the escaping
                             // We'll try to fix it.
                             if self.should_close_string(next, '\'') {
-                                self.complete_collection();
+                                self.complete_collection(CompletionState::Complete);
                                 Ok(0)
                             } else {
                                 self.consume(token)
@@ -507,8 +522,10 @@ impl JsonParseState {
                     // - A terminating json character (comma, colon, bracket, space, newline)
                     // - A character
                     let res = self.consume(token);
-                    if let Some(count) = self.should_close_unescaped_string(next) {
-                        self.complete_collection();
+                    if let Some((count, completion_state)) =
+                        self.should_close_unescaped_string(next)
+                    {
+                        self.complete_collection(completion_state);
                         Ok(count)
                     } else {
                         res
@@ -521,7 +538,7 @@ impl JsonParseState {
                     match token {
                         '\n' => {
                             // We're ready to close the comment
-                            self.complete_collection();
+                            self.complete_collection(CompletionState::Complete);
                             Ok(0)
                         }
                         _ => self.consume(token),
@@ -537,7 +554,7 @@ impl JsonParseState {
                             match next.peek() {
                                 Some((_, '/')) => {
                                     // We're ready to close the comment
-                                    self.complete_collection();
+                                    self.complete_collection(CompletionState::Complete);
                                     Ok(1)
                                 }
                                 _ => Ok(0),
@@ -624,8 +641,8 @@ impl JsonParseState {
             x => {
                 self.collection_stack
                     .push((JsonCollection::UnquotedString(x.into()), Default::default()));
-                if let Some(count) = self.should_close_unescaped_string(next) {
-                    self.complete_collection();
+                if let Some((count, completion_state)) = self.should_close_unescaped_string(next) {
+                    self.complete_collection(completion_state);
                     return Ok(count);
                 }
             }
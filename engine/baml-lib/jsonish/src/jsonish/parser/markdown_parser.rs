@@ -81,6 +81,7 @@ pub fn parse<'a>(str: &'a str, options: &ParseOptions) -> Result<Vec<MarkdownRes
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::jsonish::CompletionState;
     use test_log::test;
 
     #[test]
@@ -118,9 +119,13 @@ print("Hello, world!")
                 panic!("Expected AnyOf, got {:#?}", value);
             };
             assert!(value.contains(&Value::Object(
-                [("a".to_string(), Value::Number((1).into()))]
-                    .into_iter()
-                    .collect()
+                [(
+                    "a".to_string(),
+                    Value::Number((1).into(), CompletionState::Complete)
+                )]
+                .into_iter()
+                .collect(),
+                CompletionState::Complete
             )));
         }
         {
@@ -134,7 +139,10 @@ print("Hello, world!")
             let Value::AnyOf(value, _) = value else {
                 panic!("Expected AnyOf, got {:#?}", value);
             };
-            assert!(value.contains(&Value::String("This is a test".to_string())));
+            assert!(value.contains(&Value::String(
+                "This is a test".to_string(),
+                CompletionState::Complete
+            )));
         }
 
         Ok(())
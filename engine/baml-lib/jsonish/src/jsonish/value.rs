@@ -8,17 +8,28 @@ pub enum Fixes {
     InferredArray,
 }
 
+/// Whether a value was closed in the text it was parsed from. Only the
+/// fixing parser produces incomplete values, for text that ends (e.g.
+/// mid-stream) before a string, number, object or array is finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum CompletionState {
+    #[serde(rename = "pending")]
+    Incomplete,
+    #[serde(rename = "complete")]
+    Complete,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Value {
     // Primitive Types
-    String(String),
-    Number(serde_json::Number),
+    String(String, CompletionState),
+    Number(serde_json::Number, CompletionState),
     Boolean(bool),
     Null,
 
     // Complex Types
-    Object(BamlMap<String, Value>),
-    Array(Vec<Value>),
+    Object(BamlMap<String, Value>, CompletionState),
+    Array(Vec<Value>, CompletionState),
 
     // Fixed types
     Markdown(String, Box<Value>),
@@ -27,13 +38,34 @@ pub enum Value {
 }
 
 impl Value {
+    pub fn completion_state(&self) -> CompletionState {
+        match self {
+            Value::String(_, state)
+            | Value::Number(_, state)
+            | Value::Object(_, state)
+            | Value::Array(_, state) => *state,
+            Value::Boolean(_) | Value::Null => CompletionState::Complete,
+            Value::Markdown(_, inner) | Value::FixedJson(inner, _) => inner.completion_state(),
+            Value::AnyOf(items, _) => {
+                if items
+                    .iter()
+                    .any(|v| v.completion_state() == CompletionState::Incomplete)
+                {
+                    CompletionState::Incomplete
+                } else {
+                    CompletionState::Complete
+                }
+            }
+        }
+    }
+
     pub fn r#type(&self) -> String {
         match self {
-            Value::String(_) => "String".to_string(),
-            Value::Number(_) => "Number".to_string(),
+            Value::String(..) => "String".to_string(),
+            Value::Number(..) => "Number".to_string(),
             Value::Boolean(_) => "Boolean".to_string(),
             Value::Null => "Null".to_string(),
-            Value::Object(k) => {
+            Value::Object(k, _) => {
                 let mut s = "Object{".to_string();
                 for (key, value) in k.iter() {
                     s.push_str(&format!("{}: {}, ", key, value.r#type()));
@@ -41,7 +73,7 @@ impl Value {
                 s.push('}');
                 s
             }
-            Value::Array(i) => {
+            Value::Array(i, _) => {
                 let mut s = "Array[".to_string();
                 let items = i
                     .iter()
@@ -76,11 +108,11 @@ impl Value {
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Value::String(s) => write!(f, "{}", s),
-            Value::Number(n) => write!(f, "{}", n),
+            Value::String(s, _) => write!(f, "{}", s),
+            Value::Number(n, _) => write!(f, "{}", n),
             Value::Boolean(b) => write!(f, "{}", b),
             Value::Null => write!(f, "null"),
-            Value::Object(o) => {
+            Value::Object(o, _) => {
                 write!(f, "{{")?;
                 for (i, (k, v)) in o.iter().enumerate() {
                     if i > 0 {
@@ -90,7 +122,7 @@ impl std::fmt::Display for Value {
                 }
                 write!(f, "}}")
             }
-            Value::Array(a) => {
+            Value::Array(a, _) => {
                 write!(f, "[")?;
                 for (i, v) in a.iter().enumerate() {
                     if i > 0 {
@@ -120,8 +152,8 @@ impl<'de> serde::Deserialize<'de> for Value {
     {
         let value = serde_json::Value::deserialize(deserializer)?;
         match value {
-            serde_json::Value::String(s) => Ok(Value::String(s)),
-            serde_json::Value::Number(n) => Ok(Value::Number(n)),
+            serde_json::Value::String(s) => Ok(Value::String(s, CompletionState::Complete)),
+            serde_json::Value::Number(n) => Ok(Value::Number(n, CompletionState::Complete)),
             serde_json::Value::Bool(b) => Ok(Value::Boolean(b)),
             serde_json::Value::Null => Ok(Value::Null),
             serde_json::Value::Object(o) => {
@@ -131,7 +163,7 @@ impl<'de> serde::Deserialize<'de> for Value {
                         serde_json::from_value(v).map_err(serde::de::Error::custom)?;
                     map.insert(k, parsed_value);
                 }
-                Ok(Value::Object(map))
+                Ok(Value::Object(map, CompletionState::Complete))
             }
             serde_json::Value::Array(a) => {
                 let mut vec = Vec::new();
@@ -140,7 +172,7 @@ impl<'de> serde::Deserialize<'de> for Value {
                         serde_json::from_value(v).map_err(serde::de::Error::custom)?;
                     vec.push(parsed_value);
                 }
-                Ok(Value::Array(vec))
+                Ok(Value::Array(vec, CompletionState::Complete))
             }
         }
     }
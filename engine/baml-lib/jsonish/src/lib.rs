@@ -7,10 +7,12 @@ mod jsonish;
 
 use baml_types::FieldType;
 use deserializer::coercer::{ParsingContext, TypeCoercer};
+use deserializer::constraints::apply_constraints;
 
 pub use deserializer::types::BamlValueWithFlags;
 use internal_baml_core::ir::TypeValue;
 use internal_baml_jinja::types::OutputFormatContent;
+pub use jsonish::CompletionState;
 
 use deserializer::deserialize_flags::Flag;
 
@@ -19,9 +21,31 @@ pub fn from_str(
     target: &FieldType,
     raw_string: &str,
     allow_partials: bool,
+) -> Result<BamlValueWithFlags> {
+    let value = coerce(of, target, raw_string, allow_partials)?;
+
+    // Constraints only make sense on a complete value, so they are skipped
+    // while streaming.
+    if allow_partials {
+        Ok(value)
+    } else {
+        apply_constraints(of, value)
+    }
+}
+
+fn coerce(
+    of: &OutputFormatContent,
+    target: &FieldType,
+    raw_string: &str,
+    allow_partials: bool,
 ) -> Result<BamlValueWithFlags> {
     if matches!(target, FieldType::Primitive(TypeValue::String)) {
-        return Ok(BamlValueWithFlags::String(raw_string.to_string().into()));
+        let mut value = BamlValueWithFlags::String(raw_string.to_string().into());
+        // Nothing closes plain text, so it is pending until the stream ends.
+        if allow_partials {
+            value.mark_pending();
+        }
+        return Ok(value);
     }
 
     // When the schema is just a string, i should really just return the raw_string w/o parsing it.
@@ -48,7 +72,7 @@ pub fn from_str(
             if v.conditions()
                 .flags()
                 .iter()
-                .any(|f| matches!(f, Flag::InferedObject(jsonish::Value::String(_))))
+                .any(|f| matches!(f, Flag::InferedObject(jsonish::Value::String(..))))
             {
                 anyhow::bail!("Failed to coerce value: {:?}", v.conditions().flags());
             }
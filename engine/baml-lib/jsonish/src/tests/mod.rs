@@ -6,10 +6,13 @@ pub mod macros;
 
 mod test_basics;
 mod test_class;
+mod test_constraints;
 mod test_enum;
 mod test_lists;
+mod test_literals;
 mod test_maps;
 mod test_partials;
+mod test_streaming;
 mod test_unions;
 
 use std::{
@@ -188,13 +191,34 @@ fn relevant_data_models<'a>(
                         }
                     }
 
+                    let field_constraints = walker
+                        .as_ref()
+                        .map(|e| {
+                            e.walk_fields()
+                                .filter(|f| !f.constraints().is_empty())
+                                .map(|f| (f.name().to_string(), f.constraints().to_vec()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let field_streaming = walker
+                        .as_ref()
+                        .map(|e| {
+                            e.walk_fields()
+                                .filter(|f| !f.streaming().is_default())
+                                .map(|f| (f.name().to_string(), f.streaming()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
                     classes.push(Class {
                         name: Name::new_with_alias(cls.to_string(), walker?.alias(env_values)?),
                         fields,
+                        field_constraints,
+                        field_streaming,
                     });
                 }
             }
-            FieldType::Primitive(_) => {}
+            FieldType::Primitive(_) | FieldType::Literal(_) => {}
         }
     }
 
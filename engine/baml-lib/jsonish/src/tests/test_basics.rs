@@ -40,6 +40,18 @@ test_deserializer!(test_bool, EMPTY_FILE, "true", FieldType::bool(), true);
 test_deserializer!(test_bool_2, EMPTY_FILE, "True", FieldType::bool(), true);
 test_deserializer!(test_bool_3, EMPTY_FILE, "false", FieldType::bool(), false);
 test_deserializer!(test_bool_4, EMPTY_FILE, "False", FieldType::bool(), false);
+test_deserializer!(
+    test_bool_from_quoted_string,
+    r#"
+  class Test {
+      enabled bool
+      archived bool
+  }
+  "#,
+    r#"{"enabled": "true", "archived": "false"}"#,
+    FieldType::class("Test"),
+    {"enabled": true, "archived": false}
+);
 
 test_deserializer!(
     test_float,
@@ -1069,3 +1069,44 @@ Here's the redesigned code with these changes:
     ]
   }
 );
+
+const RECURSIVE_FILE: &str = r#"
+class Node {
+  value int
+  children Node[]
+}
+
+class LinkedList {
+  head ListNode?
+  len int
+}
+
+class ListNode {
+  value int
+  next ListNode?
+}
+"#;
+
+test_deserializer!(
+    test_recursive_class,
+    RECURSIVE_FILE,
+    r#"{"value": 1, "children": [{"value": 2, "children": [{"value": 3, "children": []}]}]}"#,
+    FieldType::Class("Node".to_string()),
+    {"value": 1, "children": [{"value": 2, "children": [{"value": 3, "children": []}]}]}
+);
+
+test_deserializer!(
+    test_mutually_recursive_class,
+    RECURSIVE_FILE,
+    r#"{"head": {"value": 1, "next": {"value": 2, "next": null}}, "len": 2}"#,
+    FieldType::Class("LinkedList".to_string()),
+    {"head": {"value": 1, "next": {"value": 2, "next": null}}, "len": 2}
+);
+
+test_deserializer!(
+    test_recursive_class_missing_optional,
+    RECURSIVE_FILE,
+    r#"{"value": 1, "next": {"value": 2}}"#,
+    FieldType::Class("ListNode".to_string()),
+    {"value": 1, "next": {"value": 2, "next": null}}
+);
@@ -0,0 +1,147 @@
+use baml_types::{Constraint, ConstraintLevel, JinjaExpression};
+
+use super::*;
+
+const PERSON_FILE: &str = r#"
+class Person {
+  name string @assert({{ this|length > 0 }})
+  age int @check(adult, {{ this >= 18 }})
+}
+"#;
+
+test_deserializer!(
+    test_assert_and_check_pass,
+    PERSON_FILE,
+    r#"{"name": "Ada", "age": 36}"#,
+    FieldType::Class("Person".to_string()),
+    {
+        "name": "Ada",
+        "age": {
+            "value": 36,
+            "checks": {
+                "adult": {"name": "adult", "expression": "this >= 18", "status": "succeeded"}
+            }
+        }
+    }
+);
+
+test_deserializer!(
+    test_failed_check_does_not_fail_parsing,
+    PERSON_FILE,
+    r#"{"name": "Ada", "age": 12}"#,
+    FieldType::Class("Person".to_string()),
+    {
+        "name": "Ada",
+        "age": {
+            "value": 12,
+            "checks": {
+                "adult": {"name": "adult", "expression": "this >= 18", "status": "failed"}
+            }
+        }
+    }
+);
+
+const NICKNAME_FILE: &str = r#"
+class Person {
+  nickname string? @check(short, {{ this|length < 10 }})
+}
+"#;
+
+test_deserializer!(
+    test_check_that_fails_to_evaluate_does_not_fail_parsing,
+    NICKNAME_FILE,
+    r#"{"nickname": null}"#,
+    FieldType::Class("Person".to_string()),
+    {
+        "nickname": {
+            "value": null,
+            "checks": {
+                "short": {"name": "short", "expression": "this|length < 10", "status": "failed"}
+            }
+        }
+    }
+);
+
+test_failing_deserializer!(
+    test_failed_assert_fails_parsing,
+    PERSON_FILE,
+    r#"{"name": "", "age": 36}"#,
+    FieldType::Class("Person".to_string())
+);
+
+test_deserializer!(
+    test_constraints_in_list,
+    PERSON_FILE,
+    r#"[{"name": "Ada", "age": 36}]"#,
+    FieldType::List(FieldType::Class("Person".to_string()).into()),
+    [{
+        "name": "Ada",
+        "age": {
+            "value": 36,
+            "checks": {
+                "adult": {"name": "adult", "expression": "this >= 18", "status": "succeeded"}
+            }
+        }
+    }]
+);
+
+test_partial_deserializer!(
+    test_constraints_skipped_when_streaming,
+    PERSON_FILE,
+    r#"{"name": "", "age": 12}"#,
+    FieldType::Class("Person".to_string()),
+    {"name": "", "age": 12}
+);
+
+#[test_log::test]
+fn test_target_assert() {
+    let target_type = FieldType::Primitive(TypeValue::Int);
+    let ir = load_test_ir(EMPTY_FILE);
+    let target = render_output_format(&ir, &target_type, &Default::default())
+        .unwrap()
+        .with_target_constraints(vec![Constraint {
+            level: ConstraintLevel::Assert,
+            expression: JinjaExpression("this > 0".to_string()),
+            label: None,
+        }]);
+
+    assert!(from_str(&target, &target_type, "12", false).is_ok());
+    assert!(from_str(&target, &target_type, "-1", false).is_err());
+}
+
+const PROFILE_FILE: &str = r#"
+class Profile {
+  name string
+  nickname string? @assert(nonempty, {{ this|length > 0 }})
+}
+"#;
+
+#[test_log::test]
+fn test_constraint_evaluation_error_names_expression() {
+    let target_type = FieldType::Class("Profile".to_string());
+    let ir = load_test_ir(PROFILE_FILE);
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    assert!(from_str(
+        &target,
+        &target_type,
+        r#"{"name": "Ada", "nickname": "A"}"#,
+        false
+    )
+    .is_ok());
+
+    let err = from_str(
+        &target,
+        &target_type,
+        r#"{"name": "Ada", "nickname": null}"#,
+        false,
+    )
+    .unwrap_err();
+    let message = format!("{err:#}");
+    assert!(
+        message.contains(
+            "Failed to evaluate @assert(nonempty, {{ this|length > 0 }}) at <root>.nickname"
+        ),
+        "{message}"
+    );
+}
@@ -0,0 +1,94 @@
+use baml_types::LiteralValue;
+
+use super::*;
+
+const EMPTY_FILE: &str = r#"
+"#;
+
+const RESULT_FILE: &str = r#"
+class Result {
+  status "success" | "error"
+  code 200 | 404
+  retry false
+}
+"#;
+
+test_deserializer!(
+    test_literal_string,
+    EMPTY_FILE,
+    r#"success"#,
+    FieldType::Literal(LiteralValue::String("success".to_string())),
+    "success"
+);
+
+test_deserializer!(
+    test_literal_string_case_insensitive,
+    EMPTY_FILE,
+    r#"SUCCESS"#,
+    FieldType::Literal(LiteralValue::String("success".to_string())),
+    "success"
+);
+
+test_deserializer!(
+    test_literal_string_with_quotes,
+    EMPTY_FILE,
+    r#""success""#,
+    FieldType::Literal(LiteralValue::String("success".to_string())),
+    "success"
+);
+
+test_failing_deserializer!(
+    test_literal_string_mismatch,
+    EMPTY_FILE,
+    r#"failure"#,
+    FieldType::Literal(LiteralValue::String("success".to_string()))
+);
+
+test_deserializer!(
+    test_literal_int,
+    EMPTY_FILE,
+    r#"2"#,
+    FieldType::Literal(LiteralValue::Int(2)),
+    2
+);
+
+test_failing_deserializer!(
+    test_literal_int_mismatch,
+    EMPTY_FILE,
+    r#"3"#,
+    FieldType::Literal(LiteralValue::Int(2))
+);
+
+test_deserializer!(
+    test_literal_bool,
+    EMPTY_FILE,
+    r#"true"#,
+    FieldType::Literal(LiteralValue::Bool(true)),
+    true
+);
+
+test_failing_deserializer!(
+    test_literal_bool_mismatch,
+    EMPTY_FILE,
+    r#"false"#,
+    FieldType::Literal(LiteralValue::Bool(true))
+);
+
+test_deserializer!(
+    test_literal_union,
+    EMPTY_FILE,
+    r#"error"#,
+    FieldType::Union(vec![
+        FieldType::Literal(LiteralValue::String("success".to_string())),
+        FieldType::Literal(LiteralValue::String("error".to_string())),
+    ]),
+    "error"
+);
+
+test_deserializer!(
+    test_literal_class_fields,
+    RESULT_FILE,
+    r#"{"status": "Error", "code": 404, "retry": false}"#,
+    FieldType::Class("Result".to_string()),
+    {"status": "error", "code": 404, "retry": false}
+);
@@ -0,0 +1,158 @@
+use super::*;
+
+const LINK_FILE: &str = r#"
+class Link {
+  url string @stream.done
+  title string
+}
+
+class Item {
+  id int @stream.not_null
+  name string
+}
+
+class Order {
+  items Item[]
+  tags string[] @stream.done
+}
+"#;
+
+test_partial_deserializer!(
+    test_stream_done_holds_back_incomplete_string,
+    LINK_FILE,
+    r#"{"url": "https://exa"#,
+    FieldType::Class("Link".to_string()),
+    {"url": null, "title": null}
+);
+
+test_partial_deserializer!(
+    test_stream_done_keeps_complete_string,
+    LINK_FILE,
+    r#"{"url": "https://example.com", "title": "Exam"#,
+    FieldType::Class("Link".to_string()),
+    {"url": "https://example.com", "title": "Exam"}
+);
+
+test_partial_deserializer!(
+    test_stream_done_holds_back_incomplete_list,
+    LINK_FILE,
+    r#"{"items": [], "tags": ["a", "b"#,
+    FieldType::Class("Order".to_string()),
+    {"items": [], "tags": []}
+);
+
+test_partial_deserializer!(
+    test_stream_done_keeps_complete_list,
+    LINK_FILE,
+    r#"{"items": [], "tags": ["a", "b"]"#,
+    FieldType::Class("Order".to_string()),
+    {"items": [], "tags": ["a", "b"]}
+);
+
+test_partial_deserializer!(
+    test_stream_not_null_holds_back_object,
+    LINK_FILE,
+    r#"{"items": [{"id": 1, "name": "a"}, {"name": "b"#,
+    FieldType::Class("Order".to_string()),
+    {"items": [{"id": 1, "name": "a"}], "tags": []}
+);
+
+test_partial_deserializer!(
+    test_stream_not_null_allows_incomplete_value,
+    LINK_FILE,
+    r#"{"items": [{"id": 1, "name": "a"}, {"id": 2"#,
+    FieldType::Class("Order".to_string()),
+    {"items": [{"id": 1, "name": "a"}, {"id": 2, "name": null}], "tags": []}
+);
+
+test_deserializer!(
+    test_stream_attributes_ignored_when_complete,
+    LINK_FILE,
+    r#"{"url": "https://example.com", "title": "Example"}"#,
+    FieldType::Class("Link".to_string()),
+    {"url": "https://example.com", "title": "Example"}
+);
+
+#[test]
+fn test_stream_not_null_holds_back_root() {
+    let ir = load_test_ir(LINK_FILE);
+    let target_type = FieldType::Class("Item".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    assert!(from_str(&target, &target_type, r#"{"name": "b"#, true).is_err());
+    assert!(from_str(&target, &target_type, r#"{"id": 3, "name": "b"#, true).is_ok());
+}
+
+#[test]
+fn test_completion_states() {
+    let ir = load_test_ir(LINK_FILE);
+    let target_type = FieldType::Class("Order".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    let value = from_str(
+        &target,
+        &target_type,
+        r#"{"items": [{"id": 1, "name": "a"}, {"id": 2"#,
+        true,
+    )
+    .unwrap();
+    assert_json_diff::assert_json_eq!(
+        value.completion_states(),
+        json!({
+            "state": "pending",
+            "fields": {
+                "items": {
+                    "state": "pending",
+                    "items": [
+                        {"state": "complete", "fields": {"id": "complete", "name": "complete"}},
+                        {"state": "pending", "fields": {"id": "pending", "name": "pending"}},
+                    ],
+                },
+                "tags": {"state": "pending", "items": []},
+            },
+        })
+    );
+
+    let value = from_str(
+        &target,
+        &target_type,
+        r#"{"items": [{"id": 1, "name": "a"}], "tags": ["x"]}"#,
+        false,
+    )
+    .unwrap();
+    assert_json_diff::assert_json_eq!(
+        value.completion_states(),
+        json!({
+            "state": "complete",
+            "fields": {
+                "items": {
+                    "state": "complete",
+                    "items": [{"state": "complete", "fields": {"id": "complete", "name": "complete"}}],
+                },
+                "tags": {"state": "complete", "items": ["complete"]},
+            },
+        })
+    );
+}
+
+#[test]
+fn test_completion_state_of_string() {
+    let ir = load_test_ir(LINK_FILE);
+    let target_type = FieldType::Class("Link".to_string());
+    let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+
+    let value = from_str(
+        &target,
+        &target_type,
+        r#"{"url": "https://example.com", "title": "Exam"#,
+        true,
+    )
+    .unwrap();
+    assert_json_diff::assert_json_eq!(
+        value.completion_states(),
+        json!({
+            "state": "pending",
+            "fields": {"url": "complete", "title": "pending"},
+        })
+    );
+}
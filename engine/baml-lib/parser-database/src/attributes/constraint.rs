@@ -0,0 +1,103 @@
+use baml_types::{Constraint, ConstraintLevel};
+use internal_baml_diagnostics::DatamodelError;
+
+use crate::context::Context;
+
+/// Visits every `@assert` and `@check` on the current attribute set.
+///
+/// Both take an optional label followed by a Jinja expression:
+///
+/// ```ignore
+/// age int @assert({{ this >= 0 }}) @check(adult, {{ this >= 18 }})
+/// ```
+///
+/// A label is required for `@check`, since it is how the result is reported.
+///
+/// Test blocks use the same attributes at block level, as `@@assert` and `@@check`.
+pub(super) fn visit_constraint_attributes(
+    ctx: &mut Context<'_>,
+    as_block: bool,
+) -> Vec<Constraint> {
+    let mut constraints = Vec::new();
+
+    for (name, level) in [
+        ("assert", ConstraintLevel::Assert),
+        ("check", ConstraintLevel::Check),
+    ] {
+        while ctx.visit_repeated_attr(name) {
+            if let Some(constraint) = visit_constraint_attribute(ctx, name, level, as_block) {
+                constraints.push(constraint);
+            }
+            ctx.validate_visited_arguments();
+        }
+    }
+
+    constraints
+}
+
+fn visit_constraint_attribute(
+    ctx: &mut Context<'_>,
+    name: &'static str,
+    level: ConstraintLevel,
+    as_block: bool,
+) -> Option<Constraint> {
+    let prefix = if as_block { "@@" } else { "@" };
+
+    let first = match ctx.visit_default_arg_with_idx(name) {
+        Ok((_, value)) => value,
+        Err(err) => {
+            ctx.push_error(err);
+            return None;
+        }
+    };
+
+    let (label, expression) = match first.as_jinja_expression() {
+        Some((expression, span)) => (None, (expression, span)),
+        None => {
+            let Some((label, _)) = first.as_string_value() else {
+                ctx.push_attribute_validation_error(
+                    "The first argument must be a name or a Jinja expression, e.g. {{ this > 0 }}.",
+                    as_block,
+                );
+                return None;
+            };
+            let Some(expression) = ctx
+                .visit_default_arg_with_idx(name)
+                .ok()
+                .and_then(|(_, second)| second.as_jinja_expression())
+            else {
+                ctx.push_attribute_validation_error(
+                    "The second argument must be a Jinja expression, e.g. {{ this > 0 }}.",
+                    as_block,
+                );
+                return None;
+            };
+            (Some(label.to_string()), expression)
+        }
+    };
+
+    if level == ConstraintLevel::Check && label.is_none() {
+        ctx.push_attribute_validation_error(
+            &format!(
+                "{prefix}check requires a name, e.g. {prefix}check(is_positive, {{{{ this > 0 }}}})."
+            ),
+            as_block,
+        );
+        return None;
+    }
+
+    let (expression, span) = expression;
+    if let Err(err) = internal_baml_jinja::validate_expression(expression) {
+        ctx.push_error(DatamodelError::new_validation_error(
+            &format!("Invalid Jinja expression in {prefix}{name}: {err}"),
+            span.clone(),
+        ));
+        return None;
+    }
+
+    Some(Constraint {
+        level,
+        expression: expression.clone(),
+        label,
+    })
+}
@@ -1,7 +1,12 @@
-use internal_baml_schema_ast::ast::{Top, TopId, TypeExpId, TypeExpressionBlock};
+use baml_types::{Constraint, StreamingBehavior};
+use internal_baml_schema_ast::ast::{
+    AttributeContainer, Top, TopId, TypeExpId, TypeExpressionBlock, ValExpId,
+};
 
 mod alias;
+mod constraint;
 mod description;
+mod stream;
 mod to_string_attribute;
 use crate::interner::StringId;
 use crate::{context::Context, types::ClassAttributes, types::EnumAttributes};
@@ -21,6 +26,12 @@ pub struct Attributes {
 
     /// Whether the node should be skipped during prompt rendering and parsing.
     pub skip: Option<bool>,
+
+    /// @check and @assert constraints on the node.
+    pub constraints: Vec<Constraint>,
+
+    /// @stream.done and @stream.not_null on a class field.
+    pub streaming: StreamingBehavior,
 }
 
 impl Attributes {
@@ -64,6 +75,15 @@ impl Attributes {
         self.skip.replace(true);
     }
 
+    /// Get the constraints.
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Get the streaming behavior.
+    pub fn streaming(&self) -> StreamingBehavior {
+        self.streaming
+    }
 }
 pub(super) fn resolve_attributes(ctx: &mut Context<'_>) {
     for top in ctx.ast.iter_tops() {
@@ -74,6 +94,12 @@ pub(super) fn resolve_attributes(ctx: &mut Context<'_>) {
             (TopId::Enum(enum_id), Top::Enum(ast_enum)) => {
                 resolve_type_exp_block_attributes(enum_id, ast_enum, ctx, SubType::Enum)
             }
+            (TopId::Function(function_id), Top::Function(_)) => {
+                resolve_function_output_attributes(function_id, ctx)
+            }
+            (TopId::TestCase(test_id), Top::TestCase(_)) => {
+                resolve_test_case_attributes(test_id, ctx)
+            }
             _ => (),
         }
     }
@@ -109,7 +135,16 @@ fn resolve_type_exp_block_attributes<'db>(
 
             for (field_idx, _field) in ast_typexpr.iter_fields() {
                 ctx.visit_attributes((type_id, field_idx).into());
-                if let Some(attrs) = to_string_attribute::visit(ctx, false) {
+                let mut attrs = to_string_attribute::visit(ctx, false);
+                let constraints = constraint::visit_constraint_attributes(ctx, false);
+                if !constraints.is_empty() {
+                    attrs.get_or_insert_with(Default::default).constraints = constraints;
+                }
+                let streaming = stream::visit_stream_attributes(ctx);
+                if !streaming.is_default() {
+                    attrs.get_or_insert_with(Default::default).streaming = streaming;
+                }
+                if let Some(attrs) = attrs {
                     class_attributes.field_serilizers.insert(field_idx, attrs);
                 }
                 ctx.validate_visited_attributes();
@@ -126,3 +161,25 @@ fn resolve_type_exp_block_attributes<'db>(
         _ => (),
     }
 }
+
+fn resolve_function_output_attributes(function_id: ValExpId, ctx: &mut Context<'_>) {
+    ctx.visit_attributes(AttributeContainer::FunctionOutput(function_id));
+    let constraints = constraint::visit_constraint_attributes(ctx, false);
+    ctx.validate_visited_attributes();
+
+    // Functions that failed to resolve have already reported an error.
+    if let Some(function) = ctx.types.function.get_mut(&function_id) {
+        function.output_constraints = constraints;
+    }
+}
+
+fn resolve_test_case_attributes(test_id: ValExpId, ctx: &mut Context<'_>) {
+    ctx.visit_attributes(AttributeContainer::TestCase(test_id));
+    let constraints = constraint::visit_constraint_attributes(ctx, true);
+    ctx.validate_visited_attributes();
+
+    // Tests that failed to resolve have already reported an error.
+    if let Some(test_case) = ctx.types.test_cases.get_mut(&test_id) {
+        test_case.constraints = constraints;
+    }
+}
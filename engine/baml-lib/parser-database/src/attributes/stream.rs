@@ -0,0 +1,27 @@
+use baml_types::StreamingBehavior;
+
+use crate::context::Context;
+
+/// Visits `@stream.done` and `@stream.not_null` on a class field.
+///
+/// ```ignore
+/// class Link {
+///   url string @stream.done
+///   title string @stream.not_null
+/// }
+/// ```
+pub(super) fn visit_stream_attributes(ctx: &mut Context<'_>) -> StreamingBehavior {
+    let mut streaming = StreamingBehavior::default();
+
+    if ctx.visit_optional_single_attr("stream.done") {
+        streaming.done = true;
+        ctx.validate_visited_arguments();
+    }
+
+    if ctx.visit_optional_single_attr("stream.not_null") {
+        streaming.not_null = true;
+        ctx.validate_visited_arguments();
+    }
+
+    streaming
+}
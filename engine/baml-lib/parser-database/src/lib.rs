@@ -40,14 +40,14 @@ use std::collections::HashSet;
 pub use coerce_expression::{coerce, coerce_array, coerce_opt};
 use either::Either;
 pub use internal_baml_schema_ast::ast;
-use internal_baml_schema_ast::ast::{SchemaAst, WithIdentifier, WithName, WithSpan};
+use internal_baml_schema_ast::ast::SchemaAst;
 pub use types::{
-    Attributes, ContantDelayStrategy, ExponentialBackoffStrategy, PrinterType, PromptAst,
-    PromptVariable, RetryPolicy, RetryPolicyStrategy, StaticType,
+    Attributes, ContantDelayStrategy, ExponentialBackoffStrategy, OutputMode, PrinterType,
+    PromptAst, PromptVariable, RetryJitter, RetryPolicy, RetryPolicyStrategy, StaticType,
 };
 
 use self::{context::Context, interner::StringId, types::Types};
-use internal_baml_diagnostics::{DatamodelError, DatamodelWarning, Diagnostics};
+use internal_baml_diagnostics::{DatamodelWarning, Diagnostics};
 use names::Names;
 
 /// ParserDatabase is a container for a Schema AST, together with information
@@ -124,89 +124,36 @@ impl ParserDatabase {
     }
 
     /// Updates the prompt
-    pub fn finalize(&mut self, diag: &mut Diagnostics) {
-        self.finalize_dependencies(diag);
+    pub fn finalize(&mut self, _diag: &mut Diagnostics) {
+        self.finalize_dependencies();
     }
 
-    fn finalize_dependencies(&mut self, diag: &mut Diagnostics) {
-        let mut deps = self
+    fn finalize_dependencies(&mut self) {
+        // Expand every class's dependencies to everything it can reach. Classes
+        // may be recursive, so this walks the graph instead of peeling off
+        // leaves, and a recursive class ends up listing itself.
+        let expanded = self
             .types
             .class_dependencies
             .iter()
-            .map(|f| {
-                (
-                    *f.0,
-                    f.1.iter()
-                        .fold((0, 0, 0), |prev, i| match self.find_type_by_str(i) {
-                            Some(Either::Left(_)) => (prev.0 + 1, prev.1 + 1, prev.2),
-                            Some(Either::Right(_)) => (prev.0 + 1, prev.1, prev.2 + 1),
-                            _ => prev,
-                        }),
-                )
+            .map(|(&cls, direct)| {
+                let mut deps = direct.clone();
+                let mut stack = direct.iter().cloned().collect::<Vec<_>>();
+                while let Some(dep) = stack.pop() {
+                    if let Some(Either::Left(walker)) = self.find_type_by_str(&dep) {
+                        for child in walker.dependencies() {
+                            if deps.insert(child.clone()) {
+                                stack.push(child.clone());
+                            }
+                        }
+                    }
+                }
+                (cls, deps)
             })
             .collect::<Vec<_>>();
 
-        // Can only process deps which have 0 class dependencies.
-        let mut max_loops = 100;
-        while !deps.is_empty() && max_loops > 0 {
-            max_loops -= 1;
-            // Remove all the ones which have 0 class dependencies.
-            let removed = deps
-                .iter()
-                .filter(|(_, v)| v.1 == 0)
-                .map(|(k, _)| *k)
-                .collect::<Vec<_>>();
-            deps.retain(|(_, v)| v.1 > 0);
-            for cls in removed {
-                let child_deps = self
-                    .types
-                    .class_dependencies
-                    .get(&cls)
-                    // These must exist by definition so safe to unwrap.
-                    .unwrap()
-                    .iter()
-                    .filter_map(|f| match self.find_type_by_str(f) {
-                        Some(Either::Left(walker)) => {
-                            Some(walker.dependencies().iter().cloned().collect::<Vec<_>>())
-                        }
-                        Some(Either::Right(walker)) => Some(vec![walker.name().to_string()]),
-                        _ => panic!("Unknown class `{}`", f),
-                    })
-                    .flatten()
-                    .collect::<HashSet<_>>();
-                let name = self.ast[cls].name();
-                deps.iter_mut()
-                    .filter(|(k, _)| self.types.class_dependencies[k].contains(name))
-                    .for_each(|(_, v)| {
-                        v.1 -= 1;
-                    });
-
-                // Get the dependencies of all my dependencies.
-                self.types
-                    .class_dependencies
-                    .get_mut(&cls)
-                    .unwrap()
-                    .extend(child_deps);
-            }
-        }
-
-        if max_loops == 0 && !deps.is_empty() {
-            let circular_deps = deps
-                .iter()
-                .map(|(k, _)| self.ast[*k].name())
-                .collect::<Vec<_>>()
-                .join(" -> ");
-
-            deps.iter().for_each(|(k, _)| {
-                diag.push_error(DatamodelError::new_validation_error(
-                    &format!(
-                        "Circular dependency detected for class `{}`.\n{}",
-                        self.ast[*k].name(),
-                        circular_deps
-                    ),
-                    self.ast[*k].identifier().span().clone(),
-                ));
-            });
+        for (cls, deps) in expanded {
+            self.types.class_dependencies.insert(cls, deps);
         }
 
         // Additionally ensure the same thing for functions, but since we've already handled classes,
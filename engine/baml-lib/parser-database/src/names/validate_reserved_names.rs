@@ -42,6 +42,7 @@ pub(crate) fn validate_class_name(
     diagnostics: &mut Diagnostics,
 ) {
     validate_name("class", ast_class.identifier(), diagnostics, true);
+    validate_type_name("class", ast_class.identifier(), diagnostics);
 }
 
 pub(crate) fn validate_class_field_name<T>(
@@ -80,6 +81,7 @@ pub(crate) fn validate_enum_name(
     diagnostics: &mut Diagnostics,
 ) {
     validate_name("enum", ast_enum.identifier(), diagnostics, true);
+    validate_type_name("enum", ast_enum.identifier(), diagnostics);
     ast_enum.iter_fields().for_each(|(_, val)| {
         validate_name("enum value", val.identifier(), diagnostics, true);
     })
@@ -140,6 +142,22 @@ fn validate_name(
     }
 }
 
+/// The generated clients declare these types next to the user's classes and
+/// enums, to hold the results of `@check`.
+fn validate_type_name(_type: &str, idn: &ast::Identifier, diagnostics: &mut Diagnostics) {
+    if let ast::Identifier::Local(val, span) = idn {
+        if RESERVED_TYPE_NAMES.contains(&val.as_str()) {
+            diagnostics.push_error(DatamodelError::new_name_error(
+                _type,
+                "This name is reserved for the results of @check.",
+                span.clone(),
+            ));
+        }
+    }
+}
+
+const RESERVED_TYPE_NAMES: &[&str] = &["Check", "Checked"];
+
 // The source of the following list is from prisma-client-js. Any edit should be done in both places.
 // https://github.com/prisma/prisma/blob/master/src/packages/client/src/generation/generateClient.ts#L443
 const RESERVED_NAMES: &[&str] = &["BamlClient"];
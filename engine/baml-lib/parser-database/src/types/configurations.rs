@@ -5,7 +5,9 @@ use std::collections::HashSet;
 
 use crate::{coerce, coerce_array, coerce_expression::coerce_map, context::Context};
 
-use super::{ContantDelayStrategy, ExponentialBackoffStrategy, RetryPolicy, RetryPolicyStrategy};
+use super::{
+    ContantDelayStrategy, ExponentialBackoffStrategy, RetryJitter, RetryPolicy, RetryPolicyStrategy,
+};
 
 fn dedent(s: &str) -> String {
     // Find the shortest indentation in the string (that's not an empty line).
@@ -37,8 +39,12 @@ pub(crate) fn visit_retry_policy<'db>(
     let mut max_reties = None;
 
     let mut strategy = Some(RetryPolicyStrategy::ConstantDelay(
-        super::ContantDelayStrategy { delay_ms: 200 },
+        super::ContantDelayStrategy {
+            delay_ms: 200,
+            jitter: RetryJitter::None,
+        },
     ));
+    let mut retry_on = None;
     let mut options = None;
 
     config
@@ -65,6 +71,20 @@ pub(crate) fn visit_retry_policy<'db>(
                     None => {}
                 }
             }
+            ("retry_on", Some(val)) => match coerce_array(val, &coerce::integer, ctx.diagnostics) {
+                Some(codes) => match codes
+                    .into_iter()
+                    .map(u16::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+                {
+                    Ok(codes) => retry_on = Some(codes),
+                    Err(_) => ctx.push_error(DatamodelError::new_validation_error(
+                        "`retry_on` must be a list of error codes, e.g. [429, 500, 503]",
+                        val.span().clone(),
+                    )),
+                },
+                None => {}
+            },
             ("options", Some(val)) => {
                 match coerce_map(val, &coerce::string_with_span, ctx.diagnostics) {
                     Some(val) => {
@@ -80,7 +100,7 @@ pub(crate) fn visit_retry_policy<'db>(
             (name, Some(_)) => ctx.push_error(DatamodelError::new_property_not_known_error(
                 name,
                 f.identifier().span().clone(),
-                ["max_retries", "strategy", "options"].to_vec(),
+                ["max_retries", "strategy", "retry_on", "options"].to_vec(),
             )),
         });
     match (max_reties, strategy) {
@@ -90,6 +110,7 @@ pub(crate) fn visit_retry_policy<'db>(
                 RetryPolicy {
                     max_retries,
                     strategy,
+                    retry_on,
                     options,
                 },
             );
@@ -117,6 +138,7 @@ fn visit_strategy(
     let mut delay_ms = None;
     let mut max_delay_ms = None;
     let mut multiplier = None;
+    let mut jitter = None;
 
     val.iter()
         .for_each(|(name_and_span, val)| match name_and_span.0 {
@@ -136,6 +158,21 @@ fn visit_strategy(
                 Some(_val) => multiplier = Some((_val, val.span())),
                 None => {}
             },
+            "jitter" => match coerce::string_with_span(val, diagnostics) {
+                Some(("none", span)) => jitter = Some((RetryJitter::None, span)),
+                Some(("full", span)) => jitter = Some((RetryJitter::Full, span)),
+                Some(("decorrelated", span)) => jitter = Some((RetryJitter::Decorrelated, span)),
+                Some((name, span)) => diagnostics.push_error(
+                    internal_baml_diagnostics::DatamodelError::new_validation_error(
+                        &format!(
+                            "Unknown jitter: {}. Options are `none`, `full` or `decorrelated`",
+                            name
+                        ),
+                        span.clone(),
+                    ),
+                ),
+                None => {}
+            },
             _ => {}
         });
 
@@ -161,8 +198,18 @@ fn visit_strategy(
                   ),
                   None => {}
               }
+            match jitter {
+                Some((RetryJitter::Decorrelated, span)) => diagnostics.push_error(
+                    internal_baml_diagnostics::DatamodelError::new_validation_error(
+                        "The `decorrelated` jitter is not supported for the `constant_delay` strategy",
+                        span.clone(),
+                    ),
+                ),
+                _ => {}
+            }
             Some(RetryPolicyStrategy::ConstantDelay(ContantDelayStrategy {
                 delay_ms: delay_ms.unwrap_or(200) as u32,
+                jitter: jitter.map(|(j, _)| j).unwrap_or_default(),
             }))
         }
         Some(("exponential_backoff", _)) => Some(RetryPolicyStrategy::ExponentialBackoff(
@@ -170,6 +217,7 @@ fn visit_strategy(
                 delay_ms: delay_ms.unwrap_or(200) as u32,
                 multiplier: multiplier.map(|(v, _)| v as f32).unwrap_or(1.5),
                 max_delay_ms: max_delay_ms.map(|(v, _)| v as u32).unwrap_or(10000),
+                jitter: jitter.map(|(j, _)| j).unwrap_or_default(),
             },
         )),
         Some((name, span)) => {
@@ -304,6 +352,7 @@ pub(crate) fn visit_test_case<'db>(
                     functions,
                     args,
                     args_field_span: args_field_span.clone(),
+                    constraints: Vec::new(),
                 },
             );
         }
@@ -5,6 +5,7 @@ use crate::coerce;
 use crate::types::configurations::visit_test_case;
 use crate::{context::Context, DatamodelError};
 
+use baml_types::Constraint;
 use indexmap::IndexMap;
 use internal_baml_diagnostics::Span;
 use internal_baml_prompt_parser::ast::{ChatBlock, PrinterBlock, Variable};
@@ -138,6 +139,8 @@ pub struct TestCase {
     // The span is the span of the argument (the expression has its own span)
     pub args: IndexMap<String, (Span, Expression)>,
     pub args_field_span: Span,
+    /// @@assert and @@check constraints on the result of the test.
+    pub constraints: Vec<Constraint>,
 }
 
 #[derive(Debug, Clone)]
@@ -171,6 +174,8 @@ pub struct RetryPolicy {
     pub max_retries: u32,
     /// The strategy to use.
     pub strategy: RetryPolicyStrategy,
+    /// The error codes to retry. Every error is retried if unset.
+    pub retry_on: Option<Vec<u16>>,
     /// Any additional options.
     pub options: Option<Vec<((String, Span), Expression)>>,
 }
@@ -189,6 +194,8 @@ pub enum RetryPolicyStrategy {
 pub struct ContantDelayStrategy {
     /// The delay in milliseconds.
     pub delay_ms: u32,
+    /// How the delay is randomized.
+    pub jitter: RetryJitter,
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize)]
@@ -200,6 +207,23 @@ pub struct ExponentialBackoffStrategy {
     pub multiplier: f32,
     /// The maximum delay in milliseconds.
     pub max_delay_ms: u32,
+    /// How the delay is randomized.
+    pub jitter: RetryJitter,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+/// How a retry delay is randomized, so that failed requests don't all retry
+/// at once.
+pub enum RetryJitter {
+    /// The delay is used as is.
+    #[default]
+    None,
+    /// A random delay between zero and the delay.
+    Full,
+    /// A random delay between `delay_ms` and `multiplier` times the previous
+    /// delay, up to `max_delay_ms`.
+    Decorrelated,
 }
 
 #[derive(Debug, Clone)]
@@ -207,6 +231,31 @@ pub struct FunctionType {
     pub dependencies: (HashSet<String>, HashSet<String>),
     pub prompt: Option<RawString>,
     pub client: Option<(String, Span)>,
+    /// How the return type is requested from the model.
+    pub output_mode: Option<(OutputMode, Span)>,
+    /// @check and @assert constraints on the return type.
+    pub output_constraints: Vec<Constraint>,
+}
+
+/// How a function asks the model for its return type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Render the schema into the prompt (`{{ ctx.output_format }}`) and
+    /// parse the reply text.
+    Prompt,
+    /// Send the schema as a tool the model must call, and parse the tool
+    /// call's arguments.
+    Tool,
+}
+
+impl OutputMode {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "prompt" => Some(Self::Prompt),
+            "tool" => Some(Self::Tool),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -342,6 +391,7 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
 
     let mut prompt = None;
     let mut client = None;
+    let mut output_mode = None;
     function
         .iter_fields()
         .for_each(|(_idx, field)| match field.name() {
@@ -358,6 +408,27 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
                     None => None,
                 }
             }
+            "output_mode" => {
+                output_mode = match &field.expr {
+                    Some(val) => match coerce::string_with_span(val, ctx.diagnostics) {
+                        Some((mode, span)) => match OutputMode::from_str(mode) {
+                            Some(mode) => Some((mode, span.clone())),
+                            None => {
+                                ctx.push_error(DatamodelError::new_validation_error(
+                                    &format!(
+                                        "Unknown output_mode `{}`. Expected one of: prompt, tool",
+                                        mode
+                                    ),
+                                    span.clone(),
+                                ));
+                                None
+                            }
+                        },
+                        None => None,
+                    },
+                    None => None,
+                }
+            }
             config => ctx.push_error(DatamodelError::new_validation_error(
                 &format!("Unknown field `{}` in function", config),
                 field.span().clone(),
@@ -372,6 +443,8 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
                     dependencies: (input_deps.clone(), output_deps),
                     prompt: Some(prompt.clone()),
                     client: Some(client),
+                    output_mode,
+                    output_constraints: Vec::new(),
                 },
             );
 
@@ -307,6 +307,18 @@ impl<'db> crate::ParserDatabase {
                 }
                 t
             }
+            FieldType::Literal(arity, literal, ..) => {
+                let mut t = match literal.literal_base_type() {
+                    TypeValue::String => Type::String,
+                    TypeValue::Int => Type::Int,
+                    TypeValue::Bool => Type::Bool,
+                    _ => Type::Unknown,
+                };
+                if arity.is_optional() {
+                    t = Type::None | t;
+                }
+                t
+            }
         };
 
         r
@@ -66,6 +66,10 @@ pub enum AttributeContainer {
     ClassField(super::TypeExpId, super::FieldId),
     Enum(super::TypeExpId),
     EnumValue(super::TypeExpId, super::FieldId),
+    /// Attributes on the return type of a function.
+    FunctionOutput(super::ValExpId),
+    /// Block attributes of a test.
+    TestCase(super::ValExpId),
 }
 
 impl From<super::TypeExpId> for AttributeContainer {
@@ -103,6 +107,11 @@ impl Index<AttributeContainer> for super::SchemaAst {
             AttributeContainer::EnumValue(enum_id, value_idx) => {
                 &self[enum_id][value_idx].attributes
             }
+            AttributeContainer::FunctionOutput(function_id) => self[function_id]
+                .output()
+                .map(|output| output.field_type.attributes())
+                .unwrap_or(&[]),
+            AttributeContainer::TestCase(test_id) => &self[test_id].attributes,
         }
     }
 }
@@ -1,4 +1,4 @@
-use baml_types::TypeValue;
+use baml_types::{JinjaExpression, TypeValue};
 
 use crate::ast::Span;
 use std::fmt;
@@ -159,6 +159,8 @@ pub enum Expression {
     Array(Vec<Expression>, Span),
     /// A mapping function.
     Map(Vec<(Expression, Expression)>, Span),
+    /// A Jinja expression, e.g. `{{ this|length > 0 }}`.
+    JinjaExpressionValue(JinjaExpression, Span),
 }
 
 impl fmt::Display for Expression {
@@ -171,6 +173,7 @@ impl fmt::Display for Expression {
             Expression::RawStringValue(val, ..) => {
                 write!(f, "{}", crate::string_literal(val.value()))
             }
+            Expression::JinjaExpressionValue(val, _) => fmt::Display::fmt(val, f),
             Expression::Array(vals, _) => {
                 let vals = vals
                     .iter()
@@ -280,6 +283,13 @@ impl Expression {
         }
     }
 
+    pub fn as_jinja_expression(&self) -> Option<(&JinjaExpression, &Span)> {
+        match self {
+            Expression::JinjaExpressionValue(expr, span) => Some((expr, span)),
+            _ => None,
+        }
+    }
+
     pub fn as_numeric_value(&self) -> Option<(&str, &Span)> {
         match self {
             Expression::NumericValue(s, span) => Some((s, span)),
@@ -296,6 +306,7 @@ impl Expression {
             Self::Identifier(id) => id.span(),
             Self::Map(_, span) => span,
             Self::Array(_, span) => span,
+            Self::JinjaExpressionValue(_, span) => span,
         }
     }
 
@@ -319,6 +330,7 @@ impl Expression {
             },
             Expression::Map(_, _) => "map",
             Expression::Array(_, _) => "array",
+            Expression::JinjaExpressionValue(_, _) => "jinja_expression",
         }
     }
 
@@ -367,6 +379,8 @@ impl Expression {
                 });
             },
             (Map(_,_), _) => panic!("Types do not match: {:?} and {:?}", self, other),
+            (JinjaExpressionValue(j1,_), JinjaExpressionValue(j2,_)) => assert_eq!(j1, j2),
+            (JinjaExpressionValue(_,_), _) => panic!("Types do not match: {:?} and {:?}", self, other),
 
         }
     }
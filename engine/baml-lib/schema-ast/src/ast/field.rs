@@ -1,4 +1,4 @@
-use baml_types::TypeValue;
+use baml_types::{LiteralValue, TypeValue};
 use internal_baml_diagnostics::DatamodelError;
 
 use super::{
@@ -113,6 +113,7 @@ impl FieldArity {
 pub enum FieldType {
     Symbol(FieldArity, Identifier, Option<Vec<Attribute>>),
     Primitive(FieldArity, TypeValue, Span, Option<Vec<Attribute>>),
+    Literal(FieldArity, LiteralValue, Span, Option<Vec<Attribute>>),
     // The second field is the number of dims for the list
     List(
         FieldArity,
@@ -137,6 +138,7 @@ impl FieldType {
         match self {
             FieldType::Symbol(_, name, ..) => name.name().to_string(),
             FieldType::Primitive(_, name, ..) => name.to_string(),
+            FieldType::Literal(_, value, ..) => value.to_string(),
             _ => "Unknown".to_string(),
         }
     }
@@ -144,6 +146,7 @@ impl FieldType {
     pub fn span(&self) -> &Span {
         match self {
             FieldType::Primitive(.., span, _) => span,
+            FieldType::Literal(.., span, _) => span,
             FieldType::Symbol(.., idn, _) => idn.span(),
             FieldType::Union(.., span, _) => span,
             FieldType::Tuple(.., span, _) => span,
@@ -160,6 +163,7 @@ impl FieldType {
         match &mut as_nullable {
             FieldType::Symbol(ref mut arity, ..) => *arity = FieldArity::Optional,
             FieldType::Primitive(ref mut arity, ..) => *arity = FieldArity::Optional,
+            FieldType::Literal(ref mut arity, ..) => *arity = FieldArity::Optional,
             FieldType::Union(ref mut arity, ..) => *arity = FieldArity::Optional,
             FieldType::Tuple(ref mut arity, ..) => *arity = FieldArity::Optional,
             FieldType::Map(ref mut arity, ..) => *arity = FieldArity::Optional,
@@ -177,6 +181,7 @@ impl FieldType {
             }
             FieldType::Tuple(arity, _, _, _) => arity.is_optional(),
             FieldType::Primitive(arity, _, _, _) => arity.is_optional(),
+            FieldType::Literal(arity, _, _, _) => arity.is_optional(),
             FieldType::Map(arity, _kv, _, _) => arity.is_optional(),
             FieldType::List(arity, _t, _, _, _) => arity.is_optional(),
         }
@@ -197,7 +202,7 @@ impl FieldType {
                 idns
             }
             FieldType::List(_, t, ..) => t.flat_idns(),
-            FieldType::Primitive(..) => vec![],
+            FieldType::Primitive(..) | FieldType::Literal(..) => vec![],
         }
     }
 
@@ -205,6 +210,7 @@ impl FieldType {
         match self {
             FieldType::Symbol(.., attr)
             | FieldType::Primitive(.., attr)
+            | FieldType::Literal(.., attr)
             | FieldType::Union(.., attr)
             | FieldType::Tuple(.., attr)
             | FieldType::Map(.., attr)
@@ -216,6 +222,7 @@ impl FieldType {
         match self {
             FieldType::Symbol(.., attr)
             | FieldType::Primitive(.., attr)
+            | FieldType::Literal(.., attr)
             | FieldType::Union(.., attr)
             | FieldType::Tuple(.., attr)
             | FieldType::Map(.., attr)
@@ -227,6 +234,7 @@ impl FieldType {
         match self {
             FieldType::Symbol(.., attr)
             | FieldType::Primitive(.., attr)
+            | FieldType::Literal(.., attr)
             | FieldType::Union(.., attr)
             | FieldType::Tuple(.., attr)
             | FieldType::Map(.., attr)
@@ -238,6 +246,7 @@ impl FieldType {
         match self {
             FieldType::Symbol(.., attr)
             | FieldType::Primitive(.., attr)
+            | FieldType::Literal(.., attr)
             | FieldType::Union(.., attr)
             | FieldType::Tuple(.., attr)
             | FieldType::Map(.., attr)
@@ -288,6 +297,18 @@ impl FieldType {
                     other.to_string()
                 )
             }
+            (Literal(arity1, value1, _, attrs1), Literal(arity2, value2, _, attrs2)) => {
+                assert_eq!(arity1, arity2);
+                assert_eq!(value1, value2);
+                attrs_eq(attrs1, attrs2);
+            }
+            (Literal(..), _) => {
+                panic!(
+                    "Different types: \n{}\n---\n{}",
+                    self.to_string(),
+                    other.to_string()
+                )
+            }
             (List(arity1, inner1, dims1, _, attrs1), List(arity2, inner2, dims2, _, attrs2)) => {
                 assert_eq!(arity1, arity2);
                 inner1.assert_eq_up_to_span(inner2);
@@ -395,6 +416,9 @@ impl std::fmt::Display for FieldType {
             FieldType::Primitive(arity, t, ..) => {
                 write!(f, "{}{}", t, if arity.is_optional() { "?" } else { "" })
             }
+            FieldType::Literal(arity, v, ..) => {
+                write!(f, "{}{}", v, if arity.is_optional() { "?" } else { "" })
+            }
         }
     }
 }
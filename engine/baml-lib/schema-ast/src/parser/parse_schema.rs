@@ -202,6 +202,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_constraint_attributes() {
+        let input = r#"
+            class Person {
+                age int @assert({{ this >= 0 }}) @check(adult, {{ this >= 18 }})
+            }
+        "#;
+
+        let root_path = "test_file.baml";
+        let source = SourceFile::new_static(root_path.into(), input);
+
+        let (schema_ast, _) = parse_schema(&root_path.into(), &source).unwrap();
+
+        match &schema_ast.tops[0] {
+            Top::Class(model) => {
+                let attributes = &model.fields[0].attributes;
+                assert_eq!(attributes.len(), 2);
+
+                let assert_args = &attributes[0].arguments.arguments;
+                assert_eq!(
+                    assert_args[0].value.as_jinja_expression().unwrap().0 .0,
+                    "this >= 0"
+                );
+
+                let check_args = &attributes[1].arguments.arguments;
+                assert_eq!(check_args[0].value.as_string_value().unwrap().0, "adult");
+                assert_eq!(
+                    check_args[1].value.as_jinja_expression().unwrap().0 .0,
+                    "this >= 18"
+                );
+            }
+            _ => panic!("Expected a model declaration"),
+        }
+    }
+
+    #[test]
+    fn test_parse_test_block_attributes() {
+        let input = r##"
+          test CountWordsTest {
+            functions [CountWords]
+            args {
+              text "one two three"
+            }
+            @@assert(three_words, {{ this == 3 }})
+            @@check({{ this > 0 }})
+          }
+        "##;
+
+        let root_path = "test_file.baml";
+        let source = SourceFile::new_static(root_path.into(), input);
+
+        let (schema_ast, _) = parse_schema(&root_path.into(), &source).unwrap();
+
+        match &schema_ast.tops[0] {
+            Top::TestCase(test) => {
+                assert_eq!(test.fields().len(), 2);
+                assert_eq!(test.attributes.len(), 2);
+                assert_eq!(test.attributes[0].name(), "assert");
+                assert_eq!(test.attributes[1].name(), "check");
+            }
+            _ => panic!("Expected a test declaration"),
+        }
+    }
+
     #[test]
     fn test_example() {
         let input = r##"
@@ -2,11 +2,14 @@ use super::{helpers::Pair, parse_attribute::parse_attribute, Rule};
 use crate::{
     assert_correct_parser,
     ast::*,
-    parser::{parse_field::parse_field_type_with_attr, parse_identifier::parse_identifier},
+    parser::{
+        parse_expression::unescape_string, parse_field::parse_field_type_with_attr,
+        parse_identifier::parse_identifier,
+    },
     unreachable_rule,
 };
-use baml_types::TypeValue;
-use internal_baml_diagnostics::Diagnostics;
+use baml_types::{LiteralValue, TypeValue};
+use internal_baml_diagnostics::{DatamodelError, Diagnostics};
 
 pub fn parse_field_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<FieldType> {
     assert_correct_parser!(pair, Rule::field_type, Rule::openParan, Rule::closeParan);
@@ -135,6 +138,12 @@ fn parse_base_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<Fiel
                         diagnostics.span(current.as_span()),
                         None,
                     ),
+                    "true" | "false" => FieldType::Literal(
+                        FieldArity::Required,
+                        LiteralValue::Bool(current.as_str() == "true"),
+                        diagnostics.span(current.as_span()),
+                        None,
+                    ),
                     _ => FieldType::Symbol(
                         FieldArity::Required,
                         Identifier::Local(
@@ -146,6 +155,7 @@ fn parse_base_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<Fiel
                 };
                 Some(field_type)
             }
+            Rule::literal_type => parse_literal_type(current, diagnostics),
             Rule::array_notation => parse_array(current, diagnostics),
             Rule::map => parse_map(current, diagnostics),
             Rule::group => parse_group(current, diagnostics),
@@ -158,6 +168,44 @@ fn parse_base_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<Fiel
     unreachable!("A base type must be one of the above");
 }
 
+fn parse_literal_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<FieldType> {
+    assert_correct_parser!(pair, Rule::literal_type);
+
+    let span = diagnostics.span(pair.as_span());
+    let Some(current) = pair.into_inner().next() else {
+        unreachable!("A literal type must have a value");
+    };
+
+    let value = match current.as_rule() {
+        Rule::quoted_string_literal => {
+            let contents = current.into_inner().next().unwrap();
+            LiteralValue::String(unescape_string(contents.as_str()))
+        }
+        Rule::numeric_literal => match current.as_str().parse::<i64>() {
+            Ok(int) => LiteralValue::Int(int),
+            Err(_) => {
+                diagnostics.push_error(DatamodelError::new_validation_error(
+                    &format!(
+                        "Only string, int and bool literal types are supported, got `{}`",
+                        current.as_str()
+                    ),
+                    span.clone(),
+                ));
+                // Keep going with the closest type so later passes still see a field type.
+                return Some(FieldType::Primitive(
+                    FieldArity::Required,
+                    TypeValue::Float,
+                    span,
+                    None,
+                ));
+            }
+        },
+        _ => unreachable_rule!(current, Rule::literal_type),
+    };
+
+    Some(FieldType::Literal(FieldArity::Required, value, span, None))
+}
+
 fn parse_parenthesized_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<FieldType> {
     assert_correct_parser!(pair, Rule::parenthesized_type);
 
@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use crate::internal::llm_client::cassette::{
+    CassetteMode, CASSETTE_DIR_ENV_VAR, CASSETTE_MODE_ENV_VAR,
+};
+
+/// Flags for recording and replaying LLM traffic, shared by every command that
+/// calls functions.
+#[derive(clap::Args, Clone, Debug)]
+pub struct CassetteArgs {
+    #[arg(
+        long,
+        value_enum,
+        help = "Record LLM responses to cassettes, or replay them instead of calling the provider"
+    )]
+    pub(super) cassette_mode: Option<CassetteMode>,
+    #[arg(
+        long,
+        help = "Directory to store cassettes in [default: ./baml_cassettes]"
+    )]
+    pub(super) cassette_dir: Option<PathBuf>,
+}
+
+impl CassetteArgs {
+    /// Runtimes built by the CLI read their env vars from the process, so the
+    /// flags are applied there, overriding BAML_CASSETTE_MODE/BAML_CASSETTE_DIR.
+    pub(super) fn apply(&self) {
+        if let Some(mode) = self.cassette_mode {
+            std::env::set_var(CASSETTE_MODE_ENV_VAR, mode.as_str());
+        }
+        if let Some(dir) = &self.cassette_dir {
+            std::env::set_var(CASSETTE_DIR_ENV_VAR, dir);
+        }
+    }
+}
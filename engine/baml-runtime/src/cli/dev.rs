@@ -1,12 +1,14 @@
 use anyhow::Result;
 use notify_debouncer_full::{new_debouncer, notify::*};
-use std::ops::DerefMut;
 use std::path::PathBuf;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use crate::{cli::generate::GenerateArgs, BamlRuntime};
 
-use super::serve::Server;
+use super::{
+    cassette::CassetteArgs,
+    serve::{ReloadOutcome, Server},
+};
 
 #[derive(clap::Args, Clone, Debug)]
 pub struct DevArgs {
@@ -16,6 +18,8 @@ pub struct DevArgs {
     port: u16,
     #[arg(long, help = "turn on preview features", default_value = "false")]
     preview: bool,
+    #[command(flatten)]
+    pub(super) cassette: CassetteArgs,
 }
 
 impl DevArgs {
@@ -109,32 +113,12 @@ Thanks for trying out BAML!
                                 n => format!("{} files changed", n),
                             }
                         );
-                        let start = Instant::now();
-                        match BamlRuntime::from_directory(&self.from, std::env::vars().collect()) {
-                            Ok(mut new_runtime) => {
-                                let elapsed = start.elapsed();
-                                let _ = GenerateArgs {
-                                    from: self.from.clone(),
-                                    no_version_check: false,
-                                }
-                                .run(defaults);
-
-                                std::mem::swap(
-                                    server.b.write().await.deref_mut(),
-                                    &mut new_runtime,
-                                );
-                                log::info!(
-                                    "Reloaded runtime in {}ms ({})",
-                                    elapsed.as_millis(),
-                                    match events.len() {
-                                        1 => "1 file changed".to_string(),
-                                        n => format!("{} files changed", n),
-                                    }
-                                );
-                            }
-                            Err(e) => {
-                                log::warn!("Failed to reload runtime: {:?}", e);
+                        if let ReloadOutcome::Reloaded { .. } = server.reload().await {
+                            let _ = GenerateArgs {
+                                from: self.from.clone(),
+                                no_version_check: false,
                             }
+                            .run(defaults);
                         }
                     }
                     Err(errors) => {
@@ -1,7 +1,10 @@
+mod cassette;
 mod dev;
 mod generate;
 mod init;
 mod serve;
+mod test;
+mod traces;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
@@ -28,6 +31,10 @@ enum Commands {
     Serve(serve::ServeArgs),
     #[command(about = "Starts a development server")]
     Dev(dev::DevArgs),
+    #[command(about = "Runs the tests in the baml_src directory")]
+    Test(test::TestArgs),
+    #[command(about = "Lists and prints the calls recorded in BAML_TRACE_DIR")]
+    Traces(traces::TracesArgs),
 }
 
 /// Default values for the CLI to use.
@@ -55,12 +62,20 @@ impl RuntimeCli {
             Commands::Init(args) => args.run(defaults),
             Commands::Serve(args) => {
                 args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                args.cassette.apply();
                 args.run()
             }
             Commands::Dev(args) => {
                 args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                args.cassette.apply();
                 args.run(defaults)
             }
+            Commands::Test(args) => {
+                args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                args.cassette.apply();
+                args.run()
+            }
+            Commands::Traces(args) => args.run(),
         }
     }
 }
@@ -0,0 +1,302 @@
+use baml_types::{BamlValue, FieldType};
+use indexmap::IndexMap;
+use internal_baml_core::ir::{repr::IntermediateRepr, IRHelper};
+use serde::{Deserialize, Serialize};
+
+use super::error::BamlError;
+use crate::type_builder::{TypeBuilder, WithMeta};
+
+/// The `type_builder` field of `__baml_options__`: the HTTP equivalent of
+/// building a `TypeBuilder` in one of the language clients.
+///
+/// Only classes and enums marked `@@dynamic` may be modified.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicTypes {
+    #[serde(default)]
+    pub classes: IndexMap<String, DynamicClass>,
+    #[serde(default)]
+    pub enums: IndexMap<String, DynamicEnum>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicClass {
+    #[serde(default)]
+    pub properties: IndexMap<String, DynamicProperty>,
+}
+
+/// A property with a `type` is added to the class; one without only updates
+/// the alias or description of an existing field.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicProperty {
+    pub r#type: Option<DynamicFieldType>,
+    pub alias: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicEnum {
+    pub alias: Option<String>,
+    #[serde(default)]
+    pub values: IndexMap<String, DynamicEnumValue>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicEnumValue {
+    pub alias: Option<String>,
+    pub description: Option<String>,
+    pub skip: Option<bool>,
+}
+
+/// A field type, e.g. `{"type": "list", "items": {"type": "class", "name": "Person"}}`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DynamicFieldType {
+    String,
+    Int,
+    Float,
+    Bool,
+    Null,
+    Class {
+        name: String,
+    },
+    Enum {
+        name: String,
+    },
+    List {
+        items: Box<DynamicFieldType>,
+    },
+    Map {
+        key: Box<DynamicFieldType>,
+        value: Box<DynamicFieldType>,
+    },
+    Union {
+        types: Vec<DynamicFieldType>,
+    },
+    Optional {
+        inner: Box<DynamicFieldType>,
+    },
+}
+
+fn invalid(message: String) -> BamlError {
+    BamlError::InvalidArgument {
+        message: format!("Invalid __baml_options__.type_builder: {}", message),
+    }
+}
+
+fn string_meta(value: &Option<String>) -> Option<BamlValue> {
+    value.as_ref().map(|s| BamlValue::String(s.clone()))
+}
+
+impl DynamicFieldType {
+    fn to_field_type(&self, ir: &IntermediateRepr) -> Result<FieldType, BamlError> {
+        Ok(match self {
+            DynamicFieldType::String => FieldType::string(),
+            DynamicFieldType::Int => FieldType::int(),
+            DynamicFieldType::Float => FieldType::float(),
+            DynamicFieldType::Bool => FieldType::bool(),
+            DynamicFieldType::Null => FieldType::null(),
+            DynamicFieldType::Class { name } => {
+                ir.find_class(name).map_err(|e| invalid(e.to_string()))?;
+                FieldType::class(name)
+            }
+            DynamicFieldType::Enum { name } => {
+                ir.find_enum(name).map_err(|e| invalid(e.to_string()))?;
+                FieldType::r#enum(name)
+            }
+            DynamicFieldType::List { items } => items.to_field_type(ir)?.as_list(),
+            DynamicFieldType::Map { key, value } => {
+                FieldType::map(key.to_field_type(ir)?, value.to_field_type(ir)?)
+            }
+            DynamicFieldType::Union { types } => {
+                if types.is_empty() {
+                    return Err(invalid("a union needs at least one type".to_string()));
+                }
+                FieldType::union(
+                    types
+                        .iter()
+                        .map(|t| t.to_field_type(ir))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            DynamicFieldType::Optional { inner } => inner.to_field_type(ir)?.as_optional(),
+        })
+    }
+}
+
+impl DynamicTypes {
+    /// Checks the payload against the IR and converts it into the
+    /// `TypeBuilder` used for a single request.
+    pub fn to_type_builder(&self, ir: &IntermediateRepr) -> Result<TypeBuilder, BamlError> {
+        let tb = TypeBuilder::new();
+
+        for (class_name, class) in &self.classes {
+            let walker = ir
+                .find_class(class_name)
+                .map_err(|e| invalid(e.to_string()))?;
+            if walker.item.attributes.get("dynamic_type").is_none() {
+                return Err(invalid(format!(
+                    "class {} is not marked @@dynamic",
+                    class_name
+                )));
+            }
+
+            let cls = tb.class(class_name);
+            let cls = cls.lock().unwrap();
+            for (property_name, property) in &class.properties {
+                let is_static = walker.find_field(property_name).is_some();
+                let field_type = match &property.r#type {
+                    Some(_) if is_static => {
+                        return Err(invalid(format!(
+                            "property {} is already defined on class {}",
+                            property_name, class_name
+                        )))
+                    }
+                    Some(t) => Some(t.to_field_type(ir)?),
+                    None if is_static => None,
+                    None => {
+                        return Err(invalid(format!(
+                            "new property {} on class {} needs a type",
+                            property_name, class_name
+                        )))
+                    }
+                };
+
+                let builder = cls.property(property_name);
+                let builder = builder.lock().unwrap();
+                if let Some(field_type) = field_type {
+                    builder.r#type(field_type);
+                }
+                if let Some(alias) = string_meta(&property.alias) {
+                    builder.with_meta("alias", alias);
+                }
+                if let Some(description) = string_meta(&property.description) {
+                    builder.with_meta("description", description);
+                }
+            }
+        }
+
+        for (enum_name, r#enum) in &self.enums {
+            let walker = ir
+                .find_enum(enum_name)
+                .map_err(|e| invalid(e.to_string()))?;
+            if walker.item.attributes.get("dynamic_type").is_none() {
+                return Err(invalid(format!(
+                    "enum {} is not marked @@dynamic",
+                    enum_name
+                )));
+            }
+
+            let enm = tb.r#enum(enum_name);
+            let enm = enm.lock().unwrap();
+            if let Some(alias) = string_meta(&r#enum.alias) {
+                enm.with_meta("alias", alias);
+            }
+            for (value_name, value) in &r#enum.values {
+                let builder = enm.value(value_name);
+                let builder = builder.lock().unwrap();
+                if let Some(alias) = string_meta(&value.alias) {
+                    builder.with_meta("alias", alias);
+                }
+                if let Some(description) = string_meta(&value.description) {
+                    builder.with_meta("description", description);
+                }
+                if let Some(skip) = value.skip {
+                    builder.with_meta("skip", BamlValue::Bool(skip));
+                }
+            }
+        }
+
+        Ok(tb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BamlRuntime, InternalRuntimeInterface};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    const BAML: &str = r##"
+      class Person {
+        name string
+        @@dynamic
+      }
+
+      class Address {
+        street string
+      }
+
+      enum Hobby {
+        READING
+        @@dynamic
+      }
+    "##;
+
+    fn runtime() -> BamlRuntime {
+        let mut files = HashMap::new();
+        files.insert("main.baml", BAML);
+        BamlRuntime::from_file_content("baml_src", &files, HashMap::<&str, &str>::new()).unwrap()
+    }
+
+    fn parse(value: serde_json::Value) -> DynamicTypes {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn converts_to_overrides() {
+        let rt = runtime();
+        let types = parse(json!({
+            "classes": {
+                "Person": {
+                    "properties": {
+                        "name": { "alias": "full_name" },
+                        "hobbies": {
+                            "type": { "type": "list", "items": { "type": "enum", "name": "Hobby" } },
+                            "description": "What they do for fun",
+                        },
+                    },
+                },
+            },
+            "enums": {
+                "Hobby": { "values": { "CHESS": { "alias": "chess" }, "READING": { "skip": true } } },
+            },
+        }));
+
+        let (classes, enums) = types.to_type_builder(rt.inner.ir()).unwrap().to_overrides();
+        let person = &classes["Person"];
+        assert_eq!(person.new_fields["hobbies"].0.to_string(), "Hobby[]");
+        assert_eq!(
+            person.update_fields["name"].alias,
+            Some(BamlValue::String("full_name".to_string()))
+        );
+        let hobby = &enums["Hobby"];
+        assert_eq!(hobby.values["READING"].skip, Some(true));
+        assert!(hobby.values.contains_key("CHESS"));
+    }
+
+    #[test]
+    fn rejects_invalid_types() {
+        let rt = runtime();
+        let ir = rt.inner.ir();
+        let invalid = [
+            json!({ "classes": { "Address": { "properties": { "zip": { "type": { "type": "string" } } } } } }),
+            json!({ "classes": { "Person": { "properties": { "age": {} } } } }),
+            json!({ "classes": { "Person": { "properties": { "name": { "type": { "type": "int" } } } } } }),
+            json!({ "classes": { "Person": { "properties": { "pet": { "type": { "type": "class", "name": "Pet" } } } } } }),
+            json!({ "enums": { "Color": { "values": { "RED": {} } } } }),
+        ];
+        for value in invalid {
+            assert!(
+                parse(value.clone()).to_type_builder(ir).is_err(),
+                "{}",
+                value
+            );
+        }
+    }
+}
@@ -27,8 +27,12 @@ pub enum BamlError {
         raw_output: String,
         message: String,
     },
-    /// This is the only variant not documented at the aforementioned link:
-    /// this is the catch-all for unclassified errors.
+    /// Not documented at the aforementioned link: returned by the introspection
+    /// endpoints for unknown functions.
+    #[serde(rename_all = "snake_case")]
+    NotFound { message: String },
+    /// Not documented at the aforementioned link either: this is the catch-all
+    /// for unclassified errors.
     #[serde(rename_all = "snake_case")]
     InternalError { message: String },
 }
@@ -61,6 +65,8 @@ impl BamlError {
                         message: format!("Something went wrong with the LLM client: {:?}", err),
                     },
                     crate::internal::llm_client::ErrorCode::Other(_)
+                    | crate::internal::llm_client::ErrorCode::Timeout
+                    | crate::internal::llm_client::ErrorCode::Cancelled
                     | crate::internal::llm_client::ErrorCode::InvalidAuthentication
                     | crate::internal::llm_client::ErrorCode::NotSupported
                     | crate::internal::llm_client::ErrorCode::RateLimited
@@ -94,6 +100,7 @@ impl IntoResponse for BamlError {
                 BamlError::InvalidArgument { .. } => StatusCode::BAD_REQUEST,
                 BamlError::ClientError { .. } => StatusCode::BAD_GATEWAY,
                 BamlError::ValidationFailure { .. } => StatusCode::INTERNAL_SERVER_ERROR, // ??? - FIXME
+                BamlError::NotFound { .. } => StatusCode::NOT_FOUND,
                 BamlError::InternalError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             },
             Json(match serde_json::to_value(&self) {
@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use axum::{
+    extract,
+    response::{IntoResponse, Response},
+};
+use baml_types::{BamlMap, BamlValue};
+use internal_baml_core::ir::{FunctionWalker, IRHelper};
+use serde::Serialize;
+use serde_json::json;
+
+use super::{error::BamlError, json_response::Json, Server};
+use crate::{BamlRuntime, InternalRuntimeInterface, RuntimeContextManager};
+
+/// A function's signature, as listed by `GET /functions`. `inputs` and
+/// `output` are JSON schemas, which refer to the classes and enums in
+/// `GET /openapi.json`.
+#[derive(Serialize, Debug)]
+struct FunctionSummary {
+    name: String,
+    inputs: serde_json::Value,
+    output: serde_json::Value,
+}
+
+/// Everything about a function, as returned by `GET /functions/:name`.
+#[derive(Serialize, Debug)]
+struct FunctionDetails {
+    #[serde(flatten)]
+    summary: FunctionSummary,
+    client: Option<String>,
+    tests: Vec<TestSummary>,
+}
+
+#[derive(Serialize, Debug)]
+struct TestSummary {
+    name: String,
+    // None if the args can't be resolved, e.g. because of a missing env var.
+    args: Option<BamlMap<String, BamlValue>>,
+}
+
+impl TryFrom<&FunctionWalker<'_>> for FunctionSummary {
+    type Error = BamlError;
+
+    fn try_from(f: &FunctionWalker<'_>) -> Result<Self, BamlError> {
+        let (inputs, output) =
+            internal_baml_codegen::openapi::function_schemas(*f).map_err(|e| {
+                BamlError::InternalError {
+                    message: format!("Failed to describe function {}: {:#}", f.name(), e),
+                }
+            })?;
+        Ok(Self {
+            name: f.name().to_string(),
+            inputs,
+            output,
+        })
+    }
+}
+
+fn function_details(runtime: &BamlRuntime, name: &str) -> Result<FunctionDetails, BamlError> {
+    let f = runtime
+        .inner
+        .ir()
+        .find_function(name)
+        .map_err(|e| BamlError::NotFound {
+            message: e.to_string(),
+        })?;
+
+    let ctx = RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None)
+        .create_ctx(None, None)
+        .map_err(BamlError::from_anyhow)?;
+    let tests = f
+        .walk_tests()
+        .map(|t| {
+            let test_name = t.item.1.elem.name.as_str();
+            TestSummary {
+                name: test_name.to_string(),
+                args: runtime.get_test_params(name, test_name, &ctx).ok(),
+            }
+        })
+        .collect();
+
+    Ok(FunctionDetails {
+        summary: FunctionSummary::try_from(&f)?,
+        client: f.client_name().map(str::to_string),
+        tests,
+    })
+}
+
+impl Server {
+    pub(super) async fn list_functions(self: Arc<Self>) -> Response {
+        let runtime = self.runtime().await;
+        let functions = runtime
+            .inner
+            .ir()
+            .walk_functions()
+            .map(|f| FunctionSummary::try_from(&f))
+            .collect::<Result<Vec<_>, _>>();
+        match functions {
+            Ok(functions) => Json(json!({ "functions": functions })).into_response(),
+            Err(e) => e.into_response(),
+        }
+    }
+
+    pub(super) async fn get_function(
+        self: Arc<Self>,
+        extract::Path(name): extract::Path<String>,
+    ) -> Response {
+        match function_details(&self.runtime().await, &name) {
+            Ok(details) => Json(details).into_response(),
+            Err(e) => e.into_response(),
+        }
+    }
+
+    /// Generated from the loaded IR on every request, so that it always
+    /// matches the functions being served.
+    pub(super) async fn openapi_json(self: Arc<Self>) -> Response {
+        match internal_baml_codegen::openapi::schema(self.runtime().await.inner.ir()) {
+            Ok(mut schema) => {
+                if let Some(address) = schema.pointer_mut("/servers/0/variables/address/default") {
+                    *address = format!("http://localhost:{}", self.port).into();
+                }
+                Json(schema).into_response()
+            }
+            Err(e) => BamlError::InternalError {
+                message: format!("Failed to generate OpenAPI schema: {:?}", e),
+            }
+            .into_response(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const BAML: &str = r##"
+      client<llm> GPT4 {
+        provider openai
+        options {
+          model gpt-4o
+          api_key "sk-test"
+        }
+      }
+
+      class Resume {
+        name string
+        skills string[]
+      }
+
+      function ExtractResume(resume: string, limit: int?) -> Resume {
+        client GPT4
+        prompt #"{{ resume }}"#
+      }
+
+      test JohnDoe {
+        functions [ExtractResume]
+        args {
+          resume "John Doe"
+        }
+      }
+    "##;
+
+    fn runtime() -> BamlRuntime {
+        let mut files = HashMap::new();
+        files.insert("main.baml", BAML);
+        BamlRuntime::from_file_content("baml_src", &files, HashMap::<&str, &str>::new()).unwrap()
+    }
+
+    #[test]
+    fn describes_function() {
+        let details = function_details(&runtime(), "ExtractResume").unwrap();
+        assert_eq!(
+            serde_json::to_value(&details.summary).unwrap(),
+            json!({
+                "name": "ExtractResume",
+                "inputs": {
+                    "type": "object",
+                    "properties": {
+                        "resume": { "type": "string" },
+                        "limit": { "type": "integer" },
+                    },
+                    "required": ["resume"],
+                    "additionalProperties": false,
+                },
+                "output": { "$ref": "#/components/schemas/Resume" },
+            })
+        );
+        assert_eq!(details.client.as_deref(), Some("GPT4"));
+        assert_eq!(details.tests.len(), 1);
+        assert_eq!(details.tests[0].name, "JohnDoe");
+        assert_eq!(
+            details.tests[0].args.as_ref().unwrap()["resume"],
+            BamlValue::String("John Doe".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_function() {
+        assert!(matches!(
+            function_details(&runtime(), "ExtractResumes"),
+            Err(BamlError::NotFound { .. })
+        ));
+    }
+}
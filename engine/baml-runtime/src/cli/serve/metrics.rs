@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use axum::response::{IntoResponse, Response};
+use http::header;
+
+use super::Server;
+
+impl Server {
+    /// The runtime's metrics in the Prometheus text exposition format.
+    pub(super) async fn metrics_handler(self: Arc<Self>) -> Response {
+        (
+            [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+            self.runtime().await.metrics().to_prometheus(),
+        )
+            .into_response()
+    }
+}
@@ -1,10 +1,15 @@
 mod arg_validation;
+mod dynamic_types;
 mod error;
+mod functions;
 mod json_response;
+mod metrics;
 mod ping;
+mod reload;
 use error::BamlError;
 use indexmap::IndexMap;
 use json_response::Json;
+pub(super) use reload::ReloadOutcome;
 
 use anyhow::{Context, Result};
 use arg_validation::BamlServeValidate;
@@ -16,7 +21,7 @@ use axum::{
         sse::{Event, KeepAlive, Sse},
         IntoResponse, Response,
     },
-    routing::{any, post},
+    routing::{any, get, post},
 };
 use axum_extra::{
     headers::{self, authorization::Basic, Authorization, Header},
@@ -24,16 +29,24 @@ use axum_extra::{
 };
 use baml_types::BamlValue;
 use core::pin::Pin;
+use dynamic_types::DynamicTypes;
 use futures::Stream;
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{RecursiveMode, Watcher},
+    DebounceEventResult,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::{path::PathBuf, sync::Arc, task::Poll};
+use std::{path::PathBuf, sync::Arc, task::Poll, time::Duration};
 use tokio::{net::TcpListener, sync::RwLock};
 use tokio_stream::StreamExt;
 
+use super::cassette::CassetteArgs;
 use crate::{
     client_registry::ClientRegistry, errors::ExposedError, internal::llm_client::LLMResponse,
-    BamlRuntime, FunctionResult, RuntimeContextManager,
+    type_builder::TypeBuilder, BamlRuntime, CancelOnDrop, CancellationToken, FunctionResult,
+    InternalRuntimeInterface, RuntimeContextManager,
 };
 
 #[derive(clap::Args, Clone, Debug)]
@@ -50,11 +63,20 @@ pub struct ServeArgs {
         default_value_t = false
     )]
     no_version_check: bool,
+    #[arg(
+        long,
+        help = "reload baml_src when it changes",
+        default_value_t = false
+    )]
+    watch: bool,
+    #[command(flatten)]
+    pub(super) cassette: CassetteArgs,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct BamlOptions {
     pub client_registry: Option<ClientRegistry>,
+    pub type_builder: Option<DynamicTypes>,
 }
 
 impl ServeArgs {
@@ -108,6 +130,44 @@ Thanks for trying out BAML!
 
         let (server, tcp_listener) = t.block_on(Server::new(self.from.clone(), self.port))?;
 
+        // Watching stops when the debouncer is dropped.
+        let _debouncer = if self.watch {
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let mut debouncer = new_debouncer(
+                Duration::from_millis(200),
+                None,
+                move |result: DebounceEventResult| {
+                    let _ = tx.send(result);
+                },
+            )?;
+            debouncer
+                .watcher()
+                .watch(self.from.as_path(), RecursiveMode::Recursive)?;
+
+            let server = server.clone();
+            let from = self.from.clone();
+            t.spawn(async move {
+                while let Some(result) = rx.recv().await {
+                    match result {
+                        Ok(_) => {
+                            server.reload().await;
+                        }
+                        Err(errors) => {
+                            log::warn!(
+                                "Encountered errors while watching {}: {:?}",
+                                from.display(),
+                                errors
+                            );
+                        }
+                    }
+                }
+            });
+            log::info!("Watching {} for changes", self.from.display());
+            Some(debouncer)
+        } else {
+            None
+        };
+
         t.block_on(server.serve(tcp_listener))?;
 
         Ok(())
@@ -122,7 +182,9 @@ Thanks for trying out BAML!
 pub(super) struct Server {
     src_dir: PathBuf,
     port: u16,
-    pub(super) b: Arc<RwLock<BamlRuntime>>,
+    pub(super) b: Arc<RwLock<Arc<BamlRuntime>>>,
+    // Held for the duration of a reload.
+    reloading: tokio::sync::Mutex<()>,
 }
 
 #[derive(Debug)]
@@ -208,10 +270,11 @@ impl Server {
             Arc::new(Self {
                 src_dir: src_dir.clone(),
                 port,
-                b: Arc::new(RwLock::new(BamlRuntime::from_directory(
+                b: Arc::new(RwLock::new(Arc::new(BamlRuntime::from_directory(
                     &src_dir,
                     std::env::vars().collect(),
-                )?)),
+                )?))),
+                reloading: Default::default(),
             }),
             tcp_listener,
         ))
@@ -285,6 +348,9 @@ impl Server {
         let app = app.route("/_debug/ping", any(ping::ping_handler));
         let app = app.route("/_debug/status", any(status_handler));
 
+        let s = self.clone();
+        let app = app.route("/_admin/reload", post(move || s.clone().reload_handler()));
+
         let s = self.clone();
         let app = app.route(
             "/call/:msg",
@@ -297,6 +363,21 @@ impl Server {
             post(move |b_fn, b_args| s.clone().baml_stream_axum2(b_fn, b_args)),
         );
 
+        let s = self.clone();
+        let app = app.route("/functions", get(move || s.clone().list_functions()));
+
+        let s = self.clone();
+        let app = app.route(
+            "/functions/:name",
+            get(move |name| s.clone().get_function(name)),
+        );
+
+        let s = self.clone();
+        let app = app.route("/openapi.json", get(move || s.clone().openapi_json()));
+
+        let s = self.clone();
+        let app = app.route("/metrics", get(move || s.clone().metrics_handler()));
+
         let service = axum::serve(
             tcp_listener,
             app.layer(axum::middleware::from_fn(Server::auth_middleware)),
@@ -333,6 +414,28 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         Ok(())
     }
 
+    /// The runtime to serve a request with. Holding on to it doesn't block
+    /// reloads, which swap in a new runtime for subsequent requests.
+    async fn runtime(&self) -> Arc<BamlRuntime> {
+        self.b.read().await.clone()
+    }
+
+    /// Splits `__baml_options__` into the client registry and the type builder
+    /// for a single request, validating any dynamic types against the IR.
+    fn resolve_options(
+        runtime: &BamlRuntime,
+        b_options: Option<BamlOptions>,
+    ) -> Result<(Option<ClientRegistry>, Option<TypeBuilder>), BamlError> {
+        let Some(options) = b_options else {
+            return Ok((None, None));
+        };
+        let type_builder = match options.type_builder {
+            Some(dynamic_types) => Some(dynamic_types.to_type_builder(runtime.inner.ir())?),
+            None => None,
+        };
+        Ok((options.client_registry, type_builder))
+    }
+
     async fn baml_call(
         self: Arc<Self>,
         b_fn: String,
@@ -344,13 +447,39 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
             Err(e) => return e.into_response(),
         };
 
-        let ctx_mgr = RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None);
-        let client_registry = b_options.and_then(|options| options.client_registry);
+        let runtime = self.runtime().await;
+        let (client_registry, type_builder) = match Self::resolve_options(&runtime, b_options) {
+            Ok(options) => options,
+            Err(e) => return e.into_response(),
+        };
 
-        let locked = self.b.read().await;
-        let (result, _trace_id) = locked
-            .call_function(b_fn, &args, &ctx_mgr, None, client_registry.as_ref())
-            .await;
+        let cancellation = CancellationToken::new();
+        let ctx_mgr = RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None)
+            .with_cancellation(cancellation.clone());
+
+        // axum drops this future if the client disconnects, which cancels the
+        // call. The call runs in its own task so that it's still traced.
+        let _cancel_on_drop = cancellation.drop_guard();
+        let call = tokio::spawn(async move {
+            runtime
+                .call_function(
+                    b_fn,
+                    &args,
+                    &ctx_mgr,
+                    type_builder.as_ref(),
+                    client_registry.as_ref(),
+                )
+                .await
+        });
+        let (result, _trace_id) = match call.await {
+            Ok(result) => result,
+            Err(e) => {
+                return BamlError::InternalError {
+                    message: format!("Function call panicked: {:?}", e),
+                }
+                .into_response()
+            }
+        };
 
         match result {
             Ok(function_result) => match function_result.llm_response() {
@@ -406,9 +535,9 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         if let Some(options_value) = b_args.get("__baml_options__") {
             match serde_json::from_value::<BamlOptions>(options_value.clone()) {
                 Ok(opts) => b_options = Some(opts),
-                Err(_) => {
+                Err(e) => {
                     return BamlError::InvalidArgument {
-                        message: "Failed to parse __baml_options__".to_string(),
+                        message: format!("Failed to parse __baml_options__: {}", e),
                     }
                     .into_response()
                 }
@@ -417,7 +546,7 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         self.baml_call(b_fn, b_args, b_options).await
     }
 
-    fn baml_stream(
+    async fn baml_stream(
         self: Arc<Self>,
         b_fn: String,
         b_args: serde_json::Value,
@@ -430,17 +559,24 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
             Err(e) => return e.into_response(),
         };
 
-        let client_registry = b_options.and_then(|options| options.client_registry);
+        let runtime = self.runtime().await;
+        let (client_registry, type_builder) = match Self::resolve_options(&runtime, b_options) {
+            Ok(options) => options,
+            Err(e) => return e.into_response(),
+        };
+        let cancellation = CancellationToken::new();
+        let stream_cancellation = cancellation.clone();
 
         tokio::spawn(async move {
             let ctx_mgr =
-                RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None);
+                RuntimeContextManager::new_from_env_vars(std::env::vars().collect(), None)
+                    .with_cancellation(stream_cancellation);
 
-            let result_stream = self.b.read().await.stream_function(
+            let result_stream = runtime.stream_function(
                 b_fn,
                 &args,
                 &ctx_mgr,
-                None,
+                type_builder.as_ref(),
                 client_registry.as_ref(),
             );
 
@@ -520,7 +656,11 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         });
 
         // TODO: streaming is broken. the above should return first.
-        let stream = Box::pin(EventStream { receiver }).map(|bv| Event::default().json_data(bv));
+        let stream = Box::pin(EventStream {
+            receiver,
+            _cancel_on_drop: cancellation.drop_guard(),
+        })
+        .map(|bv| Event::default().json_data(bv));
 
         Sse::new(stream)
             .keep_alive(KeepAlive::default())
@@ -537,20 +677,22 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         if let Some(options_value) = body.get("__baml_options__") {
             match serde_json::from_value::<BamlOptions>(options_value.clone()) {
                 Ok(opts) => b_options = Some(opts),
-                Err(_) => {
+                Err(e) => {
                     return BamlError::InvalidArgument {
-                        message: "Failed to parse __baml_options__".to_string(),
+                        message: format!("Failed to parse __baml_options__: {}", e),
                     }
                     .into_response()
                 }
             }
         }
-        self.baml_stream(path, body, b_options)
+        self.baml_stream(path, body, b_options).await
     }
 }
 
 struct EventStream {
     receiver: tokio::sync::mpsc::UnboundedReceiver<FunctionResult>,
+    // Cancels the stream when the client disconnects.
+    _cancel_on_drop: CancelOnDrop,
 }
 
 impl Stream for EventStream {
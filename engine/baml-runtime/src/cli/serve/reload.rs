@@ -0,0 +1,125 @@
+use std::{sync::Arc, time::Instant};
+
+use axum::response::{IntoResponse, Response};
+use http::StatusCode;
+use serde::Serialize;
+
+use super::{json_response::Json, Server};
+use crate::BamlRuntime;
+
+/// Returned by `POST /_admin/reload`.
+#[derive(Serialize, Debug)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub(crate) enum ReloadOutcome {
+    Reloaded { elapsed_ms: u128 },
+    // The previous runtime is still being served.
+    Failed { diagnostics: String },
+}
+
+impl Server {
+    /// Re-parses `src_dir` and swaps in the new runtime if it validates.
+    ///
+    /// Requests that already hold the previous runtime finish on it.
+    pub(crate) async fn reload(&self) -> ReloadOutcome {
+        // Without this, a slow reload could swap in files older than those of
+        // a reload that started after it.
+        let _reloading = self.reloading.lock().await;
+
+        let start = Instant::now();
+        let src_dir = self.src_dir.clone();
+        // Parsing and validating would otherwise hold up the requests that
+        // share this worker thread.
+        let loaded = tokio::task::spawn_blocking(move || {
+            BamlRuntime::from_directory(&src_dir, std::env::vars().collect())
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.into()));
+        match loaded {
+            Ok(mut runtime) => {
+                let mut b = self.b.write().await;
+                // Otherwise Prometheus would see the counters reset.
+                runtime.metrics = b.metrics.clone();
+                // In-flight requests still count against the clients' limits.
+                runtime.rate_limiters = b.rate_limiters.clone();
+                runtime.circuit_breakers = b.circuit_breakers.clone();
+                *b = Arc::new(runtime);
+                let elapsed_ms = start.elapsed().as_millis();
+                log::info!("Reloaded {} in {}ms", self.src_dir.display(), elapsed_ms);
+                ReloadOutcome::Reloaded { elapsed_ms }
+            }
+            Err(e) => {
+                let diagnostics = format!("{:?}", e);
+                log::warn!(
+                    "Failed to reload {}, still serving the previous version:\n{}",
+                    self.src_dir.display(),
+                    diagnostics
+                );
+                ReloadOutcome::Failed { diagnostics }
+            }
+        }
+    }
+
+    pub(super) async fn reload_handler(self: Arc<Self>) -> Response {
+        let outcome = self.reload().await;
+        let status = match outcome {
+            ReloadOutcome::Reloaded { .. } => StatusCode::OK,
+            ReloadOutcome::Failed { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        (status, Json(outcome)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IRHelper;
+
+    const BAML: &str = r##"
+      client<llm> Echo {
+        provider mock
+        options {
+          response_template "Hello"
+        }
+      }
+
+      function Greet(name: string) -> string {
+        client Echo
+        prompt #"{{ name }}"#
+      }
+    "##;
+
+    #[tokio::test]
+    async fn test_failed_reload_keeps_serving() {
+        let root = std::env::temp_dir().join(format!("baml-reload-{}", uuid::Uuid::new_v4()));
+        let src_dir = root.join("baml_src");
+        let main = src_dir.join("main.baml");
+        std::fs::create_dir_all(&src_dir).unwrap();
+        std::fs::write(&main, BAML).unwrap();
+        let runtime = BamlRuntime::from_directory(&src_dir, std::env::vars().collect()).unwrap();
+        let server = Server {
+            src_dir,
+            port: 0,
+            b: Arc::new(tokio::sync::RwLock::new(Arc::new(runtime))),
+            reloading: Default::default(),
+        };
+
+        std::fs::write(&main, BAML.replace("-> string", "-> Greeting")).unwrap();
+        assert!(matches!(
+            server.reload().await,
+            ReloadOutcome::Failed { .. }
+        ));
+        let runtime = server.runtime().await;
+        assert!(runtime.inner.ir().find_function("Greet").is_ok());
+
+        std::fs::write(&main, BAML.replace("Greet", "Welcome")).unwrap();
+        assert!(matches!(
+            server.reload().await,
+            ReloadOutcome::Reloaded { .. }
+        ));
+        let runtime = server.runtime().await;
+        assert!(runtime.inner.ir().find_function("Welcome").is_ok());
+        assert!(runtime.inner.ir().find_function("Greet").is_err());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
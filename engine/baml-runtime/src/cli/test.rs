@@ -0,0 +1,412 @@
+use super::cassette::CassetteArgs;
+use crate::{
+    runtime_interface::InternalRuntimeInterface, BamlRuntime, TestFailReason, TestResponse,
+    TestStatus,
+};
+use anyhow::{Context, Result};
+use baml_types::BamlValue;
+use colored::*;
+use futures::stream::{self, StreamExt};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+#[derive(clap::Args, Clone, Debug)]
+pub struct TestArgs {
+    #[arg(long, help = "path/to/baml_src", default_value = "./baml_src")]
+    pub(super) from: PathBuf,
+    #[arg(
+        long = "function",
+        help = "Only run tests for functions matching this glob (may be repeated)"
+    )]
+    pub(super) functions: Vec<String>,
+    #[arg(
+        long = "test",
+        help = "Only run tests whose name matches this glob (may be repeated)"
+    )]
+    pub(super) tests: Vec<String>,
+    #[arg(long, help = "Number of tests to run at once", default_value_t = 10)]
+    pub(super) parallel: usize,
+    #[arg(long, help = "Write a JUnit XML report to this path")]
+    pub(super) junit: Option<PathBuf>,
+    #[arg(long, help = "Write a JSON report to this path")]
+    pub(super) json: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "Treat tests whose checks need human evaluation as failures",
+        default_value_t = false
+    )]
+    pub(super) fail_on_needs_human_eval: bool,
+    #[command(flatten)]
+    pub(super) cassette: CassetteArgs,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    Passed,
+    NeedsHumanEval,
+    LlmFailure,
+    ParseFailure,
+    AssertionFailure,
+    UnableToRun,
+}
+
+impl Outcome {
+    fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Passed | Outcome::NeedsHumanEval)
+    }
+
+    fn label(&self) -> ColoredString {
+        match self {
+            Outcome::Passed => "PASS".green(),
+            Outcome::NeedsHumanEval => "NEEDS HUMAN EVAL".yellow(),
+            Outcome::LlmFailure => "LLM FAILURE".red(),
+            Outcome::ParseFailure => "PARSE FAILURE".red(),
+            Outcome::AssertionFailure => "ASSERTION FAILURE".red(),
+            Outcome::UnableToRun => "UNABLE TO RUN".red(),
+        }
+    }
+}
+
+/// The result of a single test, detached from the runtime so it can be reported.
+#[derive(Debug, serde::Serialize)]
+struct TestReport {
+    function: String,
+    test: String,
+    status: Outcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(serialize_with = "serialize_duration_ms", rename = "duration_ms")]
+    duration: Duration,
+}
+
+fn serialize_duration_ms<S: serde::Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(d.as_millis())
+}
+
+impl TestReport {
+    fn new(function: &str, test: &str, response: Result<TestResponse>, duration: Duration) -> Self {
+        let (status, message) = match &response {
+            Ok(response) => match response.status() {
+                TestStatus::Pass => (Outcome::Passed, None),
+                TestStatus::NeedsHumanEval(checks) => (
+                    Outcome::NeedsHumanEval,
+                    Some(format!("Failed checks: {}", checks.join(", "))),
+                ),
+                TestStatus::Fail(TestFailReason::TestLLMFailure(llm)) => {
+                    (Outcome::LlmFailure, Some(llm.to_string()))
+                }
+                TestStatus::Fail(TestFailReason::TestParseFailure(e)) => {
+                    (Outcome::ParseFailure, Some(format!("{e:#}")))
+                }
+                TestStatus::Fail(TestFailReason::TestAssertionFailure(result)) => (
+                    Outcome::AssertionFailure,
+                    Some(match &result.error {
+                        Some(e) => format!("{} failed: {}", result.constraint, e),
+                        None => format!("{} failed", result.constraint),
+                    }),
+                ),
+                TestStatus::Fail(TestFailReason::TestUnspecified(e)) => {
+                    (Outcome::UnableToRun, Some(format!("{e:#}")))
+                }
+            },
+            Err(e) => (Outcome::UnableToRun, Some(format!("{e:#}"))),
+        };
+
+        Self {
+            function: function.to_string(),
+            test: test.to_string(),
+            status,
+            message,
+            duration,
+        }
+    }
+}
+
+impl TestArgs {
+    pub fn run(&self) -> Result<()> {
+        let runtime = BamlRuntime::from_directory(&self.from, std::env::vars().collect())
+            .context("Failed to build BAML runtime")?;
+
+        let selected = runtime
+            .inner
+            .ir()
+            .walk_tests()
+            .map(|tc| {
+                (
+                    tc.function().name().to_string(),
+                    tc.test_case().name.to_string(),
+                )
+            })
+            .filter(|(function, test)| {
+                matches_any(&self.functions, function) && matches_any(&self.tests, test)
+            })
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            anyhow::bail!("No tests matched the given filters");
+        }
+
+        println!(
+            "Running {} test{}",
+            selected.len(),
+            if selected.len() == 1 { "" } else { "s" }
+        );
+
+        let start = Instant::now();
+        let t = BamlRuntime::get_tokio_singleton()?;
+        let mut reports = t.block_on(async {
+            stream::iter(selected.iter())
+                .map(|(function, test)| {
+                    let runtime = &runtime;
+                    async move {
+                        let ctx = runtime
+                            .create_ctx_manager(BamlValue::String("baml-cli".to_string()), None);
+                        let started = Instant::now();
+                        let (response, _) =
+                            runtime.run_test(function, test, &ctx, None::<fn(_)>).await;
+                        let report = TestReport::new(function, test, response, started.elapsed());
+                        print_report(&report);
+                        report
+                    }
+                })
+                .buffer_unordered(self.parallel.max(1))
+                .collect::<Vec<_>>()
+                .await
+        });
+        let elapsed = start.elapsed();
+
+        // Reports are written in declaration order, not completion order, so that
+        // they diff cleanly between runs.
+        reports.sort_by_key(|r| {
+            selected
+                .iter()
+                .position(|(f, t)| *f == r.function && *t == r.test)
+        });
+
+        if let Some(path) = &self.junit {
+            std::fs::write(
+                path,
+                junit_report(&reports, elapsed, self.fail_on_needs_human_eval),
+            )
+            .with_context(|| format!("Failed to write JUnit report to {}", path.display()))?;
+        }
+        if let Some(path) = &self.json {
+            std::fs::write(path, serde_json::to_string_pretty(&reports)?)
+                .with_context(|| format!("Failed to write JSON report to {}", path.display()))?;
+        }
+
+        let count = |pred: fn(&Outcome) -> bool| reports.iter().filter(|r| pred(&r.status)).count();
+        let passed = count(|s| *s == Outcome::Passed);
+        let needs_human_eval = count(|s| *s == Outcome::NeedsHumanEval);
+        let failed = count(Outcome::is_failure);
+
+        println!(
+            "\n{} passed, {} need human evaluation, {} failed ({:.2}s)",
+            passed.to_string().green(),
+            needs_human_eval.to_string().yellow(),
+            failed.to_string().red(),
+            elapsed.as_secs_f64()
+        );
+
+        if failed > 0 {
+            anyhow::bail!("{} of {} tests failed", failed, reports.len());
+        }
+        if self.fail_on_needs_human_eval && needs_human_eval > 0 {
+            anyhow::bail!(
+                "{} of {} tests need human evaluation",
+                needs_human_eval,
+                reports.len()
+            );
+        }
+        Ok(())
+    }
+}
+
+fn print_report(report: &TestReport) {
+    println!(
+        "{} {}::{} ({}ms)",
+        report.status.label(),
+        report.function,
+        report.test,
+        report.duration.as_millis()
+    );
+    if let Some(message) = &report.message {
+        if report.status != Outcome::Passed {
+            for line in message.lines() {
+                println!("    {}", line.dimmed());
+            }
+        }
+    }
+}
+
+/// An empty filter list matches everything.
+pub(super) fn matches_any(patterns: &[String], name: &str) -> bool {
+    patterns.is_empty() || patterns.iter().any(|p| glob_match(p, name))
+}
+
+/// Matches `name` against a glob supporting `*` (any run of characters) and `?`
+/// (any single character).
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+
+    let (mut p, mut n) = (0, 0);
+    // Where to resume if the current attempt fails: the last `*` seen and the
+    // position in `name` it is currently standing in for.
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+fn junit_report(
+    reports: &[TestReport],
+    elapsed: Duration,
+    fail_on_needs_human_eval: bool,
+) -> String {
+    let is_failure = |r: &&TestReport| {
+        r.status.is_failure() || (fail_on_needs_human_eval && r.status == Outcome::NeedsHumanEval)
+    };
+    let mut suites: Vec<(&str, Vec<&TestReport>)> = vec![];
+    for report in reports {
+        match suites.iter_mut().find(|(f, _)| *f == report.function) {
+            Some((_, tests)) => tests.push(report),
+            None => suites.push((&report.function, vec![report])),
+        }
+    }
+
+    let failures = reports.iter().filter(is_failure).count();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"baml\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+        reports.len(),
+        failures,
+        elapsed.as_secs_f64()
+    ));
+    for (function, tests) in suites {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            xml_escape(function),
+            tests.len(),
+            tests.iter().copied().filter(is_failure).count(),
+            tests
+                .iter()
+                .map(|r| r.duration)
+                .sum::<Duration>()
+                .as_secs_f64()
+        ));
+        for report in tests {
+            xml.push_str(&format!(
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                xml_escape(&report.test),
+                xml_escape(&report.function),
+                report.duration.as_secs_f64()
+            ));
+            let message = report.message.as_deref().unwrap_or_default();
+            match report.status {
+                Outcome::Passed => xml.push_str("/>\n"),
+                // JUnit has no notion of a test that needs review, so unless
+                // those fail the run, it is reported as passing with the
+                // failed checks in its output.
+                Outcome::NeedsHumanEval if !fail_on_needs_human_eval => xml.push_str(&format!(
+                    ">\n      <system-out>{}</system-out>\n    </testcase>\n",
+                    xml_escape(message)
+                )),
+                status => xml.push_str(&format!(
+                    ">\n      <failure type=\"{}\" message=\"{}\">{}</failure>\n    </testcase>\n",
+                    serde_json::to_value(status)
+                        .ok()
+                        .and_then(|v| v.as_str().map(str::to_string))
+                        .unwrap_or_default(),
+                    xml_escape(message.lines().next().unwrap_or_default()),
+                    xml_escape(message)
+                )),
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", "ExtractResume"));
+        assert!(glob_match("Extract*", "ExtractResume"));
+        assert!(glob_match("*Resume", "ExtractResume"));
+        assert!(glob_match("Ex*R?sume", "ExtractResume"));
+        assert!(glob_match("*a*a*", "banana"));
+        assert!(!glob_match("Extract", "ExtractResume"));
+        assert!(!glob_match("*Resumes", "ExtractResume"));
+        assert!(!glob_match("?", ""));
+    }
+
+    #[test]
+    fn test_junit_report_fails_needs_human_eval_when_asked() {
+        let reports = vec![TestReport {
+            function: "ExtractResume".into(),
+            test: "vaibhav_resume".into(),
+            status: Outcome::NeedsHumanEval,
+            message: Some("check 'has_name' failed".into()),
+            duration: Duration::from_millis(10),
+        }];
+
+        let report = junit_report(&reports, Duration::from_millis(10), false);
+        assert!(report.contains("failures=\"0\""));
+        assert!(report.contains("<system-out>check &apos;has_name&apos; failed</system-out>"));
+
+        let report = junit_report(&reports, Duration::from_millis(10), true);
+        assert!(!report.contains("failures=\"0\""));
+        assert!(report.contains(
+            "<failure type=\"needs_human_eval\" message=\"check &apos;has_name&apos; failed\">"
+        ));
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(
+            xml_escape(r#"<a href="x">&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&apos;&lt;/a&gt;"
+        );
+    }
+}
@@ -0,0 +1,444 @@
+use super::test::matches_any;
+use crate::tracing::trace_files::{list_trace_files, TRACE_DIR_ENV_VAR};
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset};
+use colored::*;
+use serde_json::Value;
+use std::{
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+#[derive(clap::Args, Clone, Debug)]
+pub struct TracesArgs {
+    #[arg(
+        long,
+        help = "Directory of the trace files [default: $BAML_TRACE_DIR, or ./baml_traces]"
+    )]
+    pub(super) dir: Option<PathBuf>,
+    #[arg(
+        long = "function",
+        help = "Only show calls of functions matching this glob (may be repeated)"
+    )]
+    pub(super) functions: Vec<String>,
+    #[arg(long, help = "Only show calls that failed", default_value_t = false)]
+    pub(super) failed: bool,
+    #[arg(
+        long = "tag",
+        value_parser = parse_tag,
+        help = "Only show calls with this tag, as key=value (may be repeated)"
+    )]
+    pub(super) tags: Vec<(String, String)>,
+    #[arg(
+        long,
+        help = "Only show calls that started at or after this time, e.g. 2024-10-01T09:00:00Z"
+    )]
+    pub(super) since: Option<DateTime<FixedOffset>>,
+    #[arg(long, help = "Only show the N most recent calls")]
+    pub(super) limit: Option<usize>,
+    #[arg(
+        long,
+        help = "Print the prompts, responses and parse results of the call with this event ID (or prefix)"
+    )]
+    pub(super) show: Option<String>,
+    #[arg(
+        long,
+        help = "Print the matching events as JSON lines",
+        default_value_t = false
+    )]
+    pub(super) json: bool,
+}
+
+fn parse_tag(tag: &str) -> Result<(String, String), String> {
+    tag.split_once('=')
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .ok_or_else(|| format!("expected key=value, got {tag}"))
+}
+
+impl TracesArgs {
+    pub fn run(&self) -> Result<()> {
+        let dir = self
+            .dir
+            .clone()
+            .or_else(|| std::env::var_os(TRACE_DIR_ENV_VAR).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from("./baml_traces"));
+        let events = read_events(&dir)?;
+
+        if let Some(id) = &self.show {
+            let found = events
+                .iter()
+                .filter(|e| str_at(e, &["event_id"]).starts_with(id.as_str()))
+                .collect::<Vec<_>>();
+            return match found.as_slice() {
+                [] => anyhow::bail!("No event with ID {id} in {}", dir.display()),
+                [event] if self.json => {
+                    println!("{}", serde_json::to_string(event)?);
+                    Ok(())
+                }
+                [event] => {
+                    print!("{}", details(event));
+                    Ok(())
+                }
+                _ => anyhow::bail!("{} events have an ID starting with {id}", found.len()),
+            };
+        }
+
+        let matching = events
+            .iter()
+            .filter(|e| self.matches(e))
+            .collect::<Vec<_>>();
+        let skip = matching.len() - self.limit.unwrap_or(usize::MAX).min(matching.len());
+        for event in &matching[skip..] {
+            if self.json {
+                println!("{}", serde_json::to_string(event)?);
+            } else {
+                println!("{}", summary(event));
+            }
+        }
+        if matching.is_empty() && !self.json {
+            println!("No matching calls in {}", dir.display());
+        }
+        Ok(())
+    }
+
+    fn matches(&self, event: &Value) -> bool {
+        matches_any(&self.functions, function_name(event))
+            && (!self.failed || !event["error"].is_null())
+            && self
+                .tags
+                .iter()
+                .all(|(k, v)| event["context"]["tags"][k].as_str() == Some(v))
+            && self
+                .since
+                .map_or(true, |since| start_time(event).is_some_and(|t| t >= since))
+    }
+}
+
+/// Every event in `dir`, by start time. Lines that can't be parsed, such as
+/// one still being written, are skipped.
+fn read_events(dir: &Path) -> Result<Vec<Value>> {
+    let mut events = vec![];
+    for path in list_trace_files(dir)? {
+        let file = std::fs::File::open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        for (i, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.with_context(|| format!("Failed to read {}", path.display()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(&line) {
+                Ok(event) => events.push(event),
+                Err(e) => log::warn!("Skipping line {} of {}: {}", i + 1, path.display(), e),
+            }
+        }
+    }
+    events.sort_by_key(start_time);
+    Ok(events)
+}
+
+fn str_at<'a>(value: &'a Value, path: &[&str]) -> &'a str {
+    path.iter()
+        .fold(value, |v, key| &v[*key])
+        .as_str()
+        .unwrap_or_default()
+}
+
+fn function_name(event: &Value) -> &str {
+    event["context"]["event_chain"]
+        .as_array()
+        .and_then(|chain| chain.last())
+        .and_then(|f| f["function_name"].as_str())
+        .unwrap_or("<unknown>")
+}
+
+fn start_time(event: &Value) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(str_at(event, &["context", "start_time"])).ok()
+}
+
+fn llm_calls(event: &Value) -> Vec<&Value> {
+    match &event["metadata"] {
+        Value::Array(calls) => calls.iter().collect(),
+        call @ Value::Object(_) => vec![call],
+        _ => vec![],
+    }
+}
+
+fn tokens(call: &Value) -> i64 {
+    let metadata = &call["output"]["metadata"];
+    metadata["total_tokens"].as_i64().unwrap_or_else(|| {
+        metadata["prompt_tokens"].as_i64().unwrap_or(0)
+            + metadata["output_tokens"].as_i64().unwrap_or(0)
+    })
+}
+
+fn summary(event: &Value) -> String {
+    let calls = llm_calls(event);
+    let status = match event["error"].is_null() {
+        true => "OK".green(),
+        false => "FAILED".red(),
+    };
+    let mut line = format!(
+        "{} {} {} ({}ms",
+        str_at(event, &["context", "start_time"]).dimmed(),
+        status,
+        function_name(event).bold(),
+        event["context"]["latency_ms"].as_i64().unwrap_or(0),
+    );
+    if !calls.is_empty() {
+        line.push_str(&format!(
+            ", {} LLM call{}, {} tokens",
+            calls.len(),
+            if calls.len() == 1 { "" } else { "s" },
+            calls.iter().map(|c| tokens(c)).sum::<i64>()
+        ));
+    }
+    line.push_str(&format!(") {}", str_at(event, &["event_id"]).dimmed()));
+    line
+}
+
+fn io_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn indent(text: &str, by: usize) -> String {
+    text.lines()
+        .map(|line| format!("{:by$}{line}\n", ""))
+        .collect()
+}
+
+fn prompt(template: &Value) -> String {
+    let Some(messages) = template.as_array() else {
+        return io_value(template);
+    };
+    messages
+        .iter()
+        .map(|message| {
+            let content = message["content"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|part| match part.get("text").and_then(|t| t.as_str()) {
+                    Some(text) => text.to_string(),
+                    None => match part.as_object().and_then(|p| p.keys().next()) {
+                        Some(kind) => format!("<{kind}>"),
+                        None => "<unknown>".to_string(),
+                    },
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            match message["role"].as_str() {
+                Some(role) => format!("[{role}]\n{content}"),
+                None => content,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn details(event: &Value) -> String {
+    let mut out = format!(
+        "{} {}\n",
+        function_name(event).bold(),
+        str_at(event, &["event_id"]).dimmed()
+    );
+    let field = |out: &mut String, name: &str, value: &str| {
+        if value.contains('\n') {
+            out.push_str(&format!("  {name}:\n{}", indent(value, 4)));
+        } else {
+            out.push_str(&format!("  {name}: {value}\n"));
+        }
+    };
+
+    field(
+        &mut out,
+        "Started",
+        str_at(event, &["context", "start_time"]),
+    );
+    field(
+        &mut out,
+        "Latency",
+        &format!("{}ms", event["context"]["latency_ms"].as_i64().unwrap_or(0)),
+    );
+    if let Some(parent) = event["parent_event_id"].as_str() {
+        field(&mut out, "Parent", parent);
+    }
+    if let Some(tags) = event["context"]["tags"]
+        .as_object()
+        .filter(|t| !t.is_empty())
+    {
+        let mut tags = tags
+            .iter()
+            .map(|(k, v)| format!("{k}={}", io_value(v)))
+            .collect::<Vec<_>>();
+        tags.sort();
+        field(&mut out, "Tags", &tags.join(", "));
+    }
+    for (name, key) in [("Input", "input"), ("Output", "output")] {
+        let value = &event["io"][key]["value"];
+        if !value.is_null() {
+            field(&mut out, name, &io_value(value));
+        }
+    }
+    if let Some(error) = event["error"]["message"].as_str() {
+        field(&mut out, "Error", &error.red().to_string());
+    }
+
+    let calls = llm_calls(event);
+    for (i, call) in calls.iter().enumerate() {
+        let attempt = &call["attempt"];
+        out.push_str(&format!(
+            "\n{} {}\n",
+            format!("LLM call {} of {}:", i + 1, calls.len()).bold(),
+            str_at(call, &["provider"])
+        ));
+        field(&mut out, "Model", str_at(call, &["model_name"]));
+        if let Some(scope) = attempt["scope"].as_str().filter(|s| !s.is_empty()) {
+            field(&mut out, "Scope", scope);
+        }
+        if let Some(latency) = attempt["latency_ms"].as_u64() {
+            field(&mut out, "Latency", &format!("{latency}ms"));
+        }
+        if call["output"].is_object() {
+            field(&mut out, "Tokens", &tokens(call).to_string());
+        }
+        field(
+            &mut out,
+            "Prompt",
+            &prompt(&call["input"]["prompt"]["template"]),
+        );
+        if let Some(response) = call["output"]["raw_text"].as_str() {
+            field(&mut out, "Response", response);
+        }
+        match attempt["parsed"].as_bool() {
+            Some(true) => field(&mut out, "Parsed", &"OK".green().to_string()),
+            Some(false) => field(
+                &mut out,
+                "Parsed",
+                &format!("{} {}", "FAILED".red(), str_at(attempt, &["parse_error"])),
+            ),
+            None => {}
+        }
+        if let Some(error) = call["error"].as_str() {
+            field(&mut out, "Error", &error.red().to_string());
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(function: &str, start_time: &str, failed: bool) -> Value {
+        json!({
+            "event_id": format!("{function}-id"),
+            "context": {
+                "start_time": start_time,
+                "latency_ms": 120,
+                "tags": { "env": "prod" },
+                "event_chain": [{ "function_name": function }],
+            },
+            "error": if failed { json!({ "message": "Failed to parse" }) } else { Value::Null },
+            "metadata": [{
+                "provider": "GPT4o",
+                "model_name": "gpt-4o",
+                "input": { "prompt": { "template": [
+                    { "role": "system", "content": [{ "text": "Extract the resume" }] },
+                ] } },
+                "output": {
+                    "raw_text": "not json",
+                    "metadata": { "prompt_tokens": 10, "output_tokens": 5 },
+                },
+                "attempt": { "scope": "GPT4o", "parsed": false, "parse_error": "Expected an object" },
+            }],
+        })
+    }
+
+    fn args() -> TracesArgs {
+        TracesArgs {
+            dir: None,
+            functions: vec![],
+            failed: false,
+            tags: vec![],
+            since: None,
+            limit: None,
+            show: None,
+            json: false,
+        }
+    }
+
+    #[test]
+    fn test_filters() {
+        let resume = event("ExtractResume", "2024-10-01T09:00:00Z", true);
+        let receipt = event("ExtractReceipt", "2024-10-01T10:00:00Z", false);
+
+        let args = TracesArgs {
+            functions: vec!["*Resume".into()],
+            ..args()
+        };
+        assert!(args.matches(&resume));
+        assert!(!args.matches(&receipt));
+
+        let args = TracesArgs {
+            failed: true,
+            since: Some(DateTime::parse_from_rfc3339("2024-10-01T08:00:00Z").unwrap()),
+            ..args()
+        };
+        assert!(args.matches(&resume));
+        assert!(!args.matches(&receipt));
+
+        let args = TracesArgs {
+            since: Some(DateTime::parse_from_rfc3339("2024-10-01T09:30:00Z").unwrap()),
+            ..args()
+        };
+        assert!(!args.matches(&resume));
+        assert!(args.matches(&receipt));
+
+        let args = TracesArgs {
+            tags: vec![parse_tag("env=prod").unwrap()],
+            ..args()
+        };
+        assert!(args.matches(&resume));
+        let args = TracesArgs {
+            tags: vec![parse_tag("env=dev").unwrap()],
+            ..args()
+        };
+        assert!(!args.matches(&resume));
+        assert!(parse_tag("env").is_err());
+    }
+
+    #[test]
+    fn test_details() {
+        colored::control::set_override(false);
+        let event = event("ExtractResume", "2024-10-01T09:00:00Z", true);
+        assert!(summary(&event).contains("FAILED ExtractResume (120ms, 1 LLM call, 15 tokens)"));
+
+        let details = details(&event);
+        assert!(details.contains("  Prompt:\n    [system]\n    Extract the resume\n"));
+        assert!(details.contains("  Response: not json\n"));
+        assert!(details.contains("  Parsed: FAILED Expected an object\n"));
+    }
+
+    #[test]
+    fn test_read_events() {
+        let dir = std::env::temp_dir().join(format!("baml-traces-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let later = event("ExtractReceipt", "2024-10-01T10:00:00Z", false);
+        let earlier = event("ExtractResume", "2024-10-01T09:00:00Z", false);
+        std::fs::write(
+            dir.join("traces-20241001T100000000-1.jsonl"),
+            format!("{later}\n{earlier}\n{{\"event_id\": "),
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a trace").unwrap();
+
+        let events = read_events(&dir).unwrap();
+        assert_eq!(events, vec![earlier, later]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
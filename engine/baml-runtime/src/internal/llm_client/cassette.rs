@@ -0,0 +1,566 @@
+//! Record/replay of LLM traffic.
+//!
+//! With `BAML_CASSETTE_MODE=record`, every call made by a primitive client is
+//! saved to a cassette file, including every chunk of a streamed response.
+//! With `BAML_CASSETTE_MODE=replay`, the same calls are served from those
+//! files instead of the provider, so functions and tests can run offline.
+//!
+//! Cassettes live under `BAML_CASSETTE_DIR` (default `./baml_cassettes`) and
+//! are keyed by client, model and a hash of everything else the provider is
+//! sent (the rendered prompt, request options and output tool or schema):
+//!
+//!   baml_cassettes/<client>/<model>-<request hash>[.stream].json
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Result};
+use futures::{future, stream, StreamExt};
+use internal_baml_jinja::RenderedPrompt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::RuntimeContext;
+
+use super::{
+    response_cache::cache_key, traits::StreamResponse, ErrorCode, LLMCompleteResponse,
+    LLMCompleteResponseMetadata, LLMErrorResponse, LLMResponse,
+};
+
+pub const CASSETTE_MODE_ENV_VAR: &str = "BAML_CASSETTE_MODE";
+pub const CASSETTE_DIR_ENV_VAR: &str = "BAML_CASSETTE_DIR";
+const DEFAULT_CASSETTE_DIR: &str = "baml_cassettes";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum CassetteMode {
+    /// Always call the provider.
+    Off,
+    /// Call the provider and save every response.
+    Record,
+    /// Never call the provider; serve saved responses instead.
+    Replay,
+}
+
+impl CassetteMode {
+    fn from_ctx(ctx: &RuntimeContext) -> Result<Self> {
+        match ctx.env.get(CASSETTE_MODE_ENV_VAR).map(|s| s.as_str()) {
+            None | Some("") | Some("off") => Ok(Self::Off),
+            Some("record") => Ok(Self::Record),
+            Some("replay") => Ok(Self::Replay),
+            Some(other) => anyhow::bail!(
+                "Invalid {}: {}. Expected one of: off, record, replay",
+                CASSETTE_MODE_ENV_VAR,
+                other
+            ),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Record => "record",
+            Self::Replay => "replay",
+        }
+    }
+}
+
+/// Identifies a single call to a primitive client.
+pub struct Cassette {
+    mode: CassetteMode,
+    path: PathBuf,
+    client: String,
+    model: String,
+    prompt: RenderedPrompt,
+    request_options: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CassetteFile {
+    client: String,
+    model: String,
+    prompt: serde_json::Value,
+    #[serde(flatten)]
+    response: RecordedCall,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum RecordedCall {
+    Call { response: RecordedResponse },
+    Stream { chunks: Vec<RecordedResponse> },
+    StreamError { response: RecordedResponse },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RecordedResponse {
+    Success {
+        model: String,
+        content: String,
+        latency_ms: u64,
+        metadata: LLMCompleteResponseMetadata,
+    },
+    LlmFailure {
+        model: Option<String>,
+        message: String,
+        code: u16,
+        latency_ms: u64,
+    },
+    UserFailure {
+        message: String,
+    },
+    InternalFailure {
+        message: String,
+    },
+}
+
+impl From<&LLMResponse> for RecordedResponse {
+    fn from(response: &LLMResponse) -> Self {
+        match response {
+            LLMResponse::Success(r) => Self::Success {
+                model: r.model.clone(),
+                content: r.content.clone(),
+                latency_ms: r.latency.as_millis() as u64,
+                metadata: r.metadata.clone(),
+            },
+            LLMResponse::LLMFailure(r) => Self::LlmFailure {
+                model: r.model.clone(),
+                message: r.message.clone(),
+                code: r.code.to_u16(),
+                latency_ms: r.latency.as_millis() as u64,
+            },
+            LLMResponse::UserFailure(message) => Self::UserFailure {
+                message: message.clone(),
+            },
+            LLMResponse::InternalFailure(message) => Self::InternalFailure {
+                message: message.clone(),
+            },
+        }
+    }
+}
+
+impl Cassette {
+    /// Returns `None` if cassettes are turned off for this context.
+    pub fn for_call(
+        ctx: &RuntimeContext,
+        client: &str,
+        request_options: &HashMap<String, serde_json::Value>,
+        prompt: &RenderedPrompt,
+        stream: bool,
+    ) -> Result<Option<Self>> {
+        let mode = CassetteMode::from_ctx(ctx)?;
+        if mode == CassetteMode::Off {
+            return Ok(None);
+        }
+
+        let model = request_options
+            .get("model")
+            .or_else(|| request_options.get("model_id"))
+            .and_then(|m| m.as_str())
+            .unwrap_or("default")
+            .to_string();
+        let dir = ctx
+            .env
+            .get(CASSETTE_DIR_ENV_VAR)
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_CASSETTE_DIR));
+
+        let key = cache_key(ctx, client, request_options, prompt);
+        let hash = format!("{:x}", Sha256::digest(key));
+        let path = dir.join(sanitize(client)).join(format!(
+            "{}-{}{}.json",
+            sanitize(&model),
+            &hash[..16],
+            if stream { ".stream" } else { "" }
+        ));
+
+        Ok(Some(Self {
+            mode,
+            path,
+            client: client.to_string(),
+            model,
+            prompt: prompt.clone(),
+            request_options: request_options.clone(),
+        }))
+    }
+
+    pub async fn single_call(
+        self,
+        call: impl std::future::Future<Output = LLMResponse>,
+    ) -> LLMResponse {
+        if self.mode == CassetteMode::Replay {
+            return match self.load() {
+                Ok(RecordedCall::Call { response }) => self.replay(response),
+                Ok(_) => self.missing("it was recorded from a streaming call"),
+                Err(e) => self.missing(&format!("{e:#}")),
+            };
+        }
+
+        let response = call.await;
+        self.save(RecordedCall::Call {
+            response: (&response).into(),
+        });
+        response
+    }
+
+    pub async fn stream(
+        self,
+        call: impl std::future::Future<Output = StreamResponse>,
+    ) -> StreamResponse {
+        if self.mode == CassetteMode::Replay {
+            return match self.load() {
+                Ok(RecordedCall::Stream { chunks }) => {
+                    let chunks = chunks
+                        .into_iter()
+                        .map(|chunk| self.replay(chunk))
+                        .collect::<Vec<_>>();
+                    Ok(Box::pin(stream::iter(chunks)))
+                }
+                Ok(RecordedCall::StreamError { response }) => Err(self.replay(response)),
+                Ok(RecordedCall::Call { .. }) => {
+                    Err(self.missing("it was recorded from a non-streaming call"))
+                }
+                Err(e) => Err(self.missing(&format!("{e:#}"))),
+            };
+        }
+
+        let response = match call.await {
+            Ok(response) => response,
+            Err(e) => {
+                self.save(RecordedCall::StreamError {
+                    response: (&e).into(),
+                });
+                return Err(e);
+            }
+        };
+
+        // Chunks are collected as they pass through, and the cassette is
+        // written once the stream is exhausted.
+        let chunks = Arc::new(Mutex::new(Vec::new()));
+        let recorded = chunks.clone();
+        let finish = stream::once(future::lazy(move |_| {
+            let chunks = std::mem::take(&mut *recorded.lock().unwrap());
+            self.save(RecordedCall::Stream { chunks });
+        }))
+        .filter_map(|()| future::ready(None));
+
+        Ok(Box::pin(
+            response
+                .map(move |chunk| {
+                    chunks.lock().unwrap().push(RecordedResponse::from(&chunk));
+                    chunk
+                })
+                .chain(finish),
+        ))
+    }
+
+    fn load(&self) -> Result<RecordedCall> {
+        let contents = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        let file: CassetteFile = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", self.path.display()))?;
+        Ok(file.response)
+    }
+
+    fn save(&self, response: RecordedCall) {
+        let file = CassetteFile {
+            client: self.client.clone(),
+            model: self.model.clone(),
+            prompt: prompt_json(&self.prompt),
+            response,
+        };
+        if let Err(e) = write_json(&self.path, &file) {
+            log::warn!("Failed to record cassette {}: {:#}", self.path.display(), e);
+        }
+    }
+
+    fn replay(&self, response: RecordedResponse) -> LLMResponse {
+        let start_time = web_time::SystemTime::now();
+        match response {
+            RecordedResponse::Success {
+                model,
+                content,
+                latency_ms,
+                metadata,
+            } => LLMResponse::Success(LLMCompleteResponse {
+                client: self.client.clone(),
+                model,
+                prompt: self.prompt.clone(),
+                request_options: self.request_options.clone(),
+                content,
+                start_time,
+                latency: web_time::Duration::from_millis(latency_ms),
+                metadata,
+            }),
+            RecordedResponse::LlmFailure {
+                model,
+                message,
+                code,
+                latency_ms,
+            } => LLMResponse::LLMFailure(LLMErrorResponse {
+                client: self.client.clone(),
+                model,
+                prompt: self.prompt.clone(),
+                request_options: self.request_options.clone(),
+                start_time,
+                latency: web_time::Duration::from_millis(latency_ms),
+                message,
+                code: ErrorCode::from_u16(code),
+                retry_after: None,
+                rate_limit_wait_ms: None,
+            }),
+            RecordedResponse::UserFailure { message } => LLMResponse::UserFailure(message),
+            RecordedResponse::InternalFailure { message } => LLMResponse::InternalFailure(message),
+        }
+    }
+
+    fn missing(&self, reason: &str) -> LLMResponse {
+        LLMResponse::InternalFailure(format!(
+            "No usable cassette for client {} ({}) at {}: {}. Run with {}=record to record one.",
+            self.client,
+            self.model,
+            self.path.display(),
+            reason,
+            CASSETTE_MODE_ENV_VAR
+        ))
+    }
+}
+
+fn prompt_json(prompt: &RenderedPrompt) -> serde_json::Value {
+    match prompt {
+        RenderedPrompt::Completion(p) => serde_json::json!(p),
+        RenderedPrompt::Chat(messages) => serde_json::json!(messages),
+    }
+}
+
+fn write_json(path: &Path, file: &CassetteFile) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(file)?)?;
+    Ok(())
+}
+
+/// Keeps cassette paths portable, whatever characters a client or model uses.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use baml_types::BamlValue;
+    use internal_baml_jinja::{ChatMessagePart, RenderedChatMessage};
+
+    use super::*;
+    use crate::{
+        client_registry::ClientProperty,
+        internal::llm_client::{
+            primitive::LLMPrimitiveProvider,
+            traits::{WithSingleCallable, WithStreamable},
+        },
+    };
+
+    /// A mock client that always answers `response`, and a context that
+    /// records to or replays from `dir`.
+    fn mock(
+        dir: &Path,
+        mode: &str,
+        response: &str,
+    ) -> Result<(LLMPrimitiveProvider, RuntimeContext)> {
+        let env = HashMap::from([
+            (CASSETTE_MODE_ENV_VAR.to_string(), mode.to_string()),
+            (CASSETTE_DIR_ENV_VAR.to_string(), dir.display().to_string()),
+        ]);
+        let ctx =
+            crate::RuntimeContextManager::new_from_env_vars(env, None).create_ctx(None, None)?;
+        let client = ClientProperty {
+            name: "Mock".into(),
+            provider: "mock".into(),
+            retry_policy: None,
+            options: [
+                ("response".to_string(), BamlValue::String(response.into())),
+                ("stream_chunk_size".to_string(), BamlValue::Int(4)),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let provider = LLMPrimitiveProvider::try_from((&client, &ctx))?;
+        Ok((provider, ctx))
+    }
+
+    fn prompt() -> RenderedPrompt {
+        RenderedPrompt::Chat(vec![RenderedChatMessage {
+            role: "user".into(),
+            allow_duplicate_role: false,
+            parts: vec![ChatMessagePart::Text("Say hello".into())],
+        }])
+    }
+
+    fn content(response: &LLMResponse) -> &str {
+        match response {
+            LLMResponse::Success(r) => &r.content,
+            other => panic!("expected a success, got {}", other),
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("baml-cassettes-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_call() -> Result<()> {
+        let dir = temp_dir();
+
+        let (provider, ctx) = mock(&dir, "record", "Hello world")?;
+        assert_eq!(
+            content(&provider.single_call(&ctx, &prompt()).await),
+            "Hello world"
+        );
+
+        // A replayed call never reaches the client, so its answer is unused.
+        let (provider, ctx) = mock(&dir, "replay", "Goodbye")?;
+        assert_eq!(
+            content(&provider.single_call(&ctx, &prompt()).await),
+            "Hello world"
+        );
+
+        // A call recorded without streaming can't be replayed as a stream.
+        assert!(provider.stream(&ctx, &prompt()).await.is_err());
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_stream() -> Result<()> {
+        let dir = temp_dir();
+
+        let (provider, ctx) = mock(&dir, "record", "Hello world")?;
+        let recorded = match provider.stream(&ctx, &prompt()).await {
+            Ok(stream) => stream.collect::<Vec<_>>().await,
+            Err(e) => panic!("failed to stream: {}", e),
+        };
+
+        let (provider, ctx) = mock(&dir, "replay", "Goodbye")?;
+        let replayed = match provider.stream(&ctx, &prompt()).await {
+            Ok(stream) => stream.collect::<Vec<_>>().await,
+            Err(e) => panic!("failed to replay: {}", e),
+        };
+        assert_eq!(
+            replayed.iter().map(content).collect::<Vec<_>>(),
+            vec!["Hell", "Hello wo", "Hello world"]
+        );
+        assert_eq!(
+            recorded.iter().map(content).collect::<Vec<_>>(),
+            replayed.iter().map(content).collect::<Vec<_>>()
+        );
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_partial_stream_is_not_recorded() -> Result<()> {
+        let dir = temp_dir();
+
+        let (provider, ctx) = mock(&dir, "record", "Hello world")?;
+        match provider.stream(&ctx, &prompt()).await {
+            Ok(mut stream) => assert_eq!(content(&stream.next().await.unwrap()), "Hell"),
+            Err(e) => panic!("failed to stream: {}", e),
+        }
+
+        let (provider, ctx) = mock(&dir, "replay", "Goodbye")?;
+        match provider.stream(&ctx, &prompt()).await {
+            Ok(_) => panic!("replayed a stream that was never finished"),
+            Err(LLMResponse::InternalFailure(message)) => {
+                assert!(message.contains("No usable cassette"), "{}", message)
+            }
+            Err(e) => panic!("unexpected error: {}", e),
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!(sanitize("gpt-4o-mini"), "gpt-4o-mini");
+        assert_eq!(
+            sanitize("anthropic.claude-3:0/v1"),
+            "anthropic.claude-3_0_v1"
+        );
+    }
+
+    #[test]
+    fn test_path_depends_on_output_tool() -> Result<()> {
+        let env = HashMap::from([(CASSETTE_MODE_ENV_VAR.to_string(), "record".to_string())]);
+        let mut ctx =
+            crate::RuntimeContextManager::new_from_env_vars(env, None).create_ctx(None, None)?;
+        let request_options = HashMap::from([("model".to_string(), serde_json::json!("gpt-4o"))]);
+        let prompt = RenderedPrompt::Completion("Extract the resume".into());
+        let path = |ctx: &RuntimeContext| -> Result<PathBuf> {
+            Ok(
+                Cassette::for_call(ctx, "GPT4o", &request_options, &prompt, false)?
+                    .unwrap()
+                    .path,
+            )
+        };
+
+        let text_mode = path(&ctx)?;
+        ctx.output_tool = Some(crate::internal::prompt_renderer::OutputTool {
+            name: "Resume".into(),
+            parameters: serde_json::json!({ "type": "object" }),
+        });
+        let tool_mode = path(&ctx)?;
+
+        assert_ne!(text_mode, tool_mode);
+        assert_eq!(text_mode.parent(), tool_mode.parent());
+        Ok(())
+    }
+
+    #[test]
+    fn test_recorded_response_roundtrip() -> Result<()> {
+        let recorded = RecordedCall::Stream {
+            chunks: vec![
+                RecordedResponse::Success {
+                    model: "gpt-4o".into(),
+                    content: "Hel".into(),
+                    latency_ms: 12,
+                    metadata: LLMCompleteResponseMetadata {
+                        baml_is_complete: false,
+                        finish_reason: None,
+                        prompt_tokens: None,
+                        output_tokens: None,
+                        total_tokens: None,
+                        cache: None,
+                        cost_usd: None,
+                        rate_limit_wait_ms: None,
+                        time_to_first_token_ms: None,
+                    },
+                },
+                RecordedResponse::LlmFailure {
+                    model: None,
+                    message: "rate limited".into(),
+                    code: 429,
+                    latency_ms: 40,
+                },
+            ],
+        };
+
+        let json = serde_json::to_string(&recorded)?;
+        match serde_json::from_str::<RecordedCall>(&json)? {
+            RecordedCall::Stream { chunks } => assert_eq!(chunks.len(), 2),
+            _ => panic!("expected a stream"),
+        }
+        Ok(())
+    }
+}
@@ -2,12 +2,18 @@ use std::collections::{HashMap, HashSet};
 
 use base64::write;
 use colored::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cassette;
 pub mod llm_provider;
 pub mod orchestrator;
+pub mod pricing;
 pub mod primitive;
 
+pub mod rate_limits;
+pub mod response_cache;
 pub mod retry_policy;
-mod strategy;
+pub mod strategy;
+pub mod timeouts;
 pub mod traits;
 
 use anyhow::Result;
@@ -77,6 +83,21 @@ impl AllowedMetadata {
     }
 }
 
+/// Removes the `structured_outputs` option from `properties`, so it is not
+/// forwarded to the provider. Off by default.
+pub fn resolve_structured_outputs(
+    properties: &mut HashMap<String, serde_json::Value>,
+) -> Result<bool> {
+    properties
+        .remove("structured_outputs")
+        .map(|v| match v.as_bool() {
+            Some(enabled) => Ok(enabled),
+            None => anyhow::bail!("structured_outputs must be a boolean"),
+        })
+        .transpose()
+        .map(|enabled| enabled.unwrap_or(false))
+}
+
 #[derive(Debug)]
 pub struct RetryLLMResponse {
     pub client: Option<String>,
@@ -160,6 +181,10 @@ pub struct LLMErrorResponse {
     // Short error message
     pub message: String,
     pub code: ErrorCode,
+    // Set from the Retry-After header of a failed response.
+    pub retry_after: Option<web_time::Duration>,
+    // How long the request was queued by the client's rate limits, if at all.
+    pub rate_limit_wait_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -170,10 +195,17 @@ pub enum ErrorCode {
     ServerError,           // 500
     ServiceUnavailable,    // 503
 
+    // A client's timeout_ms, time_to_first_token_ms or idle_timeout_ms ran out
+    Timeout,
+
+    // The call was cancelled with a CancellationToken
+    Cancelled,
+
     // We failed to parse the response
     UnsupportedResponse(u16),
 
-    // Any other error
+    // Any other error. `Other(2)` when the request got no response at all,
+    // e.g. because it couldn't be sent, which `retry_on` lists as `2`.
     Other(u16),
 }
 
@@ -185,6 +217,8 @@ impl ErrorCode {
             ErrorCode::RateLimited => "RateLimited (429)".into(),
             ErrorCode::ServerError => "ServerError (500)".into(),
             ErrorCode::ServiceUnavailable => "ServiceUnavailable (503)".into(),
+            ErrorCode::Timeout => "Timeout".into(),
+            ErrorCode::Cancelled => "Cancelled".into(),
             ErrorCode::UnsupportedResponse(code) => format!("BadResponse {}", code),
             ErrorCode::Other(code) => format!("Unspecified error code: {}", code),
         }
@@ -201,11 +235,15 @@ impl ErrorCode {
         }
     }
 
+    /// The inverse of `to_u16`, except that an `UnsupportedResponse` comes
+    /// back as `Other`.
     pub fn from_u16(code: u16) -> Self {
         match code {
             401 => ErrorCode::InvalidAuthentication,
             403 => ErrorCode::NotSupported,
+            408 => ErrorCode::Timeout,
             429 => ErrorCode::RateLimited,
+            499 => ErrorCode::Cancelled,
             500 => ErrorCode::ServerError,
             503 => ErrorCode::ServiceUnavailable,
             code => ErrorCode::Other(code),
@@ -219,6 +257,8 @@ impl ErrorCode {
             ErrorCode::RateLimited => 429,
             ErrorCode::ServerError => 500,
             ErrorCode::ServiceUnavailable => 503,
+            ErrorCode::Timeout => 408,
+            ErrorCode::Cancelled => 499,
             ErrorCode::UnsupportedResponse(code) => *code,
             ErrorCode::Other(code) => *code,
         }
@@ -237,13 +277,25 @@ pub struct LLMCompleteResponse {
     pub metadata: LLMCompleteResponseMetadata,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LLMCompleteResponseMetadata {
     pub baml_is_complete: bool,
     pub finish_reason: Option<String>,
     pub prompt_tokens: Option<u64>,
     pub output_tokens: Option<u64>,
     pub total_tokens: Option<u64>,
+    // Set when the response cache is on for the call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<response_cache::CacheStatus>,
+    // Set when the model has a price in the price table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+    // Set when the request queued behind the client's rate limits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit_wait_ms: Option<u64>,
+    // Set when the response was streamed from the provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_to_first_token_ms: Option<u64>,
 }
 
 impl std::fmt::Display for LLMCompleteResponse {
@@ -356,3 +408,46 @@ fn resolve_properties_walker(
         })
         .collect::<Result<std::collections::HashMap<_, _>>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_round_trips_through_u16() {
+        let codes = [
+            ErrorCode::InvalidAuthentication,
+            ErrorCode::NotSupported,
+            ErrorCode::RateLimited,
+            ErrorCode::ServerError,
+            ErrorCode::ServiceUnavailable,
+            ErrorCode::Timeout,
+            ErrorCode::Cancelled,
+            ErrorCode::Other(2),
+            ErrorCode::Other(418),
+        ];
+        for code in codes {
+            let round_tripped = ErrorCode::from_u16(code.to_u16());
+            assert_eq!(round_tripped.to_string(), code.to_string());
+        }
+
+        let unsupported = ErrorCode::from_u16(ErrorCode::UnsupportedResponse(422).to_u16());
+        assert!(matches!(unsupported, ErrorCode::Other(422)));
+    }
+
+    #[test]
+    fn test_resolve_structured_outputs() -> Result<()> {
+        let mut properties = HashMap::from([
+            ("model".to_string(), serde_json::json!("gpt-4o")),
+            ("structured_outputs".to_string(), serde_json::json!(true)),
+        ]);
+        assert!(resolve_structured_outputs(&mut properties)?);
+        assert_eq!(properties.len(), 1);
+        assert!(!resolve_structured_outputs(&mut properties)?);
+
+        let mut properties =
+            HashMap::from([("structured_outputs".to_string(), serde_json::json!("yes"))]);
+        assert!(resolve_structured_outputs(&mut properties).is_err());
+        Ok(())
+    }
+}
@@ -7,7 +7,8 @@ use web_time::Duration;
 use crate::{
     internal::{
         llm_client::{
-            traits::{WithPrompt, WithSingleCallable},
+            timeouts::within,
+            traits::{WithClientProperties, WithPrompt, WithSingleCallable},
             LLMResponse,
         },
         prompt_renderer::PromptRenderer,
@@ -15,7 +16,7 @@ use crate::{
     RuntimeContext,
 };
 
-use super::{OrchestrationScope, OrchestratorNodeIterator};
+use super::{OrchestrationScope, OrchestratorNodeIterator, SkippedRetries};
 
 pub async fn orchestrate(
     iter: OrchestratorNodeIterator,
@@ -35,7 +36,12 @@ pub async fn orchestrate(
     let mut results = Vec::new();
     let mut total_sleep_duration = std::time::Duration::from_secs(0);
 
+    let mut skipped: Option<SkippedRetries> = None;
+
     for node in iter {
+        if skipped.as_ref().is_some_and(|s| node.is_skipped(s)) {
+            continue;
+        }
         let prompt = match node.render_prompt(ir, prompt, ctx, params).await {
             Ok(p) => p,
             Err(e) => {
@@ -43,25 +49,80 @@ pub async fn orchestrate(
                 continue;
             }
         };
-        let response = node.single_call(&ctx, &prompt).await;
+        if ctx.cancellation.is_cancelled() {
+            let (system_start, instant_start) =
+                (web_time::SystemTime::now(), web_time::Instant::now());
+            let response = node.cancelled_response(prompt, system_start, instant_start);
+            results.push((node.scope, response, None));
+            break;
+        }
+        let cache = node.response_cache(ctx, &prompt);
+        let response = match cache.as_ref().and_then(|cache| cache.lookup()) {
+            Some(hit) => hit,
+            None => {
+                let Some(permit) = node.acquire_rate_limit(ctx, &prompt).await else {
+                    let (system_start, instant_start) =
+                        (web_time::SystemTime::now(), web_time::Instant::now());
+                    let response = node.cancelled_response(prompt, system_start, instant_start);
+                    results.push((node.scope, response, None));
+                    break;
+                };
+                if let Some(reason) = node.circuit_open(ctx) {
+                    let mut response = LLMResponse::InternalFailure(reason);
+                    permit.finish(&mut response);
+                    results.push((node.scope, response, None));
+                    continue;
+                }
+                // Time spent queued is not part of the timeout.
+                let (system_start, instant_start) =
+                    (web_time::SystemTime::now(), web_time::Instant::now());
+                let limit = node.provider.timeouts().single_call();
+                let call = ctx.cancellation.race(node.single_call(&ctx, &prompt));
+                let mut response = match within(limit, call).await {
+                    Ok(Some(response)) => response,
+                    Ok(None) => node.cancelled_response(prompt, system_start, instant_start),
+                    Err(limit) => node.timeout_response(prompt, limit, system_start, instant_start),
+                };
+                permit.finish(&mut response);
+                node.record_outcome(ctx, &response);
+                node.price(ctx, &mut response);
+                if let Some(cache) = &cache {
+                    cache.mark_miss(&mut response);
+                }
+                response
+            }
+        };
         let parsed_response = match &response {
             LLMResponse::Success(s) => Some(parse_fn(&s.content)),
             _ => None,
         };
+        if let (Some(cache), Some(Ok(_))) = (&cache, &parsed_response) {
+            cache.store(&response);
+        }
 
-        let sleep_duration = node.error_sleep_duration().cloned();
+        let sleep_duration = match node.skipped_retries(ir, &response) {
+            Some(retries) => {
+                skipped = Some(retries);
+                None
+            }
+            None => node.retry_delay(ir, &response),
+        };
         results.push((node.scope, response, parsed_response));
 
         // Currently, we break out of the loop if an LLM responded, even if we couldn't parse the result.
         if results
             .last()
             .map_or(false, |(_, r, _)| matches!(r, LLMResponse::Success(_)))
+            || ctx.cancellation.is_cancelled()
         {
             break;
         } else {
             if let Some(duration) = sleep_duration {
                 total_sleep_duration += duration;
-                async_std::task::sleep(duration).await;
+                // A cancelled sleep is recorded by the next node.
+                ctx.cancellation
+                    .race(async_std::task::sleep(duration))
+                    .await;
             }
         }
     }
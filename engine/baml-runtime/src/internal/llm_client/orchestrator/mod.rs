@@ -11,9 +11,17 @@ use crate::{
 
 use super::traits::WithRenderRawCurl;
 use super::{
-    strategy::roundrobin::RoundRobinStrategy,
-    traits::{StreamResponse, WithPrompt, WithSingleCallable, WithStreamable},
-    LLMResponse,
+    rate_limits::{estimate_tokens, RatePermit},
+    response_cache::CachedCall,
+    retry_policy::CallablePolicy,
+    strategy::{
+        circuit_breaker::CircuitBreaker, fallback::FallbackStrategy, roundrobin::RoundRobinStrategy,
+    },
+    timeouts::Limit,
+    traits::{
+        StreamResponse, WithClientProperties, WithPrompt, WithSingleCallable, WithStreamable,
+    },
+    ErrorCode, LLMErrorResponse, LLMResponse,
 };
 
 pub use super::primitive::LLMPrimitiveProvider;
@@ -43,7 +51,7 @@ impl std::fmt::Display for ExecutionScope {
                 write!(f, "RoundRobin({}, {})", strategy.name, index)
             }
             ExecutionScope::Fallback(strategy, index) => {
-                write!(f, "Fallback({}, {})", strategy, index)
+                write!(f, "Fallback({}, {})", strategy.name, index)
             }
         }
     }
@@ -81,6 +89,208 @@ impl OrchestratorNode {
             _ => None,
         })
     }
+
+    /// How long to wait after this node failed with `response`: the delay
+    /// of its innermost retry policy that retries it, or the one the provider
+    /// asked for if that is longer, capped by that policy.
+    pub fn retry_delay(&self, ir: &IntermediateRepr, response: &LLMResponse) -> Option<Duration> {
+        self.scope.scope.iter().rev().find_map(|scope| match scope {
+            ExecutionScope::Retry(policy, count, delay) => {
+                let delay = ir
+                    .walk_retry_policies()
+                    .find(|p| p.name() == policy)
+                    .map_or(*delay, |policy| {
+                        CallablePolicy::from(policy).delay_after(response, *count, *delay)
+                    });
+                (!delay.is_zero()).then_some(delay)
+            }
+            _ => None,
+        })
+    }
+
+    /// The innermost retry policy of this node, with its position in the
+    /// scope and the attempt number.
+    fn retry_scope(&self) -> Option<(usize, &str, usize)> {
+        self.scope
+            .scope
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(pos, scope)| match scope {
+                ExecutionScope::Retry(policy, count, _) => Some((pos, policy.as_str(), *count)),
+                _ => None,
+            })
+    }
+
+    /// The remaining attempts of this node's retry policy, if its
+    /// `retry_on` doesn't include the error of `response`.
+    pub fn skipped_retries(
+        &self,
+        ir: &IntermediateRepr,
+        response: &LLMResponse,
+    ) -> Option<SkippedRetries> {
+        let (pos, policy, count) = self.retry_scope()?;
+        let walker = ir.walk_retry_policies().find(|p| p.name() == policy)?;
+        if CallablePolicy::from(walker).retries(response) {
+            return None;
+        }
+        Some(SkippedRetries {
+            prefix: self.scope.scope[..pos]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            policy: policy.to_string(),
+            count,
+        })
+    }
+
+    pub fn is_skipped(&self, skipped: &SkippedRetries) -> bool {
+        let pos = skipped.prefix.len();
+        match self.scope.scope.get(pos) {
+            Some(ExecutionScope::Retry(policy, count, _)) => {
+                *policy == skipped.policy
+                    && *count > skipped.count
+                    && self.scope.scope[..pos]
+                        .iter()
+                        .map(|s| s.to_string())
+                        .eq(skipped.prefix.iter().cloned())
+            }
+            _ => false,
+        }
+    }
+
+    /// The circuit breakers of the strategies this node goes through, with
+    /// the index of its client in each.
+    fn circuits<'a>(
+        &'a self,
+        ctx: &'a RuntimeContext,
+    ) -> impl Iterator<Item = (&'a str, Arc<CircuitBreaker>, usize)> {
+        self.scope
+            .scope
+            .iter()
+            .filter_map(move |scope| match scope {
+                ExecutionScope::RoundRobin(strategy, index) => strategy
+                    .circuit_breaker(ctx)
+                    .map(|c| (strategy.name.as_str(), c, *index)),
+                ExecutionScope::Fallback(strategy, index) => strategy
+                    .circuit_breaker(ctx)
+                    .map(|c| (strategy.name.as_str(), c, *index)),
+                _ => None,
+            })
+    }
+
+    /// Why the call should be skipped, if a strategy's circuit for this
+    /// node's client is open. Only call this right before the request to the
+    /// provider, as it takes a half-open circuit's one probe.
+    pub fn circuit_open(&self, ctx: &RuntimeContext) -> Option<String> {
+        self.circuits(ctx)
+            .find(|(_, circuit, index)| !circuit.allow(*index))
+            .map(|(strategy, circuit, index)| {
+                format!(
+                    "Skipped client {}: its circuit in {} is {}",
+                    self.provider.name(),
+                    strategy,
+                    circuit.state(index)
+                )
+            })
+    }
+
+    pub fn timeout_response(
+        &self,
+        prompt: RenderedPrompt,
+        limit: Limit,
+        system_start: web_time::SystemTime,
+        instant_start: web_time::Instant,
+    ) -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: self.provider.name().into(),
+            model: None,
+            prompt,
+            start_time: system_start,
+            latency: instant_start.elapsed(),
+            request_options: self.provider.request_options().clone(),
+            message: limit.message(instant_start.elapsed()),
+            code: ErrorCode::Timeout,
+            retry_after: None,
+            rate_limit_wait_ms: None,
+        })
+    }
+
+    pub fn cancelled_response(
+        &self,
+        prompt: RenderedPrompt,
+        system_start: web_time::SystemTime,
+        instant_start: web_time::Instant,
+    ) -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: self.provider.name().into(),
+            model: None,
+            prompt,
+            start_time: system_start,
+            latency: instant_start.elapsed(),
+            request_options: self.provider.request_options().clone(),
+            message: "Cancelled".into(),
+            code: ErrorCode::Cancelled,
+            retry_after: None,
+            rate_limit_wait_ms: None,
+        })
+    }
+
+    /// The cache entry for calling this node's client with `prompt`, if the
+    /// cache is on for this call.
+    pub fn response_cache(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+    ) -> Option<CachedCall> {
+        CachedCall::for_call(
+            ctx,
+            self.provider.name(),
+            self.provider.request_options(),
+            self.provider.cache(),
+            prompt,
+        )
+    }
+
+    /// Waits for this node's client to be under its rate limits, or returns
+    /// `None` if the call is cancelled first.
+    pub async fn acquire_rate_limit(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+    ) -> Option<RatePermit> {
+        let limiter = ctx
+            .rate_limiters
+            .get(self.provider.name(), self.provider.rate_limits());
+        let tokens = estimate_tokens(prompt, self.provider.request_options());
+        ctx.cancellation.race(limiter.acquire(tokens)).await
+    }
+
+    /// Sets the cost of `response` from the context's price table.
+    pub fn price(&self, ctx: &RuntimeContext, response: &mut LLMResponse) {
+        if let LLMResponse::Success(response) = response {
+            response.metadata.cost_usd = ctx.price_table.cost(
+                self.provider.provider(),
+                &response.model,
+                response.metadata.prompt_tokens,
+                response.metadata.output_tokens,
+            );
+        }
+    }
+
+    pub fn record_outcome(&self, ctx: &RuntimeContext, response: &LLMResponse) {
+        self.circuits(ctx)
+            .for_each(|(_, circuit, index)| circuit.record(index, response));
+    }
+}
+
+/// Later attempts of a retry policy, which are skipped after an attempt
+/// failed with an error the policy doesn't retry.
+pub struct SkippedRetries {
+    // The scopes before the retry policy, e.g. the fallback it is part of.
+    prefix: Vec<String>,
+    policy: String,
+    count: usize,
 }
 
 #[derive(Default, Clone, Serialize)]
@@ -146,7 +356,7 @@ pub enum ExecutionScope {
     // StrategyName, ClientIndex
     RoundRobin(Arc<RoundRobinStrategy>, usize),
     // StrategyName, ClientIndex
-    Fallback(String, usize),
+    Fallback(Arc<FallbackStrategy>, usize),
 }
 
 pub type OrchestratorNodeIterator = Vec<OrchestratorNode>;
@@ -8,7 +8,8 @@ use web_time::Duration;
 use crate::{
     internal::{
         llm_client::{
-            traits::{WithPrompt, WithStreamable},
+            timeouts::within,
+            traits::{StreamResponse, WithClientProperties, WithPrompt, WithStreamable},
             LLMErrorResponse, LLMResponse,
         },
         prompt_renderer::PromptRenderer,
@@ -16,7 +17,7 @@ use crate::{
     FunctionResult, RuntimeContext,
 };
 
-use super::{OrchestrationScope, OrchestratorNodeIterator};
+use super::{OrchestrationScope, OrchestratorNodeIterator, SkippedRetries};
 
 pub async fn orchestrate_stream<F>(
     iter: OrchestratorNodeIterator,
@@ -42,7 +43,12 @@ where
     let mut total_sleep_duration = std::time::Duration::from_secs(0);
 
     //advanced curl viewing, use render_raw_curl on each node. TODO
+    let mut skipped: Option<SkippedRetries> = None;
+
     for node in iter {
+        if skipped.as_ref().is_some_and(|s| node.is_skipped(s)) {
+            continue;
+        }
         let prompt = match node.render_prompt(ir, prompt, ctx, params).await {
             Ok(p) => p,
             Err(e) => {
@@ -50,61 +56,166 @@ where
                 continue;
             }
         };
+        if ctx.cancellation.is_cancelled() {
+            let (system_start, instant_start) =
+                (web_time::SystemTime::now(), web_time::Instant::now());
+            let response = node.cancelled_response(prompt, system_start, instant_start);
+            results.push((node.scope, response, None));
+            break;
+        }
+
+        let cache = node.response_cache(ctx, &prompt);
+        let cached = cache.as_ref().and_then(|cache| cache.lookup());
+        let is_hit = cached.is_some();
 
+        // Held until the stream ends.
+        let permit = match is_hit {
+            true => None,
+            false => match node.acquire_rate_limit(ctx, &prompt).await {
+                Some(permit) => Some(permit),
+                None => {
+                    let (system_start, instant_start) =
+                        (web_time::SystemTime::now(), web_time::Instant::now());
+                    let response = node.cancelled_response(prompt, system_start, instant_start);
+                    results.push((node.scope, response, None));
+                    break;
+                }
+            },
+        };
+        if !is_hit {
+            if let Some(reason) = node.circuit_open(ctx) {
+                let mut response = LLMResponse::InternalFailure(reason);
+                if let Some(permit) = permit {
+                    permit.finish(&mut response);
+                }
+                results.push((node.scope, response, None));
+                continue;
+            }
+        }
+
+        // Time spent queued is not part of the timeouts.
         let (system_start, instant_start) = (web_time::SystemTime::now(), web_time::Instant::now());
-        let stream_res = node.stream(ctx, &prompt).await;
-        let final_response = match stream_res {
-            Ok(response) => response
-                .map(|stream_part| {
-                    if let Some(on_event) = on_event.as_ref() {
-                        match &stream_part {
-                            LLMResponse::Success(s) => {
-                                let parsed = partial_parse_fn(&s.content);
-                                on_event(FunctionResult::new(
-                                    node.scope.clone(),
-                                    LLMResponse::Success(s.clone()),
-                                    Some(parsed),
-                                ));
+        let timeouts = node.provider.timeouts();
+        let mut time_to_first_token = None;
+        let stream_res: StreamResponse = match cached {
+            // A hit is replayed as a stream of one chunk.
+            Some(hit) => Ok(Box::pin(futures::stream::once(futures::future::ready(hit)))),
+            None => match within(
+                timeouts.next_chunk(instant_start, false),
+                ctx.cancellation.race(node.stream(ctx, &prompt)),
+            )
+            .await
+            {
+                Ok(Some(stream_res)) => stream_res,
+                Ok(None) => {
+                    Err(node.cancelled_response(prompt.clone(), system_start, instant_start))
+                }
+                Err(limit) => {
+                    Err(node.timeout_response(prompt.clone(), limit, system_start, instant_start))
+                }
+            },
+        };
+        let mut final_response = match stream_res {
+            Ok(mut response) => {
+                let mut last = None;
+                loop {
+                    let limit = timeouts.next_chunk(instant_start, last.is_some());
+                    match within(limit, ctx.cancellation.race(response.next())).await {
+                        Ok(Some(Some(stream_part))) => {
+                            time_to_first_token.get_or_insert_with(|| instant_start.elapsed());
+                            if let Some(on_event) = on_event.as_ref() {
+                                match &stream_part {
+                                    LLMResponse::Success(s) => {
+                                        let parsed = partial_parse_fn(&s.content);
+                                        on_event(FunctionResult::new(
+                                            node.scope.clone(),
+                                            LLMResponse::Success(s.clone()),
+                                            Some(parsed),
+                                        ));
+                                    }
+                                    _ => {}
+                                }
                             }
-                            _ => {}
+                            last = Some(stream_part);
+                        }
+                        Ok(Some(None)) => {
+                            break last.unwrap_or_else(|| {
+                                LLMResponse::LLMFailure(LLMErrorResponse {
+                                    client: node.provider.name().into(),
+                                    model: None,
+                                    prompt,
+                                    start_time: system_start,
+                                    latency: instant_start.elapsed(),
+                                    request_options: node.provider.request_options().clone(),
+                                    message: "Stream ended without response".to_string(),
+                                    code: crate::internal::llm_client::ErrorCode::from_u16(2),
+                                    retry_after: None,
+                                    rate_limit_wait_ms: None,
+                                })
+                            });
+                        }
+                        Ok(None) => {
+                            break node.cancelled_response(prompt, system_start, instant_start);
+                        }
+                        Err(limit) => {
+                            break node.timeout_response(
+                                prompt,
+                                limit,
+                                system_start,
+                                instant_start,
+                            );
                         }
                     }
-                    stream_part
-                })
-                .fold(None, |_, current| Some(current))
-                .await
-                .unwrap_or_else(|| {
-                    LLMResponse::LLMFailure(LLMErrorResponse {
-                        client: node.provider.name().into(),
-                        model: None,
-                        prompt,
-                        start_time: system_start,
-                        latency: instant_start.elapsed(),
-                        request_options: node.provider.request_options().clone(),
-                        message: "Stream ended without response".to_string(),
-                        code: crate::internal::llm_client::ErrorCode::from_u16(2),
-                    })
-                }),
+                }
+            }
             Err(response) => response,
         };
+        if let Some(permit) = permit {
+            permit.finish(&mut final_response);
+        }
+        if !is_hit {
+            if let (Some(ttft), LLMResponse::Success(s)) =
+                (time_to_first_token, &mut final_response)
+            {
+                s.metadata.time_to_first_token_ms = Some(ttft.as_millis() as u64);
+            }
+            node.record_outcome(ctx, &final_response);
+            node.price(ctx, &mut final_response);
+            if let Some(cache) = &cache {
+                cache.mark_miss(&mut final_response);
+            }
+        }
 
         let parsed_response = match &final_response {
             LLMResponse::Success(s) => Some(parse_fn(&s.content)),
             _ => None,
         };
-        let sleep_duration = node.error_sleep_duration().cloned();
+        if let (Some(cache), Some(Ok(_))) = (&cache, &parsed_response) {
+            cache.store(&final_response);
+        }
+        let sleep_duration = match node.skipped_retries(ir, &final_response) {
+            Some(retries) => {
+                skipped = Some(retries);
+                None
+            }
+            None => node.retry_delay(ir, &final_response),
+        };
         results.push((node.scope, final_response, parsed_response));
 
         // Currently, we break out of the loop if an LLM responded, even if we couldn't parse the result.
         if results
             .last()
             .map_or(false, |(_, r, _)| matches!(r, LLMResponse::Success(_)))
+            || ctx.cancellation.is_cancelled()
         {
             break;
         } else {
             if let Some(duration) = sleep_duration {
                 total_sleep_duration += duration;
-                async_std::task::sleep(duration).await;
+                // A cancelled sleep is recorded by the next node.
+                ctx.cancellation
+                    .race(async_std::task::sleep(duration))
+                    .await;
             }
         }
     }
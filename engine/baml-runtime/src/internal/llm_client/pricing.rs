@@ -0,0 +1,152 @@
+//! Prices of models, used to compute the cost of LLM calls.
+//!
+//! The table is set with `BAML_PRICE_TABLE`, either as the path to a JSON
+//! file or as the JSON itself. It is keyed by provider, as written in the
+//! client, and then model, with prices in USD per million tokens:
+//!
+//!   { "openai": { "gpt-4o": { "input": 2.5, "output": 10 } } }
+//!
+//! Providers report dated model names such as `gpt-4o-2024-08-06`, so a model
+//! that isn't in the table uses the price of the longest model name it
+//! starts with.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+pub const PRICE_TABLE_ENV_VAR: &str = "BAML_PRICE_TABLE";
+
+/// USD per million tokens.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelPrice {
+    pub input: f64,
+    pub output: f64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct PriceTable {
+    providers: HashMap<String, HashMap<String, ModelPrice>>,
+}
+
+impl PriceTable {
+    /// The table set in `env`, or an empty one if `BAML_PRICE_TABLE` is unset.
+    pub fn from_env(env: &HashMap<String, String>) -> Result<Self> {
+        let Some(value) = env.get(PRICE_TABLE_ENV_VAR).map(|v| v.trim()) else {
+            return Ok(Self::default());
+        };
+        if value.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let contents = if value.starts_with('{') {
+            value.to_string()
+        } else {
+            #[cfg(target_arch = "wasm32")]
+            anyhow::bail!("{PRICE_TABLE_ENV_VAR} must contain the JSON price table in WebAssembly");
+            #[cfg(not(target_arch = "wasm32"))]
+            std::fs::read_to_string(value)
+                .with_context(|| format!("Failed to read {PRICE_TABLE_ENV_VAR} at {value}"))?
+        };
+        serde_json::from_str(&contents).with_context(|| {
+            format!(
+                "Invalid {PRICE_TABLE_ENV_VAR}. Expected {{ \"<provider>\": {{ \"<model>\": {{ \"input\": <USD per million tokens>, \"output\": <USD per million tokens> }} }} }}"
+            )
+        })
+    }
+
+    pub fn price(&self, provider: &str, model: &str) -> Option<&ModelPrice> {
+        let models = self.providers.get(provider)?;
+        models.get(model).or_else(|| {
+            models
+                .iter()
+                .filter(|(name, _)| model.starts_with(name.as_str()))
+                .max_by_key(|(name, _)| name.len())
+                .map(|(_, price)| price)
+        })
+    }
+
+    /// The cost in USD of a call, or `None` if the model has no price or the
+    /// provider didn't report any tokens.
+    pub fn cost(
+        &self,
+        provider: &str,
+        model: &str,
+        prompt_tokens: Option<u64>,
+        output_tokens: Option<u64>,
+    ) -> Option<f64> {
+        if prompt_tokens.is_none() && output_tokens.is_none() {
+            return None;
+        }
+        let price = self.price(provider, model)?;
+        Some(
+            (prompt_tokens.unwrap_or(0) as f64 * price.input
+                + output_tokens.unwrap_or(0) as f64 * price.output)
+                / 1_000_000.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> PriceTable {
+        let env = HashMap::from([(
+            PRICE_TABLE_ENV_VAR.to_string(),
+            r#"{
+                "openai": {
+                    "gpt-4o": { "input": 2.5, "output": 10 },
+                    "gpt-4o-mini": { "input": 0.15, "output": 0.6 }
+                }
+            }"#
+            .to_string(),
+        )]);
+        PriceTable::from_env(&env).unwrap()
+    }
+
+    #[test]
+    fn test_longest_prefix() {
+        let table = table();
+        assert_eq!(table.price("openai", "gpt-4o").unwrap().input, 2.5);
+        assert_eq!(
+            table
+                .price("openai", "gpt-4o-mini-2024-07-18")
+                .unwrap()
+                .input,
+            0.15
+        );
+        assert_eq!(
+            table.price("openai", "gpt-4o-2024-08-06").unwrap().input,
+            2.5
+        );
+        assert!(table.price("openai", "o1-preview").is_none());
+        assert!(table.price("anthropic", "gpt-4o").is_none());
+    }
+
+    #[test]
+    fn test_cost() {
+        let table = table();
+        assert_eq!(
+            table.cost("openai", "gpt-4o", Some(1_000_000), Some(500_000)),
+            Some(7.5)
+        );
+        assert_eq!(table.cost("openai", "gpt-4o", None, None), None);
+        assert_eq!(table.cost("openai", "o1", Some(10), Some(10)), None);
+    }
+
+    #[test]
+    fn test_from_env() {
+        assert!(PriceTable::from_env(&HashMap::new())
+            .unwrap()
+            .providers
+            .is_empty());
+        let env = HashMap::from([(
+            PRICE_TABLE_ENV_VAR.to_string(),
+            r#"{ "openai": { "gpt-4o": { "input": 2.5 } } }"#.to_string(),
+        )]);
+        assert!(PriceTable::from_env(&env).is_err());
+    }
+}
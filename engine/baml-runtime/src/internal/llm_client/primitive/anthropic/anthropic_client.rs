@@ -1,4 +1,7 @@
 use crate::internal::llm_client::{
+    rate_limits::RateLimits,
+    response_cache::CacheConfig,
+    timeouts::Timeouts,
     traits::{ToProviderMessage, ToProviderMessageExt, WithClientProperties},
     AllowedMetadata, ResolveMediaUrls,
 };
@@ -43,6 +46,9 @@ struct PostRequestProperities {
     headers: HashMap<String, String>,
     proxy_url: Option<String>,
     allowed_metadata: AllowedMetadata,
+    timeouts: Timeouts,
+    rate_limits: RateLimits,
+    cache: Option<CacheConfig>,
     // These are passed directly to the Anthropic API.
     properties: HashMap<String, serde_json::Value>,
 }
@@ -91,6 +97,9 @@ fn resolve_properties(
         )?,
         None => AllowedMetadata::None,
     };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
 
     let mut headers = match properties.remove("headers") {
         Some(headers) => headers
@@ -119,6 +128,9 @@ fn resolve_properties(
         api_key,
         headers,
         allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
         properties,
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
     })
@@ -135,6 +147,15 @@ impl WithClientProperties for AnthropicClient {
     fn allowed_metadata(&self) -> &AllowedMetadata {
         &self.properties.allowed_metadata
     }
+    fn timeouts(&self) -> &Timeouts {
+        &self.properties.timeouts
+    }
+    fn rate_limits(&self) -> &RateLimits {
+        &self.properties.rate_limits
+    }
+    fn cache(&self) -> Option<&CacheConfig> {
+        self.properties.cache.as_ref()
+    }
     fn client_properties(&self) -> &HashMap<String, serde_json::Value> {
         &self.properties.properties
     }
@@ -186,6 +207,10 @@ impl SseResponseTrait for AnthropicClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache: None,
+                            cost_usd: None,
+                            rate_limit_wait_ms: None,
+                            time_to_first_token_ms: None,
                         },
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
@@ -211,6 +236,8 @@ impl SseResponseTrait for AnthropicClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        retry_after: None,
+                                        rate_limit_wait_ms: None,
                                     },
                                 )));
                             }
@@ -221,9 +248,9 @@ impl SseResponseTrait for AnthropicClient {
                                 inner.model = body.model;
                                 let ref mut inner = inner.metadata;
                                 inner.baml_is_complete = match body.stop_reason {
-                                    Some(StopReason::StopSequence) | Some(StopReason::EndTurn) => {
-                                        true
-                                    }
+                                    Some(StopReason::StopSequence)
+                                    | Some(StopReason::EndTurn)
+                                    | Some(StopReason::ToolUse) => true,
                                     _ => false,
                                 };
                                 inner.finish_reason =
@@ -235,6 +262,8 @@ impl SseResponseTrait for AnthropicClient {
                             }
                             MessageChunk::ContentBlockDelta(event) => {
                                 inner.content += &event.delta.text;
+                                // Set instead of `text` for `output_mode tool`.
+                                inner.content += &event.delta.partial_json;
                             }
                             MessageChunk::ContentBlockStart(_) => (),
                             MessageChunk::ContentBlockStop(_) => (),
@@ -243,9 +272,9 @@ impl SseResponseTrait for AnthropicClient {
                                 let ref mut inner = inner.metadata;
 
                                 inner.baml_is_complete = match body.delta.stop_reason {
-                                    Some(StopReason::StopSequence) | Some(StopReason::EndTurn) => {
-                                        true
-                                    }
+                                    Some(StopReason::StopSequence)
+                                    | Some(StopReason::EndTurn)
+                                    | Some(StopReason::ToolUse) => true,
                                     _ => false,
                                 };
                                 inner.finish_reason = body
@@ -276,6 +305,8 @@ impl SseResponseTrait for AnthropicClient {
                                         latency: instant_start.elapsed(),
                                         message: err.message,
                                         code: ErrorCode::Other(2),
+                                        retry_after: None,
+                                        rate_limit_wait_ms: None,
                                     },
                                 )));
                             }
@@ -293,11 +324,11 @@ impl SseResponseTrait for AnthropicClient {
 impl WithStreamChat for AnthropicClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
     ) -> StreamResponse {
         let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
@@ -374,6 +405,7 @@ impl RequestBuilder for AnthropicClient {
 
     async fn build_request(
         &self,
+        ctx: &RuntimeContext,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
         allow_proxy: bool,
         stream: bool,
@@ -414,6 +446,20 @@ impl RequestBuilder for AnthropicClient {
             }
         }
 
+        if let Some(tool) = &ctx.output_tool {
+            body_obj.insert(
+                "tools".into(),
+                json!([{
+                    "name": tool.name,
+                    "input_schema": tool.parameters,
+                }]),
+            );
+            body_obj.insert(
+                "tool_choice".into(),
+                json!({ "type": "tool", "name": tool.name }),
+            );
+        }
+
         if stream {
             body_obj.insert("stream".into(), true.into());
         }
@@ -434,17 +480,19 @@ impl WithChat for AnthropicClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
-        let (response, system_now, instant_now) = match make_parsed_request::<
-            AnthropicMessageResponse,
-        >(
-            self, either::Either::Right(prompt), false
-        )
-        .await
-        {
-            Ok(v) => v,
-            Err(e) => return e,
-        };
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+        let (response, system_now, instant_now) =
+            match make_parsed_request::<AnthropicMessageResponse>(
+                self,
+                ctx,
+                either::Either::Right(prompt),
+                false,
+            )
+            .await
+            {
+                Ok(v) => v,
+                Err(e) => return e,
+            };
 
         if response.content.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -459,20 +507,24 @@ impl WithChat for AnthropicClient {
                     response.content.len()
                 ),
                 code: ErrorCode::Other(200),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             });
         }
 
         LLMResponse::Success(LLMCompleteResponse {
             client: self.context.name.to_string(),
             prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
-            content: response.content[0].text.clone(),
+            content: response.content[0].output(),
             start_time: system_now,
             latency: instant_now.elapsed(),
             request_options: self.properties.properties.clone(),
             model: response.model,
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.stop_reason {
-                    Some(StopReason::StopSequence) | Some(StopReason::EndTurn) => true,
+                    Some(StopReason::StopSequence)
+                    | Some(StopReason::EndTurn)
+                    | Some(StopReason::ToolUse) => true,
                     _ => false,
                 },
                 finish_reason: response
@@ -482,6 +534,10 @@ impl WithChat for AnthropicClient {
                 prompt_tokens: Some(response.usage.input_tokens),
                 output_tokens: Some(response.usage.output_tokens),
                 total_tokens: Some(response.usage.input_tokens + response.usage.output_tokens),
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
             },
         })
     }
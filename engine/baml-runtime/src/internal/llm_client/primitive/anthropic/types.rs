@@ -4,7 +4,22 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AnthropicMessageContent {
     pub r#type: String,
+    #[serde(default)]
     pub text: String,
+    /// The arguments of a `tool_use` block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<serde_json::Value>,
+}
+
+impl AnthropicMessageContent {
+    /// The arguments of a `tool_use` block (as with `output_mode tool`),
+    /// otherwise the text.
+    pub fn output(&self) -> String {
+        match (self.r#type.as_str(), &self.input) {
+            ("tool_use", Some(input)) => input.to_string(),
+            _ => self.text.clone(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -32,6 +47,7 @@ pub enum StopReason {
     MaxTokens,
     StopSequence,
     EndTurn,
+    ToolUse,
     #[serde(other)]
     Unknown,
 }
@@ -52,6 +68,8 @@ pub enum ContentType {
     Image,
     /// text_delta
     TextDelta,
+    /// input_json_delta
+    InputJsonDelta,
     /// tool_use
     ToolUse,
     /// tool_result
@@ -63,7 +81,8 @@ pub struct TextContentBlock {
     /// The content type. It is always `text`.
     #[serde(rename = "type")]
     pub _type: ContentType,
-    /// The text content.
+    /// The text content. Empty for `tool_use` blocks.
+    #[serde(default)]
     pub text: String,
 }
 
@@ -144,11 +163,15 @@ pub struct MessageDeltaChunk {
 /// The text delta content block.
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
 pub struct TextDeltaContentBlock {
-    /// The content type. It is always `text_delta`.
+    /// The content type, either `text_delta` or `input_json_delta`.
     #[serde(rename = "type")]
     pub _type: ContentType,
     /// The text delta content.
+    #[serde(default)]
     pub text: String,
+    /// The next fragment of a `tool_use` block's arguments.
+    #[serde(default)]
+    pub partial_json: String,
 }
 
 /// The stream stop information.
@@ -181,6 +204,7 @@ mod tests {
             delta: TextDeltaContentBlock {
                 _type: ContentType::TextDelta,
                 text: "Hello".to_string(),
+                partial_json: "".to_string(),
             },
         });
         println!("serialized = {}", serde_json::to_string(&chunk)?);
@@ -1,11 +1,20 @@
 use std::collections::HashMap;
 
-use aws_config::{identity::IdentityCache, retry::RetryConfig, BehaviorVersion, ConfigLoader};
+use aws_config::{
+    identity::IdentityCache, retry::RetryConfig, BehaviorVersion, ConfigLoader, Region,
+};
+use aws_credential_types::Credentials;
 use aws_sdk_bedrockruntime::{self as bedrock, operation::converse::ConverseOutput};
 
 use anyhow::{Context, Result};
 use aws_smithy_json::serialize::JsonObjectWriter;
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::{
+    context::BeforeTransmitInterceptorContextMut, Intercept,
+};
 use aws_smithy_runtime_api::client::result::SdkError;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::ConfigBag;
 use aws_smithy_types::Blob;
 use baml_types::BamlMediaContent;
 use baml_types::{BamlMedia, BamlMediaType};
@@ -17,6 +26,7 @@ use serde_json::Map;
 use web_time::Instant;
 use web_time::SystemTime;
 
+use crate::client_registry::ClientProperty;
 use crate::internal::llm_client::traits::{ToProviderMessageExt, WithClientProperties};
 use crate::internal::llm_client::AllowedMetadata;
 use crate::internal::llm_client::{
@@ -28,16 +38,28 @@ use crate::internal::llm_client::{
     ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse, LLMResponse,
     ModelFeatures, ResolveMediaUrls,
 };
+use crate::internal::llm_client::{
+    rate_limits::RateLimits, response_cache::CacheConfig, timeouts::Timeouts,
+};
 
+use crate::internal::prompt_renderer::OutputTool;
 use crate::{RenderCurlSettings, RuntimeContext};
 
 // stores properties required for making a post request to the API
 struct RequestProperties {
     model_id: String,
+    // Unset fields fall back to the SDK's defaults, e.g. AWS_REGION.
+    region: Option<String>,
+    profile: Option<String>,
+    credentials: Option<StaticCredentials>,
+    headers: HashMap<String, String>,
 
     default_role: String,
     inference_config: Option<bedrock::types::InferenceConfiguration>,
     allowed_metadata: AllowedMetadata,
+    timeouts: Timeouts,
+    rate_limits: RateLimits,
+    cache: Option<CacheConfig>,
 
     request_options: HashMap<String, serde_json::Value>,
     ctx_env: HashMap<String, String>,
@@ -52,22 +74,68 @@ pub struct AwsClient {
     properties: RequestProperties,
 }
 
-fn resolve_properties(client: &ClientWalker, ctx: &RuntimeContext) -> Result<RequestProperties> {
-    let mut properties = (&client.item.elem.options)
-        .iter()
-        .map(|(k, v)| {
-            Ok((
-                k.into(),
-                ctx.resolve_expression::<serde_json::Value>(v)
-                    .context(format!(
-                        "client {} could not resolve options.{}",
-                        client.name(),
-                        k
-                    ))?,
-            ))
+/// `access_key_id`, `secret_access_key` and `session_token` from the client
+/// options.
+#[derive(Clone)]
+struct StaticCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl StaticCredentials {
+    fn provider(&self, provider_name: &'static str) -> Credentials {
+        Credentials::new(
+            self.access_key_id.clone(),
+            self.secret_access_key.clone(),
+            self.session_token.clone(),
+            None,
+            provider_name,
+        )
+    }
+}
+
+/// Adds the client's `headers` to every request, before it is signed.
+#[derive(Debug)]
+struct HeadersInterceptor(HashMap<String, String>);
+
+impl Intercept for HeadersInterceptor {
+    fn name(&self) -> &'static str {
+        "HeadersInterceptor"
+    }
+
+    fn modify_before_signing(
+        &self,
+        context: &mut BeforeTransmitInterceptorContextMut<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        let headers = context.request_mut().headers_mut();
+        for (k, v) in &self.0 {
+            headers.try_insert(k.clone(), v.clone())?;
+        }
+        Ok(())
+    }
+}
+
+fn remove_string(
+    properties: &mut HashMap<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<String>> {
+    properties
+        .remove(key)
+        .map(|v| {
+            v.as_str()
+                .map(|s| s.to_string())
+                .context(format!("{key} should be a string"))
         })
-        .collect::<Result<HashMap<_, _>>>()?;
+        .transpose()
+}
 
+fn resolve_properties(
+    mut properties: HashMap<String, serde_json::Value>,
+    ctx: &RuntimeContext,
+) -> Result<RequestProperties> {
     let model_id = properties
         .remove("model_id")
         .context("model_id is required")?
@@ -79,11 +147,54 @@ fn resolve_properties(client: &ClientWalker, ctx: &RuntimeContext) -> Result<Req
         .remove("default_role")
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "user".to_string());
+
+    let region = remove_string(&mut properties, "region")?;
+    let profile = remove_string(&mut properties, "profile")?;
+    let credentials = match (
+        remove_string(&mut properties, "access_key_id")?,
+        remove_string(&mut properties, "secret_access_key")?,
+        remove_string(&mut properties, "session_token")?,
+    ) {
+        (Some(access_key_id), Some(secret_access_key), session_token) => Some(StaticCredentials {
+            access_key_id,
+            secret_access_key,
+            session_token,
+        }),
+        (None, None, None) => None,
+        (None, None, Some(_)) => {
+            anyhow::bail!("session_token requires access_key_id and secret_access_key")
+        }
+        _ => anyhow::bail!("access_key_id and secret_access_key must be set together"),
+    };
+    if credentials.is_some() && profile.is_some() {
+        anyhow::bail!("profile cannot be combined with access_key_id and secret_access_key");
+    }
+
+    let headers = match properties.remove("headers") {
+        Some(headers) => headers
+            .as_object()
+            .context("headers must be a map of strings to strings")?
+            .iter()
+            .map(|(k, v)| {
+                Ok((
+                    k.to_string(),
+                    v.as_str()
+                        .context(format!("Header '{}' must be a string", k))?
+                        .to_string(),
+                ))
+            })
+            .collect::<Result<HashMap<_, _>>>()?,
+        None => Default::default(),
+    };
+
     let allowed_metadata = match properties.remove("allowed_role_metadata") {
         Some(allowed_metadata) => serde_json::from_value(allowed_metadata)
             .context("allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']")?,
         None => AllowedMetadata::None,
     };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
     let inference_config = match properties.remove("inference_configuration") {
         Some(v) => Some(
             super::types::InferenceConfiguration::deserialize(v)
@@ -95,17 +206,55 @@ fn resolve_properties(client: &ClientWalker, ctx: &RuntimeContext) -> Result<Req
 
     Ok(RequestProperties {
         model_id,
+        region,
+        profile,
+        credentials,
+        headers,
         default_role,
         inference_config,
         allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
         request_options: properties,
         ctx_env: ctx.env.clone(),
     })
 }
 
 impl AwsClient {
+    pub fn dynamic_new(client: &ClientProperty, ctx: &RuntimeContext) -> Result<AwsClient> {
+        let post_properties = resolve_properties(
+            client
+                .options
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), serde_json::json!(v))))
+                .collect::<Result<HashMap<_, _>>>()?,
+            ctx,
+        )?;
+        let default_role = post_properties.default_role.clone();
+
+        Ok(Self {
+            name: client.name.clone(),
+            context: RenderContext_Client {
+                name: client.name.clone(),
+                provider: client.provider.clone(),
+                default_role,
+            },
+            features: ModelFeatures {
+                chat: true,
+                completion: false,
+                anthropic_system_constraints: true,
+                resolve_media_urls: ResolveMediaUrls::Always,
+                allowed_metadata: post_properties.allowed_metadata.clone(),
+            },
+            retry_policy: client.retry_policy.clone(),
+            properties: post_properties,
+        })
+    }
+
     pub fn new(client: &ClientWalker, ctx: &RuntimeContext) -> Result<AwsClient> {
-        let post_properties = resolve_properties(client, ctx)?;
+        let properties = super::super::resolve_properties_walker(client, ctx)?;
+        let post_properties = resolve_properties(properties, ctx)?;
         let default_role = post_properties.default_role.clone(); // clone before moving
 
         Ok(Self {
@@ -141,37 +290,45 @@ impl AwsClient {
         let loader: ConfigLoader = {
             cfg_if::cfg_if! {
                 if #[cfg(target_arch = "wasm32")] {
-                    use aws_config::Region;
-                    use aws_credential_types::Credentials;
-
-                    let (aws_region, aws_access_key_id, aws_secret_access_key) = match (
-                        self.properties.ctx_env.get("AWS_REGION"),
-                        self.properties.ctx_env.get("AWS_ACCESS_KEY_ID"),
-                        self.properties.ctx_env.get("AWS_SECRET_ACCESS_KEY"),
-                    ) {
-                        (Some(aws_region), Some(aws_access_key_id), Some(aws_secret_access_key)) => {
-                            (aws_region, aws_access_key_id, aws_secret_access_key)
-                        }
-                        _ => {
-                            anyhow::bail!(
-                                "AWS_REGION, AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY must be set in the environment"
-                            )
-                        }
+                    // There is no default credential chain in the browser, so
+                    // anything not in the client options comes from the environment.
+                    let region = self
+                        .properties
+                        .region
+                        .clone()
+                        .or_else(|| self.properties.ctx_env.get("AWS_REGION").cloned());
+                    let credentials = self.properties.credentials.clone().or_else(|| {
+                        Some(StaticCredentials {
+                            access_key_id: self.properties.ctx_env.get("AWS_ACCESS_KEY_ID")?.clone(),
+                            secret_access_key: self
+                                .properties
+                                .ctx_env
+                                .get("AWS_SECRET_ACCESS_KEY")?
+                                .clone(),
+                            session_token: None,
+                        })
+                    });
+                    let (Some(region), Some(credentials)) = (region, credentials) else {
+                        anyhow::bail!(
+                            "AWS_REGION, AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY must be set in the environment, or region, access_key_id and secret_access_key in the client options"
+                        )
                     };
 
-                    let loader = super::wasm::load_aws_config()
-                        .region(Region::new(aws_region.clone()))
-                        .credentials_provider(Credentials::new(
-                            aws_access_key_id.clone(),
-                            aws_secret_access_key.clone(),
-                            None,
-                            None,
-                            "baml-runtime/wasm",
-                        ));
-
-                    loader
+                    super::wasm::load_aws_config()
+                        .region(Region::new(region))
+                        .credentials_provider(credentials.provider("baml-runtime/wasm"))
                 } else {
-                    aws_config::defaults(BehaviorVersion::latest())
+                    let mut loader = aws_config::defaults(BehaviorVersion::latest());
+                    if let Some(region) = &self.properties.region {
+                        loader = loader.region(Region::new(region.clone()));
+                    }
+                    if let Some(profile) = &self.properties.profile {
+                        loader = loader.profile_name(profile);
+                    }
+                    if let Some(credentials) = &self.properties.credentials {
+                        loader = loader.credentials_provider(credentials.provider("baml-runtime"));
+                    }
+                    loader
                 }
             }
         };
@@ -182,16 +339,28 @@ impl AwsClient {
             .load()
             .await;
 
-        Ok(bedrock::Client::new(&config))
+        let mut config = bedrock::config::Builder::from(&config);
+        if !self.properties.headers.is_empty() {
+            config = config.interceptor(HeadersInterceptor(self.properties.headers.clone()));
+        }
+
+        Ok(bedrock::Client::from_conf(config.build()))
     }
 
-    async fn chat_anyhow<'r>(&self, response: &'r ConverseOutput) -> Result<&'r String> {
+    async fn chat_anyhow(&self, response: &ConverseOutput) -> Result<String> {
         let Some(bedrock::types::ConverseOutput::Message(ref message)) = response.output else {
             anyhow::bail!(
                 "Expected message output in response, but is type {}",
                 "unknown"
             );
         };
+        // With `output_mode tool`, the output is the input of the tool call.
+        if let Some(tool_use) = message.content.iter().find_map(|c| match c {
+            bedrock::types::ContentBlock::ToolUse(tool_use) => Some(tool_use),
+            _ => None,
+        }) {
+            return Ok(super::types::document_to_json(&tool_use.input).to_string());
+        }
         let content = message
             .content
             .get(0)
@@ -210,7 +379,7 @@ impl AwsClient {
             );
         };
 
-        Ok(content)
+        Ok(content.clone())
     }
 
     fn build_request(
@@ -239,16 +408,39 @@ impl AwsClient {
             .map(|m| self.role_to_message(m))
             .collect::<Result<Vec<_>>>()?;
 
+        let tool_config = match &ctx.output_tool {
+            Some(tool) => Some(output_tool_config(tool)?),
+            None => None,
+        };
+
         bedrock::operation::converse::ConverseInput::builder()
             .set_inference_config(self.properties.inference_config.clone())
             .set_model_id(Some(self.properties.model_id.clone()))
             .set_system(system_message)
             .set_messages(Some(converse_messages))
+            .set_tool_config(tool_config)
             .build()
             .context("Failed to convert BAML prompt to AWS Bedrock request")
     }
 }
 
+/// Forces the model to call `tool`, whose input is the function's output.
+fn output_tool_config(tool: &OutputTool) -> Result<bedrock::types::ToolConfiguration> {
+    let spec = bedrock::types::ToolSpecification::builder()
+        .name(tool.name.clone())
+        .input_schema(bedrock::types::ToolInputSchema::Json(
+            super::types::json_to_document(&tool.parameters),
+        ))
+        .build()?;
+    let choice = bedrock::types::SpecificToolChoice::builder()
+        .name(tool.name.clone())
+        .build()?;
+    Ok(bedrock::types::ToolConfiguration::builder()
+        .tools(bedrock::types::Tool::ToolSpec(spec))
+        .tool_choice(bedrock::types::ToolChoice::Tool(choice))
+        .build()?)
+}
+
 fn try_to_json<
     Ser: Fn(
         &mut JsonObjectWriter,
@@ -301,6 +493,15 @@ impl WithClientProperties for AwsClient {
     fn allowed_metadata(&self) -> &crate::internal::llm_client::AllowedMetadata {
         &self.properties.allowed_metadata
     }
+    fn timeouts(&self) -> &Timeouts {
+        &self.properties.timeouts
+    }
+    fn rate_limits(&self) -> &RateLimits {
+        &self.properties.rate_limits
+    }
+    fn cache(&self) -> Option<&CacheConfig> {
+        self.properties.cache.as_ref()
+    }
 }
 
 impl WithClient for AwsClient {
@@ -338,6 +539,8 @@ impl WithStreamChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
+                    rate_limit_wait_ms: None,
                 }));
             }
         };
@@ -354,6 +557,8 @@ impl WithStreamChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
+                    rate_limit_wait_ms: None,
                 }))
             }
         };
@@ -363,7 +568,8 @@ impl WithStreamChat for AwsClient {
             .set_model_id(request.model_id)
             .set_inference_config(request.inference_config)
             .set_system(request.system)
-            .set_messages(request.messages);
+            .set_messages(request.messages)
+            .set_tool_config(request.tool_config);
 
         let system_start = SystemTime::now();
         let instant_start = Instant::now();
@@ -405,6 +611,8 @@ impl WithStreamChat for AwsClient {
                         }
                         _ => ErrorCode::Other(2),
                     },
+                    retry_after: None,
+                    rate_limit_wait_ms: None,
                 }));
             }
         };
@@ -425,6 +633,10 @@ impl WithStreamChat for AwsClient {
                         prompt_tokens: None,
                         output_tokens: None,
                         total_tokens: None,
+                        cache: None,
+                        cost_usd: None,
+                        rate_limit_wait_ms: None,
+                        time_to_first_token_ms: None,
                     },
                 }),
                 response,
@@ -441,12 +653,22 @@ impl WithStreamChat for AwsClient {
                                 bedrock::types::ConverseStreamOutput::ContentBlockDelta(
                                     content_block_delta,
                                 ) => {
-                                    if let Some(bedrock::types::ContentBlockDelta::Text(
-                                        ref delta,
-                                    )) = content_block_delta.delta
-                                    {
-                                        new_state.content += delta;
-                                        // TODO- handle
+                                    match content_block_delta.delta {
+                                        Some(bedrock::types::ContentBlockDelta::Text(
+                                            ref delta,
+                                        )) => {
+                                            new_state.content += delta;
+                                        }
+                                        // Fragments of the tool call's input,
+                                        // for `output_mode tool`.
+                                        Some(bedrock::types::ContentBlockDelta::ToolUse(
+                                            ref delta,
+                                        )) => {
+                                            new_state.content += &delta.input;
+                                        }
+                                        _ => {
+                                            // TODO- handle
+                                        }
                                     }
                                     // TODO- handle
                                 }
@@ -462,7 +684,8 @@ impl WithStreamChat for AwsClient {
                                 bedrock::types::ConverseStreamOutput::MessageStop(stop) => {
                                     new_state.metadata.baml_is_complete = match stop.stop_reason {
                                         bedrock::types::StopReason::StopSequence
-                                        | bedrock::types::StopReason::EndTurn => true,
+                                        | bedrock::types::StopReason::EndTurn
+                                        | bedrock::types::StopReason::ToolUse => true,
                                         _ => false,
                                     };
                                     // TODO- handle
@@ -498,6 +721,8 @@ impl WithStreamChat for AwsClient {
                                 latency: instant_start.elapsed(),
                                 message: format!("Failed to parse event: {:#?}", e),
                                 code: ErrorCode::Other(2),
+                                retry_after: None,
+                                rate_limit_wait_ms: None,
                             }),
                             (None, response),
                         )),
@@ -618,7 +843,7 @@ impl WithChat for AwsClient {
 
     async fn chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         chat_messages: &Vec<RenderedChatMessage>,
     ) -> LLMResponse {
         let client = self.context.name.to_string();
@@ -638,11 +863,13 @@ impl WithChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
+                    rate_limit_wait_ms: None,
                 })
             }
         };
 
-        let request = match self.build_request(_ctx, chat_messages) {
+        let request = match self.build_request(ctx, chat_messages) {
             Ok(r) => r,
             Err(e) => {
                 return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -654,6 +881,8 @@ impl WithChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
+                    rate_limit_wait_ms: None,
                 })
             }
         };
@@ -662,7 +891,8 @@ impl WithChat for AwsClient {
             .set_model_id(request.model_id)
             .set_inference_config(request.inference_config)
             .set_system(request.system)
-            .set_messages(request.messages);
+            .set_messages(request.messages)
+            .set_tool_config(request.tool_config);
 
         let system_start = SystemTime::now();
         let instant_start = Instant::now();
@@ -680,6 +910,8 @@ impl WithChat for AwsClient {
                     message: format!("{:#?}", e),
                     // TODO: derive this from the aws-returned error
                     code: ErrorCode::Other(2),
+                    retry_after: None,
+                    rate_limit_wait_ms: None,
                 });
             }
         };
@@ -688,7 +920,7 @@ impl WithChat for AwsClient {
             Ok(content) => LLMResponse::Success(LLMCompleteResponse {
                 client,
                 prompt,
-                content: content.clone(),
+                content,
                 start_time: system_start.clone(),
                 latency: instant_start.elapsed(),
                 request_options,
@@ -696,7 +928,8 @@ impl WithChat for AwsClient {
                 metadata: LLMCompleteResponseMetadata {
                     baml_is_complete: match response.stop_reason {
                         bedrock::types::StopReason::StopSequence
-                        | bedrock::types::StopReason::EndTurn => true,
+                        | bedrock::types::StopReason::EndTurn
+                        | bedrock::types::StopReason::ToolUse => true,
                         _ => false,
                     },
                     finish_reason: Some(response.stop_reason().as_str().into()),
@@ -715,6 +948,10 @@ impl WithChat for AwsClient {
                         .as_ref()
                         .map(|i| i.total_tokens.try_into().ok())
                         .flatten(),
+                    cache: None,
+                    cost_usd: None,
+                    rate_limit_wait_ms: None,
+                    time_to_first_token_ms: None,
                 },
             }),
             Err(e) => LLMResponse::LLMFailure(LLMErrorResponse {
@@ -726,7 +963,114 @@ impl WithChat for AwsClient {
                 latency: instant_start.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(200),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             }),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use aws_smithy_runtime_api::client::interceptors::context::{Input, InterceptorContext};
+    use aws_smithy_runtime_api::client::orchestrator::HttpRequest;
+    use aws_smithy_runtime_api::client::runtime_components::RuntimeComponentsBuilder;
+    use baml_types::BamlValue;
+
+    use super::*;
+    use crate::RuntimeContextManager;
+
+    fn client(options: &[(&str, BamlValue)]) -> Result<AwsClient> {
+        let client = ClientProperty {
+            name: "Bedrock".into(),
+            provider: "aws-bedrock".into(),
+            retry_policy: None,
+            options: [("model_id", string("anthropic.claude-3-haiku"))]
+                .iter()
+                .chain(options)
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+        };
+        let ctx = RuntimeContextManager::new_from_env_vars(Default::default(), None)
+            .create_ctx(None, None)?;
+        AwsClient::dynamic_new(&client, &ctx)
+    }
+
+    fn string(value: &str) -> BamlValue {
+        BamlValue::String(value.into())
+    }
+
+    #[test]
+    fn test_static_credentials() -> Result<()> {
+        let bedrock = client(&[
+            ("region", string("us-west-2")),
+            ("access_key_id", string("AKIDEXAMPLE")),
+            ("secret_access_key", string("secret")),
+            ("session_token", string("token")),
+        ])?;
+        assert_eq!(bedrock.properties.region.as_deref(), Some("us-west-2"));
+        assert!(bedrock.request_options().is_empty());
+
+        let credentials = bedrock
+            .properties
+            .credentials
+            .as_ref()
+            .context("credentials should be set")?
+            .provider("baml-runtime");
+        assert_eq!(credentials.access_key_id(), "AKIDEXAMPLE");
+        assert_eq!(credentials.secret_access_key(), "secret");
+        assert_eq!(credentials.session_token(), Some("token"));
+
+        let bedrock = client(&[])?;
+        assert!(bedrock.properties.region.is_none());
+        assert!(bedrock.properties.credentials.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_incomplete_credentials() {
+        for options in [
+            vec![("access_key_id", string("AKIDEXAMPLE"))],
+            vec![("secret_access_key", string("secret"))],
+            vec![("session_token", string("token"))],
+            vec![
+                ("access_key_id", string("AKIDEXAMPLE")),
+                ("secret_access_key", string("secret")),
+                ("profile", string("dev")),
+            ],
+            vec![("region", BamlValue::Int(1))],
+        ] {
+            assert!(client(&options).is_err(), "accepted {:?}", options);
+        }
+    }
+
+    #[test]
+    fn test_headers() -> Result<()> {
+        let bedrock = client(&[(
+            "headers",
+            BamlValue::Map(
+                [("x-team".to_string(), string("search"))]
+                    .into_iter()
+                    .collect(),
+            ),
+        )])?;
+        let interceptor = HeadersInterceptor(bedrock.properties.headers.clone());
+
+        let mut context = InterceptorContext::new(Input::doesnt_matter());
+        context.enter_serialization_phase();
+        context.set_request(HttpRequest::empty());
+        let _ = context.take_input();
+        context.enter_before_transmit_phase();
+        interceptor
+            .modify_before_signing(
+                &mut (&mut context).into(),
+                &RuntimeComponentsBuilder::for_tests().build()?,
+                &mut ConfigBag::base(),
+            )
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let request = context.request().context("request should be set")?;
+        assert_eq!(request.headers().get("x-team"), Some("search"));
+        Ok(())
+    }
+}
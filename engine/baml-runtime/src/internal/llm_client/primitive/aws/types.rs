@@ -1,4 +1,5 @@
 use aws_sdk_bedrockruntime::{self as bedrock};
+use aws_smithy_types::{Document, Number};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -27,3 +28,43 @@ impl Into<bedrock::types::InferenceConfiguration> for InferenceConfiguration {
             .build()
     }
 }
+
+/// Tool parameters are sent to Bedrock as a smithy `Document`.
+pub(super) fn json_to_document(value: &serde_json::Value) -> Document {
+    match value {
+        serde_json::Value::Null => Document::Null,
+        serde_json::Value::Bool(b) => Document::Bool(*b),
+        serde_json::Value::Number(n) => Document::Number(match (n.as_u64(), n.as_i64()) {
+            (Some(u), _) => Number::PosInt(u),
+            (None, Some(i)) => Number::NegInt(i),
+            (None, None) => Number::Float(n.as_f64().unwrap_or_default()),
+        }),
+        serde_json::Value::String(s) => Document::String(s.clone()),
+        serde_json::Value::Array(items) => {
+            Document::Array(items.iter().map(json_to_document).collect())
+        }
+        serde_json::Value::Object(obj) => Document::Object(
+            obj.iter()
+                .map(|(k, v)| (k.clone(), json_to_document(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// Tool call arguments come back from Bedrock as a smithy `Document`.
+pub(super) fn document_to_json(document: &Document) -> serde_json::Value {
+    match document {
+        Document::Null => serde_json::Value::Null,
+        Document::Bool(b) => (*b).into(),
+        Document::Number(Number::PosInt(u)) => (*u).into(),
+        Document::Number(Number::NegInt(i)) => (*i).into(),
+        Document::Number(Number::Float(f)) => (*f).into(),
+        Document::String(s) => s.clone().into(),
+        Document::Array(items) => items.iter().map(document_to_json).collect(),
+        Document::Object(obj) => obj
+            .iter()
+            .map(|(k, v)| (k.clone(), document_to_json(v)))
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+    }
+}
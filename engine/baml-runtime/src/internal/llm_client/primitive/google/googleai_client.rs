@@ -2,12 +2,18 @@ use crate::client_registry::ClientProperty;
 use crate::internal::llm_client::traits::{
     ToProviderMessage, ToProviderMessageExt, WithClientProperties,
 };
-use crate::internal::llm_client::{AllowedMetadata, ResolveMediaUrls};
+use crate::internal::llm_client::{
+    rate_limits::RateLimits, resolve_structured_outputs, response_cache::CacheConfig,
+    timeouts::Timeouts, AllowedMetadata, ResolveMediaUrls,
+};
 use crate::RuntimeContext;
 use crate::{
     internal::llm_client::{
         primitive::{
-            google::types::{FinishReason, GoogleResponse},
+            google::{
+                add_response_schema, output_tool_body,
+                types::{FinishReason, GoogleResponse},
+            },
             request::{make_parsed_request, make_request, RequestBuilder},
         },
         traits::{
@@ -36,6 +42,10 @@ struct PostRequestProperities {
     model_id: Option<String>,
     properties: HashMap<String, serde_json::Value>,
     allowed_metadata: AllowedMetadata,
+    timeouts: Timeouts,
+    rate_limits: RateLimits,
+    cache: Option<CacheConfig>,
+    structured_outputs: bool,
 }
 
 pub struct GoogleAIClient {
@@ -76,6 +86,11 @@ fn resolve_properties(
         )?,
         None => AllowedMetadata::None,
     };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
+
+    let structured_outputs = resolve_structured_outputs(&mut properties)?;
 
     let headers = properties.remove("headers").map(|v| {
         if let Some(v) = v.as_object() {
@@ -109,6 +124,10 @@ fn resolve_properties(
         model_id,
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
         allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
+        structured_outputs,
     })
 }
 
@@ -125,6 +144,15 @@ impl WithClientProperties for GoogleAIClient {
     fn allowed_metadata(&self) -> &crate::internal::llm_client::AllowedMetadata {
         &self.properties.allowed_metadata
     }
+    fn timeouts(&self) -> &Timeouts {
+        &self.properties.timeouts
+    }
+    fn rate_limits(&self) -> &RateLimits {
+        &self.properties.rate_limits
+    }
+    fn cache(&self) -> Option<&CacheConfig> {
+        self.properties.cache.as_ref()
+    }
 }
 
 impl WithClient for GoogleAIClient {
@@ -176,6 +204,10 @@ impl SseResponseTrait for GoogleAIClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache: None,
+                            cost_usd: None,
+                            rate_limit_wait_ms: None,
+                            time_to_first_token_ms: None,
                         },
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
@@ -202,6 +234,8 @@ impl SseResponseTrait for GoogleAIClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        retry_after: None,
+                                        rate_limit_wait_ms: None,
                                     },
                                 )));
                             }
@@ -209,7 +243,7 @@ impl SseResponseTrait for GoogleAIClient {
 
                         if let Some(choice) = event.candidates.get(0) {
                             if let Some(content) = choice.content.parts.get(0) {
-                                inner.content += &content.text;
+                                inner.content += &content.output();
                             }
                             match choice.finish_reason.as_ref() {
                                 Some(FinishReason::Stop) => {
@@ -232,12 +266,12 @@ impl SseResponseTrait for GoogleAIClient {
 impl WithStreamChat for GoogleAIClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
     ) -> StreamResponse {
         //incomplete, streaming response object is returned
         let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
@@ -313,6 +347,7 @@ impl RequestBuilder for GoogleAIClient {
 
     async fn build_request(
         &self,
+        ctx: &RuntimeContext,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
         allow_proxy: bool,
         stream: bool,
@@ -360,6 +395,16 @@ impl RequestBuilder for GoogleAIClient {
             }
         }
 
+        if let Some(tool) = &ctx.output_tool {
+            body_obj.extend(output_tool_body(tool)?);
+        }
+
+        if self.properties.structured_outputs && ctx.output_tool.is_none() {
+            if let Some(schema) = ctx.output_schema()? {
+                add_response_schema(body_obj, schema)?;
+            }
+        }
+
         Ok(req.json(&body))
     }
 
@@ -376,15 +421,19 @@ impl WithChat for GoogleAIClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
         //non-streaming, complete response is returned
-        let (response, system_now, instant_now) =
-            match make_parsed_request::<GoogleResponse>(self, either::Either::Right(prompt), false)
-                .await
-            {
-                Ok(v) => v,
-                Err(e) => return e,
-            };
+        let (response, system_now, instant_now) = match make_parsed_request::<GoogleResponse>(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            false,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
 
         if response.candidates.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -399,13 +448,15 @@ impl WithChat for GoogleAIClient {
                     response.candidates.len()
                 ),
                 code: ErrorCode::Other(200),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             });
         }
 
         LLMResponse::Success(LLMCompleteResponse {
             client: self.context.name.to_string(),
             prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
-            content: response.candidates[0].content.parts[0].text.clone(),
+            content: response.candidates[0].content.parts[0].output(),
             start_time: system_now,
             latency: instant_now.elapsed(),
             request_options: self.properties.properties.clone(),
@@ -414,7 +465,7 @@ impl WithChat for GoogleAIClient {
                 .properties
                 .get("model")
                 .and_then(|v| v.as_str().map(|s| s.to_string()))
-                .or_else(|| _ctx.env.get("default model").map(|s| s.to_string()))
+                .or_else(|| ctx.env.get("default model").map(|s| s.to_string()))
                 .unwrap_or_else(|| "".to_string()),
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.candidates[0].finish_reason {
@@ -428,6 +479,10 @@ impl WithChat for GoogleAIClient {
                 prompt_tokens: response.usage_metadata.prompt_token_count,
                 output_tokens: response.usage_metadata.candidates_token_count,
                 total_tokens: response.usage_metadata.total_token_count,
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
             },
         })
     }
@@ -1,4 +1,6 @@
 mod googleai_client;
+mod schema;
 mod types;
 
 pub use googleai_client::GoogleAIClient;
+pub(crate) use schema::{add_response_schema, output_tool_body};
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use crate::internal::prompt_renderer::{OutputSchema, OutputTool};
+
+/// The `tools` and `toolConfig` fields forcing Gemini to call `tool`.
+pub(crate) fn output_tool_body(tool: &OutputTool) -> Result<serde_json::Map<String, Value>> {
+    let mut body = serde_json::Map::new();
+    body.insert(
+        "tools".into(),
+        json!([{
+            "functionDeclarations": [{
+                "name": tool.name,
+                "parameters": to_openapi_schema(&tool.parameters)?,
+            }],
+        }]),
+    );
+    body.insert(
+        "toolConfig".into(),
+        json!({
+            "functionCallingConfig": {
+                "mode": "ANY",
+                "allowedFunctionNames": [tool.name],
+            },
+        }),
+    );
+    Ok(body)
+}
+
+/// Sets `generationConfig.responseSchema` to `schema`, keeping any other
+/// generation config from the client's options. Fails if Gemini can't
+/// express the schema, e.g. for recursive types.
+pub(crate) fn add_response_schema(
+    body: &mut serde_json::Map<String, Value>,
+    schema: &OutputSchema,
+) -> Result<()> {
+    let response_schema = to_openapi_schema(&schema.schema)?;
+    let config = body.entry("generationConfig").or_insert_with(|| json!({}));
+    if let Some(config) = config.as_object_mut() {
+        config.insert("responseMimeType".into(), "application/json".into());
+        config.insert("responseSchema".into(), response_schema);
+    }
+    Ok(())
+}
+
+/// Gemini accepts a subset of OpenAPI 3.0 schemas rather than JSON Schema:
+/// there are no references, `null` is expressed as `nullable`, and `enum`
+/// only applies to strings.
+fn to_openapi_schema(schema: &Value) -> Result<Value> {
+    let Some(obj) = schema.as_object() else {
+        return Ok(schema.clone());
+    };
+    if obj.contains_key("$ref") {
+        anyhow::bail!("Gemini does not support recursive types in schemas");
+    }
+
+    let mut out = serde_json::Map::new();
+    for (key, value) in obj {
+        match key.as_str() {
+            "properties" => {
+                let properties = value
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .map(|(k, v)| Ok((k.clone(), to_openapi_schema(v)?)))
+                    .collect::<Result<serde_json::Map<_, _>>>()?;
+                out.insert(key.clone(), properties.into());
+            }
+            "items" => {
+                out.insert(key.clone(), to_openapi_schema(value)?);
+            }
+            "anyOf" => {
+                let items = value.as_array().into_iter().flatten();
+                let (nulls, items): (Vec<_>, Vec<_>) =
+                    items.partition(|t| t.get("type") == Some(&json!("null")));
+                if !nulls.is_empty() {
+                    out.insert("nullable".into(), true.into());
+                }
+                let mut items = items
+                    .into_iter()
+                    .map(to_openapi_schema)
+                    .collect::<Result<Vec<_>>>()?;
+                if items.len() == 1 {
+                    if let Value::Object(inner) = items.remove(0) {
+                        out.extend(inner);
+                    }
+                } else {
+                    out.insert(key.clone(), items.into());
+                }
+            }
+            "enum" if obj.get("type") != Some(&json!("string")) => {}
+            "additionalProperties" => {}
+            _ => {
+                out.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    Ok(out.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_openapi_schema() -> Result<()> {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "level": {
+                    "anyOf": [
+                        { "type": "string", "enum": ["JUNIOR", "SENIOR"] },
+                        { "type": "null" },
+                    ],
+                },
+                "code": { "type": "integer", "enum": [200] },
+                "scores": { "type": "object", "additionalProperties": { "type": "number" } },
+            },
+            "required": ["name", "code", "scores"],
+        });
+
+        assert_eq!(
+            to_openapi_schema(&schema)?,
+            json!({
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string" },
+                    "level": { "nullable": true, "type": "string", "enum": ["JUNIOR", "SENIOR"] },
+                    "code": { "type": "integer" },
+                    "scores": { "type": "object" },
+                },
+                "required": ["name", "code", "scores"],
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_response_schema() -> Result<()> {
+        let mut body = serde_json::Map::new();
+        body.insert("generationConfig".into(), json!({ "temperature": 0.5 }));
+        let schema = OutputSchema {
+            name: "ExtractResume".into(),
+            schema: json!({ "anyOf": [{ "type": "string" }, { "type": "null" }] }),
+        };
+
+        add_response_schema(&mut body, &schema)?;
+        assert_eq!(
+            body["generationConfig"],
+            json!({
+                "temperature": 0.5,
+                "responseMimeType": "application/json",
+                "responseSchema": { "nullable": true, "type": "string" },
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_openapi_schema_rejects_refs() {
+        let schema = json!({ "$ref": "#/$defs/Node", "$defs": {} });
+        assert!(to_openapi_schema(&schema).is_err());
+    }
+}
@@ -239,6 +239,7 @@ pub struct Content {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Part {
+    #[serde(default)]
     pub text: String,
     pub inline_data: Option<Blob>,
     pub file_data: Option<FileData>,
@@ -247,6 +248,21 @@ pub struct Part {
     pub video_metadata: Option<VideoMetadata>,
 }
 
+impl Part {
+    /// The arguments of a function call (as with `output_mode tool`),
+    /// otherwise the text.
+    pub fn output(&self) -> String {
+        match self
+            .function_call
+            .as_ref()
+            .and_then(|call| call.args.as_ref())
+        {
+            Some(args) => args.to_string(),
+            None => self.text.clone(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Blob {
@@ -264,7 +280,7 @@ pub struct FileData {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FunctionCall {
     pub name: String,
-    pub args: Option<Vec<Struct>>,
+    pub args: Option<serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -0,0 +1,551 @@
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use anyhow::{Context, Result};
+use baml_types::{BamlMap, BamlValue};
+use futures::stream;
+use internal_baml_core::ir::ClientWalker;
+use internal_baml_jinja::{RenderContext_Client, RenderedChatMessage, RenderedPrompt};
+use serde_json::json;
+use web_time::Duration;
+
+use crate::{
+    client_registry::ClientProperty,
+    internal::llm_client::{
+        rate_limits::RateLimits,
+        response_cache::CacheConfig,
+        timeouts::Timeouts,
+        traits::{
+            StreamResponse, WithChat, WithClient, WithClientProperties, WithNoCompletion,
+            WithRenderRawCurl, WithRetryPolicy, WithStreamChat,
+        },
+        AllowedMetadata, ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata,
+        LLMErrorResponse, LLMResponse, ModelFeatures, ResolveMediaUrls,
+    },
+    RenderCurlSettings, RuntimeContext,
+};
+
+const DEFAULT_STREAM_CHUNK_SIZE: usize = 10;
+
+// what the mock does when it is called
+#[derive(Clone, Debug)]
+enum MockReply {
+    Content(String),
+    // rendered with `prompt`, `messages` and `call_count` in scope
+    Template(String),
+    Error {
+        code: u16,
+        message: String,
+        retry_after: Option<Duration>,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct MockResponse {
+    reply: MockReply,
+    latency: Option<Duration>,
+}
+
+struct MockProperties {
+    model: String,
+    default_role: String,
+    allowed_metadata: AllowedMetadata,
+    timeouts: Timeouts,
+    rate_limits: RateLimits,
+    cache: Option<CacheConfig>,
+    // returned in order; the last one is repeated once the list runs out
+    responses: Vec<MockResponse>,
+    stream_chunk_size: usize,
+    properties: HashMap<String, serde_json::Value>,
+}
+
+// a client that never makes a network call, for deterministic tests
+pub struct MockClient {
+    pub name: String,
+    retry_policy: Option<String>,
+    context: RenderContext_Client,
+    features: ModelFeatures,
+    properties: MockProperties,
+    call_count: AtomicUsize,
+}
+
+fn as_u64(value: serde_json::Value, key: &str) -> Result<u64> {
+    value
+        .as_u64()
+        .context(format!("{} must be a non-negative integer", key))
+}
+
+fn as_string(value: serde_json::Value, key: &str) -> Result<String> {
+    match value {
+        serde_json::Value::String(s) => Ok(s),
+        _ => anyhow::bail!("{} must be a string", key),
+    }
+}
+
+// reads a single response from `response`, `response_template` or `error_code`,
+// whether they are set on the client itself or on an entry of `responses`
+fn resolve_response(
+    fields: &mut serde_json::Map<String, serde_json::Value>,
+    default_latency: Option<Duration>,
+) -> Result<MockResponse> {
+    let content = fields
+        .remove("response")
+        .map(|v| as_string(v, "response"))
+        .transpose()?;
+    let template = fields
+        .remove("response_template")
+        .map(|v| as_string(v, "response_template"))
+        .transpose()?;
+    let error_code = fields
+        .remove("error_code")
+        .map(|v| as_u64(v, "error_code"))
+        .transpose()?;
+    let error_message = fields
+        .remove("error_message")
+        .map(|v| as_string(v, "error_message"))
+        .transpose()?;
+    let retry_after = fields
+        .remove("retry_after_ms")
+        .map(|v| as_u64(v, "retry_after_ms").map(Duration::from_millis))
+        .transpose()?;
+    let latency = fields
+        .remove("latency_ms")
+        .map(|v| as_u64(v, "latency_ms").map(Duration::from_millis))
+        .transpose()?
+        .or(default_latency);
+
+    if retry_after.is_some() && error_code.is_none() {
+        anyhow::bail!("retry_after_ms can only be set with error_code");
+    }
+    let reply = match (content, template, error_code) {
+        (Some(content), None, None) => MockReply::Content(content),
+        (None, Some(template), None) => MockReply::Template(template),
+        (None, None, Some(code)) => MockReply::Error {
+            code: u16::try_from(code).context("error_code must be a valid status code")?,
+            message: error_message.unwrap_or_else(|| "Mock error".to_string()),
+            retry_after,
+        },
+        _ => anyhow::bail!("Exactly one of response, response_template or error_code must be set"),
+    };
+
+    Ok(MockResponse { reply, latency })
+}
+
+fn resolve_properties(
+    mut properties: HashMap<String, serde_json::Value>,
+) -> Result<MockProperties> {
+    let model = properties
+        .remove("model")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| "mock".to_string());
+
+    let default_role = properties
+        .remove("default_role")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| "system".to_string());
+
+    let allowed_metadata = match properties.remove("allowed_role_metadata") {
+        Some(allowed_metadata) => serde_json::from_value(allowed_metadata).context(
+            "allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']",
+        )?,
+        None => AllowedMetadata::None,
+    };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
+
+    let stream_chunk_size = match properties.remove("stream_chunk_size") {
+        Some(v) => match as_u64(v, "stream_chunk_size")? {
+            0 => anyhow::bail!("stream_chunk_size must be greater than 0"),
+            n => n as usize,
+        },
+        None => DEFAULT_STREAM_CHUNK_SIZE,
+    };
+
+    let default_latency = properties
+        .remove("latency_ms")
+        .map(|v| as_u64(v, "latency_ms").map(Duration::from_millis))
+        .transpose()?;
+
+    let responses = match properties.remove("responses") {
+        Some(serde_json::Value::Array(items)) if !items.is_empty() => items
+            .into_iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                match item {
+                    serde_json::Value::String(content) => Ok(MockResponse {
+                        reply: MockReply::Content(content),
+                        latency: default_latency,
+                    }),
+                    serde_json::Value::Object(mut fields) => {
+                        let response = resolve_response(&mut fields, default_latency)?;
+                        if let Some(key) = fields.keys().next() {
+                            anyhow::bail!("Unknown key: {}", key);
+                        }
+                        Ok(response)
+                    }
+                    _ => anyhow::bail!("Each response must be a string or a map"),
+                }
+                .context(format!("Invalid responses[{}]", idx))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Some(_) => anyhow::bail!("responses must be a non-empty array"),
+        None => {
+            let mut fields = serde_json::Map::new();
+            for key in [
+                "response",
+                "response_template",
+                "error_code",
+                "error_message",
+                "retry_after_ms",
+            ] {
+                if let Some(v) = properties.remove(key) {
+                    fields.insert(key.to_string(), v);
+                }
+            }
+            vec![resolve_response(&mut fields, default_latency).context(
+                "mock clients need one of: response, response_template, error_code or responses",
+            )?]
+        }
+    };
+
+    Ok(MockProperties {
+        model,
+        default_role,
+        allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
+        responses,
+        stream_chunk_size,
+        properties,
+    })
+}
+
+impl WithRetryPolicy for MockClient {
+    fn retry_policy_name(&self) -> Option<&str> {
+        self.retry_policy.as_deref()
+    }
+}
+
+impl WithClientProperties for MockClient {
+    fn allowed_metadata(&self) -> &AllowedMetadata {
+        &self.properties.allowed_metadata
+    }
+    fn timeouts(&self) -> &Timeouts {
+        &self.properties.timeouts
+    }
+    fn rate_limits(&self) -> &RateLimits {
+        &self.properties.rate_limits
+    }
+    fn cache(&self) -> Option<&CacheConfig> {
+        self.properties.cache.as_ref()
+    }
+    fn client_properties(&self) -> &HashMap<String, serde_json::Value> {
+        &self.properties.properties
+    }
+}
+
+impl WithClient for MockClient {
+    fn context(&self) -> &RenderContext_Client {
+        &self.context
+    }
+
+    fn model_features(&self) -> &ModelFeatures {
+        &self.features
+    }
+}
+
+impl WithNoCompletion for MockClient {}
+
+impl WithRenderRawCurl for MockClient {
+    async fn render_raw_curl(
+        &self,
+        _ctx: &RuntimeContext,
+        _prompt: &Vec<RenderedChatMessage>,
+        _render_settings: RenderCurlSettings,
+    ) -> Result<String> {
+        Ok(format!(
+            "# {} is a mock client and does not make any requests",
+            self.context.name
+        ))
+    }
+}
+
+impl MockClient {
+    pub fn dynamic_new(client: &ClientProperty, _ctx: &RuntimeContext) -> Result<Self> {
+        let properties = resolve_properties(
+            client
+                .options
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), json!(v))))
+                .collect::<Result<HashMap<_, _>>>()?,
+        )?;
+        Ok(Self::from_parts(
+            client.name.clone(),
+            client.provider.clone(),
+            client.retry_policy.clone(),
+            properties,
+        ))
+    }
+
+    pub fn new(client: &ClientWalker, ctx: &RuntimeContext) -> Result<Self> {
+        let properties = super::super::resolve_properties_walker(client, ctx)?;
+        let properties = resolve_properties(properties)?;
+        Ok(Self::from_parts(
+            client.name().into(),
+            client.elem().provider.clone(),
+            client
+                .elem()
+                .retry_policy_id
+                .as_ref()
+                .map(|s| s.to_string()),
+            properties,
+        ))
+    }
+
+    fn from_parts(
+        name: String,
+        provider: String,
+        retry_policy: Option<String>,
+        properties: MockProperties,
+    ) -> Self {
+        Self {
+            context: RenderContext_Client {
+                name: name.clone(),
+                provider,
+                default_role: properties.default_role.clone(),
+            },
+            name,
+            features: ModelFeatures {
+                chat: true,
+                completion: false,
+                anthropic_system_constraints: false,
+                resolve_media_urls: ResolveMediaUrls::Never,
+                allowed_metadata: properties.allowed_metadata.clone(),
+            },
+            retry_policy,
+            properties,
+            call_count: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn request_options(&self) -> &HashMap<String, serde_json::Value> {
+        &self.properties.properties
+    }
+
+    // picks the next scripted response, waits out its latency and resolves it
+    // into either the full content or an error
+    async fn respond(
+        &self,
+        prompt: &Vec<RenderedChatMessage>,
+    ) -> (
+        Result<String, LLMResponse>,
+        web_time::SystemTime,
+        web_time::Instant,
+    ) {
+        let (system_now, instant_now) = (web_time::SystemTime::now(), web_time::Instant::now());
+
+        let call_count = self.call_count.fetch_add(1, Ordering::Relaxed);
+        let responses = &self.properties.responses;
+        let response = &responses[call_count.min(responses.len() - 1)];
+
+        if let Some(latency) = response.latency {
+            async_std::task::sleep(latency).await;
+        }
+
+        let content = match &response.reply {
+            MockReply::Content(content) => Ok(content.clone()),
+            MockReply::Template(template) => {
+                internal_baml_jinja::render_string(template, &template_args(prompt, call_count))
+                    .map_err(|e| LLMResponse::UserFailure(format!("{:#}", e)))
+            }
+            MockReply::Error {
+                code,
+                message,
+                retry_after,
+            } => Err(LLMResponse::LLMFailure(LLMErrorResponse {
+                client: self.context.name.clone(),
+                model: Some(self.properties.model.clone()),
+                prompt: RenderedPrompt::Chat(prompt.clone()),
+                request_options: self.properties.properties.clone(),
+                start_time: system_now,
+                latency: instant_now.elapsed(),
+                message: message.clone(),
+                code: ErrorCode::from_u16(*code),
+                retry_after: *retry_after,
+                rate_limit_wait_ms: None,
+            })),
+        };
+
+        (content, system_now, instant_now)
+    }
+
+    fn complete_response(
+        &self,
+        prompt: &Vec<RenderedChatMessage>,
+        content: String,
+        is_complete: bool,
+        system_now: web_time::SystemTime,
+        instant_now: web_time::Instant,
+    ) -> LLMResponse {
+        LLMResponse::Success(LLMCompleteResponse {
+            client: self.context.name.clone(),
+            model: self.properties.model.clone(),
+            prompt: RenderedPrompt::Chat(prompt.clone()),
+            request_options: self.properties.properties.clone(),
+            content,
+            start_time: system_now,
+            latency: instant_now.elapsed(),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: is_complete,
+                finish_reason: is_complete.then(|| "stop".to_string()),
+                prompt_tokens: None,
+                output_tokens: None,
+                total_tokens: None,
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
+            },
+        })
+    }
+}
+
+// the variables available to `response_template`
+fn template_args(prompt: &Vec<RenderedChatMessage>, call_count: usize) -> BamlValue {
+    let text = |message: &RenderedChatMessage| {
+        message
+            .parts
+            .iter()
+            .filter_map(|part| part.as_text().cloned())
+            .collect::<Vec<_>>()
+            .join("")
+    };
+
+    BamlValue::Map(BamlMap::from([
+        (
+            "prompt".to_string(),
+            BamlValue::String(prompt.iter().map(text).collect::<Vec<_>>().join("\n\n")),
+        ),
+        (
+            "messages".to_string(),
+            BamlValue::List(
+                prompt
+                    .iter()
+                    .map(|message| {
+                        BamlValue::Map(BamlMap::from([
+                            ("role".to_string(), BamlValue::String(message.role.clone())),
+                            ("content".to_string(), BamlValue::String(text(message))),
+                        ]))
+                    })
+                    .collect(),
+            ),
+        ),
+        ("call_count".to_string(), BamlValue::Int(call_count as i64)),
+    ]))
+}
+
+impl WithChat for MockClient {
+    fn chat_options(&self, _ctx: &RuntimeContext) -> Result<internal_baml_jinja::ChatOptions> {
+        Ok(internal_baml_jinja::ChatOptions::new(
+            self.properties.default_role.clone(),
+            None,
+        ))
+    }
+
+    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+        match self.respond(prompt).await {
+            (Ok(content), system_now, instant_now) => {
+                self.complete_response(prompt, content, true, system_now, instant_now)
+            }
+            (Err(e), ..) => e,
+        }
+    }
+}
+
+impl WithStreamChat for MockClient {
+    async fn stream_chat(
+        &self,
+        _ctx: &RuntimeContext,
+        prompt: &Vec<RenderedChatMessage>,
+    ) -> StreamResponse {
+        let (content, system_now, instant_now) = self.respond(prompt).await;
+        let content = content?;
+
+        // each event carries everything streamed so far, like the real providers
+        let chars = content.chars().collect::<Vec<_>>();
+        let chunk_count = chars
+            .len()
+            .div_ceil(self.properties.stream_chunk_size)
+            .max(1);
+        let events = (1..=chunk_count)
+            .map(|i| {
+                let end = (i * self.properties.stream_chunk_size).min(chars.len());
+                self.complete_response(
+                    prompt,
+                    chars[..end].iter().collect(),
+                    i == chunk_count,
+                    system_now,
+                    instant_now,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Box::pin(stream::iter(events)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn props(value: serde_json::Value) -> Result<MockProperties> {
+        resolve_properties(serde_json::from_value(value)?)
+    }
+
+    #[test]
+    fn test_requires_a_response() {
+        assert!(props(json!({})).is_err());
+        assert!(props(json!({ "response": "hi", "error_code": 429 })).is_err());
+        assert!(props(json!({ "responses": [] })).is_err());
+        assert!(props(json!({ "responses": [{ "content": "hi" }] })).is_err());
+    }
+
+    #[test]
+    fn test_resolve_responses() -> Result<()> {
+        let props = props(json!({
+            "responses": [
+                { "error_code": 429, "latency_ms": 5, "retry_after_ms": 100 },
+                "{\"name\": \"Ada\"}",
+                { "response_template": "{{ prompt }}" },
+            ],
+            "latency_ms": 1,
+            "stream_chunk_size": 3,
+            "temperature": 0,
+        }))?;
+
+        assert_eq!(props.responses.len(), 3);
+        assert!(matches!(
+            props.responses[0].reply,
+            MockReply::Error {
+                code: 429,
+                retry_after: Some(d),
+                ..
+            } if d == Duration::from_millis(100)
+        ));
+        assert_eq!(props.responses[0].latency, Some(Duration::from_millis(5)));
+        assert_eq!(props.responses[1].latency, Some(Duration::from_millis(1)));
+        assert!(matches!(props.responses[2].reply, MockReply::Template(_)));
+        assert_eq!(props.stream_chunk_size, 3);
+        assert_eq!(props.model, "mock");
+        assert_eq!(
+            props.properties.keys().collect::<Vec<_>>(),
+            vec!["temperature"]
+        );
+
+        Ok(())
+    }
+}
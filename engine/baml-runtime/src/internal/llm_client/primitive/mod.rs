@@ -10,10 +10,12 @@ use crate::{
 };
 
 use self::{
-    anthropic::AnthropicClient, aws::AwsClient, google::GoogleAIClient, openai::OpenAIClient,
-    request::RequestBuilder, vertex::VertexClient,
+    anthropic::AnthropicClient, aws::AwsClient, google::GoogleAIClient, mock::MockClient,
+    openai::OpenAIClient, request::RequestBuilder, vertex::VertexClient,
 };
 
+#[cfg(not(target_arch = "wasm32"))]
+use super::cassette::Cassette;
 use super::{
     orchestrator::{
         ExecutionScope, IterOrchestrator, OrchestrationScope, OrchestrationState, OrchestratorNode,
@@ -29,6 +31,7 @@ use super::{
 mod anthropic;
 mod aws;
 mod google;
+mod mock;
 mod openai;
 pub(super) mod request;
 mod vertex;
@@ -44,6 +47,7 @@ pub enum LLMPrimitive2 {
     GoogleAIClient,
     VertexClient,
     AwsClient,
+    MockClient,
 }
 
 // #[derive(Delegate)]
@@ -55,6 +59,7 @@ pub enum LLMPrimitiveProvider {
     Google(GoogleAIClient),
     Vertex(VertexClient),
     Aws(aws::AwsClient),
+    Mock(MockClient),
 }
 
 macro_rules! match_llm_provider {
@@ -66,6 +71,7 @@ macro_rules! match_llm_provider {
             LLMPrimitiveProvider::Google(client) => client.$method($($args),*).await,
             LLMPrimitiveProvider::Aws(client) => client.$method($($args),*).await,
             LLMPrimitiveProvider::Vertex(client) => client.$method($($args),*).await,
+            LLMPrimitiveProvider::Mock(client) => client.$method($($args),*).await,
         }
     };
 
@@ -76,6 +82,7 @@ macro_rules! match_llm_provider {
             LLMPrimitiveProvider::Google(client) => client.$method($($args),*),
             LLMPrimitiveProvider::Aws(client) => client.$method($($args),*),
             LLMPrimitiveProvider::Vertex(client) => client.$method($($args),*),
+            LLMPrimitiveProvider::Mock(client) => client.$method($($args),*),
         }
     };
 }
@@ -93,6 +100,15 @@ impl WithClientProperties for LLMPrimitiveProvider {
     fn allowed_metadata(&self) -> &super::AllowedMetadata {
         match_llm_provider!(self, allowed_metadata)
     }
+    fn timeouts(&self) -> &super::timeouts::Timeouts {
+        match_llm_provider!(self, timeouts)
+    }
+    fn rate_limits(&self) -> &super::rate_limits::RateLimits {
+        match_llm_provider!(self, rate_limits)
+    }
+    fn cache(&self) -> Option<&super::response_cache::CacheConfig> {
+        match_llm_provider!(self, cache)
+    }
 }
 
 impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMPrimitiveProvider {
@@ -107,12 +123,15 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMPrimitiveProvider {
             "anthropic" => AnthropicClient::dynamic_new(value, ctx).map(Into::into),
             "google-ai" => GoogleAIClient::dynamic_new(value, ctx).map(Into::into),
             "vertex-ai" => VertexClient::dynamic_new(value, ctx).map(Into::into),
-            // dynamic_new is not implemented for aws::AwsClient
+            "mock" => MockClient::dynamic_new(value, ctx).map(Into::into),
+            "aws-bedrock" => aws::AwsClient::dynamic_new(value, ctx).map(Into::into),
             other => {
                 let options = [
                     "anthropic",
+                    "aws-bedrock",
                     "azure-openai",
                     "google-ai",
+                    "mock",
                     "openai",
                     "openai-generic",
                     "vertex-ai",
@@ -146,12 +165,14 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for LLMPrimitiveProvider {
             "google-ai" => GoogleAIClient::new(client, ctx).map(Into::into),
             "aws-bedrock" => aws::AwsClient::new(client, ctx).map(Into::into),
             "vertex-ai" => VertexClient::new(client, ctx).map(Into::into),
+            "mock" => MockClient::new(client, ctx).map(Into::into),
             other => {
                 let options = [
                     "anthropic",
                     "aws-bedrock",
                     "azure-openai",
                     "google-ai",
+                    "mock",
                     "openai",
                     "openai-generic",
                     "vertex-ai",
@@ -198,6 +219,19 @@ impl WithSingleCallable for LLMPrimitiveProvider {
         ctx: &RuntimeContext,
         prompt: &internal_baml_jinja::RenderedPrompt,
     ) -> LLMResponse {
+        #[cfg(not(target_arch = "wasm32"))]
+        match Cassette::for_call(ctx, self.name(), self.request_options(), prompt, false) {
+            Ok(Some(cassette)) => {
+                return cassette
+                    .single_call(async {
+                        match_llm_provider!(self, single_call, async, ctx, prompt)
+                    })
+                    .await
+            }
+            Ok(None) => {}
+            Err(e) => return LLMResponse::UserFailure(format!("{e:#}")),
+        }
+
         match_llm_provider!(self, single_call, async, ctx, prompt)
     }
 }
@@ -208,6 +242,17 @@ impl WithStreamable for LLMPrimitiveProvider {
         ctx: &RuntimeContext,
         prompt: &internal_baml_jinja::RenderedPrompt,
     ) -> super::traits::StreamResponse {
+        #[cfg(not(target_arch = "wasm32"))]
+        match Cassette::for_call(ctx, self.name(), self.request_options(), prompt, true) {
+            Ok(Some(cassette)) => {
+                return cassette
+                    .stream(async { match_llm_provider!(self, stream, async, ctx, prompt) })
+                    .await
+            }
+            Ok(None) => {}
+            Err(e) => return Err(LLMResponse::UserFailure(format!("{e:#}"))),
+        }
+
         match_llm_provider!(self, stream, async, ctx, prompt)
     }
 }
@@ -235,6 +280,7 @@ impl std::fmt::Display for LLMPrimitiveProvider {
             LLMPrimitiveProvider::Google(_) => write!(f, "Google"),
             LLMPrimitiveProvider::Aws(_) => write!(f, "AWS"),
             LLMPrimitiveProvider::Vertex(_) => write!(f, "Vertex"),
+            LLMPrimitiveProvider::Mock(_) => write!(f, "Mock"),
         }
     }
 }
@@ -244,6 +290,10 @@ impl LLMPrimitiveProvider {
         &match_llm_provider!(self, context).name
     }
 
+    pub fn provider(&self) -> &str {
+        &match_llm_provider!(self, context).provider
+    }
+
     pub fn request_options(&self) -> &std::collections::HashMap<String, serde_json::Value> {
         match_llm_provider!(self, request_options)
     }
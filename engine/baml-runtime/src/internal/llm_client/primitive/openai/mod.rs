@@ -1,5 +1,6 @@
 mod openai_client;
 mod properties;
+mod structured_outputs;
 #[allow(dead_code)]
 mod types;
 
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::internal::llm_client::ResolveMediaUrls;
+use crate::internal::llm_client::{
+    rate_limits::RateLimits, response_cache::CacheConfig, timeouts::Timeouts, ResolveMediaUrls,
+};
 use anyhow::Result;
 use baml_types::{BamlMedia, BamlMediaContent, BamlMediaType};
 use internal_baml_core::ir::ClientWalker;
@@ -12,6 +14,7 @@ use crate::internal::llm_client::{
 };
 
 use super::properties::{self, PostRequestProperties};
+use super::structured_outputs::response_format_body;
 use super::types::{ChatCompletionResponse, ChatCompletionResponseDelta, FinishReason};
 
 use crate::client_registry::ClientProperty;
@@ -57,6 +60,15 @@ impl WithClientProperties for OpenAIClient {
     fn allowed_metadata(&self) -> &crate::internal::llm_client::AllowedMetadata {
         &self.properties.allowed_metadata
     }
+    fn timeouts(&self) -> &Timeouts {
+        &self.properties.timeouts
+    }
+    fn rate_limits(&self) -> &RateLimits {
+        &self.properties.rate_limits
+    }
+    fn cache(&self) -> Option<&CacheConfig> {
+        self.properties.cache.as_ref()
+    }
 }
 
 impl WithClient for OpenAIClient {
@@ -149,10 +161,11 @@ impl WithChat for OpenAIClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
         let (response, system_start, instant_start) =
             match make_parsed_request::<ChatCompletionResponse>(
                 self,
+                ctx,
                 either::Either::Right(prompt),
                 false,
             )
@@ -175,6 +188,8 @@ impl WithChat for OpenAIClient {
                     response.choices.len()
                 ),
                 code: ErrorCode::Other(200),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             });
         }
 
@@ -183,12 +198,7 @@ impl WithChat for OpenAIClient {
         LLMResponse::Success(LLMCompleteResponse {
             client: self.context.name.to_string(),
             prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
-            content: response.choices[0]
-                .message
-                .content
-                .as_ref()
-                .map_or("", |s| s.as_str())
-                .to_string(),
+            content: response.choices[0].message.output().to_string(),
             start_time: system_start,
             latency: instant_start.elapsed(),
             model: response.model,
@@ -196,14 +206,16 @@ impl WithChat for OpenAIClient {
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.choices.get(0) {
                     Some(c) => match c.finish_reason {
-                        Some(FinishReason::Stop) => true,
+                        Some(FinishReason::Stop) | Some(FinishReason::ToolCalls) => true,
                         _ => false,
                     },
                     None => false,
                 },
                 finish_reason: match response.choices.get(0) {
                     Some(c) => match c.finish_reason {
-                        Some(FinishReason::Stop) => Some(FinishReason::Stop.to_string()),
+                        Some(r @ (FinishReason::Stop | FinishReason::ToolCalls)) => {
+                            Some(r.to_string())
+                        }
                         _ => None,
                     },
                     None => None,
@@ -211,6 +223,10 @@ impl WithChat for OpenAIClient {
                 prompt_tokens: usage.map(|u| u.prompt_tokens),
                 output_tokens: usage.map(|u| u.completion_tokens),
                 total_tokens: usage.map(|u| u.total_tokens),
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
             },
         })
     }
@@ -223,6 +239,7 @@ impl RequestBuilder for OpenAIClient {
 
     async fn build_request(
         &self,
+        ctx: &RuntimeContext,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
         allow_proxy: bool,
         stream: bool,
@@ -275,6 +292,35 @@ impl RequestBuilder for OpenAIClient {
             }
         }
 
+        if let Some(tool) = &ctx.output_tool {
+            body_obj.insert(
+                "tools".into(),
+                json!([{
+                    "type": "function",
+                    "function": {
+                        "name": tool.name,
+                        "parameters": tool.parameters,
+                    },
+                }]),
+            );
+            body_obj.insert(
+                "tool_choice".into(),
+                json!({
+                    "type": "function",
+                    "function": { "name": tool.name },
+                }),
+            );
+        }
+
+        if self.properties.structured_outputs && ctx.output_tool.is_none() {
+            if let Some(body) = ctx
+                .output_schema()?
+                .and_then(|schema| response_format_body(&self.provider, schema))
+            {
+                body_obj.extend(body);
+            }
+        }
+
         if stream {
             body_obj.insert("stream".into(), json!(true));
             if self.provider == "openai" {
@@ -333,6 +379,10 @@ impl SseResponseTrait for OpenAIClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache: None,
+                            cost_usd: None,
+                            rate_limit_wait_ms: None,
+                            time_to_first_token_ms: None,
                         },
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
@@ -359,6 +409,8 @@ impl SseResponseTrait for OpenAIClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        retry_after: None,
+                                        rate_limit_wait_ms: None,
                                     },
                                 )));
                             }
@@ -367,12 +419,20 @@ impl SseResponseTrait for OpenAIClient {
                             if let Some(content) = choice.delta.content.as_ref() {
                                 inner.content += content.as_str();
                             }
+                            // With `output_mode tool`, the output arrives as
+                            // fragments of the tool call's arguments instead.
+                            for call in choice.delta.tool_calls.iter().flatten() {
+                                if let Some(arguments) =
+                                    call.function.as_ref().and_then(|f| f.arguments.as_ref())
+                                {
+                                    inner.content += arguments.as_str();
+                                }
+                            }
                             inner.model = event.model;
                             match choice.finish_reason.as_ref() {
-                                Some(FinishReason::Stop) => {
+                                Some(r @ (FinishReason::Stop | FinishReason::ToolCalls)) => {
                                     inner.metadata.baml_is_complete = true;
-                                    inner.metadata.finish_reason =
-                                        Some(FinishReason::Stop.to_string());
+                                    inner.metadata.finish_reason = Some(r.to_string());
                                 }
                                 finish_reason => {
                                     inner.metadata.baml_is_complete = false;
@@ -398,11 +458,11 @@ impl SseResponseTrait for OpenAIClient {
 impl WithStreamChat for OpenAIClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
     ) -> StreamResponse {
         let (resp, system_start, instant_start) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
@@ -2,7 +2,13 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 
-use crate::{internal::llm_client::AllowedMetadata, RuntimeContext};
+use crate::{
+    internal::llm_client::{
+        rate_limits::RateLimits, resolve_structured_outputs, response_cache::CacheConfig,
+        timeouts::Timeouts, AllowedMetadata,
+    },
+    RuntimeContext,
+};
 
 use super::PostRequestProperties;
 
@@ -21,6 +27,9 @@ pub fn resolve_properties(
             .context("allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']")?,
         None => AllowedMetadata::None,
     };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
     // Ensure that either (resource_name, deployment_id) or base_url is provided
     let base_url = properties.remove("base_url");
     let resource_name = properties.remove("resource_name");
@@ -51,6 +60,8 @@ pub fn resolve_properties(
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .or_else(|| ctx.env.get("AZURE_OPENAI_API_KEY").map(|s| s.to_string()));
 
+    let structured_outputs = resolve_structured_outputs(&mut properties)?;
+
     let headers = properties.remove("headers").map(|v| {
         if let Some(v) = v.as_object() {
             v.iter()
@@ -97,6 +108,10 @@ pub fn resolve_properties(
         headers,
         properties,
         allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
+        structured_outputs,
         // Replace proxy_url with code below to disable proxying
         // proxy_url: None,
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
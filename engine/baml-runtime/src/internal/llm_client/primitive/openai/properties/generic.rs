@@ -2,7 +2,13 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 
-use crate::{internal::llm_client::AllowedMetadata, RuntimeContext};
+use crate::{
+    internal::llm_client::{
+        rate_limits::RateLimits, resolve_structured_outputs, response_cache::CacheConfig,
+        timeouts::Timeouts, AllowedMetadata,
+    },
+    RuntimeContext,
+};
 
 use super::PostRequestProperties;
 
@@ -25,6 +31,11 @@ pub fn resolve_properties(
         )?,
         None => AllowedMetadata::None,
     };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
+    let structured_outputs = resolve_structured_outputs(&mut properties)?;
+
     let headers = properties.remove("headers").map(|v| {
         if let Some(v) = v.as_object() {
             v.iter()
@@ -65,5 +76,9 @@ pub fn resolve_properties(
             .unwrap_or(None),
         query_params: Default::default(),
         allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
+        structured_outputs,
     })
 }
@@ -5,7 +5,9 @@ pub(crate) mod openai;
 
 use std::collections::HashMap;
 
-use crate::internal::llm_client::AllowedMetadata;
+use crate::internal::llm_client::{
+    rate_limits::RateLimits, response_cache::CacheConfig, timeouts::Timeouts, AllowedMetadata,
+};
 
 pub struct PostRequestProperties {
     pub default_role: String,
@@ -17,4 +19,9 @@ pub struct PostRequestProperties {
     // These are passed directly to the OpenAI API.
     pub properties: HashMap<String, serde_json::Value>,
     pub allowed_metadata: AllowedMetadata,
+    pub timeouts: Timeouts,
+    pub rate_limits: RateLimits,
+    pub cache: Option<CacheConfig>,
+    // Send the function's output schema as the response format.
+    pub structured_outputs: bool,
 }
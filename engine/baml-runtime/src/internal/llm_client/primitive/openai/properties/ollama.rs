@@ -2,7 +2,13 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 
-use crate::{internal::llm_client::AllowedMetadata, RuntimeContext};
+use crate::{
+    internal::llm_client::{
+        rate_limits::RateLimits, resolve_structured_outputs, response_cache::CacheConfig,
+        timeouts::Timeouts, AllowedMetadata,
+    },
+    RuntimeContext,
+};
 
 use super::PostRequestProperties;
 
@@ -24,6 +30,11 @@ pub fn resolve_properties(
             .context("allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']")?,
         None => AllowedMetadata::None,
     };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
+
+    let structured_outputs = resolve_structured_outputs(&mut properties)?;
 
     let headers = properties.remove("headers").map(|v| {
         if let Some(v) = v.as_object() {
@@ -54,6 +65,10 @@ pub fn resolve_properties(
         headers,
         properties,
         allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
+        structured_outputs,
         proxy_url: ctx
             .env
             .get("BOUNDARY_PROXY_URL")
@@ -0,0 +1,183 @@
+use serde_json::{json, Value};
+
+use crate::internal::prompt_renderer::OutputSchema;
+
+/// The body fields constraining the reply to `schema`, or `None` if the
+/// provider can't accept it. The reply is still parsed as usual, so the
+/// schema only narrows what the model may produce.
+pub(super) fn response_format_body(
+    provider: &str,
+    schema: &OutputSchema,
+) -> Option<serde_json::Map<String, Value>> {
+    let mut body = serde_json::Map::new();
+    if provider == "ollama" {
+        body.insert("format".into(), schema.schema.clone());
+        return Some(body);
+    }
+
+    // `json_schema` response formats must describe an object.
+    let mut root = resolve_root(&schema.schema);
+    if root.get("type") != Some(&json!("object")) {
+        return None;
+    }
+    let strict = make_strict(&mut root);
+    body.insert(
+        "response_format".into(),
+        json!({
+            "type": "json_schema",
+            "json_schema": {
+                "name": schema.name,
+                "schema": root,
+                "strict": strict,
+            },
+        }),
+    );
+    Some(body)
+}
+
+/// Inlines a root `$ref` (recursive classes) so the root is an object.
+fn resolve_root(schema: &Value) -> Value {
+    let Some(name) = schema
+        .get("$ref")
+        .and_then(|r| r.as_str())
+        .and_then(|r| r.strip_prefix("#/$defs/"))
+    else {
+        return schema.clone();
+    };
+    let mut root = schema["$defs"][name].clone();
+    if let Some(obj) = root.as_object_mut() {
+        obj.insert("$defs".into(), schema["$defs"].clone());
+    }
+    root
+}
+
+/// Strict mode requires every property to be listed in `required` and
+/// `additionalProperties: false` on every object, so maps can't be
+/// expressed. Returns false if the schema contains one.
+fn make_strict(schema: &mut Value) -> bool {
+    let Some(obj) = schema.as_object_mut() else {
+        return true;
+    };
+    if obj.contains_key("additionalProperties") {
+        return false;
+    }
+
+    let mut strict = true;
+    if let Some(properties) = obj.get_mut("properties").and_then(|p| p.as_object_mut()) {
+        for value in properties.values_mut() {
+            strict &= make_strict(value);
+        }
+        let required = properties.keys().cloned().collect::<Vec<_>>();
+        obj.insert("required".into(), required.into());
+        obj.insert("additionalProperties".into(), false.into());
+    }
+    if let Some(items) = obj.get_mut("items") {
+        strict &= make_strict(items);
+    }
+    for key in ["anyOf", "$defs"] {
+        match obj.get_mut(key) {
+            Some(Value::Array(items)) => {
+                for item in items {
+                    strict &= make_strict(item);
+                }
+            }
+            Some(Value::Object(defs)) => {
+                for def in defs.values_mut() {
+                    strict &= make_strict(def);
+                }
+            }
+            _ => {}
+        }
+    }
+    strict
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output_schema(schema: Value) -> OutputSchema {
+        OutputSchema {
+            name: "ExtractResume".into(),
+            schema,
+        }
+    }
+
+    #[test]
+    fn test_strict_response_format() {
+        let schema = output_schema(json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string" },
+                "email": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+            },
+            "required": ["name"],
+        }));
+
+        assert_eq!(
+            response_format_body("openai", &schema).unwrap()["response_format"],
+            json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "ExtractResume",
+                    "schema": {
+                        "type": "object",
+                        "properties": {
+                            "name": { "type": "string" },
+                            "email": { "anyOf": [{ "type": "string" }, { "type": "null" }] },
+                        },
+                        "required": ["name", "email"],
+                        "additionalProperties": false,
+                    },
+                    "strict": true,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_maps_are_not_strict() {
+        let schema = output_schema(json!({
+            "type": "object",
+            "properties": {
+                "scores": { "type": "object", "additionalProperties": { "type": "number" } },
+            },
+            "required": ["scores"],
+        }));
+
+        let body = response_format_body("openai", &schema).unwrap();
+        assert_eq!(body["response_format"]["json_schema"]["strict"], false);
+    }
+
+    #[test]
+    fn test_non_object_output() {
+        let schema = output_schema(json!({ "type": "string" }));
+        assert!(response_format_body("openai", &schema).is_none());
+        assert_eq!(
+            response_format_body("ollama", &schema).unwrap()["format"],
+            json!({ "type": "string" })
+        );
+    }
+
+    #[test]
+    fn test_recursive_root() {
+        let schema = output_schema(json!({
+            "$ref": "#/$defs/Node",
+            "$defs": {
+                "Node": {
+                    "type": "object",
+                    "properties": {
+                        "next": { "anyOf": [{ "$ref": "#/$defs/Node" }, { "type": "null" }] },
+                    },
+                    "required": [],
+                },
+            },
+        }));
+
+        let body = response_format_body("openai", &schema).unwrap();
+        let root = &body["response_format"]["json_schema"]["schema"];
+        assert_eq!(root["type"], "object");
+        assert_eq!(root["required"], json!(["next"]));
+        assert_eq!(root["$defs"]["Node"]["additionalProperties"], false);
+    }
+}
@@ -65,7 +65,7 @@ pub struct ChatCompletionResponseMessage {
     pub content: Option<String>,
 
     /// The tool calls generated by the model, such as function calls.
-    // pub tool_calls: Option<Vec<ChatCompletionMessageToolCall>>,
+    pub tool_calls: Option<Vec<ChatCompletionMessageToolCall>>,
 
     /// The role of the author of this message.
     pub role: ChatCompletionMessageRole,
@@ -75,6 +75,17 @@ pub struct ChatCompletionResponseMessage {
     // pub function_call: Option<FunctionCall>,
 }
 
+impl ChatCompletionResponseMessage {
+    /// The arguments of the first tool call if the model made one (as with
+    /// `output_mode tool`), otherwise the text content.
+    pub fn output(&self) -> &str {
+        match self.tool_calls.as_ref().and_then(|calls| calls.first()) {
+            Some(call) => &call.function.arguments,
+            None => self.content.as_deref().unwrap_or(""),
+        }
+    }
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct ChatCompletionChoiceDelta {
     pub index: u64,
@@ -89,6 +100,8 @@ pub struct ChatCompletionMessageDelta {
     pub role: Option<ChatCompletionMessageRole>,
     /// The contents of the message
     pub content: Option<String>,
+    /// Fragments of the tool calls generated by the model.
+    pub tool_calls: Option<Vec<ChatCompletionMessageToolCallChunk>>,
     // The name of the user in a multi-user chat
     // #[serde(skip_serializing_if = "Option::is_none")]
     // pub name: Option<String>,
@@ -99,6 +112,36 @@ pub struct ChatCompletionMessageDelta {
     // pub function_call: Option<ChatCompletionFunctionCallDelta>,
 }
 
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionMessageToolCall {
+    /// The ID of the tool call.
+    pub id: String,
+    /// The function that the model called.
+    pub function: FunctionCall,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct FunctionCall {
+    /// The name of the function to call.
+    pub name: String,
+    /// The arguments to call the function with, as generated by the model in JSON format.
+    pub arguments: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChatCompletionMessageToolCallChunk {
+    pub index: u32,
+    pub id: Option<String>,
+    pub function: Option<FunctionCallChunk>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct FunctionCallChunk {
+    pub name: Option<String>,
+    /// The next fragment of the arguments JSON.
+    pub arguments: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ChatCompletionMessageRole {
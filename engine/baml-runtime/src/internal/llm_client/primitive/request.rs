@@ -5,12 +5,16 @@ use internal_baml_jinja::RenderedChatMessage;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
 
-use crate::internal::llm_client::{traits::WithClient, ErrorCode, LLMErrorResponse, LLMResponse};
+use crate::{
+    internal::llm_client::{traits::WithClient, ErrorCode, LLMErrorResponse, LLMResponse},
+    RuntimeContext,
+};
 
 pub trait RequestBuilder {
     #[allow(async_fn_in_trait)]
     async fn build_request(
         &self,
+        ctx: &RuntimeContext,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
         allow_proxy: bool,
         stream: bool,
@@ -32,13 +36,14 @@ fn to_prompt(
 
 pub async fn make_request(
     client: &(impl WithClient + RequestBuilder),
+    ctx: &RuntimeContext,
     prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
     stream: bool,
 ) -> Result<(Response, web_time::SystemTime, web_time::Instant), LLMResponse> {
     let (system_now, instant_now) = (web_time::SystemTime::now(), web_time::Instant::now());
 
     let req = match client
-        .build_request(prompt, true, stream)
+        .build_request(ctx, prompt, true, stream)
         .await
         .context("Failed to build request")
     {
@@ -53,6 +58,8 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(2),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             }));
         }
     };
@@ -69,6 +76,8 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(2),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             }));
         }
     };
@@ -85,12 +94,15 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:?}", e),
                 code: ErrorCode::Other(2),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             }));
         }
     };
 
     let status = response.status();
     if !status.is_success() {
+        let retry_after = retry_after(status, response.headers());
         return Err(LLMResponse::LLMFailure(LLMErrorResponse {
             client: client.context().name.to_string(),
             model: None,
@@ -103,18 +115,85 @@ pub async fn make_request(
                 response.text().await.unwrap_or("<no response>".into())
             ),
             code: ErrorCode::from_status(status),
+            retry_after,
         }));
     }
 
     Ok((response, system_now, instant_now))
 }
 
+/// How long the provider asked to wait before retrying: `retry-after-ms`,
+/// sent by OpenAI and Azure, or `retry-after` in seconds or as an HTTP date.
+/// A 429 without either waits for the `x-ratelimit-reset-*` of the limits
+/// that ran out.
+fn retry_after(
+    status: reqwest::StatusCode,
+    headers: &reqwest::header::HeaderMap,
+) -> Option<web_time::Duration> {
+    let header = |name: &str| headers.get(name)?.to_str().ok().map(str::trim);
+    let seconds = |value: &str| {
+        value
+            .parse::<f64>()
+            .ok()
+            .and_then(|s| web_time::Duration::try_from_secs_f64(s).ok())
+    };
+    if let Some(ms) = header("retry-after-ms").and_then(seconds) {
+        return Some(ms / 1000);
+    }
+    if let Some(value) = header("retry-after") {
+        return seconds(value).or_else(|| {
+            let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+            (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+                .to_std()
+                .ok()
+        });
+    }
+    if status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    ["requests", "tokens"]
+        .iter()
+        .filter(|limit| {
+            header(&format!("x-ratelimit-remaining-{limit}")).map_or(true, |v| v == "0")
+        })
+        .filter_map(|limit| header(&format!("x-ratelimit-reset-{limit}")).and_then(parse_reset))
+        .max()
+}
+
+/// Parses a reset time such as `20ms`, `1s` or `6m0s`.
+fn parse_reset(value: &str) -> Option<web_time::Duration> {
+    if value.is_empty() {
+        return None;
+    }
+    let mut rest = value;
+    let mut seconds = 0.0;
+    while !rest.is_empty() {
+        let number_len = rest.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+        let number = rest[..number_len].parse::<f64>().ok()?;
+        rest = &rest[number_len..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        seconds += number
+            * match &rest[..unit_len] {
+                "h" => 3600.0,
+                "m" => 60.0,
+                "s" => 1.0,
+                "ms" => 0.001,
+                _ => return None,
+            };
+        rest = &rest[unit_len..];
+    }
+    web_time::Duration::try_from_secs_f64(seconds).ok()
+}
+
 pub async fn make_parsed_request<T: DeserializeOwned>(
     client: &(impl WithClient + RequestBuilder),
+    ctx: &RuntimeContext,
     prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
     stream: bool,
 ) -> Result<(T, web_time::SystemTime, web_time::Instant), LLMResponse> {
-    let (response, system_now, instant_now) = make_request(client, prompt, stream).await?;
+    let (response, system_now, instant_now) = make_request(client, ctx, prompt, stream).await?;
     let j = match response.json::<serde_json::Value>().await {
         Ok(response) => response,
         Err(e) => {
@@ -127,6 +206,8 @@ pub async fn make_parsed_request<T: DeserializeOwned>(
                 latency: instant_now.elapsed(),
                 message: e.to_string(),
                 code: ErrorCode::Other(2),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             }))
         }
     };
@@ -146,6 +227,103 @@ pub async fn make_parsed_request<T: DeserializeOwned>(
             latency: instant_now.elapsed(),
             message: format!("{:?}", e),
             code: ErrorCode::Other(2),
+            retry_after: None,
+            rate_limit_wait_ms: None,
         })),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reqwest::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+    use web_time::Duration;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_retry_after() {
+        assert_eq!(
+            retry_after(StatusCode::SERVICE_UNAVAILABLE, &headers(&[])),
+            None
+        );
+        assert_eq!(
+            retry_after(
+                StatusCode::SERVICE_UNAVAILABLE,
+                &headers(&[("retry-after", "20")])
+            ),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(
+            retry_after(
+                StatusCode::SERVICE_UNAVAILABLE,
+                &headers(&[("retry-after", "2"), ("retry-after-ms", "1500")])
+            ),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            retry_after(
+                StatusCode::SERVICE_UNAVAILABLE,
+                &headers(&[("retry-after", "-1")])
+            ),
+            None
+        );
+
+        let date = (chrono::Utc::now() + chrono::Duration::seconds(30)).to_rfc2822();
+        let wait = retry_after(
+            StatusCode::SERVICE_UNAVAILABLE,
+            &headers(&[("retry-after", &date)]),
+        )
+        .unwrap();
+        assert!(wait > Duration::from_secs(25) && wait <= Duration::from_secs(30));
+        assert_eq!(
+            retry_after(
+                StatusCode::SERVICE_UNAVAILABLE,
+                &headers(&[("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")])
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_retry_after_rate_limit_reset() {
+        let rate_limited = headers(&[
+            ("x-ratelimit-remaining-requests", "0"),
+            ("x-ratelimit-reset-requests", "1m30s"),
+            ("x-ratelimit-remaining-tokens", "1200"),
+            ("x-ratelimit-reset-tokens", "6m0s"),
+        ]);
+        assert_eq!(
+            retry_after(StatusCode::TOO_MANY_REQUESTS, &rate_limited),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            retry_after(StatusCode::SERVICE_UNAVAILABLE, &rate_limited),
+            None
+        );
+        assert_eq!(
+            retry_after(
+                StatusCode::TOO_MANY_REQUESTS,
+                &headers(&[("x-ratelimit-reset-tokens", "20ms")])
+            ),
+            Some(Duration::from_millis(20))
+        );
+
+        assert_eq!(
+            parse_reset("1h2m3.5s"),
+            Some(Duration::from_millis(3_723_500))
+        );
+        assert_eq!(parse_reset("10"), None);
+        assert_eq!(parse_reset("5d"), None);
+    }
+}
@@ -2,11 +2,15 @@ use crate::client_registry::ClientProperty;
 use crate::internal::llm_client::traits::{
     ToProviderMessage, ToProviderMessageExt, WithClientProperties,
 };
-use crate::internal::llm_client::{AllowedMetadata, ResolveMediaUrls};
+use crate::internal::llm_client::{
+    rate_limits::RateLimits, resolve_structured_outputs, response_cache::CacheConfig,
+    timeouts::Timeouts, AllowedMetadata, ResolveMediaUrls,
+};
 use crate::RuntimeContext;
 use crate::{
     internal::llm_client::{
         primitive::{
+            google::{add_response_schema, output_tool_body},
             request::{make_parsed_request, make_request, RequestBuilder},
             vertex::types::{FinishReason, VertexResponse},
         },
@@ -62,6 +66,10 @@ struct PostRequestProperties {
     model_id: Option<String>,
     location: Option<String>,
     allowed_metadata: AllowedMetadata,
+    timeouts: Timeouts,
+    rate_limits: RateLimits,
+    cache: Option<CacheConfig>,
+    structured_outputs: bool,
 }
 
 pub struct VertexClient {
@@ -107,6 +115,9 @@ fn resolve_properties(
         )?,
         None => AllowedMetadata::None,
     };
+    let timeouts = Timeouts::resolve(&mut properties)?;
+    let rate_limits = RateLimits::resolve(&mut properties)?;
+    let cache = CacheConfig::resolve(&mut properties)?;
     let service_account_details = {
         let authz = properties.remove("authorization");
         let creds = properties.remove("credentials");
@@ -161,6 +172,8 @@ fn resolve_properties(
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or("".to_string());
 
+    let structured_outputs = resolve_structured_outputs(&mut properties)?;
+
     let headers = properties.remove("headers").map(|v| {
         if let Some(v) = v.as_object() {
             v.iter()
@@ -195,6 +208,10 @@ fn resolve_properties(
         location: Some(location),
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
         allowed_metadata,
+        timeouts,
+        rate_limits,
+        cache,
+        structured_outputs,
     })
 }
 
@@ -211,6 +228,15 @@ impl WithClientProperties for VertexClient {
     fn allowed_metadata(&self) -> &crate::internal::llm_client::AllowedMetadata {
         &self.properties.allowed_metadata
     }
+    fn timeouts(&self) -> &Timeouts {
+        &self.properties.timeouts
+    }
+    fn rate_limits(&self) -> &RateLimits {
+        &self.properties.rate_limits
+    }
+    fn cache(&self) -> Option<&CacheConfig> {
+        self.properties.cache.as_ref()
+    }
 }
 
 impl WithClient for VertexClient {
@@ -262,6 +288,10 @@ impl SseResponseTrait for VertexClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cache: None,
+                            cost_usd: None,
+                            rate_limit_wait_ms: None,
+                            time_to_first_token_ms: None,
                         },
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
@@ -288,6 +318,8 @@ impl SseResponseTrait for VertexClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        retry_after: None,
+                                        rate_limit_wait_ms: None,
                                     },
                                 )));
                             }
@@ -295,7 +327,7 @@ impl SseResponseTrait for VertexClient {
 
                         if let Some(choice) = event.candidates.get(0) {
                             if let Some(content) = choice.content.parts.get(0) {
-                                inner.content += &content.text;
+                                inner.content += &content.output();
                             }
                             match choice.finish_reason.as_ref() {
                                 Some(FinishReason::Stop) => {
@@ -323,7 +355,7 @@ impl WithStreamChat for VertexClient {
     ) -> StreamResponse {
         //incomplete, streaming response object is returned
         let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
+            match make_request(self, ctx, either::Either::Right(prompt), true).await {
                 Ok(v) => v,
                 Err(e) => return Err(e),
             };
@@ -438,6 +470,7 @@ impl RequestBuilder for VertexClient {
 
     async fn build_request(
         &self,
+        ctx: &RuntimeContext,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
         allow_proxy: bool,
         stream: bool,
@@ -536,6 +569,16 @@ impl RequestBuilder for VertexClient {
             either::Either::Right(messages) => body_obj.extend(self.chat_to_message(messages)?),
         }
 
+        if let Some(tool) = &ctx.output_tool {
+            body_obj.extend(output_tool_body(tool)?);
+        }
+
+        if self.properties.structured_outputs && ctx.output_tool.is_none() {
+            if let Some(schema) = ctx.output_schema()? {
+                add_response_schema(body_obj, schema)?;
+            }
+        }
+
         Ok(req.json(&body))
     }
     fn request_options(&self) -> &HashMap<String, serde_json::Value> {
@@ -551,15 +594,19 @@ impl WithChat for VertexClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
         //non-streaming, complete response is returned
-        let (response, system_now, instant_now) =
-            match make_parsed_request::<VertexResponse>(self, either::Either::Right(prompt), false)
-                .await
-            {
-                Ok(v) => v,
-                Err(e) => return e,
-            };
+        let (response, system_now, instant_now) = match make_parsed_request::<VertexResponse>(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            false,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
 
         if response.candidates.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -574,6 +621,8 @@ impl WithChat for VertexClient {
                     response.candidates.len()
                 ),
                 code: ErrorCode::Other(200),
+                retry_after: None,
+                rate_limit_wait_ms: None,
             });
         }
         let usage_metadata = response.usage_metadata.clone().unwrap();
@@ -581,7 +630,7 @@ impl WithChat for VertexClient {
         LLMResponse::Success(LLMCompleteResponse {
             client: self.context.name.to_string(),
             prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
-            content: response.candidates[0].content.parts[0].text.clone(),
+            content: response.candidates[0].content.parts[0].output(),
             start_time: system_now,
             latency: instant_now.elapsed(),
             request_options: self.properties.properties.clone(),
@@ -590,7 +639,7 @@ impl WithChat for VertexClient {
                 .properties
                 .get("model")
                 .and_then(|v| v.as_str().map(|s| s.to_string()))
-                .or_else(|| _ctx.env.get("default model").map(|s| s.to_string()))
+                .or_else(|| ctx.env.get("default model").map(|s| s.to_string()))
                 .unwrap_or_else(|| "".to_string()),
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.candidates[0].finish_reason {
@@ -604,6 +653,10 @@ impl WithChat for VertexClient {
                 prompt_tokens: usage_metadata.prompt_token_count,
                 output_tokens: usage_metadata.candidates_token_count,
                 total_tokens: usage_metadata.total_token_count,
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
             },
         })
     }
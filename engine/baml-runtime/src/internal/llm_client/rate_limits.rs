@@ -0,0 +1,426 @@
+use std::{
+    collections::HashMap,
+    pin::pin,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use internal_baml_jinja::RenderedPrompt;
+use tokio::sync::Notify;
+use web_time::{Duration, Instant};
+
+use super::LLMResponse;
+
+/// The `max_concurrency`, `requests_per_minute` and `tokens_per_minute`
+/// options of a client. None of them are set by default.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RateLimits {
+    /// Requests in flight at once. A stream counts until it ends.
+    pub max_concurrency: Option<u64>,
+    pub requests_per_minute: Option<u64>,
+    /// Prompt and output tokens. A request reserves an estimate, which is
+    /// corrected once the provider reports the usage.
+    pub tokens_per_minute: Option<u64>,
+}
+
+impl RateLimits {
+    /// Removes the rate limit options from `properties`, so they are not
+    /// forwarded to the provider.
+    pub fn resolve(properties: &mut HashMap<String, serde_json::Value>) -> Result<Self> {
+        let mut count = |key: &str| {
+            properties
+                .remove(key)
+                .map(|v| match v.as_u64() {
+                    Some(0) | None => anyhow::bail!("{key} must be a positive integer"),
+                    Some(n) => Ok(n),
+                })
+                .transpose()
+        };
+        Ok(Self {
+            max_concurrency: count("max_concurrency")?,
+            requests_per_minute: count("requests_per_minute")?,
+            tokens_per_minute: count("tokens_per_minute")?,
+        })
+    }
+}
+
+/// The tokens a request will use, before it is sent: about four characters
+/// of prompt per token, plus the output limit if the client sets one.
+pub fn estimate_tokens(
+    prompt: &RenderedPrompt,
+    request_options: &HashMap<String, serde_json::Value>,
+) -> u64 {
+    let chars = match prompt {
+        RenderedPrompt::Completion(text) => text.chars().count(),
+        RenderedPrompt::Chat(messages) => messages
+            .iter()
+            .flat_map(|m| m.parts.iter())
+            .filter_map(|part| part.as_text())
+            .map(|text| text.chars().count())
+            .sum(),
+    };
+    let output = ["max_tokens", "max_completion_tokens", "max_output_tokens"]
+        .iter()
+        .find_map(|key| request_options.get(*key).and_then(|v| v.as_u64()))
+        .unwrap_or(0);
+    chars.div_ceil(4) as u64 + output
+}
+
+/// Refills continuously, up to a minute's worth.
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    // Goes below zero when a request used more tokens than it reserved.
+    available: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    fn new(per_minute: u64, now: Instant) -> Self {
+        Self {
+            capacity: per_minute as f64,
+            available: per_minute as f64,
+            refilled_at: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.add(self.capacity * elapsed.as_secs_f64() / 60.0);
+        self.refilled_at = now;
+    }
+
+    /// How long until `amount` can be taken. A request larger than the
+    /// bucket only waits for a full bucket.
+    fn wait_for(&self, amount: f64) -> Duration {
+        let missing = amount.min(self.capacity) - self.available;
+        if missing <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(missing * 60.0 / self.capacity)
+        }
+    }
+
+    /// Returns the amount taken.
+    fn take(&mut self, amount: f64) -> f64 {
+        let amount = amount.min(self.capacity);
+        self.available -= amount;
+        amount
+    }
+
+    fn add(&mut self, amount: f64) {
+        self.available = (self.available + amount).min(self.capacity);
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    in_flight: u64,
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+    // Set from the Retry-After of a rate limited response.
+    paused_until: Option<Instant>,
+}
+
+enum Wait {
+    Release,
+    For(Duration),
+}
+
+/// Queues the requests of a client so they stay within its rate limits.
+///
+/// A rate limited response with a Retry-After header also pauses every
+/// request of the client until then, whether or not it has limits.
+pub struct RateLimiter {
+    limits: RateLimits,
+    state: Mutex<State>,
+    released: Notify,
+}
+
+impl RateLimiter {
+    fn new(limits: RateLimits) -> Self {
+        let now = Instant::now();
+        Self {
+            state: Mutex::new(State {
+                in_flight: 0,
+                requests: limits.requests_per_minute.map(|n| Bucket::new(n, now)),
+                tokens: limits.tokens_per_minute.map(|n| Bucket::new(n, now)),
+                paused_until: None,
+            }),
+            limits,
+            released: Notify::new(),
+        }
+    }
+
+    /// Waits until a request estimated to use `tokens` can be sent.
+    pub async fn acquire(self: &Arc<Self>, tokens: u64) -> RatePermit {
+        let start = Instant::now();
+        let mut queued = false;
+        loop {
+            // Registered before checking, so a release in between is not missed.
+            let mut released = pin!(self.released.notified());
+            released.as_mut().enable();
+            match self.try_acquire(tokens as f64) {
+                Ok(reserved_tokens) => {
+                    return RatePermit {
+                        limiter: self.clone(),
+                        reserved_tokens,
+                        waited: queued.then(|| start.elapsed()),
+                    }
+                }
+                Err(Wait::Release) => released.await,
+                Err(Wait::For(duration)) => async_std::task::sleep(duration).await,
+            }
+            queued = true;
+        }
+    }
+
+    fn try_acquire(&self, tokens: f64) -> Result<f64, Wait> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if let Some(until) = state.paused_until {
+            if until > now {
+                return Err(Wait::For(until - now));
+            }
+            state.paused_until = None;
+        }
+        if self
+            .limits
+            .max_concurrency
+            .is_some_and(|max| state.in_flight >= max)
+        {
+            return Err(Wait::Release);
+        }
+
+        let mut wait = Duration::ZERO;
+        if let Some(bucket) = state.requests.as_mut() {
+            bucket.refill(now);
+            wait = wait.max(bucket.wait_for(1.0));
+        }
+        if let Some(bucket) = state.tokens.as_mut() {
+            bucket.refill(now);
+            wait = wait.max(bucket.wait_for(tokens));
+        }
+        if !wait.is_zero() {
+            return Err(Wait::For(wait));
+        }
+
+        if let Some(bucket) = state.requests.as_mut() {
+            bucket.take(1.0);
+        }
+        let reserved = state.tokens.as_mut().map_or(0.0, |b| b.take(tokens));
+        state.in_flight += 1;
+        Ok(reserved)
+    }
+
+    fn pause(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut state = self.state.lock().unwrap();
+        state.paused_until = Some(state.paused_until.map_or(until, |u| u.max(until)));
+    }
+}
+
+/// The rate limiters of a runtime's clients. Every context the runtime
+/// creates shares them, so all calls and streams of a client, including
+/// those of a `ClientRegistry` client with the same name, count against the
+/// same limits.
+#[derive(Clone, Default)]
+pub struct RateLimiters(Arc<Mutex<HashMap<(String, RateLimits), Arc<RateLimiter>>>>);
+
+impl RateLimiters {
+    pub fn get(&self, client: &str, limits: &RateLimits) -> Arc<RateLimiter> {
+        self.0
+            .lock()
+            .unwrap()
+            .entry((client.to_string(), limits.clone()))
+            .or_insert_with(|| Arc::new(RateLimiter::new(limits.clone())))
+            .clone()
+    }
+}
+
+/// A request's place under its client's limits, held until the response
+/// is finished.
+pub struct RatePermit {
+    limiter: Arc<RateLimiter>,
+    reserved_tokens: f64,
+    // None if the request did not queue.
+    waited: Option<Duration>,
+}
+
+impl RatePermit {
+    /// Settles the permit with the response of the request: records the time
+    /// spent queued, corrects the tokens it reserved and pauses the client if
+    /// the provider sent a Retry-After.
+    pub fn finish(self, response: &mut LLMResponse) {
+        let waited = self.waited.map(|w| w.as_millis() as u64);
+        match response {
+            LLMResponse::Success(s) => {
+                s.metadata.rate_limit_wait_ms = waited;
+                if let Some(used) = s.metadata.total_tokens {
+                    self.adjust_tokens(self.reserved_tokens - used as f64);
+                }
+            }
+            LLMResponse::LLMFailure(e) => {
+                e.rate_limit_wait_ms = waited;
+                self.adjust_tokens(self.reserved_tokens);
+                if let Some(retry_after) = e.retry_after {
+                    self.limiter.pause(retry_after);
+                }
+            }
+            _ => self.adjust_tokens(self.reserved_tokens),
+        }
+    }
+
+    fn adjust_tokens(&self, amount: f64) {
+        if let Some(bucket) = self.limiter.state.lock().unwrap().tokens.as_mut() {
+            bucket.add(amount);
+        }
+    }
+}
+
+impl Drop for RatePermit {
+    fn drop(&mut self) {
+        self.limiter.state.lock().unwrap().in_flight -= 1;
+        self.limiter.released.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(limits: RateLimits) -> Arc<RateLimiter> {
+        Arc::new(RateLimiter::new(limits))
+    }
+
+    #[test]
+    fn test_resolve() -> Result<()> {
+        let mut properties = HashMap::from([
+            ("model".to_string(), serde_json::json!("gpt-4o")),
+            ("max_concurrency".to_string(), serde_json::json!(8)),
+            ("tokens_per_minute".to_string(), serde_json::json!(30000)),
+        ]);
+        let limits = RateLimits::resolve(&mut properties)?;
+        assert_eq!(limits.max_concurrency, Some(8));
+        assert_eq!(limits.requests_per_minute, None);
+        assert_eq!(limits.tokens_per_minute, Some(30000));
+        assert_eq!(properties.len(), 1);
+
+        let mut properties =
+            HashMap::from([("requests_per_minute".to_string(), serde_json::json!(0))]);
+        assert!(RateLimits::resolve(&mut properties).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        let prompt = RenderedPrompt::Completion("x".repeat(401));
+        let options = HashMap::from([("max_tokens".to_string(), serde_json::json!(100))]);
+        assert_eq!(estimate_tokens(&prompt, &options), 201);
+        assert_eq!(estimate_tokens(&prompt, &HashMap::new()), 101);
+    }
+
+    #[test]
+    fn test_bucket() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(60, start);
+        assert_eq!(bucket.take(100.0), 60.0);
+        assert_eq!(bucket.wait_for(1.0), Duration::from_secs(1));
+        // Larger than the bucket: only wait until it is full.
+        assert_eq!(bucket.wait_for(100.0), Duration::from_secs(60));
+
+        bucket.refill(start + Duration::from_secs(30));
+        assert_eq!(bucket.wait_for(30.0), Duration::ZERO);
+        bucket.refill(start + Duration::from_secs(600));
+        assert_eq!(bucket.available, 60.0);
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency() {
+        let limiter = limiter(RateLimits {
+            max_concurrency: Some(1),
+            ..Default::default()
+        });
+        let first = limiter.acquire(0).await;
+        assert_eq!(first.waited, None);
+
+        let second = limiter.acquire(0);
+        let release = async {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            drop(first);
+        };
+        let (second, _) = futures::join!(second, release);
+        assert!(second
+            .waited
+            .is_some_and(|w| w >= Duration::from_millis(20)));
+    }
+
+    #[tokio::test]
+    async fn test_requests_per_minute() {
+        // One request per 50ms.
+        let limiter = limiter(RateLimits {
+            requests_per_minute: Some(1200),
+            ..Default::default()
+        });
+        limiter
+            .state
+            .lock()
+            .unwrap()
+            .requests
+            .as_mut()
+            .unwrap()
+            .available = 1.0;
+
+        assert_eq!(limiter.acquire(0).await.waited, None);
+        let queued = limiter.acquire(0).await;
+        assert!(queued
+            .waited
+            .is_some_and(|w| w >= Duration::from_millis(40)));
+    }
+
+    #[tokio::test]
+    async fn test_pause() {
+        let limiter = limiter(RateLimits::default());
+        limiter.pause(Duration::from_millis(30));
+        let permit = limiter.acquire(0).await;
+        assert!(permit
+            .waited
+            .is_some_and(|w| w >= Duration::from_millis(30)));
+
+        // The wait is recorded on failures too.
+        let mut response = LLMResponse::LLMFailure(super::super::LLMErrorResponse {
+            client: "GPT4o".into(),
+            model: None,
+            prompt: RenderedPrompt::Completion("hi".into()),
+            request_options: Default::default(),
+            start_time: web_time::SystemTime::now(),
+            latency: Duration::ZERO,
+            message: "failed".into(),
+            code: super::super::ErrorCode::ServerError,
+            retry_after: None,
+            rate_limit_wait_ms: None,
+        });
+        permit.finish(&mut response);
+        let LLMResponse::LLMFailure(e) = response else {
+            unreachable!()
+        };
+        assert!(e.rate_limit_wait_ms.is_some_and(|w| w >= 30));
+    }
+
+    #[test]
+    fn test_rate_limiters() {
+        let limits = RateLimits {
+            max_concurrency: Some(1),
+            ..Default::default()
+        };
+        let limiters = RateLimiters::default();
+        let gpt = limiters.get("GPT4o", &limits);
+        assert!(Arc::ptr_eq(&gpt, &limiters.get("GPT4o", &limits)));
+        assert!(!Arc::ptr_eq(&gpt, &limiters.get("Claude", &limits)));
+        // Another runtime's clients have limiters of their own.
+        assert!(!Arc::ptr_eq(
+            &gpt,
+            &RateLimiters::default().get("GPT4o", &limits)
+        ));
+    }
+}
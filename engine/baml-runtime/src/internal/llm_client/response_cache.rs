@@ -0,0 +1,496 @@
+//! Opt-in cache of LLM responses.
+//!
+//! Clients turn it on with the `cache` option, and callers can override it
+//! for their calls with `RuntimeContextManager::set_response_cache`.
+//! Responses are keyed on the client, model, request options and rendered
+//! prompt, so changing any of them is a miss. Only complete responses that
+//! parsed are stored, so a bad response is never replayed.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use anyhow::{Context, Result};
+use indexmap::IndexMap;
+use internal_baml_jinja::RenderedPrompt;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use web_time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::RuntimeContext;
+
+use super::{LLMCompleteResponse, LLMCompleteResponseMetadata, LLMResponse};
+
+const DEFAULT_CACHE_DIR: &str = ".baml_cache";
+
+/// Whether a response was served from the cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheStatus {
+    Hit,
+    Miss,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheBackend {
+    /// An LRU map per client, kept for the lifetime of the process.
+    #[default]
+    Memory,
+    /// One file per response under `dir`, shared between processes.
+    Disk,
+}
+
+/// The `cache` option of a client.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CacheConfig {
+    #[serde(default)]
+    pub backend: CacheBackend,
+    /// Most responses the memory backend keeps per client.
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+    /// Directory of the disk backend.
+    #[serde(default = "default_dir")]
+    pub dir: PathBuf,
+    /// How long a response can be served for. Never expires if unset.
+    pub ttl_ms: Option<u64>,
+}
+
+fn default_max_entries() -> usize {
+    1000
+}
+
+fn default_dir() -> PathBuf {
+    PathBuf::from(DEFAULT_CACHE_DIR)
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            backend: Default::default(),
+            max_entries: default_max_entries(),
+            dir: default_dir(),
+            ttl_ms: None,
+        }
+    }
+}
+
+impl CacheConfig {
+    /// Parses `true`, `false` (no cache) or a map of settings.
+    fn from_value(value: Value) -> Result<Option<Self>> {
+        if let Value::Bool(enabled) = value {
+            return Ok(enabled.then(Self::default));
+        }
+
+        let config = serde_json::from_value::<Self>(value).context(
+            "Invalid cache. Expected true, false or a map with keys: backend, max_entries, dir, ttl_ms",
+        )?;
+        if config.max_entries == 0 {
+            anyhow::bail!("cache.max_entries must be at least 1");
+        }
+        if config.ttl_ms == Some(0) {
+            anyhow::bail!("cache.ttl_ms must be a positive integer");
+        }
+        #[cfg(target_arch = "wasm32")]
+        if config.backend == CacheBackend::Disk {
+            anyhow::bail!("The disk cache backend is not available in WebAssembly");
+        }
+        Ok(Some(config))
+    }
+
+    /// Removes the `cache` option from `properties`, so it is not forwarded
+    /// to the provider.
+    pub fn resolve(properties: &mut HashMap<String, Value>) -> Result<Option<Self>> {
+        match properties.remove("cache") {
+            Some(value) => Self::from_value(value),
+            None => Ok(None),
+        }
+    }
+
+    fn ttl(&self) -> Option<Duration> {
+        self.ttl_ms.map(Duration::from_millis)
+    }
+}
+
+/// How the calls made with a `RuntimeContextManager` use the cache.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum CachePolicy {
+    /// Use the `cache` option of each client.
+    #[default]
+    ClientDefault,
+    /// Neither read nor write the cache.
+    Off,
+    /// Skip lookups but store new responses, for clients with a cache.
+    Refresh,
+    /// Cache the responses of every client with these settings.
+    Enabled(CacheConfig),
+}
+
+impl CachePolicy {
+    /// Parses `null` or `"default"`, `false` or `"off"`, `"refresh"`, `true`,
+    /// or a map of cache settings.
+    pub fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Null => Ok(Self::ClientDefault),
+            Value::String(policy) => match policy.as_str() {
+                "default" => Ok(Self::ClientDefault),
+                "off" => Ok(Self::Off),
+                "refresh" => Ok(Self::Refresh),
+                other => anyhow::bail!(
+                    "Invalid cache policy: {other}. Expected one of: default, off, refresh"
+                ),
+            },
+            value => Ok(CacheConfig::from_value(value)?.map_or(Self::Off, Self::Enabled)),
+        }
+    }
+}
+
+/// A response as kept by a [`CacheStore`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub model: String,
+    pub content: String,
+    pub metadata: LLMCompleteResponseMetadata,
+    /// When the response was stored, in milliseconds since the Unix epoch.
+    pub created_at_ms: u64,
+}
+
+/// Somewhere to keep cached responses. `key` identifies a request; stores
+/// may hash it but must not interpret it.
+pub trait CacheStore: Send + Sync {
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+    fn put(&self, key: &str, response: CachedResponse);
+}
+
+/// Keeps the `max_entries` most recently used responses.
+pub struct MemoryStore {
+    max_entries: usize,
+    entries: Mutex<IndexMap<String, CachedResponse>>,
+}
+
+impl MemoryStore {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            entries: Default::default(),
+        }
+    }
+
+    /// The store of `client`, shared by every runtime in the process so that
+    /// clients from a `ClientRegistry`, which are rebuilt for each call,
+    /// still hit.
+    fn shared(client: &str, max_entries: usize) -> Arc<Self> {
+        static STORES: OnceLock<Mutex<HashMap<(String, usize), Arc<MemoryStore>>>> =
+            OnceLock::new();
+        STORES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap()
+            .entry((client.to_string(), max_entries))
+            .or_insert_with(|| Arc::new(Self::new(max_entries)))
+            .clone()
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().unwrap();
+        // Move the entry to the back, which is the most recently used end.
+        let (key, response) = entries.shift_remove_entry(key)?;
+        entries.insert(key, response.clone());
+        Some(response)
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.shift_remove(key);
+        entries.insert(key.to_string(), response);
+        while entries.len() > self.max_entries {
+            entries.shift_remove_index(0);
+        }
+    }
+}
+
+/// Keeps each response in its own file, named by a hash of its key:
+///
+///   .baml_cache/<first 2 hex digits>/<sha256 of key>.json
+#[cfg(not(target_arch = "wasm32"))]
+pub struct DiskStore {
+    dir: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DiskStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        use sha2::{Digest, Sha256};
+
+        let hash = format!("{:x}", Sha256::digest(key));
+        self.dir.join(&hash[..2]).join(format!("{hash}.json"))
+    }
+
+    fn write(&self, key: &str, response: &CachedResponse) -> Result<()> {
+        let path = self.path(key);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file first so concurrent readers never see a
+        // partial response.
+        let tmp = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+        std::fs::write(&tmp, serde_json::to_string(response)?)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CacheStore for DiskStore {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let contents = std::fs::read_to_string(self.path(key)).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        if let Err(e) = self.write(key, &response) {
+            log::warn!(
+                "Failed to write to response cache {}: {:#}",
+                self.dir.display(),
+                e
+            );
+        }
+    }
+}
+
+/// The cache entry of a single call to a primitive client.
+pub struct CachedCall {
+    store: Arc<dyn CacheStore>,
+    key: String,
+    ttl: Option<Duration>,
+    refresh: bool,
+    client: String,
+    prompt: RenderedPrompt,
+    request_options: HashMap<String, Value>,
+}
+
+impl CachedCall {
+    /// Returns `None` if the cache is off for this call.
+    pub fn for_call(
+        ctx: &RuntimeContext,
+        client: &str,
+        request_options: &HashMap<String, Value>,
+        client_cache: Option<&CacheConfig>,
+        prompt: &RenderedPrompt,
+    ) -> Option<Self> {
+        let (config, refresh) = match (&ctx.response_cache, client_cache) {
+            (CachePolicy::Off, _) => return None,
+            (CachePolicy::Enabled(config), _) => (config, false),
+            (CachePolicy::Refresh, Some(config)) => (config, true),
+            (CachePolicy::ClientDefault, Some(config)) => (config, false),
+            (_, None) => return None,
+        };
+        let store: Arc<dyn CacheStore> = match config.backend {
+            CacheBackend::Memory => MemoryStore::shared(client, config.max_entries),
+            #[cfg(not(target_arch = "wasm32"))]
+            CacheBackend::Disk => Arc::new(DiskStore::new(config.dir.clone())),
+            #[cfg(target_arch = "wasm32")]
+            CacheBackend::Disk => return None,
+        };
+
+        Some(Self {
+            store,
+            key: cache_key(ctx, client, request_options, prompt),
+            ttl: config.ttl(),
+            refresh,
+            client: client.to_string(),
+            prompt: prompt.clone(),
+            request_options: request_options.clone(),
+        })
+    }
+
+    /// The stored response, unless there is none, it expired, or the call
+    /// refreshes the cache.
+    pub fn lookup(&self) -> Option<LLMResponse> {
+        if self.refresh {
+            return None;
+        }
+        let (start_time, instant_start) = (SystemTime::now(), Instant::now());
+        let cached = self.store.get(&self.key)?;
+        if let Some(ttl) = self.ttl {
+            if now_ms().saturating_sub(cached.created_at_ms) >= ttl.as_millis() as u64 {
+                return None;
+            }
+        }
+
+        Some(LLMResponse::Success(LLMCompleteResponse {
+            client: self.client.clone(),
+            model: cached.model,
+            prompt: self.prompt.clone(),
+            request_options: self.request_options.clone(),
+            content: cached.content,
+            start_time,
+            latency: instant_start.elapsed(),
+            metadata: LLMCompleteResponseMetadata {
+                cache: Some(CacheStatus::Hit),
+                ..cached.metadata
+            },
+        }))
+    }
+
+    pub fn mark_miss(&self, response: &mut LLMResponse) {
+        if let LLMResponse::Success(response) = response {
+            response.metadata.cache = Some(CacheStatus::Miss);
+        }
+    }
+
+    /// Stores `response` if it is a complete success that missed. Callers
+    /// only store responses that parsed.
+    pub fn store(&self, response: &LLMResponse) {
+        match response {
+            LLMResponse::Success(response)
+                if response.metadata.baml_is_complete
+                    && response.metadata.cache == Some(CacheStatus::Miss) =>
+            {
+                self.store.put(
+                    &self.key,
+                    CachedResponse {
+                        model: response.model.clone(),
+                        content: response.content.clone(),
+                        metadata: LLMCompleteResponseMetadata {
+                            cache: None,
+                            cost_usd: None,
+                            rate_limit_wait_ms: None,
+                            time_to_first_token_ms: None,
+                            ..response.metadata.clone()
+                        },
+                        created_at_ms: now_ms(),
+                    },
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// Everything that determines what the provider is sent. Request options
+/// are sorted, as their order is not stable. Also keys cassettes.
+pub(super) fn cache_key(
+    ctx: &RuntimeContext,
+    client: &str,
+    request_options: &HashMap<String, Value>,
+    prompt: &RenderedPrompt,
+) -> String {
+    let model = request_options
+        .get("model")
+        .or_else(|| request_options.get("model_id"))
+        .cloned()
+        .unwrap_or(Value::Null);
+    let prompt = match prompt {
+        RenderedPrompt::Completion(p) => json!(p),
+        RenderedPrompt::Chat(messages) => json!(messages),
+    };
+    json!({
+        "client": client,
+        "model": model,
+        "request_options": request_options.iter().collect::<BTreeMap<_, _>>(),
+        "prompt": prompt,
+        "output_tool": ctx.output_tool.as_ref().map(|t| json!([t.name, t.parameters])),
+        "output_schema": ctx.output_schema().ok().flatten().map(|s| json!([s.name, s.schema])),
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached(content: &str) -> CachedResponse {
+        CachedResponse {
+            model: "gpt-4o".into(),
+            content: content.into(),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: Some("stop".into()),
+                prompt_tokens: Some(10),
+                output_tokens: Some(5),
+                total_tokens: Some(15),
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
+            },
+            created_at_ms: now_ms(),
+        }
+    }
+
+    #[test]
+    fn test_memory_store_evicts_least_recently_used() {
+        let store = MemoryStore::new(2);
+        store.put("a", cached("1"));
+        store.put("b", cached("2"));
+        assert!(store.get("a").is_some());
+        store.put("c", cached("3"));
+
+        assert!(store.get("b").is_none());
+        assert_eq!(store.get("a").unwrap().content, "1");
+        assert_eq!(store.get("c").unwrap().content, "3");
+    }
+
+    #[test]
+    fn test_disk_store_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("baml-cache-{}", uuid::Uuid::new_v4()));
+        let store = DiskStore::new(dir.clone());
+        assert!(store.get("a").is_none());
+        store.put("a", cached("1"));
+        assert_eq!(DiskStore::new(dir.clone()).get("a").unwrap().content, "1");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_config() -> Result<()> {
+        let mut properties = HashMap::from([(
+            "cache".to_string(),
+            json!({ "backend": "disk", "ttl_ms": 60000 }),
+        )]);
+        let config = CacheConfig::resolve(&mut properties)?.unwrap();
+        assert_eq!(config.backend, CacheBackend::Disk);
+        assert_eq!(config.dir, PathBuf::from(DEFAULT_CACHE_DIR));
+        assert_eq!(config.ttl(), Some(Duration::from_secs(60)));
+        assert!(properties.is_empty());
+
+        assert_eq!(CacheConfig::from_value(json!(false))?, None);
+        assert!(CacheConfig::from_value(json!({ "max_entries": 0 })).is_err());
+        assert!(CacheConfig::from_value(json!({ "size": 10 })).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_policy() -> Result<()> {
+        assert_eq!(
+            CachePolicy::from_value(Value::Null)?,
+            CachePolicy::ClientDefault
+        );
+        assert_eq!(CachePolicy::from_value(json!(false))?, CachePolicy::Off);
+        assert_eq!(
+            CachePolicy::from_value(json!("refresh"))?,
+            CachePolicy::Refresh
+        );
+        assert_eq!(
+            CachePolicy::from_value(json!(true))?,
+            CachePolicy::Enabled(CacheConfig::default())
+        );
+        assert!(CachePolicy::from_value(json!("always")).is_err());
+        Ok(())
+    }
+}
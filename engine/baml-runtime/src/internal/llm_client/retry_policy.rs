@@ -1,11 +1,20 @@
 use internal_baml_core::{
-    internal_baml_parser_database::RetryPolicyStrategy, ir::RetryPolicyWalker,
+    internal_baml_parser_database::{RetryJitter, RetryPolicyStrategy},
+    ir::RetryPolicyWalker,
 };
 
+use super::LLMResponse;
+
+/// The longest a `constant_delay` policy waits for a delay the provider asked
+/// for, unless its own delay is longer. Matches the default `max_delay_ms` of
+/// `exponential_backoff`.
+const CONSTANT_DELAY_MAX_MS: u32 = 10_000;
+
 #[derive(Clone)]
 pub struct CallablePolicy {
     max_retries: u32,
     strategy: RetryPolicyStrategy,
+    retry_on: Option<Vec<u16>>,
     current: std::time::Duration,
     counter: u32,
 }
@@ -15,6 +24,7 @@ impl From<RetryPolicyWalker<'_>> for CallablePolicy {
         CallablePolicy {
             max_retries: policy.max_retries(),
             strategy: policy.strategy().clone(),
+            retry_on: policy.retry_on().map(|codes| codes.to_vec()),
             current: match policy.strategy() {
                 RetryPolicyStrategy::ExponentialBackoff(strategy) => {
                     std::time::Duration::from_millis(strategy.delay_ms as u64)
@@ -28,6 +38,45 @@ impl From<RetryPolicyWalker<'_>> for CallablePolicy {
     }
 }
 
+impl CallablePolicy {
+    /// Whether a request that failed with `response` should be retried.
+    /// Only provider errors are checked against `retry_on`.
+    pub fn retries(&self, response: &LLMResponse) -> bool {
+        match (&self.retry_on, response) {
+            (Some(codes), LLMResponse::LLMFailure(e)) => codes.contains(&e.code.to_u16()),
+            _ => true,
+        }
+    }
+
+    /// The delay before retrying `attempt`, which failed with `response`:
+    /// `planned`, or the one the provider asked for if that is longer, up to
+    /// the policy's longest delay. Nothing is retried after the last attempt,
+    /// so its `planned` delay is kept.
+    pub fn delay_after(
+        &self,
+        response: &LLMResponse,
+        attempt: usize,
+        planned: std::time::Duration,
+    ) -> std::time::Duration {
+        match response {
+            LLMResponse::LLMFailure(e) if attempt < self.max_retries as usize => e
+                .retry_after
+                .map_or(planned, |delay| delay.min(self.max_delay()).max(planned)),
+            _ => planned,
+        }
+    }
+
+    fn max_delay(&self) -> std::time::Duration {
+        let ms = match &self.strategy {
+            RetryPolicyStrategy::ExponentialBackoff(strategy) => strategy.max_delay_ms,
+            RetryPolicyStrategy::ConstantDelay(strategy) => {
+                strategy.delay_ms.max(CONSTANT_DELAY_MAX_MS)
+            }
+        };
+        std::time::Duration::from_millis(ms as u64)
+    }
+}
+
 impl Iterator for CallablePolicy {
     type Item = std::time::Duration;
 
@@ -36,16 +85,19 @@ impl Iterator for CallablePolicy {
             return None;
         }
 
-        let delay = match &self.strategy {
+        let (delay, jitter) = match &self.strategy {
             RetryPolicyStrategy::ExponentialBackoff(strategy) => {
                 let delay = (strategy.multiplier * self.current.as_millis() as f32) as u32;
-                if delay > strategy.max_delay_ms {
-                    strategy.max_delay_ms
-                } else {
-                    delay
-                }
+                let delay = match strategy.jitter {
+                    // Grows from the previous delay, which was itself random.
+                    RetryJitter::Decorrelated => {
+                        fastrand::u32(strategy.delay_ms.min(delay)..=delay)
+                    }
+                    _ => delay,
+                };
+                (delay.min(strategy.max_delay_ms), strategy.jitter)
             }
-            RetryPolicyStrategy::ConstantDelay(strategy) => strategy.delay_ms,
+            RetryPolicyStrategy::ConstantDelay(strategy) => (strategy.delay_ms, strategy.jitter),
         };
 
         if self.counter == self.max_retries {
@@ -56,6 +108,171 @@ impl Iterator for CallablePolicy {
         }
         self.counter += 1;
 
-        Some(self.current)
+        match jitter {
+            RetryJitter::Full if !self.current.is_zero() => Some(std::time::Duration::from_millis(
+                fastrand::u64(0..=self.current.as_millis() as u64),
+            )),
+            _ => Some(self.current),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use internal_baml_core::internal_baml_parser_database::{
+        ContantDelayStrategy, ExponentialBackoffStrategy,
+    };
+    use std::time::Duration;
+
+    use super::*;
+    use crate::internal::llm_client::{ErrorCode, LLMErrorResponse};
+
+    fn policy(strategy: RetryPolicyStrategy, retry_on: Option<Vec<u16>>) -> CallablePolicy {
+        CallablePolicy {
+            max_retries: 5,
+            strategy,
+            retry_on,
+            current: Duration::from_millis(100),
+            counter: 0,
+        }
+    }
+
+    fn failure(code: ErrorCode, retry_after: Option<Duration>) -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: "GPT4o".into(),
+            model: None,
+            prompt: internal_baml_jinja::RenderedPrompt::Completion("hi".into()),
+            request_options: Default::default(),
+            start_time: web_time::SystemTime::now(),
+            latency: Duration::ZERO,
+            message: "failed".into(),
+            code,
+            retry_after,
+            rate_limit_wait_ms: None,
+        })
+    }
+
+    #[test]
+    fn test_full_jitter() {
+        let delays = policy(
+            RetryPolicyStrategy::ConstantDelay(ContantDelayStrategy {
+                delay_ms: 100,
+                jitter: RetryJitter::Full,
+            }),
+            None,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(delays.len(), 6);
+        assert!(delays.iter().all(|d| *d <= Duration::from_millis(100)));
+        assert_eq!(delays.last(), Some(&Duration::ZERO));
+    }
+
+    #[test]
+    fn test_decorrelated_jitter() {
+        let delays = policy(
+            RetryPolicyStrategy::ExponentialBackoff(ExponentialBackoffStrategy {
+                delay_ms: 100,
+                multiplier: 3.0,
+                max_delay_ms: 1000,
+                jitter: RetryJitter::Decorrelated,
+            }),
+            None,
+        )
+        .collect::<Vec<_>>();
+        let mut previous = Duration::from_millis(100);
+        for delay in &delays[..5] {
+            assert!(*delay >= Duration::from_millis(100));
+            assert!(*delay <= (previous * 3).min(Duration::from_millis(1000)));
+            previous = *delay;
+        }
+    }
+
+    #[test]
+    fn test_retry_on() {
+        let strategy = RetryPolicyStrategy::ConstantDelay(ContantDelayStrategy {
+            delay_ms: 100,
+            jitter: RetryJitter::None,
+        });
+        let policy = policy(strategy, Some(vec![429, 503]));
+        assert!(policy.retries(&failure(ErrorCode::RateLimited, None)));
+        assert!(!policy.retries(&failure(ErrorCode::InvalidAuthentication, None)));
+        assert!(policy.retries(&LLMResponse::InternalFailure("bad prompt".into())));
+        assert!(!policy.retries(&failure(ErrorCode::Other(2), None)));
+
+        let transport = CallablePolicy {
+            retry_on: Some(vec![2]),
+            ..policy
+        };
+        assert!(transport.retries(&failure(ErrorCode::Other(2), None)));
+    }
+
+    #[test]
+    fn test_delay_after() {
+        let constant = policy(
+            RetryPolicyStrategy::ConstantDelay(ContantDelayStrategy {
+                delay_ms: 100,
+                jitter: RetryJitter::None,
+            }),
+            None,
+        );
+        let planned = Duration::from_millis(100);
+        let hinted = failure(ErrorCode::RateLimited, Some(Duration::from_secs(2)));
+        assert_eq!(
+            constant.delay_after(&hinted, 0, planned),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            constant.delay_after(&failure(ErrorCode::ServerError, None), 0, planned),
+            planned
+        );
+
+        // e.g. `x-ratelimit-reset-tokens: 6m0s`
+        let reset = failure(ErrorCode::RateLimited, Some(Duration::from_secs(360)));
+        assert_eq!(
+            constant.delay_after(&reset, 0, planned),
+            Duration::from_secs(10)
+        );
+
+        let exponential = policy(
+            RetryPolicyStrategy::ExponentialBackoff(ExponentialBackoffStrategy {
+                delay_ms: 100,
+                multiplier: 2.0,
+                max_delay_ms: 5000,
+                jitter: RetryJitter::None,
+            }),
+            None,
+        );
+        assert_eq!(
+            exponential.delay_after(&reset, 0, planned),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn test_delay_after_with_full_jitter() {
+        let jittered = policy(
+            RetryPolicyStrategy::ConstantDelay(ContantDelayStrategy {
+                delay_ms: 100,
+                jitter: RetryJitter::Full,
+            }),
+            None,
+        );
+        let hinted = failure(ErrorCode::RateLimited, Some(Duration::from_secs(2)));
+        let short_hint = failure(ErrorCode::RateLimited, Some(Duration::from_millis(50)));
+
+        // Full jitter can plan no delay at all, but the provider's still holds.
+        assert_eq!(
+            jittered.delay_after(&hinted, 0, Duration::ZERO),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            jittered.delay_after(&short_hint, 0, Duration::from_millis(80)),
+            Duration::from_millis(80)
+        );
+        // No retry follows the last attempt.
+        assert_eq!(
+            jittered.delay_after(&hinted, 5, Duration::ZERO),
+            Duration::ZERO
+        );
     }
 }
@@ -0,0 +1,358 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use web_time::{Duration, Instant};
+
+use crate::internal::llm_client::{ErrorCode, LLMResponse};
+
+/// The `circuit_breaker` option of fallback and round-robin clients.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CircuitBreakerConfig {
+    /// Number of most recent calls the error rate is computed over.
+    #[serde(default = "default_window")]
+    pub window: usize,
+    /// Calls needed in the window before the circuit can open.
+    #[serde(default = "default_min_requests")]
+    pub min_requests: usize,
+    /// Error rate, above 0 and up to 1, at which the circuit opens.
+    #[serde(default = "default_failure_rate")]
+    pub failure_rate: f64,
+    /// How long an open circuit skips its client before letting a probe
+    /// call through.
+    #[serde(default = "default_cooldown_ms")]
+    pub cooldown_ms: u64,
+}
+
+fn default_window() -> usize {
+    20
+}
+
+fn default_min_requests() -> usize {
+    5
+}
+
+fn default_failure_rate() -> f64 {
+    0.5
+}
+
+fn default_cooldown_ms() -> u64 {
+    30_000
+}
+
+impl CircuitBreakerConfig {
+    pub(super) fn from_value(value: serde_json::Value) -> Result<Self> {
+        let config = serde_json::from_value::<Self>(value).context(
+            "Invalid circuit_breaker. Supported keys are: window, min_requests, failure_rate, cooldown_ms",
+        )?;
+        if config.window == 0 {
+            anyhow::bail!("circuit_breaker.window must be at least 1");
+        }
+        if config.failure_rate <= 0.0 || config.failure_rate > 1.0 {
+            anyhow::bail!("circuit_breaker.failure_rate must be above 0 and at most 1");
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Calls go through and their outcomes are recorded.
+    Closed,
+    /// Calls are skipped until the cooldown passes.
+    Open,
+    /// The cooldown passed: one probe call decides whether to close the
+    /// circuit again or keep it open.
+    HalfOpen,
+}
+
+impl std::fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half-open"),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ClientHealth {
+    // true for each failed call, most recent last.
+    outcomes: VecDeque<bool>,
+    opened_at: Option<Instant>,
+    probe_started_at: Option<Instant>,
+}
+
+/// Health of each client in a strategy.
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    clients: Mutex<Vec<ClientHealth>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig, num_clients: usize) -> Self {
+        Self {
+            config,
+            clients: Mutex::new((0..num_clients).map(|_| Default::default()).collect()),
+        }
+    }
+
+    fn cooldown(&self) -> Duration {
+        Duration::from_millis(self.config.cooldown_ms)
+    }
+
+    pub fn state(&self, client: usize) -> CircuitState {
+        let clients = self.clients.lock().unwrap();
+        self.state_of(&clients[client], Instant::now())
+    }
+
+    fn state_of(&self, health: &ClientHealth, now: Instant) -> CircuitState {
+        match health.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if now.duration_since(opened_at) < self.cooldown() => {
+                CircuitState::Open
+            }
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Whether a call to `client` may go through. A half-open circuit lets
+    /// one probe through at a time; a probe whose outcome is never recorded
+    /// (e.g. the call was skipped by another strategy) expires after the
+    /// cooldown.
+    pub fn allow(&self, client: usize) -> bool {
+        let now = Instant::now();
+        let mut clients = self.clients.lock().unwrap();
+        let state = self.state_of(&clients[client], now);
+        let health = &mut clients[client];
+        match state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => match health.probe_started_at {
+                Some(started) if now.duration_since(started) < self.cooldown() => false,
+                _ => {
+                    health.probe_started_at = Some(now);
+                    true
+                }
+            },
+        }
+    }
+
+    /// Records the outcome of a call to `client`. Only provider errors count
+    /// as failures; responses that failed to render or parse, or calls that
+    /// were cancelled, don't say anything about the provider's health.
+    pub fn record(&self, client: usize, response: &LLMResponse) {
+        let failed = match response {
+            LLMResponse::Success(_) => false,
+            LLMResponse::LLMFailure(e) if matches!(e.code, ErrorCode::Cancelled) => return,
+            LLMResponse::LLMFailure(_) => true,
+            LLMResponse::UserFailure(_) | LLMResponse::InternalFailure(_) => return,
+        };
+
+        let now = Instant::now();
+        let mut clients = self.clients.lock().unwrap();
+        let state = self.state_of(&clients[client], now);
+        let health = &mut clients[client];
+        health.probe_started_at = None;
+        match (state, failed) {
+            (CircuitState::Closed, _) => {
+                health.outcomes.push_back(failed);
+                while health.outcomes.len() > self.config.window {
+                    health.outcomes.pop_front();
+                }
+                let failures = health.outcomes.iter().filter(|f| **f).count();
+                if health.outcomes.len() >= self.config.min_requests
+                    && failures as f64 >= self.config.failure_rate * health.outcomes.len() as f64
+                {
+                    health.opened_at = Some(now);
+                }
+            }
+            (_, true) => health.opened_at = Some(now),
+            (_, false) => {
+                health.opened_at = None;
+                health.outcomes.clear();
+            }
+        }
+    }
+}
+
+/// The circuit breakers of a runtime's strategies, by strategy name. Every
+/// context the runtime creates shares them, so a circuit stays open across
+/// calls, including for strategies defined with a `ClientRegistry`.
+#[derive(Clone, Default)]
+pub struct CircuitBreakers(Arc<Mutex<HashMap<String, Arc<CircuitBreaker>>>>);
+
+impl CircuitBreakers {
+    /// A strategy redefined with another configuration or number of clients
+    /// starts over with closed circuits.
+    pub fn get(
+        &self,
+        strategy: &str,
+        config: &CircuitBreakerConfig,
+        num_clients: usize,
+    ) -> Arc<CircuitBreaker> {
+        let mut breakers = self.0.lock().unwrap();
+        match breakers.get(strategy) {
+            Some(breaker)
+                if breaker.config == *config
+                    && breaker.clients.lock().unwrap().len() == num_clients =>
+            {
+                breaker.clone()
+            }
+            _ => {
+                let breaker = Arc::new(CircuitBreaker::new(config.clone(), num_clients));
+                breakers.insert(strategy.to_string(), breaker.clone());
+                breaker
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::llm_client::{
+        LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse,
+    };
+
+    fn success() -> LLMResponse {
+        LLMResponse::Success(LLMCompleteResponse {
+            client: "MyClient".into(),
+            model: "gpt-4o".into(),
+            prompt: internal_baml_jinja::RenderedPrompt::Completion("".into()),
+            request_options: Default::default(),
+            content: "{}".into(),
+            start_time: web_time::SystemTime::now(),
+            latency: Duration::ZERO,
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: None,
+                prompt_tokens: None,
+                output_tokens: None,
+                total_tokens: None,
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
+            },
+        })
+    }
+
+    fn failure() -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: "MyClient".into(),
+            model: None,
+            prompt: internal_baml_jinja::RenderedPrompt::Completion("".into()),
+            start_time: web_time::SystemTime::now(),
+            latency: Duration::ZERO,
+            request_options: Default::default(),
+            message: "Service unavailable".into(),
+            code: ErrorCode::ServiceUnavailable,
+            retry_after: None,
+            rate_limit_wait_ms: None,
+        })
+    }
+
+    fn breaker(cooldown_ms: u64) -> CircuitBreaker {
+        CircuitBreaker::new(
+            CircuitBreakerConfig {
+                window: 4,
+                min_requests: 2,
+                failure_rate: 0.5,
+                cooldown_ms,
+            },
+            2,
+        )
+    }
+
+    #[test]
+    fn test_opens_after_failures() {
+        let breaker = breaker(60_000);
+        breaker.record(0, &failure());
+        assert_eq!(breaker.state(0), CircuitState::Closed);
+        breaker.record(0, &failure());
+        assert_eq!(breaker.state(0), CircuitState::Open);
+        assert!(!breaker.allow(0));
+
+        // Other clients are unaffected.
+        assert_eq!(breaker.state(1), CircuitState::Closed);
+        assert!(breaker.allow(1));
+    }
+
+    #[test]
+    fn test_half_open_probe() {
+        let breaker = breaker(50);
+        breaker.record(0, &failure());
+        breaker.record(0, &failure());
+        std::thread::sleep(std::time::Duration::from_millis(60));
+        assert_eq!(breaker.state(0), CircuitState::HalfOpen);
+
+        // Only one probe goes through at a time.
+        assert!(breaker.allow(0));
+        assert!(!breaker.allow(0));
+
+        // Internal failures don't settle the probe.
+        breaker.record(0, &LLMResponse::InternalFailure("bad prompt".into()));
+        assert!(!breaker.allow(0));
+
+        // Neither do cancelled calls.
+        let LLMResponse::LLMFailure(mut cancelled) = failure() else {
+            unreachable!()
+        };
+        cancelled.code = ErrorCode::Cancelled;
+        breaker.record(0, &LLMResponse::LLMFailure(cancelled));
+        assert!(!breaker.allow(0));
+
+        // A failed probe reopens the circuit.
+        breaker.record(0, &failure());
+        assert_eq!(breaker.state(0), CircuitState::Open);
+
+        std::thread::sleep(std::time::Duration::from_millis(60));
+        assert!(breaker.allow(0));
+        breaker.record(0, &success());
+        assert_eq!(breaker.state(0), CircuitState::Closed);
+        assert!(breaker.allow(0));
+    }
+
+    #[test]
+    fn test_shared_by_name() {
+        let config = breaker(60_000).config;
+        let breakers = CircuitBreakers::default();
+        let breaker = breakers.get("MyStrategy", &config, 2);
+        breaker.record(0, &failure());
+        breaker.record(0, &failure());
+        assert_eq!(
+            breakers.get("MyStrategy", &config, 2).state(0),
+            CircuitState::Open
+        );
+
+        // A redefined strategy starts over.
+        assert_eq!(
+            breakers.get("MyStrategy", &config, 3).state(0),
+            CircuitState::Closed
+        );
+        assert_eq!(
+            breakers.get("OtherStrategy", &config, 2).state(0),
+            CircuitState::Closed
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(CircuitBreakerConfig::from_value(serde_json::json!({ "window": 0 })).is_err());
+        assert!(
+            CircuitBreakerConfig::from_value(serde_json::json!({ "failure_rate": 2 })).is_err()
+        );
+        assert!(CircuitBreakerConfig::from_value(serde_json::json!({ "cooldown": 10 })).is_err());
+        let config =
+            CircuitBreakerConfig::from_value(serde_json::json!({ "cooldown_ms": 1000 })).unwrap();
+        assert_eq!(config.window, 20);
+        assert_eq!(config.cooldown_ms, 1000);
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::{Context, Result};
 
@@ -12,18 +12,35 @@ use crate::{
     runtime_interface::InternalClientLookup,
     RuntimeContext,
 };
+use serde::Serialize;
 
+use super::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+
+#[derive(Serialize)]
 pub struct FallbackStrategy {
     pub name: String,
     pub(super) retry_policy: Option<String>,
     // TODO: We can add conditions to each client
     client_specs: Vec<ClientSpec>,
+    #[serde(skip)]
+    circuit_breaker: Option<CircuitBreakerConfig>,
+}
+
+impl FallbackStrategy {
+    /// Its state is kept by the runtime, so every call that goes through the
+    /// strategy shares it.
+    pub fn circuit_breaker(&self, ctx: &RuntimeContext) -> Option<Arc<CircuitBreaker>> {
+        self.circuit_breaker.as_ref().map(|config| {
+            ctx.circuit_breakers
+                .get(&self.name, config, self.client_specs.len())
+        })
+    }
 }
 
 fn resolve_strategy(
     mut properties: HashMap<String, serde_json::Value>,
     _ctx: &RuntimeContext,
-) -> Result<Vec<ClientSpec>> {
+) -> Result<(Vec<ClientSpec>, Option<CircuitBreakerConfig>)> {
     let strategy = properties
         .remove("strategy")
         .map(|v| serde_json::from_value::<Vec<String>>(v))
@@ -39,8 +56,13 @@ fn resolve_strategy(
         anyhow::bail!("Missing a strategy field");
     };
 
+    let circuit_breaker = properties
+        .remove("circuit_breaker")
+        .map(CircuitBreakerConfig::from_value)
+        .transpose()?;
+
     if !properties.is_empty() {
-        let supported_keys = ["strategy"];
+        let supported_keys = ["strategy", "circuit_breaker"];
         let unknown_keys = properties.keys().map(String::from).collect::<Vec<_>>();
         anyhow::bail!(
             "Unknown keys: {}. Supported keys are: {}",
@@ -49,7 +71,10 @@ fn resolve_strategy(
         );
     }
 
-    Ok(strategy.into_iter().map(ClientSpec::new_from_id).collect())
+    Ok((
+        strategy.into_iter().map(ClientSpec::new_from_id).collect(),
+        circuit_breaker,
+    ))
 }
 
 impl TryFrom<(&ClientProperty, &RuntimeContext)> for FallbackStrategy {
@@ -58,7 +83,7 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for FallbackStrategy {
     fn try_from(
         (client, ctx): (&ClientProperty, &RuntimeContext),
     ) -> std::result::Result<Self, Self::Error> {
-        let strategy = resolve_strategy(
+        let (strategy, circuit_breaker) = resolve_strategy(
             client
                 .options
                 .iter()
@@ -69,6 +94,7 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for FallbackStrategy {
         Ok(Self {
             name: client.name.clone(),
             retry_policy: client.retry_policy.clone(),
+            circuit_breaker,
             client_specs: strategy,
         })
     }
@@ -79,16 +105,17 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for FallbackStrategy {
 
     fn try_from((client, ctx): (&ClientWalker, &RuntimeContext)) -> Result<Self> {
         let properties = super::super::resolve_properties_walker(client, ctx)?;
-        let strategy = resolve_strategy(properties, ctx)?;
+        let (strategy, circuit_breaker) = resolve_strategy(properties, ctx)?;
         Ok(Self {
             name: client.item.elem.name.clone(),
             retry_policy: client.retry_policy().as_ref().map(String::from),
+            circuit_breaker,
             client_specs: strategy,
         })
     }
 }
 
-impl IterOrchestrator for FallbackStrategy {
+impl IterOrchestrator for Arc<FallbackStrategy> {
     fn iter_orchestrator<'a>(
         &self,
         state: &mut OrchestrationState,
@@ -106,7 +133,7 @@ impl IterOrchestrator for FallbackStrategy {
                         let client = client.clone();
                         Ok(client.iter_orchestrator(
                             state,
-                            ExecutionScope::Fallback(self.name.clone(), idx).into(),
+                            ExecutionScope::Fallback(self.clone(), idx).into(),
                             ctx,
                             client_lookup,
                         ))
@@ -1,7 +1,8 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-mod fallback;
+pub mod circuit_breaker;
+pub mod fallback;
 pub mod roundrobin;
 
 use internal_baml_core::ir::ClientWalker;
@@ -21,7 +22,7 @@ use super::{
 
 pub enum LLMStrategyProvider {
     RoundRobin(Arc<RoundRobinStrategy>),
-    Fallback(FallbackStrategy),
+    Fallback(Arc<FallbackStrategy>),
 }
 
 impl std::fmt::Display for LLMStrategyProvider {
@@ -45,9 +46,9 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for LLMStrategyProvider {
             "baml-round-robin" | "round-robin" => RoundRobinStrategy::try_from((client, ctx))
                 .map(Arc::new)
                 .map(LLMStrategyProvider::RoundRobin),
-            "baml-fallback" | "fallback" => {
-                FallbackStrategy::try_from((client, ctx)).map(LLMStrategyProvider::Fallback)
-            }
+            "baml-fallback" | "fallback" => FallbackStrategy::try_from((client, ctx))
+                .map(Arc::new)
+                .map(LLMStrategyProvider::Fallback),
             other => {
                 let options = ["round-robin", "fallback"];
                 anyhow::bail!(
@@ -68,9 +69,9 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMStrategyProvider {
             "baml-round-robin" | "round-robin" => RoundRobinStrategy::try_from((client, ctx))
                 .map(Arc::new)
                 .map(LLMStrategyProvider::RoundRobin),
-            "baml-fallback" | "fallback" => {
-                FallbackStrategy::try_from((client, ctx)).map(LLMStrategyProvider::Fallback)
-            }
+            "baml-fallback" | "fallback" => FallbackStrategy::try_from((client, ctx))
+                .map(Arc::new)
+                .map(LLMStrategyProvider::Fallback),
             other => {
                 let options = ["round-robin", "fallback"];
                 anyhow::bail!(
@@ -21,6 +21,8 @@ use crate::{
 use serde::Serialize;
 use serde::Serializer;
 
+use super::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+
 #[derive(Serialize, Debug)]
 pub struct RoundRobinStrategy {
     pub name: String,
@@ -29,6 +31,8 @@ pub struct RoundRobinStrategy {
     client_specs: Vec<ClientSpec>,
     #[serde(serialize_with = "serialize_atomic")]
     current_index: AtomicUsize,
+    #[serde(skip)]
+    circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 fn serialize_atomic<S>(value: &AtomicUsize, serializer: S) -> Result<S::Ok, S::Error>
@@ -48,12 +52,21 @@ impl RoundRobinStrategy {
         self.current_index
             .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
     }
+
+    /// Its state is kept by the runtime, so every call that goes through the
+    /// strategy shares it.
+    pub fn circuit_breaker(&self, ctx: &RuntimeContext) -> Option<Arc<CircuitBreaker>> {
+        self.circuit_breaker.as_ref().map(|config| {
+            ctx.circuit_breakers
+                .get(&self.name, config, self.client_specs.len())
+        })
+    }
 }
 
 fn resolve_strategy(
     mut properties: HashMap<String, serde_json::Value>,
     _ctx: &RuntimeContext,
-) -> Result<(Vec<ClientSpec>, usize)> {
+) -> Result<(Vec<ClientSpec>, usize, Option<CircuitBreakerConfig>)> {
     let strategy = properties
         .remove("strategy")
         .map(|v| serde_json::from_value::<Vec<String>>(v))
@@ -75,8 +88,13 @@ fn resolve_strategy(
         .transpose()
         .context("Invalid start index (not a number)")?;
 
+    let circuit_breaker = properties
+        .remove("circuit_breaker")
+        .map(CircuitBreakerConfig::from_value)
+        .transpose()?;
+
     if !properties.is_empty() {
-        let supported_keys = ["strategy", "start"];
+        let supported_keys = ["strategy", "start", "circuit_breaker"];
         let unknown_keys = properties.keys().map(String::from).collect::<Vec<_>>();
         anyhow::bail!(
             "Unknown keys: {}. Supported keys are: {}",
@@ -105,6 +123,7 @@ fn resolve_strategy(
     Ok((
         strategy.into_iter().map(ClientSpec::new_from_id).collect(),
         start,
+        circuit_breaker,
     ))
 }
 
@@ -114,7 +133,7 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for RoundRobinStrategy {
     fn try_from(
         (client, ctx): (&ClientProperty, &RuntimeContext),
     ) -> std::result::Result<Self, Self::Error> {
-        let (strategy, start) = resolve_strategy(
+        let (strategy, start, circuit_breaker) = resolve_strategy(
             client
                 .options
                 .iter()
@@ -128,6 +147,7 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for RoundRobinStrategy {
             retry_policy: client.retry_policy.clone(),
             client_specs: strategy,
             current_index: AtomicUsize::new(start),
+            circuit_breaker,
         })
     }
 }
@@ -137,12 +157,13 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for RoundRobinStrategy {
 
     fn try_from((client, ctx): (&ClientWalker, &RuntimeContext)) -> Result<Self> {
         let properties = super::super::resolve_properties_walker(client, ctx)?;
-        let (strategy, start) = resolve_strategy(properties, ctx)?;
+        let (strategy, start, circuit_breaker) = resolve_strategy(properties, ctx)?;
         Ok(Self {
             name: client.item.elem.name.clone(),
             retry_policy: client.retry_policy().as_ref().map(String::from),
             client_specs: strategy,
             current_index: AtomicUsize::new(start),
+            circuit_breaker,
         })
     }
 }
@@ -156,7 +177,17 @@ impl IterOrchestrator for Arc<RoundRobinStrategy> {
         client_lookup: &'a dyn InternalClientLookup<'a>,
     ) -> Result<OrchestratorNodeIterator> {
         let offset = state.client_to_usage.entry(self.name.clone()).or_insert(0);
-        let next = (self.current_index() + *offset) % self.client_specs.len();
+        let len = self.client_specs.len();
+        let next = (self.current_index() + *offset) % len;
+        // Route around clients whose circuit is open. If all of them are, the
+        // call is skipped with that reason.
+        let next = match self.circuit_breaker(ctx) {
+            Some(circuit) => (0..len)
+                .map(|i| (next + i) % len)
+                .find(|idx| circuit.state(*idx) != CircuitState::Open)
+                .unwrap_or(next),
+            None => next,
+        };
 
         // Update the usage count
         *offset += 1;
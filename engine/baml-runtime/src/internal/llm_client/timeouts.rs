@@ -0,0 +1,145 @@
+use std::{collections::HashMap, future::Future};
+
+use anyhow::Result;
+use web_time::{Duration, Instant};
+
+/// The `timeout_ms`, `time_to_first_token_ms` and `idle_timeout_ms` options
+/// of a client. None of them are set by default.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timeouts {
+    /// Total time for a call, including the whole stream.
+    pub request: Option<Duration>,
+    /// Time until the first chunk of a stream arrives.
+    pub first_token: Option<Duration>,
+    /// Longest gap between two chunks of a stream.
+    pub idle: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Removes the timeout options from `properties`, so they are not
+    /// forwarded to the provider.
+    pub fn resolve(properties: &mut HashMap<String, serde_json::Value>) -> Result<Self> {
+        let mut millis = |key: &str| {
+            properties
+                .remove(key)
+                .map(|v| match v.as_u64() {
+                    Some(0) | None => anyhow::bail!("{key} must be a positive integer"),
+                    Some(ms) => Ok(Duration::from_millis(ms)),
+                })
+                .transpose()
+        };
+        Ok(Self {
+            request: millis("timeout_ms")?,
+            first_token: millis("time_to_first_token_ms")?,
+            idle: millis("idle_timeout_ms")?,
+        })
+    }
+
+    /// The limit on a non-streaming call.
+    pub fn single_call(&self) -> Option<Limit> {
+        self.request.map(|t| Limit::new(t, "timeout_ms"))
+    }
+
+    /// The limit on waiting for the next chunk of a stream started at
+    /// `start`. Connecting counts as waiting for the first chunk.
+    pub fn next_chunk(&self, start: Instant, received_first: bool) -> Option<Limit> {
+        let elapsed = start.elapsed();
+        let request = self
+            .request
+            .map(|t| Limit::new(t.saturating_sub(elapsed), "timeout_ms"));
+        let chunk = if received_first {
+            self.idle.map(|t| Limit::new(t, "idle_timeout_ms"))
+        } else {
+            self.first_token
+                .map(|t| Limit::new(t.saturating_sub(elapsed), "time_to_first_token_ms"))
+        };
+        match (request, chunk) {
+            (Some(a), Some(b)) => Some(if a.remaining <= b.remaining { a } else { b }),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Limit {
+    remaining: Duration,
+    // The client option that set this limit.
+    option: &'static str,
+}
+
+impl Limit {
+    fn new(remaining: Duration, option: &'static str) -> Self {
+        Self { remaining, option }
+    }
+
+    pub fn message(&self, elapsed: Duration) -> String {
+        format!(
+            "Request timed out after {}ms ({})",
+            elapsed.as_millis(),
+            self.option
+        )
+    }
+}
+
+/// Awaits `fut`, or gives back the limit if it runs out first.
+pub async fn within<T>(limit: Option<Limit>, fut: impl Future<Output = T>) -> Result<T, Limit> {
+    match limit {
+        Some(limit) => async_std::future::timeout(limit.remaining, fut)
+            .await
+            .map_err(|_| limit),
+        None => Ok(fut.await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() -> Result<()> {
+        let mut properties = HashMap::from([
+            ("model".to_string(), serde_json::json!("gpt-4o")),
+            ("timeout_ms".to_string(), serde_json::json!(30000)),
+            ("idle_timeout_ms".to_string(), serde_json::json!(5000)),
+        ]);
+        let timeouts = Timeouts::resolve(&mut properties)?;
+        assert_eq!(timeouts.request, Some(Duration::from_secs(30)));
+        assert_eq!(timeouts.first_token, None);
+        assert_eq!(timeouts.idle, Some(Duration::from_secs(5)));
+        assert_eq!(properties.len(), 1);
+
+        let mut properties = HashMap::from([("timeout_ms".to_string(), serde_json::json!(-1))]);
+        assert!(Timeouts::resolve(&mut properties).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_chunk() {
+        let timeouts = Timeouts {
+            request: Some(Duration::from_secs(60)),
+            first_token: Some(Duration::from_secs(10)),
+            idle: Some(Duration::from_secs(120)),
+        };
+        let start = Instant::now();
+        assert_eq!(
+            timeouts.next_chunk(start, false).map(|l| l.option),
+            Some("time_to_first_token_ms")
+        );
+        assert_eq!(
+            timeouts.next_chunk(start, true).map(|l| l.option),
+            Some("timeout_ms")
+        );
+        assert_eq!(Timeouts::default().next_chunk(start, true), None);
+    }
+
+    #[test]
+    fn test_within() {
+        let limit = Some(Limit::new(Duration::from_millis(10), "timeout_ms"));
+        let slow = async_std::task::sleep(Duration::from_secs(5));
+        assert!(futures::executor::block_on(within(limit, slow)).is_err());
+        assert_eq!(
+            futures::executor::block_on(within(limit, async { 1 })),
+            Ok(1)
+        );
+    }
+}
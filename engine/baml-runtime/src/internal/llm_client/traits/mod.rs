@@ -35,6 +35,9 @@ pub trait WithRetryPolicy {
 pub trait WithClientProperties {
     fn client_properties(&self) -> &HashMap<String, serde_json::Value>;
     fn allowed_metadata(&self) -> &super::AllowedMetadata;
+    fn timeouts(&self) -> &super::timeouts::Timeouts;
+    fn rate_limits(&self) -> &super::rate_limits::RateLimits;
+    fn cache(&self) -> Option<&super::response_cache::CacheConfig>;
 }
 
 pub trait WithSingleCallable {
@@ -282,7 +285,12 @@ where
         .await?;
 
         let request_builder = self
-            .build_request(either::Right(&chat_messages), false, render_settings.stream)
+            .build_request(
+                ctx,
+                either::Right(&chat_messages),
+                false,
+                render_settings.stream,
+            )
             .await?;
         let mut request = request_builder.build()?;
         let url_header_value = {
@@ -7,7 +7,8 @@ use baml_types::{BamlValue, FieldType};
 use internal_baml_core::{
     error_unsupported,
     ir::{
-        repr::{ClientSpec, IntermediateRepr},
+        output_json_schema,
+        repr::{ClientSpec, IntermediateRepr, OutputMode},
         FunctionWalker, IRHelper,
     },
 };
@@ -23,6 +24,23 @@ pub struct PromptRenderer {
     client_spec: ClientSpec,
     output_defs: OutputFormatContent,
     output_type: FieldType,
+    output_mode: OutputMode,
+}
+
+/// The return type of a function, sent to the model as a tool it must call.
+#[derive(Clone, Debug)]
+pub struct OutputTool {
+    pub name: String,
+    /// JSON Schema for the tool's arguments.
+    pub parameters: serde_json::Value,
+}
+
+/// The return type of a function, for clients with `structured_outputs`
+/// enabled.
+#[derive(Clone, Debug)]
+pub struct OutputSchema {
+    pub name: String,
+    pub schema: serde_json::Value,
 }
 
 impl PromptRenderer {
@@ -42,8 +60,10 @@ impl PromptRenderer {
                 Some((Some(client), _)) => ClientSpec::Named(client.clone()),
                 _ => config.client.clone(),
             },
-            output_defs: render_output_format(ir, ctx, &func_v2.output)?,
+            output_defs: render_output_format(ir, ctx, &func_v2.output)?
+                .with_target_constraints(func_v2.output_constraints.clone()),
             output_type: func_v2.output.clone(),
+            output_mode: config.output_mode,
         })
     }
 
@@ -51,6 +71,25 @@ impl PromptRenderer {
         &self.client_spec
     }
 
+    pub fn output_tool(&self) -> Result<Option<OutputTool>> {
+        match self.output_mode {
+            OutputMode::Prompt => Ok(None),
+            OutputMode::Tool => Ok(Some(OutputTool {
+                name: self.function_name.clone(),
+                parameters: output_json_schema(&self.output_defs)?,
+            })),
+        }
+    }
+
+    /// Fails if the output can't be expressed as a JSON Schema (e.g. it
+    /// contains media).
+    pub fn output_schema(&self) -> Result<OutputSchema> {
+        Ok(OutputSchema {
+            name: self.function_name.clone(),
+            schema: output_json_schema(&self.output_defs)?,
+        })
+    }
+
     pub fn parse(&self, raw_string: &str, allow_partials: bool) -> Result<BamlValueWithFlags> {
         jsonish::from_str(
             &self.output_defs,
@@ -327,13 +327,36 @@ fn relevant_data_models<'a>(
                         }
                     }
 
+                    // Only fields declared in baml carry constraints and streaming
+                    // attributes; fields added through a TypeBuilder have none.
+                    let field_constraints = walker
+                        .as_ref()
+                        .map(|w| {
+                            w.walk_fields()
+                                .filter(|f| !f.constraints().is_empty())
+                                .map(|f| (f.name().to_string(), f.constraints().to_vec()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    let field_streaming = walker
+                        .as_ref()
+                        .map(|w| {
+                            w.walk_fields()
+                                .filter(|f| !f.streaming().is_default())
+                                .map(|f| (f.name().to_string(), f.streaming()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
                     classes.push(Class {
                         name: Name::new_with_alias(cls.to_string(), alias.value()),
                         fields,
+                        field_constraints,
+                        field_streaming,
                     });
                 }
             }
-            FieldType::Primitive(_) => {}
+            FieldType::Primitive(_) | FieldType::Literal(_) => {}
         }
     }
 
@@ -344,6 +367,7 @@ fn relevant_data_models<'a>(
 mod tests {
     use super::*;
     use crate::BamlRuntime;
+    use internal_baml_core::ir::output_json_schema;
     use std::collections::HashMap;
 
     #[test]
@@ -371,4 +395,83 @@ mod tests {
         assert_eq!(foo_enum.values[0].0.real_name(), "Bar".to_string());
         assert_eq!(foo_enum.values.len(), 1);
     }
+
+    fn output_schema(file_content: &str, output: FieldType) -> serde_json::Value {
+        let files = vec![("test-file.baml", file_content)].into_iter().collect();
+        let env_vars: HashMap<&str, &str> = HashMap::new();
+        let baml_runtime = BamlRuntime::from_file_content(".", &files, env_vars).unwrap();
+        let ctx_manager = baml_runtime.create_ctx_manager(BamlValue::Null, None);
+        let ctx: RuntimeContext = ctx_manager.create_ctx(None, None).unwrap();
+
+        let output_format =
+            render_output_format(baml_runtime.inner.ir.as_ref(), &ctx, &output).unwrap();
+        output_json_schema(&output_format).unwrap()
+    }
+
+    #[test]
+    fn test_output_schema_uses_aliases_and_descriptions() {
+        let schema = output_schema(
+            r#"
+          enum Level {
+            JUNIOR
+            SENIOR @alias("senior") @description("10+ years")
+          }
+
+          class Resume {
+            full_name string @alias("name") @description("The candidate's name")
+            level Level?
+            skills string[]
+          }"#,
+            FieldType::Class("Resume".to_string()),
+        );
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "name": { "type": "string", "description": "The candidate's name" },
+                    "level": {
+                        "anyOf": [
+                            {
+                                "type": "string",
+                                "enum": ["JUNIOR", "senior"],
+                                "description": "senior: 10+ years",
+                            },
+                            { "type": "null" },
+                        ]
+                    },
+                    "skills": { "type": "array", "items": { "type": "string" } },
+                },
+                "required": ["name", "skills"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_output_schema_defines_recursive_classes_once() {
+        let schema = output_schema(
+            r#"
+          class Node {
+            value int
+            children Node[]
+          }"#,
+            FieldType::Class("Node".to_string()),
+        );
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "$ref": "#/$defs/Node",
+                "$defs": {
+                    "Node": {
+                        "type": "object",
+                        "properties": {
+                            "value": { "type": "integer" },
+                            "children": { "type": "array", "items": { "$ref": "#/$defs/Node" } },
+                        },
+                        "required": ["value", "children"],
+                    }
+                }
+            })
+        );
+    }
 }
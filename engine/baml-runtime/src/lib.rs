@@ -35,6 +35,7 @@ use baml_types::BamlValue;
 use cfg_if::cfg_if;
 use client_registry::ClientRegistry;
 use indexmap::IndexMap;
+use internal::llm_client::{rate_limits::RateLimiters, strategy::circuit_breaker::CircuitBreakers};
 use internal_baml_core::configuration::Generator;
 use internal_baml_core::configuration::GeneratorOutputType;
 use on_log_event::LogEventCallbackSync;
@@ -63,6 +64,7 @@ pub(crate) use internal_baml_jinja::{ChatMessagePart, RenderedPrompt};
 #[cfg(not(feature = "internal"))]
 pub(crate) use runtime_interface::InternalRuntimeInterface;
 
+pub use internal::llm_client::response_cache::CachePolicy;
 pub use internal_baml_core::internal_baml_diagnostics;
 pub use internal_baml_core::internal_baml_diagnostics::Diagnostics as DiagnosticsError;
 pub use internal_baml_core::ir::{scope_diagnostics, FieldType, IRHelper, TypeValue};
@@ -73,6 +75,9 @@ static TOKIO_SINGLETON: OnceLock<std::io::Result<Arc<tokio::runtime::Runtime>>>
 pub struct BamlRuntime {
     pub (crate) inner: InternalBamlRuntime,
     tracer: Arc<BamlTracer>,
+    pub(crate) metrics: Metrics,
+    pub(crate) rate_limiters: RateLimiters,
+    pub(crate) circuit_breakers: CircuitBreakers,
     env_vars: HashMap<String, String>,
     #[cfg(not(target_arch = "wasm32"))]
     pub async_runtime: Arc<tokio::runtime::Runtime>,
@@ -83,6 +88,11 @@ impl BamlRuntime {
         &self.env_vars
     }
 
+    /// Counters and latencies of every function call made so far.
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn get_tokio_singleton() -> Result<Arc<tokio::runtime::Runtime>> {
         match TOKIO_SINGLETON.get_or_init(|| tokio::runtime::Runtime::new().map(Arc::new)) {
@@ -139,6 +149,9 @@ impl BamlRuntime {
         Ok(BamlRuntime {
             inner: InternalBamlRuntime::from_directory(&path)?,
             tracer: BamlTracer::new(None, env_vars.into_iter())?.into(),
+            metrics: Metrics::default(),
+            rate_limiters: RateLimiters::default(),
+            circuit_breakers: CircuitBreakers::default(),
             env_vars: copy,
             #[cfg(not(target_arch = "wasm32"))]
             async_runtime: Self::get_tokio_singleton()?,
@@ -157,6 +170,9 @@ impl BamlRuntime {
         Ok(BamlRuntime {
             inner: InternalBamlRuntime::from_file_content(root_path, files)?,
             tracer: BamlTracer::new(None, env_vars.into_iter())?.into(),
+            metrics: Metrics::default(),
+            rate_limiters: RateLimiters::default(),
+            circuit_breakers: CircuitBreakers::default(),
             env_vars: copy,
             #[cfg(not(target_arch = "wasm32"))]
             async_runtime: Self::get_tokio_singleton()?,
@@ -213,13 +229,19 @@ impl BamlRuntime {
         let response = match ctx.create_ctx(None, None) {
             Ok(rctx) => {
                 let params = self.get_test_params(function_name, test_name, &rctx);
-                match params {
-                    Ok(params) => match ctx.create_ctx(None, None) {
+                let constraints = self
+                    .inner
+                    .get_test_constraints(function_name, test_name, &rctx);
+                match params.and_then(|params| Ok((params, constraints?))) {
+                    Ok((params, constraints)) => match ctx.create_ctx(None, None) {
                         Ok(rctx_stream) => {
                             let stream = self.inner.stream_function_impl(
                                 function_name.into(),
                                 &params,
                                 self.tracer.clone(),
+                                self.metrics.clone(),
+                                self.rate_limiters.clone(),
+                                self.circuit_breakers.clone(),
                                 rctx_stream,
                                 #[cfg(not(target_arch = "wasm32"))]
                                 self.async_runtime.clone(),
@@ -229,6 +251,10 @@ impl BamlRuntime {
                                     let (response, span) =
                                         stream.run(on_event, ctx, None, None).await;
                                     response.map(|res| TestResponse {
+                                        constraints_result: TestConstraintResult::evaluate_all(
+                                            &constraints,
+                                            &res,
+                                        ),
                                         function_response: res,
                                         function_span: span,
                                     })
@@ -286,12 +312,18 @@ impl BamlRuntime {
         let span = self.tracer.start_span(&function_name, ctx, &params);
         let response = match ctx.create_ctx(tb, cb) {
             Ok(rctx) => {
+                let rctx = RuntimeContext {
+                    rate_limiters: self.rate_limiters.clone(),
+                    circuit_breakers: self.circuit_breakers.clone(),
+                    ..rctx
+                };
                 self.inner
-                    .call_function_impl(function_name, params, rctx)
+                    .call_function_impl(function_name.clone(), params, rctx)
                     .await
             }
             Err(e) => Err(e),
         };
+        self.metrics.record(&function_name, &response);
 
         let mut target_id = None;
         if let Some(span) = span {
@@ -321,6 +353,9 @@ impl BamlRuntime {
             function_name,
             params,
             self.tracer.clone(),
+            self.metrics.clone(),
+            self.rate_limiters.clone(),
+            self.circuit_breakers.clone(),
             ctx.create_ctx(tb, cb)?,
             #[cfg(not(target_arch = "wasm32"))]
             self.async_runtime.clone(),
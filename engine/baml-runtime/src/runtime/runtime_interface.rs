@@ -13,18 +13,20 @@ use crate::{
                 orchestrate_call, IterOrchestrator, OrchestrationScope, OrchestratorNode,
             },
             primitive::LLMPrimitiveProvider,
+            rate_limits::RateLimiters,
             retry_policy::CallablePolicy,
+            strategy::circuit_breaker::CircuitBreakers,
             traits::{WithPrompt, WithRenderRawCurl},
         },
         prompt_renderer::PromptRenderer,
     },
     runtime_interface::{InternalClientLookup, RuntimeConstructor},
     tracing::BamlTracer,
-    FunctionResult, FunctionResultStream, InternalRuntimeInterface, RenderCurlSettings,
+    FunctionResult, FunctionResultStream, InternalRuntimeInterface, Metrics, RenderCurlSettings,
     RuntimeContext, RuntimeInterface,
 };
 use anyhow::{Context, Result};
-use baml_types::{BamlMap, BamlValue};
+use baml_types::{BamlMap, BamlValue, Constraint};
 use internal_baml_core::{
     internal_baml_diagnostics::SourceFile,
     ir::{
@@ -286,6 +288,17 @@ impl InternalRuntimeInterface for InternalBamlRuntime {
             Err(e) => return Err(anyhow::anyhow!("Unable to resolve test params: {:?}", e)),
         }
     }
+
+    fn get_test_constraints(
+        &self,
+        function_name: &str,
+        test_name: &str,
+        ctx: &RuntimeContext,
+    ) -> Result<Vec<Constraint>> {
+        let func = self.get_function(function_name, ctx)?;
+        let test = self.ir().find_test(&func, test_name)?;
+        Ok(test.constraints().to_vec())
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -427,6 +440,11 @@ impl RuntimeInterface for InternalBamlRuntime {
 
         let renderer = PromptRenderer::from_function(&func, self.ir(), &ctx)?;
         let orchestrator = self.orchestration_graph(renderer.client_spec(), &ctx)?;
+        let ctx = RuntimeContext {
+            output_tool: renderer.output_tool()?,
+            output_schema: Some(renderer.output_schema()),
+            ..ctx
+        };
 
         // Now actually execute the code.
         let (history, _) =
@@ -443,6 +461,9 @@ impl RuntimeInterface for InternalBamlRuntime {
         function_name: String,
         params: &BamlMap<String, BamlValue>,
         tracer: Arc<BamlTracer>,
+        metrics: Metrics,
+        rate_limiters: RateLimiters,
+        circuit_breakers: CircuitBreakers,
         ctx: RuntimeContext,
         #[cfg(not(target_arch = "wasm32"))] tokio_runtime: Arc<tokio::runtime::Runtime>,
     ) -> Result<FunctionResultStream> {
@@ -469,6 +490,9 @@ impl RuntimeInterface for InternalBamlRuntime {
             params: baml_args,
             orchestrator,
             tracer,
+            metrics,
+            rate_limiters,
+            circuit_breakers,
             renderer,
             #[cfg(not(target_arch = "wasm32"))]
             tokio_runtime,
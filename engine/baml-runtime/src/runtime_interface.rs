@@ -1,5 +1,5 @@
 use anyhow::Result;
-use baml_types::{BamlMap, BamlValue};
+use baml_types::{BamlMap, BamlValue, Constraint};
 use internal_baml_core::internal_baml_diagnostics::Diagnostics;
 use internal_baml_core::ir::repr::ClientSpec;
 use internal_baml_core::ir::{repr::IntermediateRepr, FunctionWalker};
@@ -12,10 +12,16 @@ use crate::internal::llm_client::AllowedMetadata;
 use crate::tracing::{BamlTracer, TracingSpan};
 use crate::types::on_log_event::LogEventCallbackSync;
 use crate::{
-    internal::{ir_features::IrFeatures, llm_client::retry_policy::CallablePolicy},
+    internal::{
+        ir_features::IrFeatures,
+        llm_client::{
+            rate_limits::RateLimiters, retry_policy::CallablePolicy,
+            strategy::circuit_breaker::CircuitBreakers,
+        },
+    },
     runtime::InternalBamlRuntime,
     types::FunctionResultStream,
-    FunctionResult, RuntimeContext,
+    FunctionResult, Metrics, RuntimeContext,
 };
 use crate::{RenderCurlSettings, RuntimeContextManager};
 
@@ -44,6 +50,9 @@ pub trait RuntimeInterface {
         function_name: String,
         params: &BamlMap<String, BamlValue>,
         tracer: Arc<BamlTracer>,
+        metrics: Metrics,
+        rate_limiters: RateLimiters,
+        circuit_breakers: CircuitBreakers,
         ctx: RuntimeContext,
         #[cfg(not(target_arch = "wasm32"))] tokio_runtime: Arc<tokio::runtime::Runtime>,
     ) -> Result<FunctionResultStream>;
@@ -159,4 +168,11 @@ pub trait InternalRuntimeInterface {
         test_name: &str,
         ctx: &RuntimeContext,
     ) -> Result<BamlMap<String, BamlValue>>;
+
+    fn get_test_constraints(
+        &self,
+        function_name: &str,
+        test_name: &str,
+        ctx: &RuntimeContext,
+    ) -> Result<Vec<Constraint>>;
 }
@@ -130,6 +130,18 @@ pub struct LLMOutputModelMetadata {
     pub output_tokens: Option<i64>,
     pub total_tokens: Option<i64>,
     pub finish_reason: Option<String>,
+    // "hit" or "miss" when the response cache is on for the call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache: Option<String>,
+    // Set when the model has a price in `BAML_PRICE_TABLE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+    // Set when the request queued behind the client's rate limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_wait_ms: Option<i64>,
+    // Set when the response was streamed from the provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_to_first_token_ms: Option<i64>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -191,6 +203,26 @@ pub struct LLMEventSchema {
     pub input: LLMEventInput,
     pub output: Option<LLMOutputModel>,
     pub error: Option<String>,
+    // Only exported over OTLP.
+    #[serde(skip)]
+    pub attempt: LLMAttempt,
+}
+
+/// Details of an LLM attempt that aren't sent to Boundary.
+#[derive(Debug, Clone, Default)]
+pub struct LLMAttempt {
+    /// The strategies and retry policies the attempt went through.
+    pub scope: String,
+    /// Set when the attempt is a retry of an earlier one.
+    pub retry: Option<usize>,
+    pub start_time: Option<web_time::SystemTime>,
+    pub latency: Option<web_time::Duration>,
+    /// The parse error, if the response was parsed.
+    pub parsed: Option<Result<(), String>>,
+    /// Set when the call was cancelled during the attempt.
+    pub cancelled: bool,
+    /// Set when the request queued behind the client's rate limits.
+    pub rate_limit_wait_ms: Option<u64>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -51,6 +51,56 @@ fn default_max_log_chunk_chars() -> usize {
     64_000
 }
 
+/// The standard `OTEL_*` variables read by the OTLP exporter. The
+/// `OTEL_EXPORTER_OTLP_TRACES_*` variables take precedence over the generic
+/// ones.
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct OtlpConfig {
+    pub exporter_otlp_endpoint: Option<String>,
+    pub exporter_otlp_traces_endpoint: Option<String>,
+    pub exporter_otlp_protocol: Option<String>,
+    pub exporter_otlp_traces_protocol: Option<String>,
+    pub exporter_otlp_headers: Option<String>,
+    pub exporter_otlp_traces_headers: Option<String>,
+    pub exporter_otlp_timeout: Option<u64>,
+    pub exporter_otlp_traces_timeout: Option<u64>,
+    pub service_name: Option<String>,
+}
+
+impl OtlpConfig {
+    pub fn from_env_vars<T: AsRef<str>>(env_vars: impl Iterator<Item = (T, T)>) -> Result<Self> {
+        envy::prefixed("OTEL_")
+            .from_iter(env_vars.map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string())))
+            .map_err(|err| {
+                anyhow::anyhow!(
+                    "Failed to parse OpenTelemetry config from environment variables: {}",
+                    err
+                )
+            })
+    }
+}
+
+/// The `BAML_TRACE_*` variables of the JSONL trace files.
+#[derive(Deserialize, Debug, Default, Clone)]
+pub struct TraceFileConfig {
+    pub dir: Option<std::path::PathBuf>,
+    pub max_file_mb: Option<u64>,
+    pub max_files: Option<usize>,
+}
+
+impl TraceFileConfig {
+    pub fn from_env_vars<T: AsRef<str>>(env_vars: impl Iterator<Item = (T, T)>) -> Result<Self> {
+        envy::prefixed("BAML_TRACE_")
+            .from_iter(env_vars.map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string())))
+            .map_err(|err| {
+                anyhow::anyhow!(
+                    "Failed to parse trace file config from environment variables: {}",
+                    err
+                )
+            })
+    }
+}
+
 impl Config {
     pub fn from_env_vars<T: AsRef<str>>(env_vars: impl Iterator<Item = (T, T)>) -> Result<Self> {
         let config: Result<Config, envy::Error> = envy::prefixed("BOUNDARY_")
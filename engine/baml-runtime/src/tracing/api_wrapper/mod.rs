@@ -10,10 +10,17 @@ use crate::request::create_tracing_client;
 
 pub(super) use self::api_interface::{BoundaryAPI, BoundaryTestAPI};
 use self::core_types::{TestCaseStatus, UpdateTestCase};
+pub(super) use self::env_setup::{OtlpConfig, TraceFileConfig};
 
 #[derive(Debug, Clone)]
 pub struct APIWrapper {
     pub(super) config: APIConfig,
+    // Spans are also exported over OTLP when an endpoint is set.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    pub(super) otlp: OtlpConfig,
+    // And written to JSONL files when a directory is set.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    pub(super) trace_files: TraceFileConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -296,7 +303,12 @@ impl BoundaryTestAPI for APIWrapper {
 
 impl APIWrapper {
     pub fn from_env_vars<T: AsRef<str>>(value: impl Iterator<Item = (T, T)>) -> Result<Self> {
-        let config = env_setup::Config::from_env_vars(value).unwrap();
+        let value = value
+            .map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string()))
+            .collect::<Vec<_>>();
+        let config = env_setup::Config::from_env_vars(value.iter().cloned()).unwrap();
+        let otlp = OtlpConfig::from_env_vars(value.iter().cloned())?;
+        let trace_files = TraceFileConfig::from_env_vars(value.into_iter())?;
         if config.log_redaction_enabled {
             log::info!("Redaction enabled: {}", config.log_redaction_enabled);
         }
@@ -314,6 +326,8 @@ impl APIWrapper {
                     log_redaction_placeholder: config.log_redaction_placeholder,
                     max_log_chunk_chars: config.max_log_chunk_chars,
                 }),
+                otlp,
+                trace_files,
             },
             _ => Self {
                 config: APIConfig::LocalOnly(PartialAPIConfig {
@@ -327,6 +341,8 @@ impl APIWrapper {
                     log_redaction_placeholder: config.log_redaction_placeholder,
                     max_log_chunk_chars: config.max_log_chunk_chars,
                 }),
+                otlp,
+                trace_files,
             },
         })
     }
@@ -13,14 +13,16 @@ use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 use crate::{
-    client_registry::ClientRegistry, internal::llm_client::LLMResponse,
-    tracing::api_wrapper::core_types::Role, type_builder::TypeBuilder, FunctionResult,
-    RuntimeContext, RuntimeContextManager, SpanCtx, TestResponse, TraceStats,
+    client_registry::ClientRegistry,
+    internal::llm_client::{orchestrator::ExecutionScope, ErrorCode, LLMResponse},
+    tracing::api_wrapper::core_types::Role,
+    type_builder::TypeBuilder,
+    FunctionResult, RuntimeContext, RuntimeContextManager, SpanCtx, TestResponse, TraceStats,
 };
 
 use self::api_wrapper::{
     core_types::{
-        ContentPart, EventChain, IOValue, LLMChat, LLMEventInput, LLMEventInputPrompt,
+        ContentPart, EventChain, IOValue, LLMAttempt, LLMChat, LLMEventInput, LLMEventInputPrompt,
         LLMEventSchema, LLMOutputModel, LogSchema, LogSchemaContext, MetadataType, Template,
         TypeSchema, IO,
     },
@@ -32,7 +34,9 @@ cfg_if! {
         mod wasm_tracer;
         use self::wasm_tracer::NonThreadedTracer as TracerImpl;
     } else {
+        mod otlp;
         mod threaded_tracer;
+        pub(crate) mod trace_files;
         use self::threaded_tracer::ThreadedTracer as TracerImpl;
     }
 }
@@ -156,12 +160,24 @@ impl BamlTracer {
 
         let trace_stats = TraceStats::default();
 
-        let tracer = BamlTracer {
-            tracer: if options.enabled() {
-                Some(TracerImpl::new(&options, 20, trace_stats.clone()))
+        cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                let tracer = options
+                    .enabled()
+                    .then(|| TracerImpl::new(&options, 20, trace_stats.clone()));
             } else {
-                None
-            },
+                let otlp = match otlp::OtlpOptions::from_config(&options.otlp)? {
+                    Some(otlp) => Some(otlp::OtlpExporter::new(otlp, &options)?),
+                    None => None,
+                };
+                let trace_files = trace_files::TraceFileWriter::from_config(&options.trace_files)?;
+                let tracer = (options.enabled() || otlp.is_some() || trace_files.is_some())
+                    .then(|| TracerImpl::new(&options, otlp, trace_files, 20, trace_stats.clone()));
+            }
+        }
+
+        let tracer = BamlTracer {
+            tracer,
             options,
             trace_stats,
         };
@@ -291,6 +307,7 @@ impl BamlTracer {
         }
 
         if let Ok(response) = &response {
+            self.trace_stats.add_usage(&response.usage());
             let name = event_chain.last().map(|s| s.name.as_str());
             let is_ok = response.parsed().as_ref().is_some_and(|r| r.is_ok());
             log::log!(
@@ -338,6 +355,7 @@ impl BamlTracer {
         }
 
         if let Ok(response) = &response {
+            self.trace_stats.add_usage(&response.usage());
             let name = event_chain.last().map(|s| s.name.as_str());
             let is_ok = response.parsed().as_ref().is_some_and(|r| r.is_ok());
             log::log!(
@@ -628,7 +646,23 @@ impl From<&FunctionResult> for MetadataType {
             result
                 .event_chain()
                 .iter()
-                .map(|(_, r, _)| r.into())
+                .map(|(scope, r, parsed)| {
+                    let mut event: LLMEventSchema = r.into();
+                    event.attempt.scope = scope
+                        .scope
+                        .iter()
+                        .map(|s| s.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" + ");
+                    event.attempt.retry = scope.scope.iter().rev().find_map(|s| match s {
+                        ExecutionScope::Retry(_, count, _) => Some(*count),
+                        _ => None,
+                    });
+                    event.attempt.parsed = parsed
+                        .as_ref()
+                        .map(|p| p.as_ref().map(|_| ()).map_err(|e| e.to_string()));
+                    event
+                })
                 .collect::<Vec<_>>(),
         )
     }
@@ -650,6 +684,7 @@ impl From<&LLMResponse> for LLMEventSchema {
                 },
                 output: None,
                 error: Some(s.clone()),
+                attempt: Default::default(),
             },
             LLMResponse::InternalFailure(s) => LLMEventSchema {
                 model_name: "<unknown>".into(),
@@ -664,6 +699,7 @@ impl From<&LLMResponse> for LLMEventSchema {
                 },
                 output: None,
                 error: Some(s.clone()),
+                attempt: Default::default(),
             },
             LLMResponse::Success(s) => LLMEventSchema {
                 model_name: s.model.clone(),
@@ -684,6 +720,12 @@ impl From<&LLMResponse> for LLMEventSchema {
                     r#override: None,
                 }),
                 error: None,
+                attempt: LLMAttempt {
+                    start_time: Some(s.start_time),
+                    latency: Some(s.latency),
+                    rate_limit_wait_ms: s.metadata.rate_limit_wait_ms,
+                    ..Default::default()
+                },
             },
             LLMResponse::LLMFailure(s) => LLMEventSchema {
                 model_name: s
@@ -702,6 +744,13 @@ impl From<&LLMResponse> for LLMEventSchema {
                 },
                 output: None,
                 error: Some(s.message.clone()),
+                attempt: LLMAttempt {
+                    start_time: Some(s.start_time),
+                    latency: Some(s.latency),
+                    cancelled: matches!(s.code, ErrorCode::Cancelled),
+                    rate_limit_wait_ms: s.rate_limit_wait_ms,
+                    ..Default::default()
+                },
             },
         }
     }
@@ -0,0 +1,1049 @@
+//! Exports BAML spans to an OpenTelemetry collector over OTLP.
+//!
+//! Each function call becomes a span, with a child span for every LLM attempt
+//! it made, retries and fallbacks included. Attempt spans follow the GenAI
+//! semantic conventions and carry the result of parsing the response as an
+//! event. Spans are exported after the function call finishes, by the same
+//! thread that sends them to Boundary.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use web_time::{Duration, UNIX_EPOCH};
+
+use super::api_wrapper::{
+    core_types::{LLMEventSchema, LogSchema, MetadataType, Template, ValueType},
+    APIWrapper, OtlpConfig,
+};
+
+const GRPC_EXPORT_PATH: &str = "/opentelemetry.proto.collector.trace.v1.TraceService/Export";
+const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum OtlpProtocol {
+    Grpc,
+    HttpProtobuf,
+    HttpJson,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct OtlpOptions {
+    /// The full URL spans are posted to.
+    pub endpoint: String,
+    pub protocol: OtlpProtocol,
+    pub headers: Vec<(String, String)>,
+    pub timeout: Duration,
+    pub service_name: String,
+}
+
+impl OtlpOptions {
+    /// `None` if neither `OTEL_EXPORTER_OTLP_ENDPOINT` nor
+    /// `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT` is set.
+    pub fn from_config(config: &OtlpConfig) -> Result<Option<Self>> {
+        let protocol = match config
+            .exporter_otlp_traces_protocol
+            .as_deref()
+            .or(config.exporter_otlp_protocol.as_deref())
+        {
+            None | Some("http/protobuf") => OtlpProtocol::HttpProtobuf,
+            Some("http/json") => OtlpProtocol::HttpJson,
+            Some("grpc") => OtlpProtocol::Grpc,
+            Some(other) => anyhow::bail!(
+                "Unsupported OTEL_EXPORTER_OTLP_PROTOCOL: {other}. Supported protocols are: grpc, http/protobuf, http/json"
+            ),
+        };
+
+        // Like the OpenTelemetry SDKs, the signal-specific endpoint is used
+        // as is, while `/v1/traces` is appended to the generic one.
+        let endpoint = match (
+            &config.exporter_otlp_traces_endpoint,
+            &config.exporter_otlp_endpoint,
+        ) {
+            (Some(endpoint), _) => endpoint.clone(),
+            (None, Some(base)) if protocol == OtlpProtocol::Grpc => base.clone(),
+            (None, Some(base)) => format!("{}/v1/traces", base.trim_end_matches('/')),
+            (None, None) => return Ok(None),
+        };
+        let endpoint = match protocol {
+            OtlpProtocol::Grpc => format!("{}{}", endpoint.trim_end_matches('/'), GRPC_EXPORT_PATH),
+            _ => endpoint,
+        };
+
+        let headers = config
+            .exporter_otlp_traces_headers
+            .as_deref()
+            .or(config.exporter_otlp_headers.as_deref())
+            .unwrap_or_default()
+            .split(',')
+            .filter(|h| !h.trim().is_empty())
+            .map(|h| match h.split_once('=') {
+                Some((k, v)) => Ok((k.trim().to_string(), v.trim().to_string())),
+                None => anyhow::bail!(
+                    "Invalid OTEL_EXPORTER_OTLP_HEADERS: expected key=value pairs separated by commas"
+                ),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(Self {
+            endpoint,
+            protocol,
+            headers,
+            timeout: Duration::from_millis(
+                config
+                    .exporter_otlp_traces_timeout
+                    .or(config.exporter_otlp_timeout)
+                    .unwrap_or(DEFAULT_TIMEOUT_MS),
+            ),
+            service_name: config
+                .service_name
+                .clone()
+                .unwrap_or_else(|| "baml".to_string()),
+        }))
+    }
+}
+
+pub(super) struct OtlpExporter {
+    options: OtlpOptions,
+    client: reqwest::Client,
+    resource: Vec<(&'static str, AttributeValue)>,
+}
+
+impl OtlpExporter {
+    pub fn new(options: OtlpOptions, api: &APIWrapper) -> Result<Self> {
+        let mut builder = reqwest::Client::builder().timeout(options.timeout);
+        // Over TLS, HTTP/2 is negotiated with ALPN. Without TLS there is
+        // nothing to negotiate with, so gRPC has to assume it.
+        if options.protocol == OtlpProtocol::Grpc && options.endpoint.starts_with("http://") {
+            builder = builder.http2_prior_knowledge();
+        }
+        let client = builder
+            .build()
+            .context("Failed to create the OTLP exporter client")?;
+
+        let resource = vec![
+            ("service.name", options.service_name.clone().into()),
+            ("host.name", api.host_name().into()),
+            ("deployment.environment", api.stage().into()),
+            ("telemetry.sdk.name", "baml".into()),
+            ("telemetry.sdk.language", "rust".into()),
+            ("telemetry.sdk.version", env!("CARGO_PKG_VERSION").into()),
+            ("baml.session_id", api.session_id().into()),
+        ];
+
+        Ok(Self {
+            options,
+            client,
+            resource,
+        })
+    }
+
+    pub async fn export(&self, logs: &[LogSchema]) -> Result<()> {
+        let spans = logs.iter().flat_map(spans_from_log).collect::<Vec<_>>();
+        if spans.is_empty() {
+            return Ok(());
+        }
+
+        let endpoint = &self.options.endpoint;
+        let (content_type, body) = match self.options.protocol {
+            OtlpProtocol::HttpJson => (
+                "application/json",
+                serde_json::to_vec(&self.to_json(&spans))?,
+            ),
+            OtlpProtocol::HttpProtobuf => ("application/x-protobuf", self.to_protobuf(&spans)),
+            OtlpProtocol::Grpc => ("application/grpc", grpc_frame(&self.to_protobuf(&spans))),
+        };
+
+        let mut request = self
+            .client
+            .post(endpoint)
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body);
+        if self.options.protocol == OtlpProtocol::Grpc {
+            request = request.header("te", "trailers");
+        }
+        for (key, value) in &self.options.headers {
+            request = request.header(key, value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("Failed to export spans to {endpoint}"))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!("Failed to export spans to {endpoint}. Status: {status}\nBody: {body}");
+        }
+        if self.options.protocol == OtlpProtocol::Grpc {
+            check_grpc_status(response)
+                .await
+                .with_context(|| format!("Failed to export spans to {endpoint}"))?;
+        }
+        Ok(())
+    }
+
+    fn scope_json() -> Value {
+        json!({ "name": "baml", "version": env!("CARGO_PKG_VERSION") })
+    }
+
+    fn to_json(&self, spans: &[Span]) -> Value {
+        json!({
+            "resourceSpans": [{
+                "resource": { "attributes": attributes_json(&self.resource) },
+                "scopeSpans": [{
+                    "scope": Self::scope_json(),
+                    "spans": spans.iter().map(Span::to_json).collect::<Vec<_>>(),
+                }],
+            }],
+        })
+    }
+
+    /// Encodes an `ExportTraceServiceRequest`.
+    fn to_protobuf(&self, spans: &[Span]) -> Vec<u8> {
+        let mut request = ProtoWriter::default();
+        // ResourceSpans
+        request.message(1, |resource_spans| {
+            // Resource
+            resource_spans.message(1, |resource| {
+                for (key, value) in &self.resource {
+                    resource.message(1, |kv| kv.key_value(key, value));
+                }
+            });
+            // ScopeSpans
+            resource_spans.message(2, |scope_spans| {
+                scope_spans.message(1, |scope| {
+                    scope.string(1, "baml");
+                    scope.string(2, env!("CARGO_PKG_VERSION"));
+                });
+                for span in spans {
+                    scope_spans.message(2, |s| span.encode(s));
+                }
+            });
+        });
+        request.buf
+    }
+}
+
+/// gRPC reports the outcome of a call in the `grpc-status` trailer, or in the
+/// headers of a trailers-only response.
+async fn check_grpc_status(response: reqwest::Response) -> Result<()> {
+    let headers = response.headers().clone();
+    let body = http::Response::<reqwest::Body>::from(response).into_body();
+    let trailers = http_body_util::BodyExt::collect(body)
+        .await
+        .context("Failed to read the gRPC response")?
+        .trailers()
+        .cloned()
+        .unwrap_or_default();
+    let get = |name: &str| {
+        trailers
+            .get(name)
+            .or_else(|| headers.get(name))
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string())
+    };
+    match get("grpc-status").as_deref() {
+        Some("0") => Ok(()),
+        Some(code) => anyhow::bail!(
+            "gRPC status: {code} {}",
+            get("grpc-message").unwrap_or_default()
+        ),
+        None => anyhow::bail!("The response has no grpc-status"),
+    }
+}
+
+/// Prefixes a gRPC message with its uncompressed flag and length.
+fn grpc_frame(message: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(message.len() + 5);
+    body.push(0);
+    body.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    body.extend_from_slice(message);
+    body
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum AttributeValue {
+    String(String),
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    StringArray(Vec<String>),
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        AttributeValue::String(value.to_string())
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        AttributeValue::String(value)
+    }
+}
+
+impl AttributeValue {
+    fn to_json(&self) -> Value {
+        match self {
+            AttributeValue::String(s) => json!({ "stringValue": s }),
+            AttributeValue::Bool(b) => json!({ "boolValue": b }),
+            // 64-bit integers are strings in OTLP/JSON.
+            AttributeValue::Int(i) => json!({ "intValue": i.to_string() }),
+            AttributeValue::Double(d) => json!({ "doubleValue": d }),
+            AttributeValue::StringArray(values) => json!({
+                "arrayValue": {
+                    "values": values.iter().map(|v| json!({ "stringValue": v })).collect::<Vec<_>>(),
+                },
+            }),
+        }
+    }
+
+    /// Encodes an `AnyValue`.
+    fn encode(&self, w: &mut ProtoWriter) {
+        match self {
+            AttributeValue::String(s) => w.string(1, s),
+            AttributeValue::Bool(b) => w.varint_field(2, *b as u64),
+            AttributeValue::Int(i) => w.varint_field(3, *i as u64),
+            AttributeValue::Double(d) => w.fixed64(4, d.to_bits()),
+            AttributeValue::StringArray(values) => w.message(5, |array| {
+                for value in values {
+                    array.message(1, |v| v.string(1, value));
+                }
+            }),
+        }
+    }
+}
+
+fn attributes_json<K: AsRef<str>>(attributes: &[(K, AttributeValue)]) -> Value {
+    attributes
+        .iter()
+        .map(|(key, value)| json!({ "key": key.as_ref(), "value": value.to_json() }))
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SpanKind {
+    Internal = 1,
+    Client = 3,
+}
+
+#[derive(Debug)]
+struct SpanEvent {
+    time_unix_nano: u64,
+    name: &'static str,
+    attributes: Vec<(String, AttributeValue)>,
+}
+
+#[derive(Debug)]
+struct Span {
+    trace_id: [u8; 16],
+    span_id: [u8; 8],
+    parent_span_id: Option<[u8; 8]>,
+    name: String,
+    kind: SpanKind,
+    start_time_unix_nano: u64,
+    end_time_unix_nano: u64,
+    attributes: Vec<(String, AttributeValue)>,
+    events: Vec<SpanEvent>,
+    // Set for spans that failed.
+    error: Option<String>,
+}
+
+impl Span {
+    #[cfg(test)]
+    fn attribute(&self, key: &str) -> Option<&AttributeValue> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    fn to_json(&self) -> Value {
+        let mut span = json!({
+            "traceId": hex(&self.trace_id),
+            "spanId": hex(&self.span_id),
+            "name": self.name,
+            "kind": self.kind as i32,
+            "startTimeUnixNano": self.start_time_unix_nano.to_string(),
+            "endTimeUnixNano": self.end_time_unix_nano.to_string(),
+            "attributes": attributes_json(&self.attributes),
+            "events": self.events.iter().map(|e| json!({
+                "timeUnixNano": e.time_unix_nano.to_string(),
+                "name": e.name,
+                "attributes": attributes_json(&e.attributes),
+            })).collect::<Vec<_>>(),
+        });
+        if let Some(parent) = &self.parent_span_id {
+            span["parentSpanId"] = hex(parent).into();
+        }
+        if let Some(message) = &self.error {
+            span["status"] = json!({ "code": STATUS_CODE_ERROR, "message": message });
+        }
+        span
+    }
+
+    /// Encodes a `Span`.
+    fn encode(&self, w: &mut ProtoWriter) {
+        w.bytes(1, &self.trace_id);
+        w.bytes(2, &self.span_id);
+        if let Some(parent) = &self.parent_span_id {
+            w.bytes(4, parent);
+        }
+        w.string(5, &self.name);
+        w.varint_field(6, self.kind as u64);
+        w.fixed64(7, self.start_time_unix_nano);
+        w.fixed64(8, self.end_time_unix_nano);
+        for (key, value) in &self.attributes {
+            w.message(9, |kv| kv.key_value(key, value));
+        }
+        for event in &self.events {
+            w.message(11, |e| {
+                e.fixed64(1, event.time_unix_nano);
+                e.string(2, event.name);
+                for (key, value) in &event.attributes {
+                    e.message(3, |kv| kv.key_value(key, value));
+                }
+            });
+        }
+        if let Some(message) = &self.error {
+            w.message(15, |status| {
+                status.string(2, message);
+                status.varint_field(3, STATUS_CODE_ERROR as u64);
+            });
+        }
+    }
+}
+
+const STATUS_CODE_ERROR: i32 = 2;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn trace_id(event_id: &str) -> [u8; 16] {
+    uuid::Uuid::parse_str(event_id)
+        .map(|id| *id.as_bytes())
+        .unwrap_or_default()
+}
+
+/// Span IDs are 8 bytes, so the two halves of the event's UUID are folded
+/// together.
+fn span_id(event_id: &str) -> [u8; 8] {
+    let id = trace_id(event_id);
+    let mut folded = [0; 8];
+    for i in 0..8 {
+        folded[i] = id[i] ^ id[i + 8];
+    }
+    folded
+}
+
+/// Attempts don't have an event ID of their own, so theirs is derived from
+/// the function span's.
+fn attempt_span_id(parent: [u8; 8], index: usize) -> [u8; 8] {
+    let id = u64::from_be_bytes(parent) ^ (index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    id.max(1).to_be_bytes()
+}
+
+fn unix_nanos(time: web_time::SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+fn io_value(value: &ValueType) -> String {
+    match value {
+        ValueType::String(s) => s.clone(),
+        ValueType::List(values) => {
+            serde_json::to_string(values).unwrap_or_else(|_| "<unknown>".to_string())
+        }
+    }
+}
+
+fn spans_from_log(log: &LogSchema) -> Vec<Span> {
+    let trace_id = trace_id(&log.root_event_id);
+    let function_span_id = span_id(&log.event_id);
+    let start = chrono::DateTime::parse_from_rfc3339(&log.context.start_time)
+        .ok()
+        .and_then(|t| t.timestamp_nanos_opt())
+        .unwrap_or(0) as u64;
+    let end = start + log.context.latency_ms.max(0) as u64 * 1_000_000;
+    let function_name = log
+        .context
+        .event_chain
+        .last()
+        .map(|e| e.function_name.clone())
+        .unwrap_or_else(|| "baml".to_string());
+
+    let mut attributes = vec![(
+        "baml.function.name".to_string(),
+        function_name.clone().into(),
+    )];
+    for (key, value) in &log.context.tags {
+        let key = match key.starts_with("baml.") {
+            true => key.clone(),
+            false => format!("baml.tags.{key}"),
+        };
+        attributes.push((key, value.clone().into()));
+    }
+    if let Some(input) = &log.io.input {
+        attributes.push(("baml.input".into(), io_value(&input.value).into()));
+    }
+    if let Some(output) = &log.io.output {
+        attributes.push(("baml.output".into(), io_value(&output.value).into()));
+    }
+
+    let mut spans = vec![Span {
+        trace_id,
+        span_id: function_span_id,
+        parent_span_id: log.parent_event_id.as_deref().map(span_id),
+        name: function_name,
+        kind: SpanKind::Internal,
+        start_time_unix_nano: start,
+        end_time_unix_nano: end,
+        attributes,
+        events: vec![],
+        error: log.error.as_ref().map(|e| e.message.clone()),
+    }];
+
+    let attempts = match &log.metadata {
+        Some(MetadataType::Single(event)) => std::slice::from_ref(event),
+        Some(MetadataType::Multi(events)) => events.as_slice(),
+        None => &[],
+    };
+    for (index, event) in attempts.iter().enumerate() {
+        let attempt_start = event.attempt.start_time.map(unix_nanos).unwrap_or(start);
+        let attempt_end = event
+            .attempt
+            .latency
+            .map(|latency| attempt_start + latency.as_nanos() as u64)
+            .unwrap_or(end);
+        spans.push(Span {
+            trace_id,
+            span_id: attempt_span_id(function_span_id, index),
+            parent_span_id: Some(function_span_id),
+            kind: SpanKind::Client,
+            start_time_unix_nano: attempt_start,
+            end_time_unix_nano: attempt_end,
+            error: event.error.clone(),
+            events: event
+                .attempt
+                .parsed
+                .as_ref()
+                .map(|parsed| SpanEvent {
+                    time_unix_nano: attempt_end,
+                    name: "baml.parse",
+                    attributes: match parsed {
+                        Ok(()) => vec![("baml.parse.success".into(), AttributeValue::Bool(true))],
+                        Err(e) => vec![
+                            ("baml.parse.success".into(), AttributeValue::Bool(false)),
+                            ("baml.parse.error".into(), e.clone().into()),
+                        ],
+                    },
+                })
+                .into_iter()
+                .collect(),
+            ..attempt_span(event, trace_id, index)
+        });
+    }
+    spans
+}
+
+/// The name and GenAI attributes of an attempt.
+fn attempt_span(event: &LLMEventSchema, trace_id: [u8; 16], index: usize) -> Span {
+    let operation = match event.input.prompt.template {
+        Template::Multiple(_) => "chat",
+        Template::Single(_) => "text_completion",
+    };
+    let request_model = event
+        .input
+        .request_options
+        .get("model")
+        .and_then(|m| m.as_str())
+        .unwrap_or(&event.model_name)
+        .to_string();
+
+    let mut attributes: Vec<(String, AttributeValue)> = vec![
+        ("gen_ai.operation.name".into(), operation.into()),
+        ("gen_ai.request.model".into(), request_model.clone().into()),
+        ("baml.client".into(), event.provider.clone().into()),
+        ("baml.attempt".into(), AttributeValue::Int(index as i64)),
+    ];
+    for (option, key) in [
+        ("temperature", "gen_ai.request.temperature"),
+        ("top_p", "gen_ai.request.top_p"),
+    ] {
+        if let Some(value) = event
+            .input
+            .request_options
+            .get(option)
+            .and_then(|v| v.as_f64())
+        {
+            attributes.push((key.into(), AttributeValue::Double(value)));
+        }
+    }
+    if let Some(value) = event
+        .input
+        .request_options
+        .get("max_tokens")
+        .and_then(|v| v.as_i64())
+    {
+        attributes.push((
+            "gen_ai.request.max_tokens".into(),
+            AttributeValue::Int(value),
+        ));
+    }
+    if !event.attempt.scope.is_empty() {
+        attributes.push((
+            "baml.orchestration.scope".into(),
+            event.attempt.scope.clone().into(),
+        ));
+    }
+    if let Some(retry) = event.attempt.retry {
+        attributes.push(("baml.retry".into(), AttributeValue::Int(retry as i64)));
+    }
+    if event.attempt.cancelled {
+        attributes.push(("baml.cancelled".into(), AttributeValue::Bool(true)));
+    }
+    if let Some(wait) = event.attempt.rate_limit_wait_ms {
+        attributes.push((
+            "baml.rate_limit.wait_ms".into(),
+            AttributeValue::Int(wait as i64),
+        ));
+    }
+    if let Some(output) = &event.output {
+        let metadata = &output.metadata;
+        attributes.push((
+            "gen_ai.response.model".into(),
+            event.model_name.clone().into(),
+        ));
+        if let Some(tokens) = metadata.prompt_tokens {
+            attributes.push((
+                "gen_ai.usage.input_tokens".into(),
+                AttributeValue::Int(tokens),
+            ));
+        }
+        if let Some(tokens) = metadata.output_tokens {
+            attributes.push((
+                "gen_ai.usage.output_tokens".into(),
+                AttributeValue::Int(tokens),
+            ));
+        }
+        if let Some(reason) = &metadata.finish_reason {
+            attributes.push((
+                "gen_ai.response.finish_reasons".into(),
+                AttributeValue::StringArray(vec![reason.clone()]),
+            ));
+        }
+        if let Some(cache) = &metadata.cache {
+            attributes.push(("baml.cache".into(), cache.clone().into()));
+        }
+        if let Some(cost) = metadata.cost_usd {
+            attributes.push(("baml.cost_usd".into(), AttributeValue::Double(cost)));
+        }
+        if let Some(ttft) = metadata.time_to_first_token_ms {
+            attributes.push((
+                "baml.time_to_first_token_ms".into(),
+                AttributeValue::Int(ttft),
+            ));
+        }
+    }
+
+    Span {
+        trace_id,
+        span_id: [0; 8],
+        parent_span_id: None,
+        name: format!("{operation} {request_model}"),
+        kind: SpanKind::Client,
+        start_time_unix_nano: 0,
+        end_time_unix_nano: 0,
+        attributes,
+        events: vec![],
+        error: None,
+    }
+}
+
+/// Just enough of the protobuf wire format to encode OTLP requests.
+#[derive(Default)]
+struct ProtoWriter {
+    buf: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn tag(&mut self, field: u32, wire_type: u8) {
+        self.varint(((field as u64) << 3) | wire_type as u64);
+    }
+
+    fn varint_field(&mut self, field: u32, value: u64) {
+        self.tag(field, 0);
+        self.varint(value);
+    }
+
+    fn fixed64(&mut self, field: u32, value: u64) {
+        self.tag(field, 1);
+        self.buf.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u32, value: &[u8]) {
+        self.tag(field, 2);
+        self.varint(value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn string(&mut self, field: u32, value: &str) {
+        self.bytes(field, value.as_bytes());
+    }
+
+    fn message(&mut self, field: u32, encode: impl FnOnce(&mut ProtoWriter)) {
+        let mut inner = ProtoWriter::default();
+        encode(&mut inner);
+        self.bytes(field, &inner.buf);
+    }
+
+    /// Encodes a `KeyValue`.
+    fn key_value(&mut self, key: &str, value: &AttributeValue) {
+        self.string(1, key);
+        self.message(2, |v| value.encode(v));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use baml_types::BamlMap;
+    use internal_baml_jinja::RenderedPrompt;
+
+    use super::*;
+    use crate::{
+        internal::llm_client::{
+            orchestrator::{ExecutionScope, OrchestrationScope},
+            ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse,
+            LLMResponse,
+        },
+        tracing::{ToLogSchema, TracingSpan},
+        FunctionResult, SpanCtx,
+    };
+
+    fn config(vars: &[(&str, &str)]) -> OtlpConfig {
+        OtlpConfig::from_env_vars(vars.iter().copied()).unwrap()
+    }
+
+    #[test]
+    fn test_from_config() {
+        assert!(OtlpOptions::from_config(&config(&[])).unwrap().is_none());
+
+        let options = OtlpOptions::from_config(&config(&[(
+            "OTEL_EXPORTER_OTLP_ENDPOINT",
+            "http://localhost:4318/",
+        )]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(options.endpoint, "http://localhost:4318/v1/traces");
+        assert_eq!(options.protocol, OtlpProtocol::HttpProtobuf);
+        assert_eq!(options.service_name, "baml");
+
+        let options = OtlpOptions::from_config(&config(&[
+            ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318"),
+            ("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT", "http://tempo:4317"),
+            ("OTEL_EXPORTER_OTLP_TRACES_PROTOCOL", "grpc"),
+            (
+                "OTEL_EXPORTER_OTLP_HEADERS",
+                "x-scope-orgid=acme, x-team=ml",
+            ),
+            ("OTEL_SERVICE_NAME", "extractor"),
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            options.endpoint,
+            "http://tempo:4317/opentelemetry.proto.collector.trace.v1.TraceService/Export"
+        );
+        assert_eq!(options.protocol, OtlpProtocol::Grpc);
+        assert_eq!(
+            options.headers,
+            vec![
+                ("x-scope-orgid".to_string(), "acme".to_string()),
+                ("x-team".to_string(), "ml".to_string())
+            ]
+        );
+        assert_eq!(options.service_name, "extractor");
+
+        assert!(OtlpOptions::from_config(&config(&[
+            ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318"),
+            ("OTEL_EXPORTER_OTLP_PROTOCOL", "thrift"),
+        ]))
+        .is_err());
+    }
+
+    fn function_log() -> LogSchema {
+        let prompt = RenderedPrompt::Completion("Extract the resume".into());
+        let start_time = web_time::SystemTime::now();
+        let failure = LLMResponse::LLMFailure(LLMErrorResponse {
+            client: "GPT4o".into(),
+            model: None,
+            prompt: prompt.clone(),
+            start_time,
+            latency: Duration::from_millis(20),
+            request_options: Default::default(),
+            message: "Service unavailable".into(),
+            code: ErrorCode::ServiceUnavailable,
+            retry_after: None,
+            rate_limit_wait_ms: None,
+        });
+        let success = LLMResponse::Success(LLMCompleteResponse {
+            client: "GPT4o".into(),
+            model: "gpt-4o-2024-08-06".into(),
+            prompt,
+            request_options: [("model".to_string(), json!("gpt-4o"))].into(),
+            content: "not json".into(),
+            start_time,
+            latency: Duration::from_millis(30),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: Some("stop".into()),
+                prompt_tokens: Some(120),
+                output_tokens: Some(30),
+                total_tokens: Some(150),
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
+            },
+        });
+        let scope = |retry| {
+            OrchestrationScope::from(vec![
+                ExecutionScope::Retry("Exponential".into(), retry, Duration::ZERO),
+                ExecutionScope::Direct("GPT4o".into()),
+            ])
+        };
+        let result = FunctionResult::new_chain(vec![
+            (scope(0), failure, None),
+            (
+                scope(1),
+                success,
+                Some(Err(anyhow::anyhow!("Expected an object"))),
+            ),
+        ])
+        .unwrap();
+
+        let span_id = uuid::Uuid::new_v4();
+        let api = APIWrapper::from_env_vars(std::iter::empty::<(&str, &str)>()).unwrap();
+        result.to_log_schema(
+            &api,
+            vec![SpanCtx {
+                span_id,
+                name: "ExtractResume".into(),
+            }],
+            Default::default(),
+            TracingSpan {
+                span_id,
+                params: BamlMap::new(),
+                start_time,
+            },
+        )
+    }
+
+    #[test]
+    fn test_spans_from_log() {
+        let spans = spans_from_log(&function_log());
+        assert_eq!(spans.len(), 3);
+
+        let function = &spans[0];
+        assert_eq!(function.name, "ExtractResume");
+        assert_eq!(function.kind, SpanKind::Internal);
+        assert_eq!(function.parent_span_id, None);
+        assert!(function.error.is_some());
+
+        let failed = &spans[1];
+        assert_eq!(failed.parent_span_id, Some(function.span_id));
+        assert_eq!(failed.trace_id, function.trace_id);
+        assert_eq!(failed.error.as_deref(), Some("Service unavailable"));
+        assert_eq!(
+            failed.attribute("baml.retry"),
+            Some(&AttributeValue::Int(0))
+        );
+        assert!(failed.events.is_empty());
+
+        let retried = &spans[2];
+        assert_ne!(retried.span_id, failed.span_id);
+        assert_eq!(retried.name, "text_completion gpt-4o");
+        assert_eq!(retried.kind, SpanKind::Client);
+        assert_eq!(retried.error, None);
+        assert_eq!(
+            retried.attribute("gen_ai.response.model"),
+            Some(&"gpt-4o-2024-08-06".into())
+        );
+        assert_eq!(
+            retried.attribute("gen_ai.usage.input_tokens"),
+            Some(&AttributeValue::Int(120))
+        );
+        assert_eq!(
+            retried.attribute("gen_ai.response.finish_reasons"),
+            Some(&AttributeValue::StringArray(vec!["stop".into()]))
+        );
+        assert_eq!(
+            retried.attribute("baml.retry"),
+            Some(&AttributeValue::Int(1))
+        );
+        assert_eq!(retried.events[0].name, "baml.parse");
+        assert_eq!(
+            retried.events[0].attributes[0],
+            (
+                "baml.parse.success".to_string(),
+                AttributeValue::Bool(false)
+            )
+        );
+        assert_eq!(
+            retried.end_time_unix_nano - retried.start_time_unix_nano,
+            30_000_000
+        );
+    }
+
+    #[test]
+    fn test_protobuf_encoding() {
+        let mut w = ProtoWriter::default();
+        w.key_value("a", &"b".into());
+        assert_eq!(w.buf, [0x0a, 0x01, b'a', 0x12, 0x03, 0x0a, 0x01, b'b']);
+
+        let mut w = ProtoWriter::default();
+        w.varint_field(3, 300);
+        assert_eq!(w.buf, [0x18, 0xac, 0x02]);
+
+        assert_eq!(grpc_frame(&[7, 8]), [0, 0, 0, 0, 2, 7, 8]);
+    }
+
+    /// Receives exports like an OpenTelemetry collector would.
+    async fn start_collector() -> (String, Arc<Mutex<Vec<(String, Vec<u8>)>>>) {
+        let received = Arc::new(Mutex::new(vec![]));
+        let app = axum::Router::new().route(
+            "/v1/traces",
+            axum::routing::post({
+                let received = received.clone();
+                move |headers: axum::http::HeaderMap, body: axum::body::Bytes| {
+                    let received = received.clone();
+                    async move {
+                        let content_type = headers
+                            .get(axum::http::header::CONTENT_TYPE)
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or_default()
+                            .to_string();
+                        received.lock().unwrap().push((content_type, body.to_vec()));
+                        "{}"
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (format!("http://{addr}"), received)
+    }
+
+    /// Answers gRPC exports with `grpc_status` in the trailers, the way a
+    /// collector does once it has read the request.
+    async fn start_grpc_collector(grpc_status: &'static str) -> (String, Arc<Mutex<Vec<Vec<u8>>>>) {
+        let received = Arc::new(Mutex::new(vec![]));
+        let app = axum::Router::new().route(
+            GRPC_EXPORT_PATH,
+            axum::routing::post({
+                let received = received.clone();
+                move |body: axum::body::Bytes| {
+                    let received = received.clone();
+                    async move {
+                        received.lock().unwrap().push(body.to_vec());
+                        let mut trailers = axum::http::HeaderMap::new();
+                        trailers.insert("grpc-status", grpc_status.parse().unwrap());
+                        let frames = futures::stream::iter([
+                            Ok::<_, std::convert::Infallible>(http_body::Frame::data(
+                                bytes::Bytes::from(grpc_frame(&[])),
+                            )),
+                            Ok(http_body::Frame::trailers(trailers)),
+                        ]);
+                        (
+                            [(axum::http::header::CONTENT_TYPE, "application/grpc")],
+                            axum::body::Body::new(http_body_util::StreamBody::new(frames)),
+                        )
+                    }
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (format!("http://{addr}"), received)
+    }
+
+    #[tokio::test]
+    async fn test_export_over_grpc() {
+        let api = APIWrapper::from_env_vars(std::iter::empty::<(&str, &str)>()).unwrap();
+
+        // 14 is UNAVAILABLE.
+        for (grpc_status, succeeds) in [("0", true), ("14", false)] {
+            let (endpoint, received) = start_grpc_collector(grpc_status).await;
+            let options = OtlpOptions::from_config(&config(&[
+                ("OTEL_EXPORTER_OTLP_ENDPOINT", endpoint.as_str()),
+                ("OTEL_EXPORTER_OTLP_PROTOCOL", "grpc"),
+            ]))
+            .unwrap()
+            .unwrap();
+            let exporter = OtlpExporter::new(options, &api).unwrap();
+            let result = exporter.export(&[function_log()]).await;
+            assert_eq!(result.is_ok(), succeeds, "{:?}", result);
+
+            let received = received.lock().unwrap();
+            assert_eq!(received.len(), 1);
+            let body = &received[0];
+            // Uncompressed, then the length of the message.
+            assert_eq!(body[0], 0);
+            assert_eq!(
+                u32::from_be_bytes(body[1..5].try_into().unwrap()) as usize,
+                body.len() - 5
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_to_collector() {
+        let (endpoint, received) = start_collector().await;
+        let api = APIWrapper::from_env_vars(std::iter::empty::<(&str, &str)>()).unwrap();
+
+        for protocol in ["http/json", "http/protobuf"] {
+            let options = OtlpOptions::from_config(&config(&[
+                ("OTEL_EXPORTER_OTLP_ENDPOINT", endpoint.as_str()),
+                ("OTEL_EXPORTER_OTLP_PROTOCOL", protocol),
+            ]))
+            .unwrap()
+            .unwrap();
+            let exporter = OtlpExporter::new(options, &api).unwrap();
+            exporter.export(&[function_log()]).await.unwrap();
+        }
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+
+        let (content_type, body) = &received[0];
+        assert_eq!(content_type, "application/json");
+        let body: Value = serde_json::from_slice(body).unwrap();
+        let resource_spans = &body["resourceSpans"][0];
+        assert!(resource_spans["resource"]["attributes"]
+            .as_array()
+            .unwrap()
+            .contains(&json!({ "key": "service.name", "value": { "stringValue": "baml" } })));
+        let spans = resource_spans["scopeSpans"][0]["spans"].as_array().unwrap();
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1]["parentSpanId"], spans[0]["spanId"]);
+        assert_eq!(spans[1]["status"]["code"], 2);
+
+        let (content_type, body) = &received[1];
+        assert_eq!(content_type, "application/x-protobuf");
+        // Field 1 (resource_spans), length-delimited.
+        assert_eq!(body[0], 0x0a);
+    }
+}
@@ -0,0 +1,232 @@
+//! Writes every traced event to JSONL files, so LLM calls can be audited
+//! without the Boundary backend. `baml-cli traces` reads them back.
+//!
+//! Each line is the event sent to Boundary, redacted the same way, with the
+//! retry scope, timing and parse result of each LLM call added under
+//! `metadata[].attempt`. A new file is started once the current one reaches
+//! `BAML_TRACE_MAX_FILE_MB`, and the oldest files are deleted beyond
+//! `BAML_TRACE_MAX_FILES`.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+use super::{
+    api_wrapper::{
+        core_types::{LLMAttempt, LogSchema, MetadataType},
+        TraceFileConfig,
+    },
+    to_iso_string,
+};
+
+pub const TRACE_DIR_ENV_VAR: &str = "BAML_TRACE_DIR";
+const FILE_PREFIX: &str = "traces-";
+const FILE_EXTENSION: &str = "jsonl";
+const DEFAULT_MAX_FILE_MB: u64 = 50;
+
+/// The trace files in `dir`, oldest first.
+pub fn list_trace_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read trace directory {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == FILE_EXTENSION)
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(FILE_PREFIX))
+        })
+        .collect::<Vec<_>>();
+    // Names start with the time the file was created.
+    files.sort();
+    Ok(files)
+}
+
+struct CurrentFile {
+    file: File,
+    size: u64,
+}
+
+pub(super) struct TraceFileWriter {
+    dir: PathBuf,
+    max_file_bytes: u64,
+    // 0 keeps every file.
+    max_files: usize,
+    current: Mutex<Option<CurrentFile>>,
+}
+
+impl TraceFileWriter {
+    /// `None` if `BAML_TRACE_DIR` is unset.
+    pub fn from_config(config: &TraceFileConfig) -> Result<Option<Self>> {
+        let Some(dir) = &config.dir else {
+            return Ok(None);
+        };
+        std::fs::create_dir_all(dir).with_context(|| {
+            format!("Failed to create {TRACE_DIR_ENV_VAR} at {}", dir.display())
+        })?;
+        Ok(Some(Self {
+            dir: dir.clone(),
+            max_file_bytes: config.max_file_mb.unwrap_or(DEFAULT_MAX_FILE_MB).max(1) * 1024 * 1024,
+            max_files: config.max_files.unwrap_or(0),
+            current: Mutex::new(None),
+        }))
+    }
+
+    pub fn write(&self, logs: &[LogSchema]) -> Result<()> {
+        let mut current = self.current.lock().unwrap();
+        for log in logs {
+            let mut line = serde_json::to_string(&to_record(log)?)?;
+            line.push('\n');
+
+            if current.as_ref().map_or(true, |c| {
+                c.size > 0 && c.size + line.len() as u64 > self.max_file_bytes
+            }) {
+                *current = Some(self.rotate()?);
+            }
+            let Some(file) = current.as_mut() else {
+                unreachable!("a trace file was just opened");
+            };
+            file.file
+                .write_all(line.as_bytes())
+                .context("Failed to write trace file")?;
+            file.size += line.len() as u64;
+        }
+        Ok(())
+    }
+
+    fn rotate(&self) -> Result<CurrentFile> {
+        let name = format!(
+            "{FILE_PREFIX}{}-{}.{FILE_EXTENSION}",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S%3f"),
+            std::process::id()
+        );
+        let path = self.dir.join(name);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open trace file {}", path.display()))?;
+        let size = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        if self.max_files > 0 {
+            let files = list_trace_files(&self.dir)?;
+            for old in files
+                .iter()
+                .take(files.len().saturating_sub(self.max_files))
+            {
+                if let Err(e) = std::fs::remove_file(old) {
+                    log::warn!("Failed to delete old trace file {}: {}", old.display(), e);
+                }
+            }
+        }
+
+        Ok(CurrentFile { file, size })
+    }
+}
+
+fn to_record(log: &LogSchema) -> Result<Value> {
+    let mut record = serde_json::to_value(log)?;
+    let attempts = match &log.metadata {
+        Some(MetadataType::Single(event)) => vec![&event.attempt],
+        Some(MetadataType::Multi(events)) => events.iter().map(|e| &e.attempt).collect(),
+        None => vec![],
+    };
+    let events = match &mut record["metadata"] {
+        Value::Array(events) => events.iter_mut().collect::<Vec<_>>(),
+        event @ Value::Object(_) => vec![event],
+        _ => vec![],
+    };
+    for (event, attempt) in events.into_iter().zip(attempts) {
+        event["attempt"] = attempt_json(attempt);
+    }
+    Ok(record)
+}
+
+fn attempt_json(attempt: &LLMAttempt) -> Value {
+    json!({
+        "scope": attempt.scope,
+        "retry": attempt.retry,
+        "start_time": attempt.start_time.as_ref().map(to_iso_string),
+        "latency_ms": attempt.latency.map(|l| l.as_millis() as u64),
+        "parsed": attempt.parsed.as_ref().map(|p| p.is_ok()),
+        "parse_error": attempt.parsed.as_ref().and_then(|p| p.as_ref().err()),
+        "cancelled": attempt.cancelled,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracing::api_wrapper::core_types::{EventType, LogSchemaContext, IO};
+
+    fn log(event_id: &str, padding: usize) -> LogSchema {
+        LogSchema {
+            project_id: None,
+            event_type: EventType::FuncCode,
+            root_event_id: event_id.into(),
+            event_id: event_id.into(),
+            parent_event_id: None,
+            context: LogSchemaContext {
+                hostname: "localhost".into(),
+                process_id: "session".into(),
+                stage: None,
+                latency_ms: 10,
+                start_time: "2024-10-01T00:00:00Z".into(),
+                tags: [("padding".to_string(), "x".repeat(padding))].into(),
+                event_chain: vec![],
+            },
+            io: IO {
+                input: None,
+                output: None,
+            },
+            error: None,
+            metadata: None,
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("baml-traces-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_rotation() {
+        let dir = temp_dir();
+        let writer = TraceFileWriter::from_config(&TraceFileConfig {
+            dir: Some(dir.clone()),
+            max_file_mb: Some(1),
+            max_files: Some(2),
+        })
+        .unwrap()
+        .unwrap();
+
+        // Each event is a bit over half a file.
+        for i in 0..3 {
+            writer
+                .write(&[log(&format!("event-{i}"), 600 * 1024)])
+                .unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        let files = list_trace_files(&dir).unwrap();
+        assert_eq!(files.len(), 2);
+        let last = std::fs::read_to_string(&files[1]).unwrap();
+        assert_eq!(last.lines().count(), 1);
+        let record: Value = serde_json::from_str(last.lines().next().unwrap()).unwrap();
+        assert_eq!(record["event_id"], "event-2");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_disabled() {
+        assert!(TraceFileWriter::from_config(&TraceFileConfig::default())
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use futures::future::Either;
+
+/// Stops a function call or stream that is in flight.
+///
+/// Cancelling drops the pending request to the provider, skips the remaining
+/// retries and fallbacks, and finishes the call with a `Cancelled` error, so
+/// the call is still traced. Set it on a call with
+/// `RuntimeContextManager::with_cancellation`.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        if !self.inner.cancelled.swap(true, Ordering::SeqCst) {
+            for waker in self.inner.wakers.lock().unwrap().drain(..) {
+                waker.wake();
+            }
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once the token is cancelled.
+    pub fn cancelled(&self) -> WaitForCancellation<'_> {
+        WaitForCancellation { token: self }
+    }
+
+    /// Awaits `fut`, or drops it and returns `None` if the token is cancelled
+    /// first.
+    pub async fn race<T>(&self, fut: impl Future<Output = T>) -> Option<T> {
+        if self.is_cancelled() {
+            return None;
+        }
+        match futures::future::select(pin!(fut), self.cancelled()).await {
+            Either::Left((value, _)) => Some(value),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Cancels the token when the guard is dropped, e.g. when the future
+    /// holding it is aborted.
+    pub fn drop_guard(self) -> CancelOnDrop {
+        CancelOnDrop(self)
+    }
+}
+
+pub struct WaitForCancellation<'a> {
+    token: &'a CancellationToken,
+}
+
+impl Future for WaitForCancellation<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        {
+            let mut wakers = self.token.inner.wakers.lock().unwrap();
+            if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+        // The token may have been cancelled before the waker was registered.
+        match self.token.is_cancelled() {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        }
+    }
+}
+
+pub struct CancelOnDrop(CancellationToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_race() {
+        let token = CancellationToken::new();
+        assert_eq!(token.race(async { 1 }).await, Some(1));
+
+        let pending = token.race(futures::future::pending::<()>());
+        let cancel = async {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            token.cancel();
+        };
+        let (result, _) = futures::join!(pending, cancel);
+        assert_eq!(result, None);
+        assert!(token.is_cancelled());
+
+        // Once cancelled, nothing runs.
+        assert_eq!(token.race(async { 1 }).await, None);
+    }
+
+    #[test]
+    fn test_drop_guard() {
+        let token = CancellationToken::new();
+        drop(token.clone().drop_guard());
+        assert!(token.is_cancelled());
+    }
+}
@@ -7,7 +7,12 @@ use anyhow::{Context, Result};
 use baml_types::BamlValue;
 use std::fmt;
 
-use crate::{client_registry::ClientRegistry, type_builder::TypeBuilder, RuntimeContext, SpanCtx};
+use crate::{
+    client_registry::ClientRegistry,
+    internal::llm_client::{pricing::PriceTable, response_cache::CachePolicy},
+    type_builder::TypeBuilder,
+    CancellationToken, RuntimeContext, SpanCtx,
+};
 
 use super::runtime_context::BamlSrcReader;
 
@@ -20,6 +25,10 @@ pub struct RuntimeContextManager {
     context: Arc<Mutex<Vec<BamlContext>>>,
     env_vars: HashMap<String, String>,
     global_tags: Arc<Mutex<HashMap<String, BamlValue>>>,
+    response_cache: Arc<Mutex<CachePolicy>>,
+    // Loaded once from `BAML_PRICE_TABLE`; empty if it is invalid.
+    price_table: Arc<PriceTable>,
+    cancellation: CancellationToken,
 }
 
 impl fmt::Debug for RuntimeContextManager {
@@ -39,6 +48,9 @@ impl RuntimeContextManager {
             context: Arc::new(Mutex::new(self.context.lock().unwrap().clone())),
             env_vars: self.env_vars.clone(),
             global_tags: Arc::new(Mutex::new(self.global_tags.lock().unwrap().clone())),
+            response_cache: Arc::new(Mutex::new(self.response_cache.lock().unwrap().clone())),
+            price_table: self.price_table.clone(),
+            cancellation: self.cancellation.clone(),
         }
     }
 
@@ -49,8 +61,14 @@ impl RuntimeContextManager {
         Self {
             baml_src_reader: Arc::new(baml_src_reader),
             context: Default::default(),
+            price_table: Arc::new(PriceTable::from_env(&env_vars).unwrap_or_else(|e| {
+                log::warn!("Costs will not be computed: {:#}", e);
+                Default::default()
+            })),
             env_vars,
             global_tags: Default::default(),
+            response_cache: Default::default(),
+            cancellation: Default::default(),
         }
     }
 
@@ -63,6 +81,21 @@ impl RuntimeContextManager {
         }
     }
 
+    /// Sets how the calls made with this context use the response cache,
+    /// until it is set again.
+    pub fn set_response_cache(&self, policy: CachePolicy) {
+        *self.response_cache.lock().unwrap() = policy;
+    }
+
+    /// The same context, for a call that `token` can cancel. Spans and tags
+    /// are still shared with this context.
+    pub fn with_cancellation(&self, token: CancellationToken) -> Self {
+        Self {
+            cancellation: token,
+            ..self.clone()
+        }
+    }
+
     fn clone_last_tags(&self) -> HashMap<String, BamlValue> {
         self.context
             .lock()
@@ -128,7 +161,6 @@ impl RuntimeContextManager {
         };
 
         let (cls, enm) = tb.map(|tb| tb.to_overrides()).unwrap_or_default();
-
         let mut ctx = RuntimeContext {
             baml_src: self.baml_src_reader.clone(),
             env: self.env_vars.clone(),
@@ -136,6 +168,13 @@ impl RuntimeContextManager {
             client_overrides: Default::default(),
             class_override: cls,
             enum_overrides: enm,
+            output_tool: None,
+            output_schema: None,
+            response_cache: self.response_cache.lock().unwrap().clone(),
+            price_table: self.price_table.clone(),
+            cancellation: self.cancellation.clone(),
+            rate_limiters: Default::default(),
+            circuit_breakers: Default::default(),
         };
 
         let client_overrides = match cb {
@@ -168,6 +207,13 @@ impl RuntimeContextManager {
             client_overrides: Default::default(),
             class_override: Default::default(),
             enum_overrides: Default::default(),
+            output_tool: None,
+            output_schema: None,
+            response_cache: Default::default(),
+            price_table: self.price_table.clone(),
+            cancellation: Default::default(),
+            rate_limiters: Default::default(),
+            circuit_breakers: Default::default(),
         }
     }
 
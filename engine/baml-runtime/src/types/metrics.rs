@@ -0,0 +1,596 @@
+//! Counters and latency histograms about the function calls made through a
+//! `BamlRuntime`, labelled by function and client.
+//!
+//! Unlike `TraceStats`, these are never reset, so that they can be scraped
+//! as Prometheus counters (see `baml serve`'s `/metrics`).
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    internal::llm_client::{
+        orchestrator::{ExecutionScope, OrchestrationScope},
+        response_cache::CacheStatus,
+        ErrorCode, LLMResponse,
+    },
+    FunctionResult,
+};
+
+/// Upper bounds of the latency buckets, in seconds.
+const LATENCY_BUCKETS: [f64; 12] = [
+    0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0,
+];
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Histogram {
+    /// Upper bounds of the buckets, in seconds.
+    pub bounds: Vec<f64>,
+    /// Cumulative: `counts[i]` observations were less than or equal to
+    /// `bounds[i]`.
+    pub counts: Vec<u64>,
+    pub sum: f64,
+    pub count: u64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            bounds: LATENCY_BUCKETS.to_vec(),
+            counts: vec![0; LATENCY_BUCKETS.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+}
+
+impl Histogram {
+    fn observe(&mut self, seconds: f64) {
+        for (bound, count) in self.bounds.iter().zip(self.counts.iter_mut()) {
+            if seconds <= *bound {
+                *count += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ClientMetrics {
+    /// Requests sent to the provider, whether or not they succeeded.
+    pub requests: u64,
+    /// Responses served by the response cache. They are not in `requests`.
+    pub cache_hits: u64,
+    /// Failed requests, by `ErrorCode`.
+    pub errors: BTreeMap<String, u64>,
+    pub prompt_tokens: u64,
+    pub output_tokens: u64,
+    pub latency_seconds: Histogram,
+    /// Only observed for streamed responses.
+    pub time_to_first_token_seconds: Histogram,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FunctionMetrics {
+    pub calls: u64,
+    /// Calls that didn't return a parsed value.
+    pub failures: u64,
+    /// LLM responses that couldn't be parsed into the function's output type.
+    pub parse_failures: u64,
+    /// Attempts made by a retry policy after the first.
+    pub retries: u64,
+    /// Attempts that moved on to another client of a fallback strategy.
+    pub fallbacks: u64,
+    pub clients: BTreeMap<String, ClientMetrics>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MetricsSnapshot {
+    pub functions: BTreeMap<String, FunctionMetrics>,
+}
+
+#[derive(Clone, Default)]
+pub struct Metrics {
+    inner: Arc<Mutex<MetricsSnapshot>>,
+}
+
+impl Metrics {
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.inner.lock().unwrap().clone()
+    }
+
+    pub(crate) fn record(&self, function_name: &str, result: &Result<FunctionResult>) {
+        let mut inner = self.inner.lock().unwrap();
+        let function = inner
+            .functions
+            .entry(function_name.to_string())
+            .or_default();
+        function.calls += 1;
+
+        let Ok(result) = result else {
+            function.failures += 1;
+            return;
+        };
+        if !matches!(result.parsed(), Some(Ok(_))) {
+            function.failures += 1;
+        }
+
+        for (scope, response, parsed) in result.event_chain() {
+            if matches!(parsed, Some(Err(_))) {
+                function.parse_failures += 1;
+            }
+
+            // Skipped nodes, e.g. with an open circuit, weren't attempted and
+            // sent nothing to the provider.
+            if matches!(
+                response,
+                LLMResponse::UserFailure(_) | LLMResponse::InternalFailure(_)
+            ) {
+                continue;
+            }
+            match attempt_kind(scope) {
+                AttemptKind::Retry => function.retries += 1,
+                AttemptKind::Fallback => function.fallbacks += 1,
+                AttemptKind::First => {}
+            }
+
+            let client = function
+                .clients
+                .entry(client_name(scope, response))
+                .or_default();
+            match response {
+                LLMResponse::Success(s) if s.metadata.cache == Some(CacheStatus::Hit) => {
+                    client.cache_hits += 1;
+                }
+                LLMResponse::Success(s) => {
+                    client.requests += 1;
+                    client.prompt_tokens += s.metadata.prompt_tokens.unwrap_or(0);
+                    client.output_tokens += s.metadata.output_tokens.unwrap_or(0);
+                    client.latency_seconds.observe(s.latency.as_secs_f64());
+                    if let Some(ttft) = s.metadata.time_to_first_token_ms {
+                        client
+                            .time_to_first_token_seconds
+                            .observe(ttft as f64 / 1000.0);
+                    }
+                }
+                LLMResponse::LLMFailure(e) => {
+                    client.requests += 1;
+                    *client.errors.entry(error_label(&e.code)).or_default() += 1;
+                    client.latency_seconds.observe(e.latency.as_secs_f64());
+                }
+                // Skipped above.
+                LLMResponse::UserFailure(_) | LLMResponse::InternalFailure(_) => {}
+            }
+        }
+    }
+}
+
+enum AttemptKind {
+    First,
+    Retry,
+    Fallback,
+}
+
+/// Decided by the innermost scope that isn't at its first attempt, so a
+/// retry of the second client of a fallback is a retry, and moving on to a
+/// fallback client during a retry of the whole strategy is a fallback.
+fn attempt_kind(scope: &OrchestrationScope) -> AttemptKind {
+    scope
+        .scope
+        .iter()
+        .rev()
+        .find_map(|scope| match scope {
+            ExecutionScope::Retry(_, count, _) if *count > 0 => Some(AttemptKind::Retry),
+            ExecutionScope::Fallback(_, index) if *index > 0 => Some(AttemptKind::Fallback),
+            _ => None,
+        })
+        .unwrap_or(AttemptKind::First)
+}
+
+fn client_name(scope: &OrchestrationScope, response: &LLMResponse) -> String {
+    match response {
+        LLMResponse::Success(s) => s.client.clone(),
+        LLMResponse::LLMFailure(e) => e.client.clone(),
+        LLMResponse::UserFailure(_) | LLMResponse::InternalFailure(_) => scope
+            .direct_client_name()
+            .cloned()
+            .unwrap_or_else(|| scope.name()),
+    }
+}
+
+fn error_label(code: &ErrorCode) -> String {
+    match code {
+        ErrorCode::InvalidAuthentication => "InvalidAuthentication".into(),
+        ErrorCode::NotSupported => "NotSupported".into(),
+        ErrorCode::RateLimited => "RateLimited".into(),
+        ErrorCode::ServerError => "ServerError".into(),
+        ErrorCode::ServiceUnavailable => "ServiceUnavailable".into(),
+        ErrorCode::Timeout => "Timeout".into(),
+        ErrorCode::Cancelled => "Cancelled".into(),
+        ErrorCode::UnsupportedResponse(code) => format!("UnsupportedResponse({})", code),
+        ErrorCode::Other(code) => format!("Other({})", code),
+    }
+}
+
+fn labels(pairs: &[(&str, &str)]) -> String {
+    let pairs = pairs
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{name}=\"{value}\"")
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", pairs.join(","))
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} {kind}\n"));
+}
+
+fn write_counter<'a>(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    samples: impl Iterator<Item = (Vec<(&'a str, &'a str)>, u64)>,
+) {
+    write_header(out, name, "counter", help);
+    for (pairs, value) in samples {
+        out.push_str(&format!("{name}{} {value}\n", labels(&pairs)));
+    }
+}
+
+fn write_histogram<'a>(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    samples: impl Iterator<Item = (Vec<(&'a str, &'a str)>, &'a Histogram)>,
+) {
+    write_header(out, name, "histogram", help);
+    for (pairs, histogram) in samples {
+        let bounds = histogram.bounds.iter().map(|bound| bound.to_string());
+        let counts = histogram.counts.iter().chain([&histogram.count]);
+        for (le, count) in bounds.chain(["+Inf".to_string()]).zip(counts) {
+            let mut pairs = pairs.clone();
+            pairs.push(("le", &le));
+            out.push_str(&format!("{name}_bucket{} {count}\n", labels(&pairs)));
+        }
+        let pairs = labels(&pairs);
+        out.push_str(&format!("{name}_sum{pairs} {}\n", histogram.sum));
+        out.push_str(&format!("{name}_count{pairs} {}\n", histogram.count));
+    }
+}
+
+impl MetricsSnapshot {
+    /// Renders the metrics in the Prometheus text exposition format.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let functions = || {
+            self.functions
+                .iter()
+                .map(|(name, f)| (vec![("function", name.as_str())], f))
+        };
+        let clients = || {
+            self.functions.iter().flat_map(|(function, f)| {
+                f.clients.iter().map(move |(client, c)| {
+                    (
+                        vec![("function", function.as_str()), ("client", client.as_str())],
+                        c,
+                    )
+                })
+            })
+        };
+
+        write_counter(
+            &mut out,
+            "baml_function_calls_total",
+            "BAML function calls.",
+            functions().map(|(l, f)| (l, f.calls)),
+        );
+        write_counter(
+            &mut out,
+            "baml_function_failures_total",
+            "BAML function calls that didn't return a parsed value.",
+            functions().map(|(l, f)| (l, f.failures)),
+        );
+        write_counter(
+            &mut out,
+            "baml_parse_failures_total",
+            "LLM responses that couldn't be parsed into the output type.",
+            functions().map(|(l, f)| (l, f.parse_failures)),
+        );
+        write_counter(
+            &mut out,
+            "baml_retries_total",
+            "Attempts made by a retry policy after the first.",
+            functions().map(|(l, f)| (l, f.retries)),
+        );
+        write_counter(
+            &mut out,
+            "baml_fallbacks_total",
+            "Attempts that moved on to another client of a fallback strategy.",
+            functions().map(|(l, f)| (l, f.fallbacks)),
+        );
+        write_counter(
+            &mut out,
+            "baml_llm_requests_total",
+            "Requests sent to LLM providers.",
+            clients().map(|(l, c)| (l, c.requests)),
+        );
+        write_counter(
+            &mut out,
+            "baml_llm_cache_hits_total",
+            "Responses served by the response cache.",
+            clients().map(|(l, c)| (l, c.cache_hits)),
+        );
+        write_counter(
+            &mut out,
+            "baml_llm_errors_total",
+            "Failed requests to LLM providers, by error code.",
+            clients().flat_map(|(l, c)| {
+                c.errors.iter().map(move |(code, n)| {
+                    let mut l = l.clone();
+                    l.push(("code", code.as_str()));
+                    (l, *n)
+                })
+            }),
+        );
+        write_counter(
+            &mut out,
+            "baml_llm_tokens_total",
+            "Tokens reported by LLM providers.",
+            clients().flat_map(|(l, c)| {
+                [("prompt", c.prompt_tokens), ("output", c.output_tokens)]
+                    .into_iter()
+                    .map(move |(kind, n)| {
+                        let mut l = l.clone();
+                        l.push(("type", kind));
+                        (l, n)
+                    })
+            }),
+        );
+        write_histogram(
+            &mut out,
+            "baml_llm_latency_seconds",
+            "Latency of requests to LLM providers.",
+            clients().map(|(l, c)| (l, &c.latency_seconds)),
+        );
+        write_histogram(
+            &mut out,
+            "baml_llm_time_to_first_token_seconds",
+            "Time until the first chunk of a streamed LLM response.",
+            clients().map(|(l, c)| (l, &c.time_to_first_token_seconds)),
+        );
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client_registry::ClientProperty,
+        internal::llm_client::{
+            strategy::{fallback::FallbackStrategy, roundrobin::RoundRobinStrategy},
+            LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse,
+        },
+        RuntimeContextManager,
+    };
+    use baml_types::BamlValue;
+    use internal_baml_jinja::RenderedPrompt;
+    use web_time::{Duration, SystemTime};
+
+    fn strategy<T>(provider: &str, clients: &[&str]) -> Arc<T>
+    where
+        T: for<'a> TryFrom<(&'a ClientProperty, &'a crate::RuntimeContext), Error = anyhow::Error>,
+    {
+        let client = ClientProperty {
+            name: "Resilient".into(),
+            provider: provider.into(),
+            retry_policy: None,
+            options: [(
+                "strategy".to_string(),
+                BamlValue::List(
+                    clients
+                        .iter()
+                        .map(|c| BamlValue::String(c.to_string()))
+                        .collect(),
+                ),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let ctx = RuntimeContextManager::new_from_env_vars(Default::default(), None)
+            .create_ctx(None, None)
+            .unwrap();
+        Arc::new(T::try_from((&client, &ctx)).unwrap())
+    }
+
+    fn retry(count: usize) -> ExecutionScope {
+        ExecutionScope::Retry("Exponential".into(), count, Duration::ZERO)
+    }
+
+    fn scope(outer: Vec<ExecutionScope>, client: &str) -> OrchestrationScope {
+        OrchestrationScope::from(
+            outer
+                .into_iter()
+                .chain([ExecutionScope::Direct(client.into())])
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn failure(client: &str, code: ErrorCode) -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: client.into(),
+            model: None,
+            prompt: RenderedPrompt::Completion("hi".into()),
+            request_options: Default::default(),
+            start_time: SystemTime::now(),
+            latency: Duration::from_millis(200),
+            message: "failed".into(),
+            code,
+            retry_after: None,
+            rate_limit_wait_ms: None,
+        })
+    }
+
+    fn success(client: &str) -> LLMResponse {
+        LLMResponse::Success(LLMCompleteResponse {
+            client: client.into(),
+            model: "gpt-4o".into(),
+            prompt: RenderedPrompt::Completion("hi".into()),
+            request_options: Default::default(),
+            content: "not json".into(),
+            start_time: SystemTime::now(),
+            latency: Duration::from_millis(800),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: Some("stop".into()),
+                prompt_tokens: Some(120),
+                output_tokens: Some(30),
+                total_tokens: Some(150),
+                cache: None,
+                cost_usd: None,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: Some(300),
+            },
+        })
+    }
+
+    #[test]
+    fn records_attempts() {
+        let metrics = Metrics::default();
+        let fallback = strategy::<FallbackStrategy>("fallback", &["GPT4o", "Claude"]);
+        let at = |index| ExecutionScope::Fallback(fallback.clone(), index);
+        let result = FunctionResult::new_chain(vec![
+            (
+                scope(vec![at(0), retry(0)], "GPT4o"),
+                failure("GPT4o", ErrorCode::RateLimited),
+                None,
+            ),
+            (
+                scope(vec![at(0), retry(1)], "GPT4o"),
+                failure("GPT4o", ErrorCode::ServerError),
+                None,
+            ),
+            (
+                scope(vec![at(1), retry(0)], "Claude"),
+                success("Claude"),
+                Some(Err(anyhow::anyhow!("not json"))),
+            ),
+        ]);
+        metrics.record("ExtractResume", &result);
+        metrics.record("ExtractResume", &Err(anyhow::anyhow!("invalid args")));
+
+        let snapshot = metrics.snapshot();
+        let function = &snapshot.functions["ExtractResume"];
+        assert_eq!(function.calls, 2);
+        assert_eq!(function.failures, 2);
+        assert_eq!(function.parse_failures, 1);
+        assert_eq!(function.retries, 1);
+        assert_eq!(function.fallbacks, 1);
+
+        let gpt = &function.clients["GPT4o"];
+        assert_eq!(gpt.requests, 2);
+        assert_eq!(gpt.errors["RateLimited"], 1);
+        assert_eq!(gpt.errors["ServerError"], 1);
+        assert_eq!(gpt.latency_seconds.count, 2);
+
+        let claude = &function.clients["Claude"];
+        assert_eq!(claude.prompt_tokens, 120);
+        assert_eq!(claude.output_tokens, 30);
+        assert_eq!(claude.time_to_first_token_seconds.counts[3], 1);
+
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("baml_function_calls_total{function=\"ExtractResume\"} 2\n"));
+        assert!(text.contains(
+            "baml_llm_errors_total{function=\"ExtractResume\",client=\"GPT4o\",code=\"RateLimited\"} 1\n"
+        ));
+        assert!(text.contains(
+            "baml_llm_latency_seconds_bucket{function=\"ExtractResume\",client=\"Claude\",le=\"1\"} 1\n"
+        ));
+        assert!(text.contains(
+            "baml_llm_latency_seconds_count{function=\"ExtractResume\",client=\"GPT4o\"} 2\n"
+        ));
+    }
+
+    #[test]
+    fn round_robin_retries_are_retries() {
+        let metrics = Metrics::default();
+        let round_robin = strategy::<RoundRobinStrategy>("round-robin", &["GPT4o", "Claude"]);
+        let at = |index| ExecutionScope::RoundRobin(round_robin.clone(), index);
+        let result = FunctionResult::new_chain(vec![
+            (
+                scope(vec![retry(0), at(0)], "GPT4o"),
+                failure("GPT4o", ErrorCode::ServerError),
+                None,
+            ),
+            (
+                scope(vec![retry(1), at(1)], "Claude"),
+                success("Claude"),
+                None,
+            ),
+        ]);
+        metrics.record("ExtractResume", &result);
+
+        let function = &metrics.snapshot().functions["ExtractResume"];
+        assert_eq!(function.retries, 1);
+        assert_eq!(function.fallbacks, 0);
+    }
+
+    #[test]
+    fn repeated_fallback_clients_are_fallbacks() {
+        let metrics = Metrics::default();
+        let fallback = strategy::<FallbackStrategy>("fallback", &["GPT4o", "GPT4o"]);
+        let at = |index| ExecutionScope::Fallback(fallback.clone(), index);
+        let result = FunctionResult::new_chain(vec![
+            (
+                scope(vec![at(0)], "GPT4o"),
+                failure("GPT4o", ErrorCode::ServerError),
+                None,
+            ),
+            (scope(vec![at(1)], "GPT4o"), success("GPT4o"), None),
+        ]);
+        metrics.record("ExtractResume", &result);
+
+        let function = &metrics.snapshot().functions["ExtractResume"];
+        assert_eq!(function.retries, 0);
+        assert_eq!(function.fallbacks, 1);
+        assert_eq!(function.clients["GPT4o"].requests, 2);
+    }
+
+    #[test]
+    fn skipped_clients_are_not_attempts() {
+        let metrics = Metrics::default();
+        let fallback = strategy::<FallbackStrategy>("fallback", &["GPT4o", "Claude", "Gemini"]);
+        let at = |index| ExecutionScope::Fallback(fallback.clone(), index);
+        let result = FunctionResult::new_chain(vec![
+            (
+                scope(vec![at(0)], "GPT4o"),
+                failure("GPT4o", ErrorCode::ServerError),
+                None,
+            ),
+            (
+                scope(vec![at(1)], "Claude"),
+                LLMResponse::InternalFailure(
+                    "Skipped client Claude: its circuit in Resilient is open".into(),
+                ),
+                None,
+            ),
+            (scope(vec![at(2)], "Gemini"), success("Gemini"), None),
+        ]);
+        metrics.record("ExtractResume", &result);
+
+        let function = &metrics.snapshot().functions["ExtractResume"];
+        assert_eq!(function.retries, 0);
+        assert_eq!(function.fallbacks, 1);
+        assert!(!function.clients.contains_key("Claude"));
+    }
+}
@@ -1,16 +1,24 @@
+mod cancellation;
 mod context_manager;
 mod expression_helper;
+mod metrics;
 pub mod on_log_event;
 mod response;
 pub(crate) mod runtime_context;
 mod stream;
 mod trace_stats;
+mod usage;
 
+pub use cancellation::{CancelOnDrop, CancellationToken};
 pub use context_manager::RuntimeContextManager;
-pub use response::{FunctionResult, TestFailReason, TestResponse, TestStatus};
+pub use metrics::{ClientMetrics, FunctionMetrics, Histogram, Metrics, MetricsSnapshot};
+pub use response::{
+    FunctionResult, TestConstraintResult, TestFailReason, TestResponse, TestStatus,
+};
 pub use runtime_context::{RuntimeContext, SpanCtx};
 pub use stream::FunctionResultStream;
 pub use trace_stats::{InnerTraceStats, TraceStats};
+pub use usage::Usage;
 
 #[derive(Debug, Clone, Copy)]
 pub struct RenderCurlSettings {
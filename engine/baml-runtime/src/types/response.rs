@@ -1,9 +1,12 @@
 pub use crate::internal::llm_client::LLMResponse;
-use crate::{errors::ExposedError, internal::llm_client::orchestrator::OrchestrationScope};
+use crate::{
+    errors::ExposedError, internal::llm_client::orchestrator::OrchestrationScope, types::Usage,
+};
 use anyhow::Result;
 use colored::*;
 
-use baml_types::BamlValue;
+use baml_types::{BamlValue, Constraint, ConstraintLevel};
+use internal_baml_jinja::evaluate_predicate;
 use jsonish::BamlValueWithFlags;
 
 pub struct FunctionResult {
@@ -91,10 +94,25 @@ impl FunctionResult {
         &self.event_chain.last().unwrap().0
     }
 
+    /// Usage of every attempt in the chain, not just the last one.
+    pub fn usage(&self) -> Usage {
+        self.event_chain
+            .iter()
+            .map(|(_, response, _)| response)
+            .sum()
+    }
+
     pub fn parsed(&self) -> &Option<Result<BamlValueWithFlags>> {
         &self.event_chain.last().unwrap().2
     }
 
+    /// Which values of a partial result are still being written, shaped like
+    /// the result itself (see [`BamlValueWithFlags::completion_states`]).
+    /// Everything in a final result is complete.
+    pub fn completion_states(&self) -> Result<serde_json::Value> {
+        self.parsed_content().map(|v| v.completion_states())
+    }
+
     pub fn parsed_content(&self) -> Result<&BamlValueWithFlags> {
         self.parsed()
             .as_ref()
@@ -141,6 +159,49 @@ impl FunctionResult {
 pub struct TestResponse {
     pub function_response: FunctionResult,
     pub function_span: Option<uuid::Uuid>,
+    /// The outcome of each `@@assert` and `@@check` in the test block, in order.
+    pub constraints_result: Vec<TestConstraintResult>,
+}
+
+/// The outcome of one `@@assert` or `@@check` in a test block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestConstraintResult {
+    pub constraint: Constraint,
+    pub passed: bool,
+    /// Set when the expression could not be evaluated, which counts as a failure.
+    pub error: Option<String>,
+}
+
+impl TestConstraintResult {
+    /// Evaluates the test's constraints against the parsed result, available as `this`.
+    ///
+    /// Nothing is evaluated if the response could not be parsed.
+    pub(crate) fn evaluate_all(
+        constraints: &[Constraint],
+        function_response: &FunctionResult,
+    ) -> Vec<TestConstraintResult> {
+        let Some(Ok(parsed)) = function_response.parsed() else {
+            return vec![];
+        };
+        let this: BamlValue = parsed.into();
+        constraints
+            .iter()
+            .map(
+                |constraint| match evaluate_predicate(&this, &constraint.expression) {
+                    Ok(passed) => TestConstraintResult {
+                        constraint: constraint.clone(),
+                        passed,
+                        error: None,
+                    },
+                    Err(e) => TestConstraintResult {
+                        constraint: constraint.clone(),
+                        passed: false,
+                        error: Some(format!("{e:#}")),
+                    },
+                },
+            )
+            .collect()
+    }
 }
 
 impl std::fmt::Display for TestResponse {
@@ -152,6 +213,8 @@ impl std::fmt::Display for TestResponse {
 #[derive(Debug, PartialEq, Eq)]
 pub enum TestStatus<'a> {
     Pass,
+    /// The response parsed, but some `@check`s failed and need a human to look.
+    NeedsHumanEval(Vec<String>),
     Fail(TestFailReason<'a>),
 }
 
@@ -159,6 +222,10 @@ impl From<TestStatus<'_>> for BamlValue {
     fn from(status: TestStatus) -> Self {
         match status {
             TestStatus::Pass => BamlValue::String("pass".to_string()),
+            TestStatus::NeedsHumanEval(checks) => BamlValue::String(format!(
+                "checks need human evaluation: {}",
+                checks.join(", ")
+            )),
             TestStatus::Fail(r) => BamlValue::String(format!("failed! {:?}", r)),
         }
     }
@@ -169,6 +236,7 @@ pub enum TestFailReason<'a> {
     TestUnspecified(&'a anyhow::Error),
     TestLLMFailure(&'a LLMResponse),
     TestParseFailure(&'a anyhow::Error),
+    TestAssertionFailure(&'a TestConstraintResult),
 }
 
 impl PartialEq for TestFailReason<'_> {
@@ -179,6 +247,7 @@ impl PartialEq for TestFailReason<'_> {
             (Self::TestParseFailure(a), Self::TestParseFailure(b)) => {
                 a.to_string() == b.to_string()
             }
+            (Self::TestAssertionFailure(a), Self::TestAssertionFailure(b)) => a == b,
             _ => false,
         }
     }
@@ -190,8 +259,27 @@ impl TestResponse {
     pub fn status(&self) -> TestStatus {
         let func_res = &self.function_response;
         if let Some(parsed) = func_res.parsed() {
-            if parsed.is_ok() {
-                TestStatus::Pass
+            if let Ok(value) = parsed {
+                let failed = |level: ConstraintLevel| {
+                    self.constraints_result
+                        .iter()
+                        .filter(move |r| r.constraint.level == level && !r.passed)
+                };
+                if let Some(result) = failed(ConstraintLevel::Assert).next() {
+                    return TestStatus::Fail(TestFailReason::TestAssertionFailure(result));
+                }
+
+                let failed_checks = value
+                    .failed_checks()
+                    .into_iter()
+                    .map(|c| c.name)
+                    .chain(failed(ConstraintLevel::Check).map(|r| r.constraint.name()))
+                    .collect::<Vec<_>>();
+                if failed_checks.is_empty() {
+                    TestStatus::Pass
+                } else {
+                    TestStatus::NeedsHumanEval(failed_checks)
+                }
             } else {
                 TestStatus::Fail(TestFailReason::TestParseFailure(
                     parsed.as_ref().unwrap_err(),
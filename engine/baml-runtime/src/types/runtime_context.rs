@@ -6,7 +6,14 @@ use serde;
 use serde_json;
 use std::{collections::HashMap, sync::Arc};
 
-use crate::internal::llm_client::llm_provider::LLMProvider;
+use crate::internal::{
+    llm_client::{
+        llm_provider::LLMProvider, pricing::PriceTable, rate_limits::RateLimiters,
+        response_cache::CachePolicy, strategy::circuit_breaker::CircuitBreakers,
+    },
+    prompt_renderer::{OutputSchema, OutputTool},
+};
+use crate::CancellationToken;
 
 #[derive(Debug)]
 pub struct SpanCtx {
@@ -58,6 +65,21 @@ pub struct RuntimeContext {
     pub client_overrides: Option<(Option<String>, HashMap<String, Arc<LLMProvider>>)>,
     pub class_override: IndexMap<String, RuntimeClassOverride>,
     pub enum_overrides: IndexMap<String, RuntimeEnumOverride>,
+    // Set for functions with `output_mode tool`: clients send this as a
+    // forced tool call and return its arguments as the response content.
+    pub output_tool: Option<OutputTool>,
+    // Sent as a response schema by clients with `structured_outputs` enabled.
+    // Read it through `RuntimeContext::output_schema`.
+    pub output_schema: Option<Result<OutputSchema>>,
+    // Set with `RuntimeContextManager::set_response_cache`.
+    pub response_cache: CachePolicy,
+    // Prices the calls in this context, from `BAML_PRICE_TABLE`.
+    pub price_table: Arc<PriceTable>,
+    // Set with `RuntimeContextManager::with_cancellation`.
+    pub cancellation: CancellationToken,
+    // Those of the runtime making the call, so they outlive the context.
+    pub rate_limiters: RateLimiters,
+    pub circuit_breakers: CircuitBreakers,
 }
 
 impl RuntimeContext {
@@ -77,4 +99,16 @@ impl RuntimeContext {
             )
         })
     }
+
+    /// The schema for clients with `structured_outputs` enabled, or the
+    /// reason the function's return type can't be expressed as one.
+    pub fn output_schema(&self) -> Result<Option<&OutputSchema>> {
+        match &self.output_schema {
+            Some(Ok(schema)) => Ok(Some(schema)),
+            Some(Err(e)) => Err(anyhow::anyhow!(
+                "structured_outputs can't describe the return type: {e:#}"
+            )),
+            None => Ok(None),
+        }
+    }
 }
@@ -7,12 +7,16 @@ use std::sync::Arc;
 use crate::{
     client_registry::ClientRegistry,
     internal::{
-        llm_client::orchestrator::{orchestrate_stream, OrchestratorNodeIterator},
+        llm_client::{
+            orchestrator::{orchestrate_stream, OrchestratorNodeIterator},
+            rate_limits::RateLimiters,
+            strategy::circuit_breaker::CircuitBreakers,
+        },
         prompt_renderer::PromptRenderer,
     },
     tracing::BamlTracer,
     type_builder::TypeBuilder,
-    FunctionResult, RuntimeContextManager,
+    FunctionResult, Metrics, RuntimeContext, RuntimeContextManager,
 };
 
 /// Wrapper that holds a stream of responses from a BAML function call.
@@ -27,6 +31,9 @@ pub struct FunctionResultStream {
     pub(crate) ir: Arc<IntermediateRepr>,
     pub(crate) orchestrator: OrchestratorNodeIterator,
     pub(crate) tracer: Arc<BamlTracer>,
+    pub(crate) metrics: Metrics,
+    pub(crate) rate_limiters: RateLimiters,
+    pub(crate) circuit_breakers: CircuitBreakers,
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) tokio_runtime: Arc<tokio::runtime::Runtime>,
 }
@@ -91,7 +98,15 @@ impl FunctionResultStream {
             .tracer
             .start_span(&self.function_name, ctx, &local_params);
 
-        let rctx = ctx.create_ctx(tb, cb);
+        let rctx = ctx.create_ctx(tb, cb).and_then(|rctx| {
+            Ok(RuntimeContext {
+                output_tool: self.renderer.output_tool()?,
+                output_schema: Some(self.renderer.output_schema()),
+                rate_limiters: self.rate_limiters.clone(),
+                circuit_breakers: self.circuit_breakers.clone(),
+                ..rctx
+            })
+        });
         let res = match rctx {
             Ok(rctx) => {
                 let (history, _) = orchestrate_stream(
@@ -110,6 +125,7 @@ impl FunctionResultStream {
             }
             Err(e) => Err(e),
         };
+        self.metrics.record(&self.function_name, &res);
 
         let mut target_id = None;
         if let Some(span) = span {
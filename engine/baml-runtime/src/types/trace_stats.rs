@@ -8,6 +8,8 @@
 ///
 use std::sync::{Arc, Mutex};
 
+use super::Usage;
+
 #[derive(Clone, Default)]
 pub struct InnerTraceStats {
     // Happen on the main runtime thread.
@@ -20,6 +22,9 @@ pub struct InnerTraceStats {
     pub done: u32,
     // All errors are counted here.
     pub failed: u32,
+
+    // Summed over the function calls that finished.
+    pub usage: Usage,
 }
 
 #[derive(Clone, Default)]
@@ -39,6 +44,11 @@ impl TraceStats {
         SpanGuard::new(self.clone())
     }
 
+    pub(crate) fn add_usage(&self, usage: &Usage) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.usage += usage;
+    }
+
     // Add methods to access and modify the inner fields if needed
     fn inc_started(&self) {
         let mut inner = self.inner.lock().unwrap();
@@ -0,0 +1,180 @@
+use serde::Serialize;
+
+use crate::internal::llm_client::{response_cache::CacheStatus, LLMResponse};
+
+/// Tokens and cost of the LLM calls behind a function call, including
+/// retries and fallbacks that failed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Usage {
+    /// Requests sent to a provider, whether or not they succeeded.
+    pub llm_calls: u64,
+    /// Responses served by the response cache. They cost nothing.
+    pub cached_calls: u64,
+    pub prompt_tokens: u64,
+    pub output_tokens: u64,
+    pub total_tokens: u64,
+    /// Sum of the calls that have a price in `BAML_PRICE_TABLE`.
+    pub cost_usd: f64,
+    /// Successful calls missing from `cost_usd`, because their model has no
+    /// price or the provider didn't report tokens.
+    pub unpriced_calls: u64,
+}
+
+impl Usage {
+    /// Failures that happened before a request was sent, e.g. a prompt that
+    /// failed to render, are not counted.
+    pub fn of_response(response: &LLMResponse) -> Self {
+        match response {
+            LLMResponse::Success(response) => {
+                let metadata = &response.metadata;
+                if metadata.cache == Some(CacheStatus::Hit) {
+                    return Self {
+                        cached_calls: 1,
+                        ..Default::default()
+                    };
+                }
+                let prompt_tokens = metadata.prompt_tokens.unwrap_or(0);
+                let output_tokens = metadata.output_tokens.unwrap_or(0);
+                Self {
+                    llm_calls: 1,
+                    prompt_tokens,
+                    output_tokens,
+                    total_tokens: metadata
+                        .total_tokens
+                        .unwrap_or(prompt_tokens + output_tokens),
+                    cost_usd: metadata.cost_usd.unwrap_or(0.0),
+                    unpriced_calls: metadata.cost_usd.is_none() as u64,
+                    ..Default::default()
+                }
+            }
+            LLMResponse::LLMFailure(_) => Self {
+                llm_calls: 1,
+                ..Default::default()
+            },
+            LLMResponse::UserFailure(_) | LLMResponse::InternalFailure(_) => Self::default(),
+        }
+    }
+}
+
+impl std::ops::AddAssign<&Usage> for Usage {
+    fn add_assign(&mut self, other: &Usage) {
+        self.llm_calls += other.llm_calls;
+        self.cached_calls += other.cached_calls;
+        self.prompt_tokens += other.prompt_tokens;
+        self.output_tokens += other.output_tokens;
+        self.total_tokens += other.total_tokens;
+        self.cost_usd += other.cost_usd;
+        self.unpriced_calls += other.unpriced_calls;
+    }
+}
+
+impl<'a> std::iter::Sum<&'a LLMResponse> for Usage {
+    fn sum<I: Iterator<Item = &'a LLMResponse>>(iter: I) -> Self {
+        iter.fold(Self::default(), |mut usage, response| {
+            usage += &Self::of_response(response);
+            usage
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::llm_client::{
+        ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse,
+    };
+    use internal_baml_jinja::RenderedPrompt;
+    use web_time::{Duration, SystemTime};
+
+    fn failure(client: &str) -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: client.into(),
+            model: None,
+            prompt: RenderedPrompt::Completion("hi".into()),
+            request_options: Default::default(),
+            start_time: SystemTime::now(),
+            latency: Duration::from_millis(200),
+            message: "failed".into(),
+            code: ErrorCode::ServiceUnavailable,
+            retry_after: None,
+            rate_limit_wait_ms: None,
+        })
+    }
+
+    fn success(client: &str, cost_usd: Option<f64>, cache: Option<CacheStatus>) -> LLMResponse {
+        LLMResponse::Success(LLMCompleteResponse {
+            client: client.into(),
+            model: "gpt-4o".into(),
+            prompt: RenderedPrompt::Completion("hi".into()),
+            request_options: Default::default(),
+            content: "{}".into(),
+            start_time: SystemTime::now(),
+            latency: Duration::from_millis(800),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: Some("stop".into()),
+                prompt_tokens: Some(120),
+                output_tokens: Some(30),
+                total_tokens: None,
+                cache,
+                cost_usd,
+                rate_limit_wait_ms: None,
+                time_to_first_token_ms: None,
+            },
+        })
+    }
+
+    #[test]
+    fn test_retried_call() {
+        let usage: Usage = [
+            failure("GPT4o"),
+            failure("GPT4o"),
+            success("GPT4o", Some(0.0006), None),
+        ]
+        .iter()
+        .sum();
+        assert_eq!(
+            usage,
+            Usage {
+                llm_calls: 3,
+                cached_calls: 0,
+                prompt_tokens: 120,
+                output_tokens: 30,
+                total_tokens: 150,
+                cost_usd: 0.0006,
+                unpriced_calls: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let usage: Usage = [
+            failure("GPT4o"),
+            success("Claude", None, None),
+            success("GPT4o", Some(0.0006), None),
+            LLMResponse::InternalFailure("failed to render the prompt".into()),
+        ]
+        .iter()
+        .sum();
+        assert_eq!(usage.llm_calls, 3);
+        assert_eq!(usage.prompt_tokens, 240);
+        assert_eq!(usage.total_tokens, 300);
+        assert_eq!(usage.cost_usd, 0.0006);
+        assert_eq!(usage.unpriced_calls, 1);
+    }
+
+    #[test]
+    fn test_cache_hit_is_free() {
+        let usage: Usage = [
+            success("GPT4o", Some(0.0006), Some(CacheStatus::Hit)),
+            success("GPT4o", Some(0.0006), Some(CacheStatus::Miss)),
+        ]
+        .iter()
+        .sum();
+        assert_eq!(usage.cached_calls, 1);
+        assert_eq!(usage.llm_calls, 1);
+        assert_eq!(usage.total_tokens, 150);
+        assert_eq!(usage.cost_usd, 0.0006);
+    }
+}
@@ -240,6 +240,23 @@ mod test_cli {
         Ok(())
     }
 
+    #[rstest]
+    fn test_fails_if_no_tests_match() -> Result<()> {
+        let h = Harness::new("test_no_match_test")?;
+
+        let run = h.run_cli("init")?.output()?;
+        assert_eq!(run.status.code(), Some(0));
+
+        let run = h
+            .run_cli("test --function DoesNotExist* --json report.json")?
+            .output()?;
+        assert_ne!(run.status.code(), Some(0));
+        assert!(String::from_utf8(run.stderr)?.contains("No tests matched the given filters"));
+        assert!(!h.test_dir.join("report.json").exists());
+
+        Ok(())
+    }
+
     #[rstest]
     #[tokio::test]
     async fn call_function_error_codes() -> Result<()> {
@@ -6,6 +6,7 @@ mod internal_tests {
     use std::any;
     use std::collections::HashMap;
 
+    use baml_runtime::client_registry::{ClientProperty, ClientRegistry};
     use baml_runtime::BamlRuntime;
     use std::sync::Once;
 
@@ -281,4 +282,180 @@ mod internal_tests {
 
         Ok(())
     }
+
+    const MOCK_BAML: &str = r##"
+      class Person {
+        name string
+        age int
+      }
+
+      retry_policy Twice {
+        max_retries 2
+      }
+
+      client<llm> Flaky {
+        provider mock
+        retry_policy Twice
+        options {
+          responses [
+            { error_code 503, error_message "overloaded" },
+            #"{ "name": "Ada", "age": 36 }"#,
+          ]
+        }
+      }
+
+      client<llm> AlwaysDown {
+        provider mock
+        options {
+          error_code 500
+        }
+      }
+
+      client<llm> Echo {
+        provider mock
+        options {
+          response_template #"{ "name": "{{ messages[-1].content | trim }}", "age": {{ call_count }} }"#
+          stream_chunk_size 4
+        }
+      }
+
+      client<llm> DownThenEcho {
+        provider fallback
+        options {
+          strategy [AlwaysDown, Echo]
+        }
+      }
+
+      function ExtractFlaky(input: string) -> Person {
+        client Flaky
+        prompt #"{{ input }}"#
+      }
+
+      function ExtractFallback(input: string) -> Person {
+        client DownThenEcho
+        prompt #"{{ input }}"#
+      }
+    "##;
+
+    fn mock_runtime() -> Result<BamlRuntime, Box<dyn std::error::Error>> {
+        let mut files = HashMap::new();
+        files.insert("main.baml", MOCK_BAML);
+        Ok(BamlRuntime::from_file_content(
+            "baml_src",
+            &files,
+            HashMap::<&str, &str>::new(),
+        )?)
+    }
+
+    fn input(value: &str) -> baml_types::BamlMap<String, BamlValue> {
+        [("input".to_string(), BamlValue::String(value.to_string()))]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_mock_client_retries() -> Result<(), Box<dyn std::error::Error>> {
+        let runtime = mock_runtime()?;
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".to_string()), None);
+
+        let (res, _) = runtime.async_runtime.block_on(runtime.call_function(
+            "ExtractFlaky".to_string(),
+            &input("Ada"),
+            &ctx,
+            None,
+            None,
+        ));
+        let res = res?;
+
+        let parsed: BamlValue = res.parsed_content()?.into();
+        assert_eq!(
+            serde_json::json!(parsed),
+            serde_json::json!({ "name": "Ada", "age": 36 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_mock_client_fallback_and_stream() -> Result<(), Box<dyn std::error::Error>> {
+        let runtime = mock_runtime()?;
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".to_string()), None);
+
+        let events = std::cell::Cell::new(0);
+        let mut stream = runtime.stream_function(
+            "ExtractFallback".to_string(),
+            &input("Grace"),
+            &ctx,
+            None,
+            None,
+        )?;
+        let (res, _) = runtime.async_runtime.block_on(stream.run(
+            Some(|_| events.set(events.get() + 1)),
+            &ctx,
+            None,
+            None,
+        ));
+        let res = res?;
+
+        assert!(events.get() > 1, "expected several stream events");
+        let parsed: BamlValue = res.parsed_content()?.into();
+        assert_eq!(
+            serde_json::json!(parsed),
+            serde_json::json!({ "name": "Grace", "age": 0 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_registry_circuit_breaker() -> Result<(), Box<dyn std::error::Error>> {
+        let runtime = mock_runtime()?;
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".to_string()), None);
+
+        let mut registry = ClientRegistry::new();
+        registry.add_client(ClientProperty {
+            name: "Breaker".to_string(),
+            provider: "fallback".to_string(),
+            retry_policy: None,
+            options: [
+                (
+                    "strategy".to_string(),
+                    BamlValue::List(vec![
+                        BamlValue::String("AlwaysDown".to_string()),
+                        BamlValue::String("Echo".to_string()),
+                    ]),
+                ),
+                // Opens on the first failure.
+                (
+                    "circuit_breaker".to_string(),
+                    BamlValue::Map(
+                        [("min_requests".to_string(), BamlValue::Int(1))]
+                            .into_iter()
+                            .collect(),
+                    ),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        });
+        registry.set_primary("Breaker".to_string());
+
+        // The registry's clients are created anew for each call.
+        for name in ["Ada", "Grace"] {
+            let (res, _) = runtime.async_runtime.block_on(runtime.call_function(
+                "ExtractFallback".to_string(),
+                &input(name),
+                &ctx,
+                None,
+                Some(&registry),
+            ));
+            res?.parsed_content()?;
+        }
+
+        // The second call skipped AlwaysDown.
+        let metrics = runtime.metrics();
+        assert_eq!(
+            metrics.functions["ExtractFallback"].clients["AlwaysDown"].requests,
+            1
+        );
+        Ok(())
+    }
 }
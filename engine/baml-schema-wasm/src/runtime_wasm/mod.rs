@@ -12,7 +12,7 @@ use baml_runtime::RenderCurlSettings;
 use baml_runtime::{
     internal::llm_client::LLMResponse, BamlRuntime, DiagnosticsError, IRHelper, RenderedPrompt,
 };
-use baml_types::{BamlMediaType, BamlValue, GeneratorOutputType, TypeValue};
+use baml_types::{BamlMediaType, BamlValue, Constraint, GeneratorOutputType, TypeValue};
 use internal_baml_codegen::version_check::GeneratorType;
 use internal_baml_codegen::version_check::{check_version, VersionCheckMode};
 
@@ -371,6 +371,48 @@ pub struct WasmTestCase {
     pub span: WasmSpan,
     #[wasm_bindgen(readonly)]
     pub parent_functions: Vec<WasmParentFunction>,
+    #[wasm_bindgen(readonly)]
+    pub assertions: Vec<WasmTestAssertion>,
+}
+
+/// An `@@assert` or `@@check` in a test block, and its outcome once the test has run.
+#[wasm_bindgen(getter_with_clone, inspectable)]
+#[derive(Clone)]
+pub struct WasmTestAssertion {
+    #[wasm_bindgen(readonly)]
+    pub name: String,
+    #[wasm_bindgen(readonly)]
+    pub expression: String,
+    /// Either "assert" or "check".
+    #[wasm_bindgen(readonly)]
+    pub level: String,
+    /// Unset until the test has run.
+    #[wasm_bindgen(readonly)]
+    pub passed: Option<bool>,
+    #[wasm_bindgen(readonly)]
+    pub error: Option<String>,
+}
+
+impl From<&Constraint> for WasmTestAssertion {
+    fn from(constraint: &Constraint) -> Self {
+        WasmTestAssertion {
+            name: constraint.name(),
+            expression: constraint.expression.0.clone(),
+            level: constraint.level.to_string(),
+            passed: None,
+            error: None,
+        }
+    }
+}
+
+impl From<&baml_runtime::TestConstraintResult> for WasmTestAssertion {
+    fn from(result: &baml_runtime::TestConstraintResult) -> Self {
+        WasmTestAssertion {
+            passed: Some(result.passed),
+            error: result.error.clone(),
+            ..(&result.constraint).into()
+        }
+    }
 }
 
 #[wasm_bindgen(getter_with_clone, inspectable)]
@@ -411,6 +453,8 @@ pub enum TestStatus {
     LLMFailure,
     ParseFailure,
     UnableToRun,
+    NeedsHumanEval,
+    AssertionFailure,
 }
 
 #[wasm_bindgen(getter_with_clone, inspectable)]
@@ -499,10 +543,14 @@ impl WasmTestResponse {
         match &self.test_response {
             Ok(t) => match t.status() {
                 baml_runtime::TestStatus::Pass => TestStatus::Passed,
+                baml_runtime::TestStatus::NeedsHumanEval(_) => TestStatus::NeedsHumanEval,
                 baml_runtime::TestStatus::Fail(r) => match r {
                     baml_runtime::TestFailReason::TestUnspecified(_) => TestStatus::UnableToRun,
                     baml_runtime::TestFailReason::TestLLMFailure(_) => TestStatus::LLMFailure,
                     baml_runtime::TestFailReason::TestParseFailure(_) => TestStatus::ParseFailure,
+                    baml_runtime::TestFailReason::TestAssertionFailure(_) => {
+                        TestStatus::AssertionFailure
+                    }
                 },
             },
             Err(_) => TestStatus::UnableToRun,
@@ -556,11 +604,22 @@ impl WasmTestResponse {
         })
     }
 
+    #[wasm_bindgen]
+    pub fn assertion_results(&self) -> Vec<WasmTestAssertion> {
+        self.test_response
+            .as_ref()
+            .map(|r| r.constraints_result.iter().map(Into::into).collect())
+            .unwrap_or_default()
+    }
+
     #[wasm_bindgen]
     pub fn failure_message(&self) -> Option<String> {
         match self.test_response.as_ref() {
             Ok(r) => match r.status() {
                 baml_runtime::TestStatus::Pass => None,
+                baml_runtime::TestStatus::NeedsHumanEval(checks) => {
+                    Some(format!("Failed checks: {}", checks.join(", ")))
+                }
                 baml_runtime::TestStatus::Fail(r) => r.render_error(),
             },
             Err(e) => Some(format!("{e:#}")),
@@ -676,6 +735,10 @@ impl WithRenderError for baml_runtime::TestFailReason<'_> {
             baml_runtime::TestFailReason::TestUnspecified(e) => Some(format!("{e:#}")),
             baml_runtime::TestFailReason::TestLLMFailure(f) => f.render_error(),
             baml_runtime::TestFailReason::TestParseFailure(e) => Some(format!("{e:#}")),
+            baml_runtime::TestFailReason::TestAssertionFailure(r) => Some(match &r.error {
+                Some(e) => format!("Assertion {} failed: {e}", r.constraint),
+                None => format!("Assertion {} failed", r.constraint),
+            }),
         }
     }
 }
@@ -730,6 +793,7 @@ fn get_dummy_value(
 
             Some(dummy)
         }
+        baml_runtime::FieldType::Literal(v) => Some(v.to_string()),
         baml_runtime::FieldType::Enum(_) => None,
         baml_runtime::FieldType::Class(_) => None,
         baml_runtime::FieldType::List(item) => {
@@ -952,6 +1016,7 @@ impl WasmRuntime {
                                         }
                                     })
                                     .collect(),
+                                assertions: tc.constraints().iter().map(Into::into).collect(),
                             }
                         })
                         .collect(),
@@ -1260,6 +1325,7 @@ impl WasmRuntime {
                             }
                         })
                         .collect(),
+                    assertions: tc.constraints().iter().map(Into::into).collect(),
                 }
             })
             .collect()
@@ -1575,9 +1641,9 @@ impl ToJsValue for ExecutionScope {
                 );
                 set_property(&obj, "index", JsValue::from_f64(*index as f64));
             }
-            ExecutionScope::Fallback(name, index) => {
+            ExecutionScope::Fallback(strategy, index) => {
                 set_property(&obj, "type", JsValue::from_str("Fallback"));
-                set_property(&obj, "name", JsValue::from_str(name));
+                set_property(&obj, "name", JsValue::from_str(&strategy.name));
                 set_property(&obj, "index", JsValue::from_f64(*index as f64));
             }
         }
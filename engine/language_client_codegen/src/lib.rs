@@ -8,9 +8,10 @@ use std::{collections::BTreeMap, path::PathBuf};
 use version_check::{check_version, GeneratorType, VersionCheckMode};
 
 mod dir_writer;
-mod openapi;
+pub mod openapi;
 mod python;
 mod ruby;
+mod type_refs;
 mod typescript;
 pub mod version_check;
 
@@ -1,7 +1,7 @@
 use std::{path::PathBuf, process::Command};
 
 use anyhow::{Context, Result};
-use baml_types::{BamlMediaType, FieldType, TypeValue};
+use baml_types::{BamlMediaType, FieldType, LiteralValue, TypeValue};
 use indexmap::IndexMap;
 use internal_baml_core::ir::{
     repr::{Function, IntermediateRepr, Node, Walker},
@@ -245,10 +245,121 @@ impl Serialize for OpenApiSchema<'_> {
                                         }
                                     },
                                     "required": ["clients"]
+                                },
+                                "type_builder": {
+                                    "$ref": "#/components/schemas/BamlTypeBuilder"
+                                }
+                            }
+                        })
+                    ),
+                    (
+                        "BamlTypeBuilder",
+                        json!({
+                            "type": "object",
+                            "nullable": false,
+                            "properties": {
+                                "classes": {
+                                    "type": "object",
+                                    "additionalProperties": {
+                                        "type": "object",
+                                        "properties": {
+                                            "properties": {
+                                                "type": "object",
+                                                "additionalProperties": {
+                                                    "$ref": "#/components/schemas/BamlDynamicProperty"
+                                                }
+                                            }
+                                        }
+                                    }
+                                },
+                                "enums": {
+                                    "type": "object",
+                                    "additionalProperties": {
+                                        "type": "object",
+                                        "properties": {
+                                            "alias": {
+                                                "type": "string"
+                                            },
+                                            "values": {
+                                                "type": "object",
+                                                "additionalProperties": {
+                                                    "$ref": "#/components/schemas/BamlDynamicEnumValue"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        })
+                    ),
+                    (
+                        "BamlDynamicProperty",
+                        json!({
+                            "type": "object",
+                            "properties": {
+                                "type": {
+                                    "$ref": "#/components/schemas/BamlFieldType"
+                                },
+                                "alias": {
+                                    "type": "string"
+                                },
+                                "description": {
+                                    "type": "string"
+                                }
+                            }
+                        })
+                    ),
+                    (
+                        "BamlDynamicEnumValue",
+                        json!({
+                            "type": "object",
+                            "properties": {
+                                "alias": {
+                                    "type": "string"
+                                },
+                                "description": {
+                                    "type": "string"
+                                },
+                                "skip": {
+                                    "type": "boolean"
                                 }
                             }
                         })
                     ),
+                    (
+                        "BamlFieldType",
+                        json!({
+                            "type": "object",
+                            "properties": {
+                                "type": {
+                                    "type": "string",
+                                    "enum": ["string", "int", "float", "bool", "null", "class", "enum", "list", "map", "union", "optional"]
+                                },
+                                "name": {
+                                    "type": "string"
+                                },
+                                "items": {
+                                    "$ref": "#/components/schemas/BamlFieldType"
+                                },
+                                "key": {
+                                    "$ref": "#/components/schemas/BamlFieldType"
+                                },
+                                "value": {
+                                    "$ref": "#/components/schemas/BamlFieldType"
+                                },
+                                "types": {
+                                    "type": "array",
+                                    "items": {
+                                        "$ref": "#/components/schemas/BamlFieldType"
+                                    }
+                                },
+                                "inner": {
+                                    "$ref": "#/components/schemas/BamlFieldType"
+                                }
+                            },
+                            "required": ["type"]
+                        })
+                    ),
                     (
                         "ClientProperty",
                         json!({
@@ -335,7 +446,7 @@ pub(crate) fn generate(
 ) -> Result<IndexMap<PathBuf, String>> {
     let mut collector = FileCollector::<OpenApiLanguageFeatures>::new();
 
-    let schema: OpenApiSchema = (ir, generator).try_into()?;
+    let schema: OpenApiSchema = ir.try_into()?;
 
     collector.add_file("openapi.yaml", serde_yaml::to_string(&schema)?);
     collector.add_file(
@@ -350,10 +461,35 @@ pub(crate) fn generate(
     stats
 }
 
-impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for OpenApiSchema<'ir> {
+/// The OpenAPI document for `baml-cli serve`, as served live at `/openapi.json`.
+pub fn schema(ir: &IntermediateRepr) -> Result<serde_json::Value> {
+    let schema: OpenApiSchema = ir.try_into()?;
+    Ok(serde_json::to_value(&schema)?)
+}
+
+/// The schemas of a function's arguments, as one object, and of its return
+/// type, for `baml-cli serve`. Classes and enums are `$ref`s to the
+/// `#/components/schemas` of [`schema`].
+pub fn function_schemas(
+    function: Walker<'_, &Node<Function>>,
+) -> Result<(serde_json::Value, serde_json::Value)> {
+    let (properties, required) = function_inputs(function)?;
+    let inputs = TypeDef::Class {
+        properties,
+        required,
+        additional_properties: false,
+    };
+    let output = function.item.elem.output().to_type_spec(function.db)?;
+    Ok((
+        serde_json::to_value(&inputs)?,
+        serde_json::to_value(&output)?,
+    ))
+}
+
+impl<'ir> TryFrom<&'ir IntermediateRepr> for OpenApiSchema<'ir> {
     type Error = anyhow::Error;
 
-    fn try_from((ir, _): (&'ir IntermediateRepr, &'_ crate::GeneratorArgs)) -> Result<Self> {
+    fn try_from(ir: &'ir IntermediateRepr) -> Result<Self> {
         Ok(Self {
             paths: ir
                 .walk_functions()
@@ -378,20 +514,7 @@ impl<'ir> TryFrom<Walker<'ir, &'ir Node<Function>>> for OpenApiMethodDef<'ir> {
 
     fn try_from(value: Walker<'ir, &'ir Node<Function>>) -> Result<Self> {
         let function_name = value.item.elem.name();
-        let mut properties: IndexMap<String, TypeSpecWithMeta> = value
-                        .item
-                        .elem
-                        .inputs()
-                        .iter()
-                        .map(|(name, t)| {
-                            Ok((
-                                name.to_string(),
-                                t.to_type_spec(value.db).context(format!(
-                                    "Failed to convert arg {name} (for function {function_name}) to OpenAPI type",
-                                ))?,
-                            ))
-                        })
-                        .collect::<Result<_>>()?;
+        let (mut properties, required) = function_inputs(value)?;
         properties.insert(
             "__baml_options__".to_string(),
             TypeSpecWithMeta {
@@ -424,19 +547,7 @@ impl<'ir> TryFrom<Walker<'ir, &'ir Node<Function>>> for OpenApiMethodDef<'ir> {
                 },
                 type_spec: TypeSpec::Inline(TypeDef::Class {
                     properties,
-                    required: value
-                        .item
-                        .elem
-                        .inputs()
-                        .iter()
-                        .filter_map(|(name, t)| {
-                            if t.is_optional() {
-                                None
-                            } else {
-                                Some(name.to_string())
-                            }
-                        })
-                        .collect(),
+                    required,
                     additional_properties: false,
                 }),
             },
@@ -449,6 +560,31 @@ impl<'ir> TryFrom<Walker<'ir, &'ir Node<Function>>> for OpenApiMethodDef<'ir> {
     }
 }
 
+/// The schemas of a function's arguments, and the names of the required ones.
+fn function_inputs<'ir>(
+    function: Walker<'ir, &'ir Node<Function>>,
+) -> Result<(IndexMap<String, TypeSpecWithMeta>, Vec<String>)> {
+    let function_name = function.item.elem.name();
+    let inputs = function.item.elem.inputs();
+    let properties = inputs
+        .iter()
+        .map(|(name, t)| {
+            Ok((
+                name.to_string(),
+                t.to_type_spec(function.db).context(format!(
+                    "Failed to convert arg {name} (for function {function_name}) to OpenAPI type",
+                ))?,
+            ))
+        })
+        .collect::<Result<_>>()?;
+    let required = inputs
+        .iter()
+        .filter(|(_, t)| !t.is_optional())
+        .map(|(name, _)| name.to_string())
+        .collect();
+    Ok((properties, required))
+}
+
 impl<'ir> TryFrom<EnumWalker<'ir>> for TypeSpecWithMeta {
     type Error = anyhow::Error;
 
@@ -590,6 +726,23 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                     },
                 },
             },
+            FieldType::Literal(value) => TypeSpecWithMeta {
+                meta: TypeMetadata {
+                    title: None,
+                    r#enum: None,
+                    r#const: Some(match value {
+                        LiteralValue::String(s) => json!(s),
+                        LiteralValue::Int(i) => json!(i),
+                        LiteralValue::Bool(b) => json!(b),
+                    }),
+                    nullable: false,
+                },
+                type_spec: match value {
+                    LiteralValue::String(_) => TypeSpec::Inline(TypeDef::String),
+                    LiteralValue::Int(_) => TypeSpec::Inline(TypeDef::Int),
+                    LiteralValue::Bool(_) => TypeSpec::Inline(TypeDef::Bool),
+                },
+            },
             FieldType::Union(inner) => {
                 let (_nulls, nonnull_types): (Vec<_>, Vec<_>) =
                     inner.into_iter().partition(|t| t.is_null());
@@ -647,9 +800,9 @@ struct TypeMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     r#enum: Option<Vec<String>>,
 
-    /// We only allow string-shaped const values
+    /// The value of a literal type.
     #[serde(skip_serializing_if = "Option::is_none")]
-    r#const: Option<String>,
+    r#const: Option<serde_json::Value>,
     // description: Option<String>,
     /// Nulls in OpenAPI are weird: https://swagger.io/docs/specification/data-models/data-types/
     #[serde(skip_serializing_if = "std::ops::Not::not")]
@@ -1,6 +1,7 @@
 use anyhow::Result;
 
 use super::python_language_features::ToPython;
+use crate::type_refs::{partial_field_type, with_checks, PartialTypeRefs};
 use internal_baml_core::ir::{
     repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType, IRHelper,
 };
@@ -103,7 +104,11 @@ impl<'ir> From<ClassWalker<'ir>> for PythonClass<'ir> {
                         f.elem.name.as_str(),
                         add_default_value(
                             &f.elem.r#type.elem,
-                            &f.elem.r#type.elem.to_type_ref(&c.db),
+                            &with_checks(
+                                f.elem.r#type.elem.to_type_ref(&c.db),
+                                &f.attributes.constraints,
+                                |t| format!("Checked[{t}]"),
+                            ),
                         ),
                     )
                 })
@@ -140,7 +145,12 @@ impl<'ir> From<ClassWalker<'ir>> for PartialPythonClass<'ir> {
                         f.elem.name.as_str(),
                         add_default_value(
                             &f.elem.r#type.elem,
-                            &f.elem.r#type.elem.to_partial_type_ref(&c.db, false),
+                            &partial_field_type(
+                                &PythonTypeRefs,
+                                &f.elem.r#type.elem,
+                                f.attributes.streaming(),
+                                &c.db,
+                            ),
                         ),
                     )
                 })
@@ -149,6 +159,26 @@ impl<'ir> From<ClassWalker<'ir>> for PartialPythonClass<'ir> {
     }
 }
 
+struct PythonTypeRefs;
+
+impl PartialTypeRefs for PythonTypeRefs {
+    fn finished_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String {
+        r#type.to_type_ref_in_module(ir, "types.")
+    }
+
+    fn partial_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String {
+        r#type.to_partial_type_ref(ir, false)
+    }
+
+    fn non_null_partial_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String {
+        r#type.to_non_null_partial_type_ref(ir)
+    }
+
+    fn nullable(&self, type_ref: String) -> String {
+        format!("Optional[{type_ref}]")
+    }
+}
+
 pub fn add_default_value(node: &FieldType, type_str: &String) -> String {
     if type_str.starts_with("Optional[") {
         return format!("{} = None", type_str);
@@ -159,11 +189,17 @@ pub fn add_default_value(node: &FieldType, type_str: &String) -> String {
 
 trait ToTypeReferenceInTypeDefinition {
     fn to_type_ref(&self, ir: &IntermediateRepr) -> String;
+    fn to_type_ref_in_module(&self, ir: &IntermediateRepr, module: &str) -> String;
     fn to_partial_type_ref(&self, ir: &IntermediateRepr, wrapped: bool) -> String;
+    fn to_non_null_partial_type_ref(&self, ir: &IntermediateRepr) -> String;
 }
 
 impl ToTypeReferenceInTypeDefinition for FieldType {
     fn to_type_ref(&self, ir: &IntermediateRepr) -> String {
+        self.to_type_ref_in_module(ir, "")
+    }
+
+    fn to_type_ref_in_module(&self, ir: &IntermediateRepr, module: &str) -> String {
         match self {
             FieldType::Enum(name) => {
                 if ir
@@ -171,22 +207,27 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                     .map(|e| e.item.attributes.get("dynamic_type").is_some())
                     .unwrap_or(false)
                 {
-                    format!("Union[\"{name}\", str]")
+                    format!("Union[\"{module}{name}\", str]")
                 } else {
-                    format!("\"{name}\"")
+                    format!("\"{module}{name}\"")
                 }
             }
-            FieldType::Class(name) => format!("\"{name}\""),
-            FieldType::List(inner) => format!("List[{}]", inner.to_type_ref(ir)),
+            FieldType::Class(name) => format!("\"{module}{name}\""),
+            FieldType::List(inner) => format!("List[{}]", inner.to_type_ref_in_module(ir, module)),
             FieldType::Map(key, value) => {
-                format!("Dict[{}, {}]", key.to_type_ref(ir), value.to_type_ref(ir))
+                format!(
+                    "Dict[{}, {}]",
+                    key.to_type_ref_in_module(ir, module),
+                    value.to_type_ref_in_module(ir, module)
+                )
             }
             FieldType::Primitive(r#type) => r#type.to_python(),
+            FieldType::Literal(value) => value.to_python(),
             FieldType::Union(inner) => format!(
                 "Union[{}]",
                 inner
                     .iter()
-                    .map(|t| t.to_type_ref(ir))
+                    .map(|t| t.to_type_ref_in_module(ir, module))
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
@@ -194,32 +235,37 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                 "Tuple[{}]",
                 inner
                     .iter()
-                    .map(|t| t.to_type_ref(ir))
+                    .map(|t| t.to_type_ref_in_module(ir, module))
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
-            FieldType::Optional(inner) => format!("Optional[{}]", inner.to_type_ref(ir)),
+            FieldType::Optional(inner) => {
+                format!("Optional[{}]", inner.to_type_ref_in_module(ir, module))
+            }
         }
     }
 
     fn to_partial_type_ref(&self, ir: &IntermediateRepr, wrapped: bool) -> String {
         match self {
-            FieldType::Class(name) => {
-                if wrapped {
-                    format!("\"{name}\"")
-                } else {
-                    format!("Optional[\"{name}\"]")
-                }
-            }
+            FieldType::Class(_) if wrapped => self.to_non_null_partial_type_ref(ir),
+            FieldType::List(_) | FieldType::Map(..) => self.to_non_null_partial_type_ref(ir),
+            FieldType::Optional(inner) => inner.to_partial_type_ref(ir, false),
+            _ => format!("Optional[{}]", self.to_non_null_partial_type_ref(ir)),
+        }
+    }
+
+    fn to_non_null_partial_type_ref(&self, ir: &IntermediateRepr) -> String {
+        match self {
+            FieldType::Class(name) => format!("\"{name}\""),
             FieldType::Enum(name) => {
                 if ir
                     .find_enum(name)
                     .map(|e| e.item.attributes.get("dynamic_type").is_some())
                     .unwrap_or(false)
                 {
-                    format!("Optional[Union[types.{name}, str]]")
+                    format!("Union[types.{name}, str]")
                 } else {
-                    format!("Optional[types.{name}]")
+                    format!("types.{name}")
                 }
             }
             FieldType::List(inner) => format!("List[{}]", inner.to_partial_type_ref(ir, true)),
@@ -230,9 +276,10 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                     value.to_partial_type_ref(ir, false)
                 )
             }
-            FieldType::Primitive(r#type) => format!("Optional[{}]", r#type.to_python()),
+            FieldType::Primitive(r#type) => r#type.to_python(),
+            FieldType::Literal(value) => value.to_python(),
             FieldType::Union(inner) => format!(
-                "Optional[Union[{}]]",
+                "Union[{}]",
                 inner
                     .iter()
                     .map(|t| t.to_partial_type_ref(ir, true))
@@ -240,14 +287,36 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                     .join(", ")
             ),
             FieldType::Tuple(inner) => format!(
-                "Optional[Tuple[{}]]",
+                "Tuple[{}]",
                 inner
                     .iter()
                     .map(|t| t.to_partial_type_ref(ir, false))
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
-            FieldType::Optional(inner) => inner.to_partial_type_ref(ir, false),
+            FieldType::Optional(inner) => inner.to_non_null_partial_type_ref(ir),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_refs::{load_test_ir, STREAMING_LINK};
+
+    #[test]
+    fn test_partial_class_with_stream_attributes() {
+        let ir = load_test_ir(STREAMING_LINK);
+        let partial = PartialPythonClass::from(ir.find_class("Link").unwrap());
+        assert_eq!(
+            partial.fields,
+            vec![
+                ("url", "Optional[str] = None".to_string()),
+                ("title", "str".to_string()),
+                ("tags", "List[str]".to_string()),
+                ("note", "Optional[str] = None".to_string()),
+                ("next", "\"Link\"".to_string()),
+            ]
+        );
+    }
+}
@@ -12,6 +12,7 @@ use internal_baml_core::{
 
 use self::python_language_features::{PythonLanguageFeatures, ToPython};
 use crate::dir_writer::FileCollector;
+use crate::type_refs::with_checks;
 
 #[derive(askama::Template)]
 #[template(path = "async_client.py.j2", escape = "none")]
@@ -158,7 +159,11 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for PythonClient
                         Ok(PythonFunction {
                             name: f.name().to_string(),
                             partial_return_type: f.elem().output().to_partial_type_ref(ir),
-                            return_type: f.elem().output().to_type_ref(ir),
+                            return_type: with_checks(
+                                f.elem().output().to_type_ref(ir),
+                                &f.elem().output_constraints,
+                                |t| format!("types.Checked[{t}]"),
+                            ),
                             args: f
                                 .inputs()
                                 .iter()
@@ -203,6 +208,7 @@ impl ToTypeReferenceInClientDefinition for FieldType {
                 format!("Dict[{}, {}]", key.to_type_ref(ir), value.to_type_ref(ir))
             }
             FieldType::Primitive(r#type) => r#type.to_python(),
+            FieldType::Literal(value) => value.to_python(),
             FieldType::Union(inner) => format!(
                 "Union[{}]",
                 inner
@@ -246,6 +252,7 @@ impl ToTypeReferenceInClientDefinition for FieldType {
                 )
             }
             FieldType::Primitive(r#type) => format!("Optional[{}]", r#type.to_python()),
+            FieldType::Literal(value) => format!("Optional[{}]", value.to_python()),
             FieldType::Union(inner) => format!(
                 "Optional[Union[{}]]",
                 inner
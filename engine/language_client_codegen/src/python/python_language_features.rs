@@ -1,5 +1,5 @@
 use crate::dir_writer::LanguageFeatures;
-use baml_types::{BamlMediaType, TypeValue};
+use baml_types::{BamlMediaType, LiteralValue, TypeValue};
 
 #[derive(Default)]
 pub(super) struct PythonLanguageFeatures {}
@@ -42,3 +42,33 @@ impl ToPython for TypeValue {
         .to_string()
     }
 }
+
+impl ToPython for LiteralValue {
+    fn to_python(&self) -> String {
+        match self {
+            LiteralValue::String(value) => format!("Literal[{}]", python_string(value)),
+            LiteralValue::Int(value) => format!("Literal[{value}]"),
+            LiteralValue::Bool(true) => "Literal[True]".to_string(),
+            LiteralValue::Bool(false) => "Literal[False]".to_string(),
+        }
+    }
+}
+
+/// A Python string literal. JSON escapes are all valid in Python strings,
+/// unlike Rust's `{:?}` escapes such as `\u{7}`.
+fn python_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_literal() {
+        assert_eq!(
+            LiteralValue::String("say \"hi\"\n\u{7}".into()).to_python(),
+            r#"Literal["say \"hi\"\n\u0007"]"#
+        );
+    }
+}
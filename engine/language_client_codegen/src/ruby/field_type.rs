@@ -1,4 +1,4 @@
-use baml_types::{BamlMediaType, FieldType, TypeValue};
+use baml_types::{BamlMediaType, FieldType, LiteralValue, TypeValue};
 
 use super::ruby_language_features::ToRuby;
 
@@ -24,6 +24,13 @@ impl ToRuby for FieldType {
                 TypeValue::Media(BamlMediaType::Audio) => "Baml::Audio",
             }
             .to_string(),
+            // Sorbet has no literal types, so literals are typed by their base type.
+            FieldType::Literal(value) => match value {
+                LiteralValue::Bool(_) => "T::Boolean",
+                LiteralValue::Int(_) => "Integer",
+                LiteralValue::String(_) => "String",
+            }
+            .to_string(),
             FieldType::Union(inner) => format!(
                 // https://sorbet.org/docs/union-types
                 "T.any({})",
@@ -1,6 +1,7 @@
 use anyhow::Result;
 
 use super::ruby_language_features::ToRuby;
+use crate::type_refs::{partial_field_type, with_checks, PartialTypeRefs};
 use internal_baml_core::ir::{repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType};
 
 #[derive(askama::Template)]
@@ -79,7 +80,16 @@ impl<'ir> From<ClassWalker<'ir>> for RubyStruct<'ir> {
                 .elem
                 .static_fields
                 .iter()
-                .map(|f| (f.elem.name.as_str(), f.elem.r#type.elem.to_type_ref()))
+                .map(|f| {
+                    (
+                        f.elem.name.as_str(),
+                        with_checks(
+                            f.elem.r#type.elem.to_type_ref(),
+                            &f.attributes.constraints,
+                            checked,
+                        ),
+                    )
+                })
                 .collect(),
         }
     }
@@ -107,7 +117,12 @@ impl<'ir> From<ClassWalker<'ir>> for PartialRubyStruct<'ir> {
                 .map(|f| {
                     (
                         f.elem.name.as_str(),
-                        f.elem.r#type.elem.to_partial_type_ref(),
+                        partial_field_type(
+                            &RubyTypeRefs,
+                            &f.elem.r#type.elem,
+                            f.attributes.streaming(),
+                            &c.db,
+                        ),
                     )
                 })
                 .collect(),
@@ -115,9 +130,35 @@ impl<'ir> From<ClassWalker<'ir>> for PartialRubyStruct<'ir> {
     }
 }
 
+struct RubyTypeRefs;
+
+impl PartialTypeRefs for RubyTypeRefs {
+    fn finished_type_ref(&self, r#type: &FieldType, _ir: &IntermediateRepr) -> String {
+        r#type.to_type_ref()
+    }
+
+    fn partial_type_ref(&self, r#type: &FieldType, _ir: &IntermediateRepr) -> String {
+        r#type.to_partial_type_ref()
+    }
+
+    fn non_null_partial_type_ref(&self, r#type: &FieldType, _ir: &IntermediateRepr) -> String {
+        r#type.to_non_null_partial_type_ref()
+    }
+
+    fn nullable(&self, type_ref: String) -> String {
+        format!("T.nilable({type_ref})")
+    }
+}
+
+/// Sorbet structs cannot be generic, so the wrapped value is untyped.
+pub(super) fn checked(_type_ref: String) -> String {
+    "Baml::Types::Checked".to_string()
+}
+
 pub(super) trait ToTypeReferenceInTypeDefinition {
     fn to_type_ref(&self) -> String;
     fn to_partial_type_ref(&self) -> String;
+    fn to_non_null_partial_type_ref(&self) -> String;
 }
 
 impl ToTypeReferenceInTypeDefinition for FieldType {
@@ -139,7 +180,9 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                     value.to_partial_type_ref()
                 )
             }
-            FieldType::Primitive(_) => format!("T.nilable({})", self.to_type_ref()),
+            FieldType::Primitive(_) | FieldType::Literal(_) => {
+                format!("T.nilable({})", self.to_type_ref())
+            }
             FieldType::Union(inner) => format!(
                 // https://sorbet.org/docs/union-types
                 "T.nilable(T.any({}))",
@@ -161,6 +204,33 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
             FieldType::Optional(inner) => inner.to_partial_type_ref(),
         }
     }
+
+    fn to_non_null_partial_type_ref(&self) -> String {
+        match self {
+            FieldType::Enum(name) => format!("Baml::Types::{}", name.clone()),
+            FieldType::Primitive(_) | FieldType::Literal(_) => self.to_type_ref(),
+            FieldType::Union(inner) => format!(
+                "T.any({})",
+                inner
+                    .iter()
+                    .map(|t| t.to_partial_type_ref())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            FieldType::Tuple(inner) => format!(
+                "[{}]",
+                inner
+                    .iter()
+                    .map(|t| t.to_partial_type_ref())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            FieldType::Optional(inner) => inner.to_non_null_partial_type_ref(),
+            FieldType::Class(_) | FieldType::List(_) | FieldType::Map(..) => {
+                self.to_partial_type_ref()
+            }
+        }
+    }
 }
 
 impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for TypeRegistry<'ir> {
@@ -175,3 +245,26 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for TypeReg
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_refs::{load_test_ir, STREAMING_LINK};
+    use internal_baml_core::ir::IRHelper;
+
+    #[test]
+    fn test_partial_class_with_stream_attributes() {
+        let ir = load_test_ir(STREAMING_LINK);
+        let partial = PartialRubyStruct::from(ir.find_class("Link").unwrap());
+        assert_eq!(
+            partial.fields,
+            vec![
+                ("url", "T.nilable(String)".to_string()),
+                ("title", "String".to_string()),
+                ("tags", "T::Array[String]".to_string()),
+                ("note", "T.nilable(String)".to_string()),
+                ("next", "Baml::PartialTypes::Link".to_string()),
+            ]
+        );
+    }
+}
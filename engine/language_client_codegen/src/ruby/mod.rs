@@ -14,6 +14,7 @@ use either::Either;
 use internal_baml_core::ir::repr::IntermediateRepr;
 
 use crate::dir_writer::FileCollector;
+use crate::type_refs::with_checks;
 
 use generate_types::ToTypeReferenceInTypeDefinition;
 use ruby_language_features::RubyLanguageFeatures;
@@ -67,7 +68,11 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir crate::GeneratorArgs)> for RubyCl
                         Ok(RubyFunction {
                             name: f.name().to_string(),
                             partial_return_type: f.elem().output().to_partial_type_ref(),
-                            return_type: f.elem().output().to_ruby(),
+                            return_type: with_checks(
+                                f.elem().output().to_ruby(),
+                                &f.elem().output_constraints,
+                                generate_types::checked,
+                            ),
                             args: f
                                 .inputs()
                                 .iter()
@@ -0,0 +1,85 @@
+use baml_types::{Constraint, ConstraintLevel, StreamingBehavior};
+use internal_baml_core::ir::{repr::IntermediateRepr, FieldType};
+
+/// How a generator spells the types of a partial class's fields.
+pub(crate) trait PartialTypeRefs {
+    /// A finished value, referring to the classes and enums in `types`.
+    fn finished_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String;
+
+    /// A value that may still be streaming, including its `null`.
+    fn partial_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String;
+
+    /// A value that may still be streaming, but is never `null`.
+    fn non_null_partial_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String;
+
+    fn nullable(&self, type_ref: String) -> String;
+}
+
+/// The type of a field of a partial class. A `@stream.done` field only ever
+/// holds a finished value, and a `@stream.not_null` field is never null.
+pub(crate) fn partial_field_type(
+    refs: &impl PartialTypeRefs,
+    r#type: &FieldType,
+    streaming: StreamingBehavior,
+    ir: &IntermediateRepr,
+) -> String {
+    match (streaming.done, streaming.not_null) {
+        (true, true) => refs.finished_type_ref(non_null(r#type), ir),
+        (true, false) => refs.nullable(refs.finished_type_ref(non_null(r#type), ir)),
+        (false, true) => refs.non_null_partial_type_ref(r#type, ir),
+        (false, false) => refs.partial_type_ref(r#type, ir),
+    }
+}
+
+/// Values with `@check`s are returned wrapped together with the check results,
+/// in the type that `checked` spells.
+pub(crate) fn with_checks(
+    type_ref: String,
+    constraints: &[Constraint],
+    checked: impl FnOnce(String) -> String,
+) -> String {
+    if constraints
+        .iter()
+        .any(|c| c.level == ConstraintLevel::Check)
+    {
+        checked(type_ref)
+    } else {
+        type_ref
+    }
+}
+
+fn non_null(r#type: &FieldType) -> &FieldType {
+    match r#type {
+        FieldType::Optional(inner) => non_null(inner),
+        _ => r#type,
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn load_test_ir(file_content: &str) -> IntermediateRepr {
+    use internal_baml_core::{internal_baml_diagnostics::SourceFile, validate};
+    use std::path::PathBuf;
+
+    let mut schema = validate(
+        &PathBuf::from("./baml_src"),
+        vec![SourceFile::from((
+            PathBuf::from("./baml_src/example.baml"),
+            file_content.to_string(),
+        ))],
+    );
+    if let Err(e) = schema.diagnostics.to_result() {
+        panic!("Failed to validate schema: {}", e);
+    }
+    IntermediateRepr::from_parser_database(&schema.db, schema.configuration).unwrap()
+}
+
+#[cfg(test)]
+pub(crate) const STREAMING_LINK: &str = r#"
+class Link {
+  url string @stream.done
+  title string? @stream.not_null
+  tags string[] @stream.done @stream.not_null
+  note string? @stream.done
+  next Link? @stream.not_null
+}
+"#;
@@ -1,10 +1,11 @@
 use anyhow::Result;
 
-use internal_baml_core::ir::{repr::IntermediateRepr, ClassWalker, EnumWalker};
+use internal_baml_core::ir::{repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType};
 
+use crate::type_refs::{partial_field_type, with_checks, PartialTypeRefs};
 use crate::GeneratorArgs;
 
-use super::ToTypeReferenceInClientDefinition;
+use super::{checked, ToTypeReferenceInClientDefinition};
 
 #[derive(askama::Template)]
 #[template(path = "type_builder.ts.j2", escape = "none")]
@@ -20,6 +21,12 @@ pub(crate) struct TypescriptTypes<'ir> {
     classes: Vec<TypescriptClass<'ir>>,
 }
 
+#[derive(askama::Template)]
+#[template(path = "partial_types.ts.j2", escape = "none")]
+pub(crate) struct TypescriptPartialTypes<'ir> {
+    partial_classes: Vec<PartialTypescriptClass<'ir>>,
+}
+
 struct TypescriptEnum<'ir> {
     pub name: &'ir str,
     pub values: Vec<&'ir str>,
@@ -32,6 +39,12 @@ struct TypescriptClass<'ir> {
     dynamic: bool,
 }
 
+struct PartialTypescriptClass<'ir> {
+    name: &'ir str,
+    fields: Vec<(&'ir str, bool, String)>,
+    dynamic: bool,
+}
+
 impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypescriptTypes<'ir> {
     type Error = anyhow::Error;
 
@@ -51,6 +64,21 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypescriptTyp
     }
 }
 
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypescriptPartialTypes<'ir> {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        (ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs),
+    ) -> Result<TypescriptPartialTypes<'ir>> {
+        Ok(TypescriptPartialTypes {
+            partial_classes: ir
+                .walk_classes()
+                .map(|e| Into::<PartialTypescriptClass>::into(&e))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
 impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypeBuilder<'ir> {
     type Error = anyhow::Error;
 
@@ -95,13 +123,87 @@ impl<'ir> From<&ClassWalker<'ir>> for TypescriptClass<'ir> {
                 .static_fields
                 .iter()
                 .map(|f| {
+                    let type_ref = f.elem.r#type.elem.to_type_ref(&c.db);
+                    let checked = with_checks(type_ref.clone(), &f.attributes.constraints, checked);
+                    // A checked field is always present, wrapping its (possibly null) value.
                     (
                         f.elem.name.as_str(),
-                        f.elem.r#type.elem.is_optional(),
-                        f.elem.r#type.elem.to_type_ref(&c.db),
+                        f.elem.r#type.elem.is_optional() && checked == type_ref,
+                        checked,
                     )
                 })
                 .collect(),
         }
     }
 }
+
+impl<'ir> From<&ClassWalker<'ir>> for PartialTypescriptClass<'ir> {
+    fn from(c: &ClassWalker<'ir>) -> PartialTypescriptClass<'ir> {
+        PartialTypescriptClass {
+            name: c.name(),
+            dynamic: c.item.attributes.get("dynamic_type").is_some(),
+            fields: c
+                .item
+                .elem
+                .static_fields
+                .iter()
+                .map(|f| {
+                    let streaming = f.attributes.streaming();
+                    (
+                        f.elem.name.as_str(),
+                        !streaming.not_null,
+                        partial_field_type(
+                            &TypescriptTypeRefs,
+                            &f.elem.r#type.elem,
+                            streaming,
+                            &c.db,
+                        ),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+struct TypescriptTypeRefs;
+
+impl PartialTypeRefs for TypescriptTypeRefs {
+    fn finished_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String {
+        r#type.to_type_ref_in_module(ir, "types.")
+    }
+
+    fn partial_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String {
+        r#type.to_partial_type_ref(ir, false)
+    }
+
+    fn non_null_partial_type_ref(&self, r#type: &FieldType, ir: &IntermediateRepr) -> String {
+        r#type.to_non_null_partial_type_ref(ir)
+    }
+
+    fn nullable(&self, type_ref: String) -> String {
+        format!("({type_ref} | null)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_refs::{load_test_ir, STREAMING_LINK};
+    use internal_baml_core::ir::IRHelper;
+
+    #[test]
+    fn test_partial_class_with_stream_attributes() {
+        let ir = load_test_ir(STREAMING_LINK);
+        let partial = PartialTypescriptClass::from(&ir.find_class("Link").unwrap());
+        assert_eq!(
+            partial.fields,
+            vec![
+                ("url", true, "(string | null)".to_string()),
+                ("title", false, "string".to_string()),
+                ("tags", false, "string[]".to_string()),
+                ("note", true, "(string | null)".to_string()),
+                ("next", false, "partial_types.Link".to_string()),
+            ]
+        );
+    }
+}
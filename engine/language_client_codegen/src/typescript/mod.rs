@@ -13,6 +13,7 @@ use internal_baml_core::{
 
 use self::typescript_language_features::{ToTypescript, TypescriptLanguageFeatures};
 use crate::dir_writer::FileCollector;
+use crate::type_refs::with_checks;
 
 #[derive(askama::Template)]
 #[template(path = "async_client.ts.j2", escape = "none")]
@@ -54,7 +55,7 @@ impl From<TypescriptClient> for SyncTypescriptClient {
 #[derive(Debug)]
 struct TypescriptFunction {
     name: String,
-    // partial_return_type: String,
+    partial_return_type: String,
     return_type: String,
     args: Vec<(String, bool, String)>,
 }
@@ -87,6 +88,10 @@ pub(crate) fn generate(
 ) -> Result<IndexMap<PathBuf, String>> {
     let mut collector = FileCollector::<TypescriptLanguageFeatures>::new();
     collector.add_template::<generate_types::TypescriptTypes>("types.ts", (ir, generator))?;
+    collector.add_template::<generate_types::TypescriptPartialTypes>(
+        "partial_types.ts",
+        (ir, generator),
+    )?;
     collector.add_template::<generate_types::TypeBuilder>("type_builder.ts", (ir, generator))?;
     collector.add_template::<AsyncTypescriptClient>("async_client.ts", (ir, generator))?;
     collector.add_template::<SyncTypescriptClient>("sync_client.ts", (ir, generator))?;
@@ -130,8 +135,12 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptCli
                         let (_function, _impl_) = c.item;
                         Ok(TypescriptFunction {
                             name: f.name().to_string(),
-                            return_type: f.elem().output().to_type_ref(ir),
-                            // partial_return_type: f.elem().output().to_partial_type_ref(ir),
+                            return_type: with_checks(
+                                f.elem().output().to_type_ref(ir),
+                                &f.elem().output_constraints,
+                                checked,
+                            ),
+                            partial_return_type: f.elem().output().to_partial_type_ref(ir, true),
                             args: f
                                 .inputs()
                                 .iter()
@@ -153,9 +162,9 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptCli
             .flatten()
             .collect();
 
-        let types = ir
-            .walk_classes()
-            .map(|c| c.name().to_string())
+        let types = ["Checked".to_string()]
+            .into_iter()
+            .chain(ir.walk_classes().map(|c| c.name().to_string()))
             .chain(ir.walk_enums().map(|e| e.name().to_string()))
             .collect();
         Ok(TypescriptClient {
@@ -201,57 +210,78 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptIni
     }
 }
 
+fn checked(type_ref: String) -> String {
+    format!("Checked<{type_ref}>")
+}
+
 trait ToTypeReferenceInClientDefinition {
     fn to_type_ref(&self, ir: &IntermediateRepr) -> String;
 
-    // fn to_partial_type_ref(&self, ir: &IntermediateRepr) -> String;
+    fn to_type_ref_in_module(&self, ir: &IntermediateRepr, module: &str) -> String;
+
+    /// The type of a value while it is streaming. `wrapped` leaves out the
+    /// `null` of a class, which is never null at the root or in a list.
+    fn to_partial_type_ref(&self, ir: &IntermediateRepr, wrapped: bool) -> String;
+
+    fn to_non_null_partial_type_ref(&self, ir: &IntermediateRepr) -> String;
 }
 
 impl ToTypeReferenceInClientDefinition for FieldType {
-    // fn to_partial_type_ref(&self, ir: &IntermediateRepr) -> String {
-    //     match self {
-    //         FieldType::Enum(name) => {
-    //             if ir
-    //                 .find_enum(name)
-    //                 .map(|e| e.item.attributes.get("dynamic_type").is_some())
-    //                 .unwrap_or(false)
-    //             {
-    //                 format!("(string | {name} | null)")
-    //             } else {
-    //                 format!("({name} | null)")
-    //             }
-    //         }
-    //         FieldType::Class(name) => format!("(RecursivePartialNull<{name}>)"),
-    //         FieldType::List(inner) => format!("{}[]", inner.to_partial_type_ref(ir)),
-    //         FieldType::Map(key, value) => {
-    //             format!(
-    //                 "(Record<{}, {}> | null)",
-    //                 key.to_type_ref(ir),
-    //                 value.to_partial_type_ref(ir)
-    //             )
-    //         }
-    //         FieldType::Primitive(r#type) => format!("({} | null)", r#type.to_typescript()),
-    //         FieldType::Union(inner) => format!(
-    //             "({} | null)",
-    //             inner
-    //                 .iter()
-    //                 .map(|t| t.to_partial_type_ref(ir))
-    //                 .collect::<Vec<_>>()
-    //                 .join(" | ")
-    //         ),
-    //         FieldType::Tuple(inner) => format!(
-    //             "([{}] | null)",
-    //             inner
-    //                 .iter()
-    //                 .map(|t| t.to_partial_type_ref(ir))
-    //                 .collect::<Vec<_>>()
-    //                 .join(", ")
-    //         ),
-    //         FieldType::Optional(inner) => format!("({} | null)", inner.to_partial_type_ref(ir)),
-    //     }
-    // }
+    fn to_partial_type_ref(&self, ir: &IntermediateRepr, wrapped: bool) -> String {
+        match self {
+            FieldType::Class(_) if wrapped => self.to_non_null_partial_type_ref(ir),
+            FieldType::List(_) | FieldType::Map(..) => self.to_non_null_partial_type_ref(ir),
+            FieldType::Optional(inner) => inner.to_partial_type_ref(ir, false),
+            _ => format!("({} | null)", self.to_non_null_partial_type_ref(ir)),
+        }
+    }
+
+    fn to_non_null_partial_type_ref(&self, ir: &IntermediateRepr) -> String {
+        match self {
+            FieldType::Enum(name) => {
+                if ir
+                    .find_enum(name)
+                    .map(|e| e.item.attributes.get("dynamic_type").is_some())
+                    .unwrap_or(false)
+                {
+                    format!("string | types.{name}")
+                } else {
+                    format!("types.{name}")
+                }
+            }
+            FieldType::Class(name) => format!("partial_types.{name}"),
+            FieldType::List(inner) => format!("{}[]", inner.to_partial_type_ref(ir, true)),
+            FieldType::Map(key, value) => {
+                format!(
+                    "Record<{}, {}>",
+                    key.to_type_ref(ir),
+                    value.to_partial_type_ref(ir, false)
+                )
+            }
+            FieldType::Primitive(r#type) => r#type.to_typescript(),
+            FieldType::Literal(value) => value.to_typescript(),
+            FieldType::Union(inner) => inner
+                .iter()
+                .map(|t| t.to_partial_type_ref(ir, true))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            FieldType::Tuple(inner) => format!(
+                "[{}]",
+                inner
+                    .iter()
+                    .map(|t| t.to_partial_type_ref(ir, false))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            FieldType::Optional(inner) => inner.to_non_null_partial_type_ref(ir),
+        }
+    }
 
     fn to_type_ref(&self, ir: &IntermediateRepr) -> String {
+        self.to_type_ref_in_module(ir, "")
+    }
+
+    fn to_type_ref_in_module(&self, ir: &IntermediateRepr, module: &str) -> String {
         match self {
             FieldType::Enum(name) => {
                 if ir
@@ -259,27 +289,32 @@ impl ToTypeReferenceInClientDefinition for FieldType {
                     .map(|e| e.item.attributes.get("dynamic_type").is_some())
                     .unwrap_or(false)
                 {
-                    format!("(string | {name})")
+                    format!("(string | {module}{name})")
                 } else {
-                    format!("{name}")
+                    format!("{module}{name}")
                 }
             }
-            FieldType::Class(name) => format!("{name}"),
+            FieldType::Class(name) => format!("{module}{name}"),
             FieldType::List(inner) => match inner.as_ref() {
                 FieldType::Union(_) | FieldType::Optional(_) => {
-                    format!("({})[]", inner.to_type_ref(ir))
+                    format!("({})[]", inner.to_type_ref_in_module(ir, module))
                 }
-                _ => format!("{}[]", inner.to_type_ref(ir)),
+                _ => format!("{}[]", inner.to_type_ref_in_module(ir, module)),
             },
             FieldType::Map(key, value) => {
-                format!("Record<{}, {}>", key.to_type_ref(ir), value.to_type_ref(ir))
+                format!(
+                    "Record<{}, {}>",
+                    key.to_type_ref_in_module(ir, module),
+                    value.to_type_ref_in_module(ir, module)
+                )
             }
             FieldType::Primitive(r#type) => r#type.to_typescript(),
+            FieldType::Literal(value) => value.to_typescript(),
             FieldType::Union(inner) => format!(
                 "{}",
                 inner
                     .iter()
-                    .map(|t| t.to_type_ref(ir))
+                    .map(|t| t.to_type_ref_in_module(ir, module))
                     .collect::<Vec<_>>()
                     .join(" | ")
             ),
@@ -287,11 +322,13 @@ impl ToTypeReferenceInClientDefinition for FieldType {
                 "[{}]",
                 inner
                     .iter()
-                    .map(|t| t.to_type_ref(ir))
+                    .map(|t| t.to_type_ref_in_module(ir, module))
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
-            FieldType::Optional(inner) => format!("{} | null", inner.to_type_ref(ir)),
+            FieldType::Optional(inner) => {
+                format!("{} | null", inner.to_type_ref_in_module(ir, module))
+            }
         }
     }
 }
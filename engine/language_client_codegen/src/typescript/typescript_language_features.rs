@@ -1,5 +1,5 @@
 use crate::dir_writer::LanguageFeatures;
-use baml_types::{BamlMediaType, TypeValue};
+use baml_types::{BamlMediaType, LiteralValue, TypeValue};
 
 #[derive(Default)]
 pub(super) struct TypescriptLanguageFeatures {}
@@ -45,10 +45,34 @@ impl ToTypescript for TypeValue {
     }
 }
 
+impl ToTypescript for LiteralValue {
+    fn to_typescript(&self) -> String {
+        match self {
+            LiteralValue::String(value) => typescript_string(value),
+            LiteralValue::Int(value) => value.to_string(),
+            LiteralValue::Bool(value) => value.to_string(),
+        }
+    }
+}
+
+/// A TypeScript string literal. JSON escapes are all valid in TypeScript
+/// strings, unlike Rust's `{:?}` escapes such as `\u{7}`.
+fn typescript_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_string_literal() {
+        assert_eq!(
+            LiteralValue::String("say \"hi\"\n\u{7}".into()).to_typescript(),
+            r#""say \"hi\"\n\u0007""#
+        );
+    }
+
     #[test]
     fn test_eslint_before_ts_nocheck() {
         let content = TypescriptLanguageFeatures::CONTENT_PREFIX;
@@ -1,4 +1,4 @@
-from typing import Any, Callable, Dict, Optional, Tuple
+from typing import Any, Callable, Dict, Optional, Tuple, Union
 
 class FunctionResult:
     """The result of a BAML function call.
@@ -18,6 +18,10 @@ class FunctionResult:
     def parsed(self) -> Any: ...
     # Returns True if the function call was successful, False otherwise
     def is_ok(self) -> bool: ...
+    # Whether each value in the parsed result is "pending" or "complete"
+    def completion_states(self) -> Any: ...
+    # Tokens and cost of every attempt, including failed retries and fallbacks
+    def usage(self) -> Dict[str, Any]: ...
 
 class FunctionResultStream:
     """The result of a BAML function stream.
@@ -69,6 +73,9 @@ class BamlAudioPy:
 
 class RuntimeContextManager:
     def upsert_tags(self, tags: Dict[str, Any]) -> None: ...
+    def set_response_cache(
+        self, policy: Union[None, bool, str, Dict[str, Any]]
+    ) -> None: ...
     def deep_clone(self) -> RuntimeContextManager: ...
     def context_depth(self) -> int: ...
 
@@ -111,6 +118,8 @@ class BamlRuntime:
     def create_context_manager(self) -> RuntimeContextManager: ...
     def flush(self) -> None: ...
     def drain_stats(self) -> TraceStats: ...
+    def metrics(self) -> Dict[str, Any]: ...
+    def metrics_prometheus(self) -> str: ...
     def set_log_event_callback(
         self, handler: Optional[Callable[[BamlLogEvent], None]]
     ) -> None: ...
@@ -153,6 +162,8 @@ class TraceStats:
     def sent(self) -> int: ...
     @property
     def done(self) -> int: ...
+    @property
+    def usage(self) -> Dict[str, Any]: ...
 
 class BamlSpan:
     @staticmethod
@@ -70,6 +70,12 @@ class CtxManager:
         mngr = self.__ctx()
         mngr.upsert_tags(tags)
 
+    def set_response_cache(
+        self, policy: typing.Union[None, bool, str, typing.Dict[str, typing.Any]]
+    ) -> None:
+        mngr = self.__ctx()
+        mngr.set_response_cache(policy)
+
     def get(self) -> RuntimeContextManager:
         return self.__ctx()
 
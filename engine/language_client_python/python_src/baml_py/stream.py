@@ -5,7 +5,7 @@ from .baml_py import (
     SyncFunctionResultStream,
     RuntimeContextManager,
 )
-from typing import Callable, Generic, Optional, TypeVar
+from typing import Any, Callable, Dict, Generic, Optional, Tuple, TypeVar
 import threading
 import asyncio
 import concurrent.futures
@@ -24,6 +24,8 @@ class BamlStream(Generic[PartialOutputType, FinalOutputType]):
     __task: Optional[threading.Thread]
     __event_queue: queue.Queue[Optional[FunctionResult]]
     __future: concurrent.futures.Future[FunctionResult]
+    __done: Optional[Tuple[asyncio.AbstractEventLoop, asyncio.Future[FunctionResult]]]
+    __cancelled: bool
 
     def __init__(
         self,
@@ -39,18 +41,29 @@ class BamlStream(Generic[PartialOutputType, FinalOutputType]):
         self.__task = None
         self.__event_queue = queue.Queue()
         self.__future = concurrent.futures.Future()  # Initialize the future here
+        self.__done = None
+        self.__cancelled = False
 
     def __enqueue(self, data: FunctionResult) -> None:
         self.__event_queue.put_nowait(data)
 
     async def __drive_to_completion(self) -> FunctionResult:
         try:
-            retval = await self.__ffi_stream.done(self.__ctx_manager)
-
-            self.__future.set_result(retval)
+            done = asyncio.ensure_future(self.__ffi_stream.done(self.__ctx_manager))
+            self.__done = (asyncio.get_running_loop(), done)
+            if self.__cancelled:
+                done.cancel()
+            retval = await done
+
+            if not self.__future.cancelled():
+                self.__future.set_result(retval)
             return retval
+        except asyncio.CancelledError:
+            self.__future.cancel()
+            raise
         except Exception as e:
-            self.__future.set_exception(e)
+            if not self.__future.cancelled():
+                self.__future.set_exception(e)
             raise
         finally:
             self.__event_queue.put_nowait(None)
@@ -62,23 +75,66 @@ class BamlStream(Generic[PartialOutputType, FinalOutputType]):
         return self.__future
 
     def threading_target(self):
-        asyncio.run(self.__drive_to_completion(), debug=True)
-
-    async def __aiter__(self):
-        # TODO: This is deliberately __aiter__ and not __iter__ because we want to
-        # ensure that the caller is using an async for loop.
-        # Eventually we do not want to create a new thread for each stream.
+        try:
+            asyncio.run(self.__drive_to_completion(), debug=True)
+        except asyncio.CancelledError:
+            pass
+
+    def cancel(self) -> None:
+        """Stops the stream and its request to the LLM.
+
+        Cancelling the task that iterates the stream or awaits its final response
+        does the same. Awaiting the final response afterwards raises
+        `asyncio.CancelledError`.
+        """
+        self.__cancelled = True
+        if self.__done is not None:
+            loop, done = self.__done
+            loop.call_soon_threadsafe(done.cancel)
+
+    async def __events(self):
         self.__drive_to_completion_in_bg()
+        loop = asyncio.get_running_loop()
         while True:
-            event = self.__event_queue.get()
+            try:
+                event = await loop.run_in_executor(None, self.__event_queue.get)
+            except asyncio.CancelledError:
+                self.cancel()
+                raise
             if event is None:
                 break
             if event.is_ok():
-                yield self.__partial_coerce(event.parsed())
+                yield event
+
+    async def __aiter__(self):
+        # TODO: This is deliberately __aiter__ and not __iter__ because we want to
+        # ensure that the caller is using an async for loop.
+        # Eventually we do not want to create a new thread for each stream.
+        async for event in self.__events():
+            yield self.__partial_coerce(event.parsed())
+
+    async def with_states(self):
+        """Iterates the stream like `async for`, also yielding the completion
+        states of each partial response.
+        """
+        async for event in self.__events():
+            yield self.__partial_coerce(event.parsed()), event.completion_states()
 
     async def get_final_response(self):
         final = self.__drive_to_completion_in_bg()
-        return self.__final_coerce((await asyncio.wrap_future(final)).parsed())
+        try:
+            return self.__final_coerce((await asyncio.wrap_future(final)).parsed())
+        except asyncio.CancelledError:
+            self.cancel()
+            raise
+
+    async def get_usage(self) -> Dict[str, Any]:
+        """Tokens and cost of the stream, including failed retries and fallbacks.
+
+        Waits for the stream to finish.
+        """
+        final = self.__drive_to_completion_in_bg()
+        return (await asyncio.wrap_future(final)).usage()
 
 
 class BamlSyncStream(Generic[PartialOutputType, FinalOutputType]):
@@ -129,18 +185,29 @@ class BamlSyncStream(Generic[PartialOutputType, FinalOutputType]):
     def __threading_target(self):
         self.__drive_to_completion()
 
-    def __iter__(self):
-        # TODO: This is deliberately __iter__ and not __aiter__ because we want to
-        # ensure that the caller is NOT using an async for loop.
+    def __events(self):
         self.__drive_to_completion_in_bg()
         while True:
             event = self.__event_queue.get()
             if event is None:
                 break
             if event.is_ok():
-                yield self.__partial_coerce(event.parsed())
+                yield event
 
-    def get_final_response(self):
+    def __iter__(self):
+        # TODO: This is deliberately __iter__ and not __aiter__ because we want to
+        # ensure that the caller is NOT using an async for loop.
+        for event in self.__events():
+            yield self.__partial_coerce(event.parsed())
+
+    def with_states(self):
+        """Iterates the stream like `for`, also yielding the completion states
+        of each partial response.
+        """
+        for event in self.__events():
+            yield self.__partial_coerce(event.parsed()), event.completion_states()
+
+    def __wait_for_result(self) -> FunctionResult:
         self.__drive_to_completion_in_bg()
         if self.__task is not None:
             self.__task.join()
@@ -153,4 +220,14 @@ class BamlSyncStream(Generic[PartialOutputType, FinalOutputType]):
                 "BAML Internal error: Stream did not complete successfully. Please report this issue."
             )
 
-        return self.__final_coerce(self.__result.parsed())
+        return self.__result
+
+    def get_final_response(self):
+        return self.__final_coerce(self.__wait_for_result().parsed())
+
+    def get_usage(self) -> Dict[str, Any]:
+        """Tokens and cost of the stream, including failed retries and fallbacks.
+
+        Waits for the stream to finish.
+        """
+        return self.__wait_for_result().usage()
@@ -137,7 +137,9 @@ impl BamlError {
                     PyErr::new::<BamlError, _>(format!("Unexpected error from BAML: {}", err))
                 }
                 LLMResponse::LLMFailure(failed) => match &failed.code {
-                    baml_runtime::internal::llm_client::ErrorCode::Other(2) => {
+                    baml_runtime::internal::llm_client::ErrorCode::Other(2)
+                    | baml_runtime::internal::llm_client::ErrorCode::Timeout
+                    | baml_runtime::internal::llm_client::ErrorCode::Cancelled => {
                         PyErr::new::<BamlClientError, _>(format!(
                             "Something went wrong with the LLM client: {}",
                             err
@@ -9,8 +9,10 @@ use crate::types::type_builder::TypeBuilder;
 use crate::types::ClientRegistry;
 use baml_runtime::runtime_interface::ExperimentalTracingInterface;
 use baml_runtime::BamlRuntime as CoreBamlRuntime;
+use baml_runtime::CancellationToken;
 use pyo3::prelude::{pymethods, PyResult};
 use pyo3::{pyclass, PyObject, Python, ToPyObject};
+use pythonize::pythonize;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -136,15 +138,23 @@ impl BamlRuntime {
         log::debug!("pyo3 call_function parsed args into: {:#?}", args_map);
 
         let baml_runtime = self.inner.clone();
-        let ctx_mng = ctx.inner.clone();
+        let cancellation = CancellationToken::new();
+        let ctx_mng = ctx.inner.with_cancellation(cancellation.clone());
         let tb = tb.map(|tb| tb.inner.clone());
         let cb = cb.map(|cb| cb.inner.clone());
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let ctx_mng = ctx_mng;
-            let (result, _) = baml_runtime
-                .call_function(function_name, &args_map, &ctx_mng, tb.as_ref(), cb.as_ref())
-                .await;
+            // Cancelling the asyncio task drops this future, which cancels the
+            // call. The call runs in its own task so that it's still traced.
+            let _cancel_on_drop = cancellation.drop_guard();
+            let call = tokio::spawn(async move {
+                baml_runtime
+                    .call_function(function_name, &args_map, &ctx_mng, tb.as_ref(), cb.as_ref())
+                    .await
+            });
+            let (result, _) = call
+                .await
+                .map_err(|e| BamlError::from_anyhow(anyhow::Error::from(e)))?;
 
             result
                 .map(FunctionResult::from)
@@ -283,6 +293,18 @@ impl BamlRuntime {
         self.inner.drain_stats().into()
     }
 
+    /// Counters and latencies of every function call made so far, as a dict.
+    #[pyo3()]
+    fn metrics(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(pythonize(py, &self.inner.metrics())?)
+    }
+
+    /// The same metrics, in the Prometheus text exposition format.
+    #[pyo3()]
+    fn metrics_prometheus(&self) -> String {
+        self.inner.metrics().to_prometheus()
+    }
+
     #[pyo3()]
     fn set_log_event_callback(&self, callback: Option<PyObject>) -> PyResult<()> {
         let callback = callback.clone();
@@ -1,3 +1,4 @@
+use baml_runtime::CancellationToken;
 use pyo3::prelude::{pymethods, PyResult};
 use pyo3::{PyObject, PyRefMut, Python};
 
@@ -87,15 +88,22 @@ impl FunctionResultStream {
             }
         });
 
-        let ctx_mng = ctx.inner.clone();
+        let cancellation = CancellationToken::new();
+        let ctx_mng = ctx.inner.with_cancellation(cancellation.clone());
         let tb = self.tb.as_ref().map(|tb| tb.clone());
         let cb = self.cb.as_ref().map(|cb| cb.clone());
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let ctx_mng = ctx_mng;
-            let mut locked = inner.lock().await;
-            let (res, _) = locked
-                .run(on_event, &ctx_mng, tb.as_ref(), cb.as_ref())
-                .await;
+            // See `BamlRuntime::call_function`.
+            let _cancel_on_drop = cancellation.drop_guard();
+            let run = tokio::spawn(async move {
+                let mut locked = inner.lock().await;
+                locked
+                    .run(on_event, &ctx_mng, tb.as_ref(), cb.as_ref())
+                    .await
+            });
+            let (res, _) = run
+                .await
+                .map_err(|e| BamlError::from_anyhow(anyhow::Error::from(e)))?;
             res.map(FunctionResult::from)
                 .map_err(BamlError::from_anyhow)
         })
@@ -25,4 +25,19 @@ impl FunctionResult {
 
         Ok(pythonize(py, &BamlValue::from(parsed))?)
     }
+
+    /// Whether each value in the parsed result is "pending" or "complete".
+    fn completion_states(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let states = self
+            .inner
+            .completion_states()
+            .map_err(BamlError::from_anyhow)?;
+
+        Ok(pythonize(py, &states)?)
+    }
+
+    /// Tokens and cost of every attempt behind this result, as a dict.
+    fn usage(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(pythonize(py, &self.inner.usage())?)
+    }
 }
@@ -1,3 +1,4 @@
+use baml_runtime::CachePolicy;
 use pyo3::prelude::{pymethods, PyResult};
 use pyo3::{PyObject, Python, ToPyObject};
 
@@ -21,6 +22,18 @@ impl RuntimeContextManager {
         Ok(true)
     }
 
+    #[pyo3()]
+    fn set_response_cache(&self, py: Python<'_>, policy: PyObject) -> PyResult<()> {
+        let policy = match parse_py_type(policy.into_bound(py).to_object(py), true)? {
+            Some(policy) => serde_json::to_value(policy)
+                .map_err(|e| BamlError::new_err(format!("Failed to parse cache policy: {e}")))?,
+            None => serde_json::Value::Null,
+        };
+        let policy = CachePolicy::from_value(policy).map_err(BamlError::from_anyhow)?;
+        self.inner.set_response_cache(policy);
+        Ok(())
+    }
+
     #[pyo3()]
     fn deep_clone(&self) -> Self {
         RuntimeContextManager {
@@ -1,4 +1,6 @@
-use pyo3::pymethods;
+use pyo3::prelude::{pymethods, PyResult};
+use pyo3::{PyObject, Python};
+use pythonize::pythonize;
 
 crate::lang_wrapper!(TraceStats, baml_runtime::InnerTraceStats);
 
@@ -34,15 +36,24 @@ impl TraceStats {
         self.inner.done
     }
 
+    /// Tokens and cost of the function calls that finished, as a dict.
+    #[getter]
+    pub fn usage(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(pythonize(py, &self.inner.usage)?)
+    }
+
     pub fn __repr__(&self) -> String {
         format!(
-            "TraceStats(failed={}, started={}, finalized={}, submitted={}, sent={}, done={})",
+            "TraceStats(failed={}, started={}, finalized={}, submitted={}, sent={}, done={}, llm_calls={}, total_tokens={}, cost_usd={})",
             self.failed(),
             self.started(),
             self.finalized(),
             self.submitted(),
             self.sent(),
-            self.done()
+            self.done(),
+            self.inner.usage.llm_calls,
+            self.inner.usage.total_tokens,
+            self.inner.usage.cost_usd
         )
     }
 }
@@ -54,6 +54,23 @@ impl FunctionResult {
         }
     }
 
+    /// Whether each value in the parsed result is "pending" or "complete",
+    /// as a Hash.
+    pub fn completion_states(ruby: &Ruby, rb_self: &FunctionResult) -> Result<Value> {
+        match rb_self.inner.completion_states() {
+            Ok(states) => serde_magnus::serialize(&states),
+            Err(_) => Err(Error::new(
+                ruby.exception_runtime_error(),
+                format!("Failed to parse LLM response: {}", rb_self.inner),
+            )),
+        }
+    }
+
+    /// Tokens and cost of every attempt behind this result, as a Hash.
+    pub fn usage(&self) -> Result<Value> {
+        serde_magnus::serialize(&self.inner.usage())
+    }
+
     /// For usage in magnus::init
     ///
     /// TODO: use traits and macros to implement this
@@ -64,6 +81,11 @@ impl FunctionResult {
             "parsed_using_types",
             method!(FunctionResult::parsed_using_types, 1),
         )?;
+        cls.define_method(
+            "completion_states",
+            method!(FunctionResult::completion_states, 0),
+        )?;
+        cls.define_method("usage", method!(FunctionResult::usage, 0))?;
 
         Ok(())
     }
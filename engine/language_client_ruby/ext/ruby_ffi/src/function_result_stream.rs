@@ -3,8 +3,10 @@ use std::sync::Arc;
 
 use magnus::{class, method, Module, RModule, Ruby};
 
+use super::types::cancellation_token::CancellationToken;
 use super::types::runtime_ctx_manager::RuntimeContextManager;
 use crate::function_result::FunctionResult;
+use crate::gvl;
 use crate::Error;
 use crate::Result;
 
@@ -29,26 +31,54 @@ impl FunctionResultStream {
         ruby: &Ruby,
         rb_self: &FunctionResultStream,
         ctx: &RuntimeContextManager,
+        cancellation_token: Option<&CancellationToken>,
     ) -> Result<FunctionResult> {
+        let token = cancellation_token
+            .map(|t| t.inner.clone())
+            .unwrap_or_default();
+        let ctx = ctx.inner.with_cancellation(token.clone());
+
+        // Other Ruby threads run while we're off the GVL.
+        let Ok(mut inner) = rb_self.inner.try_borrow_mut() else {
+            return Err(Error::new(
+                ruby.exception_runtime_error(),
+                "The stream is already being consumed by another thread",
+            ));
+        };
+
+        // If the block raises or breaks, the stream is cancelled and the error
+        // is re-raised once it stops.
+        let block_error = RefCell::new(None);
         let on_event = if ruby.block_given() {
             let proc = ruby.block_proc()?;
-            Some(move |event: baml_runtime::FunctionResult| {
-                // ignore errors if they happen
-                let _ = proc.call::<_, magnus::Value>((FunctionResult::new(event),));
-                ()
+            Some(|event: baml_runtime::FunctionResult| {
+                if block_error.borrow().is_some() {
+                    return;
+                }
+                let result =
+                    gvl::with_gvl(|| proc.call::<_, magnus::Value>((FunctionResult::new(event),)));
+                if let Err(e) = result {
+                    *block_error.borrow_mut() = Some(e);
+                    token.cancel();
+                }
             })
         } else {
             None
         };
 
-        match rb_self.t.block_on(
-            rb_self
-                .inner
-                .borrow_mut()
-                .run(on_event, &ctx.inner, None, None),
-        ) {
-            (Ok(res), _) => Ok(FunctionResult::new(res)),
-            (Err(e), _) => Err(Error::new(
+        let result = gvl::without_gvl(&token, || {
+            rb_self.t.block_on(inner.run(on_event, &ctx, None, None))
+        });
+        if let Some(e) = block_error.into_inner() {
+            return Err(e);
+        }
+        match result {
+            None => Err(Error::new(
+                ruby.exception_interrupt(),
+                "The stream was interrupted before it started",
+            )),
+            Some((Ok(res), _)) => Ok(FunctionResult::new(res)),
+            Some((Err(e), _)) => Err(Error::new(
                 ruby.exception_runtime_error(),
                 format!("{:?}", e),
             )),
@@ -61,7 +91,7 @@ impl FunctionResultStream {
     pub fn define_in_ruby(module: &RModule) -> Result<()> {
         let cls = module.define_class("FunctionResultStream", class::object())?;
 
-        cls.define_method("done", method!(FunctionResultStream::done, 1))?;
+        cls.define_method("done", method!(FunctionResultStream::done, 2))?;
 
         Ok(())
     }
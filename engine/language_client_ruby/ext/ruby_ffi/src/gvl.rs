@@ -0,0 +1,80 @@
+use std::ffi::c_void;
+
+/// Runs `f` without holding the GVL, so that other Ruby threads keep running
+/// while we wait on the LLM.
+///
+/// If Ruby interrupts the thread in the meantime (`Thread#kill`, `Thread#raise`,
+/// `Timeout.timeout`), `token` is cancelled so that `f` returns early. Returns
+/// `None` if an interrupt was already pending and `f` never ran. Ruby raises
+/// the interrupt once we return to it.
+///
+/// `f` must not call into Ruby.
+pub(crate) fn without_gvl<F, R>(token: &baml_runtime::CancellationToken, f: F) -> Option<R>
+where
+    F: FnOnce() -> R,
+{
+    struct Call<F, R> {
+        f: Option<F>,
+        result: Option<R>,
+    }
+
+    unsafe extern "C" fn call<F: FnOnce() -> R, R>(data: *mut c_void) -> *mut c_void {
+        let call = &mut *(data as *mut Call<F, R>);
+        if let Some(f) = call.f.take() {
+            call.result = Some(f());
+        }
+        std::ptr::null_mut()
+    }
+
+    unsafe extern "C" fn unblock(data: *mut c_void) {
+        (*(data as *const baml_runtime::CancellationToken)).cancel();
+    }
+
+    let mut data = Call {
+        f: Some(f),
+        result: None,
+    };
+    // Unlike `rb_thread_call_without_gvl`, this doesn't raise pending
+    // interrupts itself, which would unwind through our frames.
+    unsafe {
+        rb_sys::rb_thread_call_without_gvl2(
+            Some(call::<F, R>),
+            &mut data as *mut Call<F, R> as *mut c_void,
+            Some(unblock),
+            token as *const baml_runtime::CancellationToken as *mut c_void,
+        );
+    }
+    data.result
+}
+
+/// Runs `f` with the GVL, from inside `without_gvl`. This is how the callbacks
+/// of a call made without the GVL get to call into Ruby.
+pub(crate) fn with_gvl<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct Call<F, R> {
+        f: Option<F>,
+        result: Option<R>,
+    }
+
+    unsafe extern "C" fn call<F: FnOnce() -> R, R>(data: *mut c_void) -> *mut c_void {
+        let call = &mut *(data as *mut Call<F, R>);
+        if let Some(f) = call.f.take() {
+            call.result = Some(f());
+        }
+        std::ptr::null_mut()
+    }
+
+    let mut data = Call {
+        f: Some(f),
+        result: None,
+    };
+    unsafe {
+        rb_sys::rb_thread_call_with_gvl(
+            Some(call::<F, R>),
+            &mut data as *mut Call<F, R> as *mut c_void,
+        );
+    }
+    data.result.expect("rb_thread_call_with_gvl always calls f")
+}
@@ -7,10 +7,12 @@ use std::sync::Arc;
 
 use function_result::FunctionResult;
 use function_result_stream::FunctionResultStream;
+use types::cancellation_token::CancellationToken;
 use types::runtime_ctx_manager::RuntimeContextManager;
 
 mod function_result;
 mod function_result_stream;
+mod gvl;
 mod ruby_to_json;
 mod types;
 
@@ -103,6 +105,31 @@ impl BamlRuntimeFfi {
         }
     }
 
+    /// Span counts and usage since the last call, as a Hash.
+    pub fn drain_stats(&self) -> Result<magnus::Value> {
+        use baml_runtime::runtime_interface::ExperimentalTracingInterface;
+        let stats = self.inner.drain_stats();
+        serde_magnus::serialize(&serde_json::json!({
+            "failed": stats.failed,
+            "started": stats.started,
+            "finalized": stats.finalized,
+            "submitted": stats.submitted,
+            "sent": stats.sent,
+            "done": stats.done,
+            "usage": stats.usage,
+        }))
+    }
+
+    /// Counters and latencies of every function call made so far, as a Hash.
+    pub fn metrics(&self) -> Result<magnus::Value> {
+        serde_magnus::serialize(&self.inner.metrics())
+    }
+
+    /// The same metrics, in the Prometheus text exposition format.
+    pub fn metrics_prometheus(&self) -> String {
+        self.inner.metrics().to_prometheus()
+    }
+
     pub fn call_function(
         ruby: &Ruby,
         rb_self: &BamlRuntimeFfi,
@@ -111,6 +138,7 @@ impl BamlRuntimeFfi {
         ctx: &RuntimeContextManager,
         type_registry: Option<&types::type_builder::TypeBuilder>,
         client_registry: Option<&types::client_registry::ClientRegistry>,
+        cancellation_token: Option<&CancellationToken>,
     ) -> Result<FunctionResult> {
         let args = match ruby_to_json::RubyToJson::convert_hash_to_json(args) {
             Ok(args) => args.into_iter().collect(),
@@ -122,15 +150,31 @@ impl BamlRuntimeFfi {
             }
         };
 
-        let retval = match rb_self.t.block_on(rb_self.inner.call_function(
-            function_name.clone(),
-            &args,
-            &ctx.inner,
-            type_registry.map(|t| &t.inner),
-            client_registry.map(|c| c.inner.borrow_mut()).as_deref(),
-        )) {
-            (Ok(res), _) => Ok(FunctionResult::new(res)),
-            (Err(e), _) => Err(Error::new(
+        let token = cancellation_token
+            .map(|t| t.inner.clone())
+            .unwrap_or_default();
+        let ctx = ctx.inner.with_cancellation(token.clone());
+        let type_registry = type_registry.map(|t| t.inner.clone());
+        // Other Ruby threads may use the registry while we're off the GVL.
+        let client_registry = client_registry.map(|c| c.inner.borrow().clone());
+
+        let result = gvl::without_gvl(&token, || {
+            rb_self.t.block_on(rb_self.inner.call_function(
+                function_name.clone(),
+                &args,
+                &ctx,
+                type_registry.as_ref(),
+                client_registry.as_ref(),
+            ))
+        });
+
+        let retval = match result {
+            None => Err(Error::new(
+                ruby.exception_interrupt(),
+                format!("{function_name} was interrupted before it started"),
+            )),
+            Some((Ok(res), _)) => Ok(FunctionResult::new(res)),
+            Some((Err(e), _)) => Err(Error::new(
                 ruby.exception_runtime_error(),
                 format!(
                     "{:?}",
@@ -225,7 +269,13 @@ fn init(ruby: &Ruby) -> Result<()> {
         "create_context_manager",
         method!(BamlRuntimeFfi::create_context_manager, 0),
     )?;
-    runtime_class.define_method("call_function", method!(BamlRuntimeFfi::call_function, 5))?;
+    runtime_class.define_method("drain_stats", method!(BamlRuntimeFfi::drain_stats, 0))?;
+    runtime_class.define_method("metrics", method!(BamlRuntimeFfi::metrics, 0))?;
+    runtime_class.define_method(
+        "metrics_prometheus",
+        method!(BamlRuntimeFfi::metrics_prometheus, 0),
+    )?;
+    runtime_class.define_method("call_function", method!(BamlRuntimeFfi::call_function, 6))?;
     runtime_class.define_method(
         "stream_function",
         method!(BamlRuntimeFfi::stream_function, 5),
@@ -244,6 +294,7 @@ fn init(ruby: &Ruby) -> Result<()> {
     types::type_builder::FieldType::define_in_ruby(&module)?;
 
     types::client_registry::ClientRegistry::define_in_ruby(&module)?;
+    types::cancellation_token::CancellationToken::define_in_ruby(&module)?;
     types::media::Audio::define_in_ruby(&module)?;
     types::media::Image::define_in_ruby(&module)?;
 
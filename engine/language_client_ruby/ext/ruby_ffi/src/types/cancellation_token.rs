@@ -0,0 +1,34 @@
+use magnus::{class, function, method, Module, Object, RModule};
+
+use crate::Result;
+
+#[magnus::wrap(class = "Baml::Ffi::CancellationToken", free_immediately, size)]
+pub(crate) struct CancellationToken {
+    pub(crate) inner: baml_runtime::CancellationToken,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self {
+            inner: baml_runtime::CancellationToken::new(),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancel()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+
+    pub fn define_in_ruby(module: &RModule) -> Result<()> {
+        let cls = module.define_class("CancellationToken", class::object())?;
+
+        cls.define_singleton_method("new", function!(CancellationToken::new, 0))?;
+        cls.define_method("cancel", method!(CancellationToken::cancel, 0))?;
+        cls.define_method("cancelled?", method!(CancellationToken::is_cancelled, 0))?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod cancellation_token;
 pub(crate) mod client_registry;
 mod lang_wrapper;
 pub(crate) mod media;
@@ -10,6 +10,7 @@ require_relative "struct"
 
 module Baml
   ClientRegistry = Baml::Ffi::ClientRegistry
+  CancellationToken = Baml::Ffi::CancellationToken
   Image = Baml::Ffi::Image
   Audio = Baml::Ffi::Audio
 
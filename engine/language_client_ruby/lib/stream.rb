@@ -26,10 +26,12 @@ module Baml
 
     def initialize(
       ffi_stream:,
-      ctx_manager:
+      ctx_manager:,
+      cancellation_token: nil
     )
       @ffi_stream = ffi_stream
       @ctx_manager = ctx_manager
+      @cancellation_token = cancellation_token
 
       @final_response = nil
     end
@@ -37,7 +39,8 @@ module Baml
     # Calls the given block once for each event in the stream, where event is a parsed
     # partial response. Returns `self` to enable chaining `.get_final_response`.
     #
-    # Must be called with a block.
+    # Must be called with a block. Breaking out of the block, or raising from it,
+    # cancels the stream.
     #
     # @yieldparam [PartialType] event the parsed partial response
     # @return [BamlStream] self
@@ -45,13 +48,21 @@ module Baml
     def each(&block)
       # Implementing this and include-ing Enumerable allows users to treat this as a Ruby
       # collection: https://ruby-doc.org/3.1.6/Enumerable.html#module-Enumerable-label-Usage
-      if @final_response == nil
-        @final_response = @ffi_stream.done(@ctx_manager) do |event|
-          block.call event.parsed_using_types(Baml::PartialTypes)
-        end
+      each_event do |event|
+        block.call event.parsed_using_types(Baml::PartialTypes)
       end
+    end
 
-      self
+    # Like `each`, but also yields the completion states of each partial response.
+    #
+    # @yieldparam [PartialType] event the parsed partial response
+    # @yieldparam [Hash, String] states the completion states of the partial response
+    # @return [BamlStream] self
+    sig { params(block: T.proc.params(event: PartialType, states: T.untyped).void).returns(BamlStream)}
+    def each_with_states(&block)
+      each_event do |event|
+        block.call event.parsed_using_types(Baml::PartialTypes), event.completion_states
+      end
     end
 
 
@@ -61,10 +72,35 @@ module Baml
     sig {returns(FinalType)}
     def get_final_response
       if @final_response == nil
-        @final_response = @ffi_stream.done(@ctx_manager)
+        @final_response = @ffi_stream.done(@ctx_manager, @cancellation_token)
       end
 
       @final_response.parsed_using_types(Baml::Types)
     end
+
+    # Gets the tokens and cost of the stream, including failed retries and
+    # fallbacks. Waits for the stream to finish.
+    #
+    # @return [Hash] the usage summary
+    sig {returns(T::Hash[String, T.untyped])}
+    def usage
+      if @final_response == nil
+        @final_response = @ffi_stream.done(@ctx_manager, @cancellation_token)
+      end
+
+      @final_response.usage
+    end
+
+    private
+
+    # Drives the stream to completion, calling the block with each raw event.
+    sig { params(block: T.proc.params(event: T.untyped).void).returns(BamlStream)}
+    def each_event(&block)
+      if @final_response == nil
+        @final_response = @ffi_stream.done(@ctx_manager, @cancellation_token, &block)
+      end
+
+      self
+    end
   end
 end
\ No newline at end of file
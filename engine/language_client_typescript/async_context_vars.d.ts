@@ -1,4 +1,10 @@
 import { BamlSpan, RuntimeContextManager, BamlRuntime, BamlLogEvent } from './native';
+/**
+ * Runs `call` with a copy of `ctx` that aborting `signal` cancels, which stops the call, its
+ * retries and its fallbacks. The abort listener is removed once the call settles, so a
+ * long-lived signal doesn't keep one for every call made with it.
+ */
+export declare function withAbortSignal<T>(ctx: RuntimeContextManager, signal: AbortSignal | undefined, call: (ctx: RuntimeContextManager) => Promise<T>): Promise<T>;
 export declare class BamlCtxManager {
     private rt;
     private ctx;
@@ -6,7 +12,9 @@ export declare class BamlCtxManager {
     allowResets(): boolean;
     reset(): void;
     upsertTags(tags: Record<string, string>): void;
+    setResponseCache(policy: boolean | string | Record<string, any> | null): void;
     cloneContext(): RuntimeContextManager;
+    callWithSignal<T>(signal: AbortSignal | undefined, call: (ctx: RuntimeContextManager) => Promise<T>): Promise<T>;
     startTrace(name: string, args: Record<string, any>): [RuntimeContextManager, BamlSpan];
     endTrace(span: BamlSpan, response: any): void;
     flush(): void;
@@ -1,8 +1,33 @@
 "use strict";
 Object.defineProperty(exports, "__esModule", { value: true });
-exports.BamlCtxManager = void 0;
+exports.BamlCtxManager = exports.withAbortSignal = void 0;
 const native_1 = require("./native");
 const async_hooks_1 = require("async_hooks");
+/**
+ * Runs `call` with a copy of `ctx` that aborting `signal` cancels, which stops the call, its
+ * retries and its fallbacks. The abort listener is removed once the call settles, so a
+ * long-lived signal doesn't keep one for every call made with it.
+ */
+async function withAbortSignal(ctx, signal, call) {
+    if (!signal) {
+        return call(ctx);
+    }
+    const token = new native_1.CancellationToken();
+    const cancel = () => token.cancel();
+    if (signal.aborted) {
+        token.cancel();
+    }
+    else {
+        signal.addEventListener('abort', cancel, { once: true });
+    }
+    try {
+        return await call(ctx.withCancellation(token));
+    }
+    finally {
+        signal.removeEventListener('abort', cancel);
+    }
+}
+exports.withAbortSignal = withAbortSignal;
 class BamlCtxManager {
     rt;
     ctx;
@@ -32,6 +57,10 @@ class BamlCtxManager {
         const manager = this.ctx.getStore();
         manager.upsertTags(tags);
     }
+    setResponseCache(policy) {
+        const manager = this.ctx.getStore();
+        manager.setResponseCache(policy);
+    }
     cloneContext() {
         let store = this.ctx.getStore();
         if (store === undefined) {
@@ -40,6 +69,9 @@ class BamlCtxManager {
         }
         return store.deepClone();
     }
+    callWithSignal(signal, call) {
+        return withAbortSignal(this.cloneContext(), signal, call);
+    }
     startTrace(name, args) {
         const mng = this.cloneContext();
         return [mng, native_1.BamlSpan.new(this.rt, name, args, mng)];
@@ -30,6 +30,8 @@ export declare class BamlRuntime {
   setLogEventCallback(func?: undefined | ((err: any, param: BamlLogEvent) => void)): void
   flush(): void
   drainStats(): TraceStats
+  metrics(): any
+  metricsPrometheus(): string
 }
 
 export declare class BamlSpan {
@@ -48,6 +50,12 @@ export declare class ClassPropertyBuilder {
   description(description?: string | undefined | null): ClassPropertyBuilder
 }
 
+export declare class CancellationToken {
+  constructor()
+  cancel(): void
+  get cancelled(): boolean
+}
+
 export declare class ClientRegistry {
   constructor()
   addLlmClient(name: string, provider: string, options: { [string]: any }, retryPolicy?: string | undefined | null): void
@@ -74,6 +82,10 @@ export declare class FieldType {
 export declare class FunctionResult {
   isOk(): boolean
   parsed(): any
+  /** Whether each value in the parsed result is "pending" or "complete". */
+  completionStates(): any
+  /** Tokens and cost of every attempt behind this result. */
+  usage(): any
 }
 
 export declare class FunctionResultStream {
@@ -83,7 +95,10 @@ export declare class FunctionResultStream {
 
 export declare class RuntimeContextManager {
   upsertTags(tags: any): void
+  setResponseCache(policy?: any | undefined | null): void
   deepClone(): RuntimeContextManager
+  /** A context whose calls are cancelled by `token`. */
+  withCancellation(token: CancellationToken): RuntimeContextManager
   contextDepth(): number
 }
 
@@ -94,6 +109,7 @@ export declare class TraceStats {
   get submitted(): number
   get sent(): number
   get done(): number
+  get usage(): any
   toJson(): string
 }
 
@@ -365,6 +365,7 @@ module.exports.BamlAudio = nativeBinding.BamlAudio
 module.exports.BamlImage = nativeBinding.BamlImage
 module.exports.BamlRuntime = nativeBinding.BamlRuntime
 module.exports.BamlSpan = nativeBinding.BamlSpan
+module.exports.CancellationToken = nativeBinding.CancellationToken
 module.exports.ClassBuilder = nativeBinding.ClassBuilder
 module.exports.ClassPropertyBuilder = nativeBinding.ClassPropertyBuilder
 module.exports.ClientRegistry = nativeBinding.ClientRegistry
@@ -30,7 +30,9 @@ pub fn from_anyhow_error(err: anyhow::Error) -> napi::Error {
                 format!("BamlError: Unexpected error from BAML: {}", err),
             ),
             LLMResponse::LLMFailure(failed) => match &failed.code {
-                baml_runtime::internal::llm_client::ErrorCode::Other(2) => napi::Error::new(
+                baml_runtime::internal::llm_client::ErrorCode::Other(2)
+                | baml_runtime::internal::llm_client::ErrorCode::Timeout
+                | baml_runtime::internal::llm_client::ErrorCode::Cancelled => napi::Error::new(
                     napi::Status::GenericFailure,
                     format!(
                         "BamlError: BamlClientError: Something went wrong with the LLM client: {}",
@@ -354,6 +354,18 @@ impl BamlRuntime {
     pub fn drain_stats(&self) -> TraceStats {
         self.inner.drain_stats().into()
     }
+
+    /// Counters and latencies of every function call made so far.
+    #[napi]
+    pub fn metrics(&self) -> serde_json::Value {
+        serde_json::json!(self.inner.metrics())
+    }
+
+    /// The same metrics, in the Prometheus text exposition format.
+    #[napi]
+    pub fn metrics_prometheus(&self) -> String {
+        self.inner.metrics().to_prometheus()
+    }
 }
 
 impl ObjectFinalize for BamlRuntime {
@@ -0,0 +1,22 @@
+use napi_derive::napi;
+
+crate::lang_wrapper!(CancellationToken, baml_runtime::CancellationToken);
+
+/// Bridges an `AbortSignal` to the runtime; see `BamlCtxManager.cloneContext`.
+#[napi]
+impl CancellationToken {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        baml_runtime::CancellationToken::new().into()
+    }
+
+    #[napi]
+    pub fn cancel(&self) {
+        self.inner.cancel()
+    }
+
+    #[napi(getter)]
+    pub fn cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+}
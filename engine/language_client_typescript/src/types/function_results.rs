@@ -25,4 +25,18 @@ impl FunctionResult {
 
         Ok(serde_json::json!(BamlValue::from(parsed)))
     }
+
+    /// Whether each value in the parsed result is "pending" or "complete".
+    #[napi]
+    pub fn completion_states(&self) -> napi::Result<serde_json::Value> {
+        self.inner
+            .completion_states()
+            .map_err(|e| from_anyhow_error(e))
+    }
+
+    /// Tokens and cost of every attempt behind this result.
+    #[napi]
+    pub fn usage(&self) -> serde_json::Value {
+        serde_json::json!(self.inner.usage())
+    }
 }
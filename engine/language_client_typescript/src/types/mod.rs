@@ -1,6 +1,7 @@
 mod lang_wrappers;
 
 pub mod audio;
+pub(crate) mod cancellation_token;
 pub(crate) mod client_registry;
 pub(crate) mod function_result_stream;
 pub(crate) mod function_results;
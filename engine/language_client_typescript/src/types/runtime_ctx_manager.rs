@@ -1,7 +1,10 @@
+use baml_runtime::CachePolicy;
 use baml_types::BamlValue;
 use napi_derive::napi;
 
-use crate::errors::invalid_argument_error;
+use crate::errors::{from_anyhow_error, invalid_argument_error};
+
+use super::cancellation_token::CancellationToken;
 
 crate::lang_wrapper!(RuntimeContextManager, baml_runtime::RuntimeContextManager);
 
@@ -23,6 +26,14 @@ impl RuntimeContextManager {
         Ok(())
     }
 
+    #[napi]
+    pub fn set_response_cache(&self, policy: Option<serde_json::Value>) -> napi::Result<()> {
+        let policy =
+            CachePolicy::from_value(policy.unwrap_or_default()).map_err(from_anyhow_error)?;
+        self.inner.set_response_cache(policy);
+        Ok(())
+    }
+
     #[napi]
     pub fn deep_clone(&self) -> Self {
         RuntimeContextManager {
@@ -30,6 +41,14 @@ impl RuntimeContextManager {
         }
     }
 
+    /// A context whose calls are cancelled by `token`.
+    #[napi]
+    pub fn with_cancellation(&self, token: &CancellationToken) -> Self {
+        RuntimeContextManager {
+            inner: self.inner.with_cancellation(token.inner.clone()),
+        }
+    }
+
     #[napi]
     pub fn context_depth(&self) -> u32 {
         self.inner.context_depth() as u32
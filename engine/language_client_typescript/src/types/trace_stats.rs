@@ -34,6 +34,11 @@ impl TraceStats {
         self.inner.done
     }
 
+    #[napi(getter)]
+    pub fn get_usage(&self) -> serde_json::Value {
+        serde_json::json!(self.inner.usage)
+    }
+
     #[napi]
     pub fn to_json(&self) -> String {
         serde_json::json!({
@@ -43,6 +48,7 @@ impl TraceStats {
             "submitted": self.inner.submitted,
             "sent": self.inner.sent,
             "done": self.inner.done,
+            "usage": self.inner.usage,
         })
         .to_string()
     }
@@ -4,12 +4,21 @@ export declare class BamlStream<PartialOutputType, FinalOutputType> {
     private partialCoerce;
     private finalCoerce;
     private ctxManager;
+    private signal?;
     private task;
     private eventQueue;
-    constructor(ffiStream: FunctionResultStream, partialCoerce: (result: FunctionResult) => PartialOutputType, finalCoerce: (result: FunctionResult) => FinalOutputType, ctxManager: RuntimeContextManager);
+    constructor(ffiStream: FunctionResultStream, partialCoerce: (result: FunctionResult) => PartialOutputType, finalCoerce: (result: FunctionResult) => FinalOutputType, ctxManager: RuntimeContextManager, signal?: AbortSignal | undefined);
     private driveToCompletion;
     private driveToCompletionInBg;
+    private events;
     [Symbol.asyncIterator](): AsyncIterableIterator<PartialOutputType>;
+    /** Iterates the stream like `for await`, also yielding the completion states of each partial response. */
+    withStates(): AsyncIterableIterator<{
+        partial: PartialOutputType;
+        states: any;
+    }>;
     getFinalResponse(): Promise<FinalOutputType>;
+    /** Tokens and cost of the stream, including failed retries and fallbacks. Waits for the stream to finish. */
+    getUsage(): Promise<any>;
 }
 //# sourceMappingURL=stream.d.ts.map
\ No newline at end of file
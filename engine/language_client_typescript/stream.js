@@ -1,18 +1,21 @@
 "use strict";
 Object.defineProperty(exports, "__esModule", { value: true });
 exports.BamlStream = void 0;
+const async_context_vars_1 = require("./async_context_vars");
 class BamlStream {
     ffiStream;
     partialCoerce;
     finalCoerce;
     ctxManager;
+    signal;
     task = null;
     eventQueue = [];
-    constructor(ffiStream, partialCoerce, finalCoerce, ctxManager) {
+    constructor(ffiStream, partialCoerce, finalCoerce, ctxManager, signal) {
         this.ffiStream = ffiStream;
         this.partialCoerce = partialCoerce;
         this.finalCoerce = finalCoerce;
         this.ctxManager = ctxManager;
+        this.signal = signal;
     }
     async driveToCompletion() {
         try {
@@ -24,9 +27,15 @@ class BamlStream {
                     this.eventQueue.push(data);
                 }
             });
-            const retval = await this.ffiStream.done(this.ctxManager);
+            const retval = await (0, async_context_vars_1.withAbortSignal)(this.ctxManager, this.signal, (ctx) => this.ffiStream.done(ctx));
             return retval;
         }
+        catch (error) {
+            if (this.signal?.aborted) {
+                throw this.signal.reason;
+            }
+            throw error;
+        }
         finally {
             this.eventQueue.push(null);
         }
@@ -37,7 +46,7 @@ class BamlStream {
         }
         return this.task;
     }
-    async *[Symbol.asyncIterator]() {
+    async *events() {
         this.driveToCompletionInBg();
         while (true) {
             const event = this.eventQueue.shift();
@@ -49,13 +58,29 @@ class BamlStream {
                 break;
             }
             if (event.isOk()) {
-                yield this.partialCoerce(event.parsed());
+                yield event;
             }
         }
     }
+    async *[Symbol.asyncIterator]() {
+        for await (const event of this.events()) {
+            yield this.partialCoerce(event.parsed());
+        }
+    }
+    /** Iterates the stream like `for await`, also yielding the completion states of each partial response. */
+    async *withStates() {
+        for await (const event of this.events()) {
+            yield { partial: this.partialCoerce(event.parsed()), states: event.completionStates() };
+        }
+    }
     async getFinalResponse() {
         const final = await this.driveToCompletionInBg();
         return this.finalCoerce(final.parsed());
     }
+    /** Tokens and cost of the stream, including failed retries and fallbacks. Waits for the stream to finish. */
+    async getUsage() {
+        const final = await this.driveToCompletionInBg();
+        return final.usage();
+    }
 }
 exports.BamlStream = BamlStream;
@@ -1,6 +1,33 @@
-import { BamlSpan, RuntimeContextManager, BamlRuntime, BamlLogEvent } from './native'
+import { BamlSpan, RuntimeContextManager, BamlRuntime, BamlLogEvent, CancellationToken } from './native'
 import { AsyncLocalStorage } from 'async_hooks'
 
+/**
+ * Runs `call` with a copy of `ctx` that aborting `signal` cancels, which stops the call, its
+ * retries and its fallbacks. The abort listener is removed once the call settles, so a
+ * long-lived signal doesn't keep one for every call made with it.
+ */
+export async function withAbortSignal<T>(
+  ctx: RuntimeContextManager,
+  signal: AbortSignal | undefined,
+  call: (ctx: RuntimeContextManager) => Promise<T>,
+): Promise<T> {
+  if (!signal) {
+    return call(ctx)
+  }
+  const token = new CancellationToken()
+  const cancel = () => token.cancel()
+  if (signal.aborted) {
+    token.cancel()
+  } else {
+    signal.addEventListener('abort', cancel, { once: true })
+  }
+  try {
+    return await call(ctx.withCancellation(token))
+  } finally {
+    signal.removeEventListener('abort', cancel)
+  }
+}
+
 export class BamlCtxManager {
   private rt: BamlRuntime
   private ctx: AsyncLocalStorage<RuntimeContextManager>
@@ -36,6 +63,11 @@ export class BamlCtxManager {
     manager.upsertTags(tags)
   }
 
+  setResponseCache(policy: boolean | string | Record<string, any> | null): void {
+    const manager = this.ctx.getStore()!
+    manager.setResponseCache(policy)
+  }
+
   cloneContext(): RuntimeContextManager {
     let store = this.ctx.getStore()
     if (store === undefined) {
@@ -45,6 +77,13 @@ export class BamlCtxManager {
     return store.deepClone()
   }
 
+  callWithSignal<T>(
+    signal: AbortSignal | undefined,
+    call: (ctx: RuntimeContextManager) => Promise<T>,
+  ): Promise<T> {
+    return withAbortSignal(this.cloneContext(), signal, call)
+  }
+
   startTrace(name: string, args: Record<string, any>): [RuntimeContextManager, BamlSpan] {
     const mng = this.cloneContext()
     return [mng, BamlSpan.new(this.rt, name, args, mng)]
@@ -1,4 +1,5 @@
 import { FunctionResult, FunctionResultStream, RuntimeContextManager } from './native'
+import { withAbortSignal } from './async_context_vars'
 
 export class BamlStream<PartialOutputType, FinalOutputType> {
   private task: Promise<FunctionResult> | null = null
@@ -10,6 +11,7 @@ export class BamlStream<PartialOutputType, FinalOutputType> {
     private partialCoerce: (result: FunctionResult) => PartialOutputType,
     private finalCoerce: (result: FunctionResult) => FinalOutputType,
     private ctxManager: RuntimeContextManager,
+    private signal?: AbortSignal,
   ) {}
 
   private async driveToCompletion(): Promise<FunctionResult> {
@@ -21,9 +23,14 @@ export class BamlStream<PartialOutputType, FinalOutputType> {
           this.eventQueue.push(data)
         }
       })
-      const retval = await this.ffiStream.done(this.ctxManager)
+      const retval = await withAbortSignal(this.ctxManager, this.signal, (ctx) => this.ffiStream.done(ctx))
 
       return retval
+    } catch (error) {
+      if (this.signal?.aborted) {
+        throw this.signal.reason
+      }
+      throw error
     } finally {
       this.eventQueue.push(null)
     }
@@ -37,7 +44,7 @@ export class BamlStream<PartialOutputType, FinalOutputType> {
     return this.task
   }
 
-  async *[Symbol.asyncIterator](): AsyncIterableIterator<PartialOutputType> {
+  private async *events(): AsyncIterableIterator<FunctionResult> {
     this.driveToCompletionInBg()
 
     while (true) {
@@ -53,14 +60,34 @@ export class BamlStream<PartialOutputType, FinalOutputType> {
       }
 
       if (event.isOk()) {
-        yield this.partialCoerce(event.parsed())
+        yield event
       }
     }
   }
 
+  async *[Symbol.asyncIterator](): AsyncIterableIterator<PartialOutputType> {
+    for await (const event of this.events()) {
+      yield this.partialCoerce(event.parsed())
+    }
+  }
+
+  /** Iterates the stream like `for await`, also yielding the completion states of each partial response. */
+  async *withStates(): AsyncIterableIterator<{ partial: PartialOutputType; states: any }> {
+    for await (const event of this.events()) {
+      yield { partial: this.partialCoerce(event.parsed()), states: event.completionStates() }
+    }
+  }
+
   async getFinalResponse(): Promise<FinalOutputType> {
     const final = await this.driveToCompletionInBg()
 
     return this.finalCoerce(final.parsed())
   }
+
+  /** Tokens and cost of the stream, including failed retries and fallbacks. Waits for the stream to finish. */
+  async getUsage(): Promise<any> {
+    const final = await this.driveToCompletionInBg()
+
+    return final.usage()
+  }
 }
@@ -803,6 +803,30 @@ class BamlAsyncClient:
       mdl = create_model("FnOutputClassWithEnumReturnType", inner=(types.TestClassWithEnum, ...))
       return coerce(mdl, raw.parsed())
     
+    async def FnOutputLinkPreview(
+        self,
+        input: str,
+        baml_options: BamlCallOptions = {},
+    ) -> types.LinkPreview:
+      __tb__ = baml_options.get("tb", None)
+      if __tb__ is not None:
+        tb = __tb__._tb
+      else:
+        tb = None
+      __cr__ = baml_options.get("client_registry", None)
+
+      raw = await self.__runtime.call_function(
+        "FnOutputLinkPreview",
+        {
+          "input": input,
+        },
+        self.__ctx_manager.get(),
+        tb,
+        __cr__,
+      )
+      mdl = create_model("FnOutputLinkPreviewReturnType", inner=(types.LinkPreview, ...))
+      return coerce(mdl, raw.parsed())
+    
     async def FnOutputStringList(
         self,
         input: str,
@@ -2968,6 +2992,39 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
       )
     
+    def FnOutputLinkPreview(
+        self,
+        input: str,
+        baml_options: BamlCallOptions = {},
+    ) -> baml_py.BamlStream[partial_types.LinkPreview, types.LinkPreview]:
+      __tb__ = baml_options.get("tb", None)
+      if __tb__ is not None:
+        tb = __tb__._tb
+      else:
+        tb = None
+      __cr__ = baml_options.get("client_registry", None)
+
+      raw = self.__runtime.stream_function(
+        "FnOutputLinkPreview",
+        {
+          "input": input,
+        },
+        None,
+        self.__ctx_manager.get(),
+        tb,
+        __cr__,
+      )
+
+      mdl = create_model("FnOutputLinkPreviewReturnType", inner=(types.LinkPreview, ...))
+      partial_mdl = create_model("FnOutputLinkPreviewPartialReturnType", inner=(partial_types.LinkPreview, ...))
+
+      return baml_py.BamlStream[partial_types.LinkPreview, types.LinkPreview](
+        raw,
+        lambda x: coerce(partial_mdl, x),
+        lambda x: coerce(mdl, x),
+        self.__ctx_manager.get(),
+      )
+    
     def FnOutputStringList(
         self,
         input: str,
@@ -158,6 +158,14 @@ class InnerClass2(BaseModel):
     prop2: Optional[int] = None
     prop3: Optional[float] = None
 
+class LinkPreview(BaseModel):
+    
+    
+    url: Optional[str] = None
+    title: str
+    tags: List[str]
+    description: Optional[str] = None
+
 class NamedArgsSingleClass(BaseModel):
     
     
@@ -249,6 +249,14 @@ class InnerClass2(BaseModel):
     prop2: int
     prop3: float
 
+class LinkPreview(BaseModel):
+    
+    
+    url: str
+    title: str
+    tags: List[str]
+    description: Optional[str] = None
+
 class NamedArgsSingleClass(BaseModel):
     
     
@@ -871,6 +871,21 @@ async def test_nested_class_streaming():
     print("final ", final.model_dump(mode="json"))
 
 
+@pytest.mark.asyncio
+async def test_stream_attributes():
+    stream = b.stream.FnOutputLinkPreview(input="the history of the bicycle")
+    msgs: List[partial_types.LinkPreview] = []
+    async for msg in stream:
+        msgs.append(msg)
+    final = await stream.get_final_response()
+
+    assert len(msgs) > 0, "Expected at least one streamed response but got none."
+    for msg in msgs:
+        assert msg.title is not None
+        assert msg.tags is not None
+        assert msg.url in (None, final.url)
+
+
 @pytest.mark.asyncio
 async def test_dynamic_client_with_openai():
     cb = baml_py.ClientRegistry()
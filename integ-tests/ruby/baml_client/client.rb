@@ -54,7 +54,7 @@ module Baml
       params(
         varargs: T.untyped,
         recipe: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::Recipe)
     }
     def AaaSamOutputFormat(
@@ -66,8 +66,8 @@ module Baml
         
         raise ArgumentError.new("AaaSamOutputFormat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -78,6 +78,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -86,7 +87,7 @@ module Baml
       params(
         varargs: T.untyped,
         aud: Baml::Audio,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def AudioInput(
@@ -98,8 +99,8 @@ module Baml
         
         raise ArgumentError.new("AudioInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -110,6 +111,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -118,7 +120,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.any(Baml::Types::DynEnumTwo, String))
     }
     def ClassifyDynEnumTwo(
@@ -130,8 +132,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyDynEnumTwo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -142,6 +144,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -150,7 +153,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.any(Baml::Types::Category, String))
     }
     def ClassifyMessage(
@@ -162,8 +165,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -174,6 +177,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -182,7 +186,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.any(Baml::Types::Category, String))
     }
     def ClassifyMessage2(
@@ -194,8 +198,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -206,6 +210,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -214,7 +219,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.any(Baml::Types::Category, String))
     }
     def ClassifyMessage3(
@@ -226,8 +231,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -238,6 +243,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -246,7 +252,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.any(Baml::Types::BookOrder, Baml::Types::FlightConfirmation, Baml::Types::GroceryReceipt))
     }
     def CustomTask(
@@ -258,8 +264,8 @@ module Baml
         
         raise ArgumentError.new("CustomTask may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -270,6 +276,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -278,7 +285,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def DescribeImage(
@@ -290,8 +297,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -302,6 +309,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -310,7 +318,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def DescribeImage2(
@@ -322,8 +330,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -334,6 +342,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -342,7 +351,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def DescribeImage3(
@@ -354,8 +363,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -366,6 +375,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -374,7 +384,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def DescribeImage4(
@@ -386,8 +396,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage4 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -398,6 +408,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -406,7 +417,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::DummyOutput)
     }
     def DummyOutputFunction(
@@ -418,8 +429,8 @@ module Baml
         
         raise ArgumentError.new("DummyOutputFunction may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -430,6 +441,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -438,7 +450,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynamicClassOne,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::DynamicClassTwo)
     }
     def DynamicFunc(
@@ -450,8 +462,8 @@ module Baml
         
         raise ArgumentError.new("DynamicFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -462,6 +474,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -470,7 +483,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynInputOutput,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::DynInputOutput)
     }
     def DynamicInputOutput(
@@ -482,8 +495,8 @@ module Baml
         
         raise ArgumentError.new("DynamicInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -494,6 +507,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -502,7 +516,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T::Array[Baml::Types::DynInputOutput],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Array[Baml::Types::DynInputOutput])
     }
     def DynamicListInputOutput(
@@ -514,8 +528,8 @@ module Baml
         
         raise ArgumentError.new("DynamicListInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -526,6 +540,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -534,7 +549,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def ExpectFailure(
@@ -546,8 +561,8 @@ module Baml
         
         raise ArgumentError.new("ExpectFailure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -558,6 +573,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -566,7 +582,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Array[String])
     }
     def ExtractNames(
@@ -578,8 +594,8 @@ module Baml
         
         raise ArgumentError.new("ExtractNames may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -590,6 +606,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -598,7 +615,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Array[Baml::Types::Person])
     }
     def ExtractPeople(
@@ -610,8 +627,8 @@ module Baml
         
         raise ArgumentError.new("ExtractPeople may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -622,6 +639,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -630,7 +648,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::ReceiptInfo)
     }
     def ExtractReceiptInfo(
@@ -642,8 +660,8 @@ module Baml
         
         raise ArgumentError.new("ExtractReceiptInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -654,6 +672,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -662,7 +681,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,img: T.nilable(Baml::Image),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::Resume)
     }
     def ExtractResume(
@@ -674,8 +693,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -686,6 +705,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -694,7 +714,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::Resume)
     }
     def ExtractResume2(
@@ -706,8 +726,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -718,6 +738,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -726,7 +747,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.nilable(Baml::Types::ClassOptionalOutput))
     }
     def FnClassOptionalOutput(
@@ -738,8 +759,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -750,6 +771,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -758,7 +780,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.nilable(Baml::Types::ClassOptionalOutput2))
     }
     def FnClassOptionalOutput2(
@@ -770,8 +792,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -782,6 +804,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -790,7 +813,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Array[T.any(Baml::Types::EnumOutput, String)])
     }
     def FnEnumListOutput(
@@ -802,8 +825,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumListOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -814,6 +837,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -822,7 +846,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.any(Baml::Types::EnumOutput, String))
     }
     def FnEnumOutput(
@@ -834,8 +858,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -846,6 +870,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -854,7 +879,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: T.nilable(String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def FnNamedArgsSingleStringOptional(
@@ -866,8 +891,8 @@ module Baml
         
         raise ArgumentError.new("FnNamedArgsSingleStringOptional may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -878,6 +903,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -886,7 +912,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Boolean)
     }
     def FnOutputBool(
@@ -898,8 +924,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -910,6 +936,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -918,7 +945,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::TestOutputClass)
     }
     def FnOutputClass(
@@ -930,8 +957,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -942,6 +969,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -950,7 +978,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Array[Baml::Types::TestOutputClass])
     }
     def FnOutputClassList(
@@ -962,8 +990,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -974,6 +1002,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -982,7 +1011,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::TestClassNested)
     }
     def FnOutputClassNested(
@@ -994,8 +1023,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassNested may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1006,6 +1035,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1014,7 +1044,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::TestClassWithEnum)
     }
     def FnOutputClassWithEnum(
@@ -1026,8 +1056,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassWithEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1038,6 +1068,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1046,7 +1077,40 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
+      ).returns(Baml::Types::LinkPreview)
+    }
+    def FnOutputLinkPreview(
+        *varargs,
+        input:,
+        baml_options: {}
+    )
+      if varargs.any?
+        
+        raise ArgumentError.new("FnOutputLinkPreview may only be called with keyword arguments")
+      end
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
+      end
+
+      raw = @runtime.call_function(
+        "FnOutputLinkPreview",
+        {
+          input: input,
+        },
+        @ctx_manager,
+        baml_options[:tb]&.instance_variable_get(:@registry),
+        baml_options[:client_registry],
+        baml_options[:cancellation_token],
+      )
+      (raw.parsed_using_types(Baml::Types))
+    end
+
+    sig {
+      params(
+        varargs: T.untyped,
+        input: String,
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Array[String])
     }
     def FnOutputStringList(
@@ -1058,8 +1122,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1070,6 +1134,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1078,7 +1143,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T.any(Baml::Types::TestEnum, String))
     }
     def FnTestAliasedEnumOutput(
@@ -1090,8 +1155,8 @@ module Baml
         
         raise ArgumentError.new("FnTestAliasedEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1102,6 +1167,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1110,7 +1176,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::TestClassAlias)
     }
     def FnTestClassAlias(
@@ -1122,8 +1188,8 @@ module Baml
         
         raise ArgumentError.new("FnTestClassAlias may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1134,6 +1200,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1142,7 +1209,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T.any(Baml::Types::NamedArgsSingleEnum, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def FnTestNamedArgsSingleEnum(
@@ -1154,8 +1221,8 @@ module Baml
         
         raise ArgumentError.new("FnTestNamedArgsSingleEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1166,6 +1233,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1174,7 +1242,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::RaysData)
     }
     def GetDataType(
@@ -1186,8 +1254,8 @@ module Baml
         
         raise ArgumentError.new("GetDataType may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1198,6 +1266,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1206,7 +1275,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: Baml::Types::Email,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::OrderInfo)
     }
     def GetOrderInfo(
@@ -1218,8 +1287,8 @@ module Baml
         
         raise ArgumentError.new("GetOrderInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1230,6 +1299,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1238,7 +1308,7 @@ module Baml
       params(
         varargs: T.untyped,
         query: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::SearchParams)
     }
     def GetQuery(
@@ -1250,8 +1320,8 @@ module Baml
         
         raise ArgumentError.new("GetQuery may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1262,6 +1332,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1270,7 +1341,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::DynamicOutput)
     }
     def MyFunc(
@@ -1282,8 +1353,8 @@ module Baml
         
         raise ArgumentError.new("MyFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1294,6 +1365,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1302,7 +1374,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Array[T.nilable(Baml::Types::OptionalTest_ReturnType)])
     }
     def OptionalTest_Function(
@@ -1314,8 +1386,8 @@ module Baml
         
         raise ArgumentError.new("OptionalTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1326,6 +1398,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1334,7 +1407,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def PromptTestClaude(
@@ -1346,8 +1419,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaude may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1358,6 +1431,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1366,7 +1440,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def PromptTestClaudeChat(
@@ -1378,8 +1452,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1390,6 +1464,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1398,7 +1473,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def PromptTestClaudeChatNoSystem(
@@ -1410,8 +1485,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1422,6 +1497,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1430,7 +1506,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def PromptTestOpenAI(
@@ -1442,8 +1518,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAI may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1454,6 +1530,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1462,7 +1539,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def PromptTestOpenAIChat(
@@ -1474,8 +1551,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1486,6 +1563,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1494,7 +1572,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def PromptTestOpenAIChatNoSystem(
@@ -1506,8 +1584,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1518,6 +1596,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1526,7 +1605,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def PromptTestStreaming(
@@ -1538,8 +1617,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestStreaming may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1550,6 +1629,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1558,7 +1638,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::Schema)
     }
     def SchemaDescriptions(
@@ -1570,8 +1650,8 @@ module Baml
         
         raise ArgumentError.new("SchemaDescriptions may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1582,6 +1662,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1590,7 +1671,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestAnthropic(
@@ -1602,8 +1683,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1614,6 +1695,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1622,7 +1704,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestAnthropicShorthand(
@@ -1634,8 +1716,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropicShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1646,6 +1728,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1654,7 +1737,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestAws(
@@ -1666,8 +1749,8 @@ module Baml
         
         raise ArgumentError.new("TestAws may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1678,6 +1761,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1686,7 +1770,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestAzure(
@@ -1698,8 +1782,8 @@ module Baml
         
         raise ArgumentError.new("TestAzure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1710,6 +1794,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1718,7 +1803,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestCaching(
@@ -1730,8 +1815,8 @@ module Baml
         
         raise ArgumentError.new("TestCaching may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1742,6 +1827,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1750,7 +1836,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFallbackClient(
@@ -1762,8 +1848,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackClient may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1774,6 +1860,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1782,7 +1869,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFallbackToShorthand(
@@ -1794,8 +1881,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackToShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1806,6 +1893,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1814,7 +1902,7 @@ module Baml
       params(
         varargs: T.untyped,
         myBool: T::Boolean,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleBool(
@@ -1826,8 +1914,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1838,6 +1926,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1846,7 +1935,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleClass(
@@ -1858,8 +1947,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1870,6 +1959,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1878,7 +1968,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[T.any(Baml::Types::NamedArgsSingleEnumList, String)],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleEnumList(
@@ -1890,8 +1980,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleEnumList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1902,6 +1992,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1910,7 +2001,7 @@ module Baml
       params(
         varargs: T.untyped,
         myFloat: Float,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleFloat(
@@ -1922,8 +2013,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleFloat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1934,6 +2025,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1942,7 +2034,7 @@ module Baml
       params(
         varargs: T.untyped,
         myInt: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleInt(
@@ -1954,8 +2046,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleInt may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1966,6 +2058,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1974,7 +2067,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, Baml::Types::StringToClassEntry],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Hash[String, Baml::Types::StringToClassEntry])
     }
     def TestFnNamedArgsSingleMapStringToClass(
@@ -1986,8 +2079,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -1998,6 +2091,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2006,7 +2100,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, T::Hash[String, String]],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Hash[String, T::Hash[String, String]])
     }
     def TestFnNamedArgsSingleMapStringToMap(
@@ -2018,8 +2112,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToMap may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2030,6 +2124,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2038,7 +2133,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(T::Hash[String, String])
     }
     def TestFnNamedArgsSingleMapStringToString(
@@ -2050,8 +2145,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2062,6 +2157,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2070,7 +2166,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleString(
@@ -2082,8 +2178,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2094,6 +2190,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2102,7 +2199,7 @@ module Baml
       params(
         varargs: T.untyped,
         myStringArray: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleStringArray(
@@ -2114,8 +2211,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringArray may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2126,6 +2223,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2134,7 +2232,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleStringList(
@@ -2146,8 +2244,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2158,6 +2256,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2166,7 +2265,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestGemini(
@@ -2178,8 +2277,8 @@ module Baml
         
         raise ArgumentError.new("TestGemini may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2190,6 +2289,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2198,7 +2298,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestImageInput(
@@ -2210,8 +2310,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2222,6 +2322,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2230,7 +2331,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestImageInputAnthropic(
@@ -2242,8 +2343,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInputAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2254,6 +2355,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2262,7 +2364,7 @@ module Baml
       params(
         varargs: T.untyped,
         imgs: T::Array[Baml::Image],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestImageListInput(
@@ -2274,8 +2376,8 @@ module Baml
         
         raise ArgumentError.new("TestImageListInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2286,6 +2388,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2294,7 +2397,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,myArg2: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestMulticlassNamedArgs(
@@ -2306,8 +2409,8 @@ module Baml
         
         raise ArgumentError.new("TestMulticlassNamedArgs may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2318,6 +2421,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2326,7 +2430,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestOllama(
@@ -2338,8 +2442,8 @@ module Baml
         
         raise ArgumentError.new("TestOllama may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2350,6 +2454,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2358,7 +2463,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestOpenAILegacyProvider(
@@ -2370,8 +2475,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAILegacyProvider may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2382,6 +2487,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2390,7 +2496,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestOpenAIShorthand(
@@ -2402,8 +2508,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAIShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2414,6 +2520,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2422,7 +2529,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestRetryConstant(
@@ -2434,8 +2541,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryConstant may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2446,6 +2553,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2454,7 +2562,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestRetryExponential(
@@ -2466,8 +2574,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryExponential may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2478,6 +2586,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2486,7 +2595,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(String)
     }
     def TestVertex(
@@ -2498,8 +2607,8 @@ module Baml
         
         raise ArgumentError.new("TestVertex may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2510,6 +2619,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2518,7 +2628,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T.any(String, T::Boolean),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::Types::UnionTest_ReturnType)
     }
     def UnionTest_Function(
@@ -2530,8 +2640,8 @@ module Baml
         
         raise ArgumentError.new("UnionTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.call_function(
@@ -2542,6 +2652,7 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:cancellation_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2563,7 +2674,7 @@ module Baml
       params(
         varargs: T.untyped,
         recipe: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::Recipe])
     }
     def AaaSamOutputFormat(
@@ -2575,8 +2686,8 @@ module Baml
         
         raise ArgumentError.new("AaaSamOutputFormat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2590,7 +2701,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::Recipe, Baml::Types::Recipe].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2598,7 +2710,7 @@ module Baml
       params(
         varargs: T.untyped,
         aud: Baml::Audio,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def AudioInput(
@@ -2610,8 +2722,8 @@ module Baml
         
         raise ArgumentError.new("AudioInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2625,7 +2737,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2633,7 +2746,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::DynEnumTwo, String)])
     }
     def ClassifyDynEnumTwo(
@@ -2645,8 +2758,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyDynEnumTwo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2660,7 +2773,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(Baml::Types::DynEnumTwo), T.any(Baml::Types::DynEnumTwo, String)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2668,7 +2782,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::Category, String)])
     }
     def ClassifyMessage(
@@ -2680,8 +2794,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2695,7 +2809,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(Baml::Types::Category), T.any(Baml::Types::Category, String)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2703,7 +2818,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::Category, String)])
     }
     def ClassifyMessage2(
@@ -2715,8 +2830,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2730,7 +2845,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(Baml::Types::Category), T.any(Baml::Types::Category, String)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2738,7 +2854,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::Category, String)])
     }
     def ClassifyMessage3(
@@ -2750,8 +2866,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2765,7 +2881,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(Baml::Types::Category), T.any(Baml::Types::Category, String)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2773,7 +2890,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::BookOrder, Baml::Types::FlightConfirmation, Baml::Types::GroceryReceipt)])
     }
     def CustomTask(
@@ -2785,8 +2902,8 @@ module Baml
         
         raise ArgumentError.new("CustomTask may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2800,7 +2917,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(T.any(Baml::PartialTypes::BookOrder, Baml::PartialTypes::FlightConfirmation, Baml::PartialTypes::GroceryReceipt)), T.any(Baml::Types::BookOrder, Baml::Types::FlightConfirmation, Baml::Types::GroceryReceipt)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2808,7 +2926,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage(
@@ -2820,8 +2938,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2835,7 +2953,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2843,7 +2962,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage2(
@@ -2855,8 +2974,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2870,7 +2989,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2878,7 +2998,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage3(
@@ -2890,8 +3010,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2905,7 +3025,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2913,7 +3034,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage4(
@@ -2925,8 +3046,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage4 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2940,7 +3061,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2948,7 +3070,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::DummyOutput])
     }
     def DummyOutputFunction(
@@ -2960,8 +3082,8 @@ module Baml
         
         raise ArgumentError.new("DummyOutputFunction may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -2975,7 +3097,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::DummyOutput, Baml::Types::DummyOutput].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -2983,7 +3106,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynamicClassOne,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::DynamicClassTwo])
     }
     def DynamicFunc(
@@ -2995,8 +3118,8 @@ module Baml
         
         raise ArgumentError.new("DynamicFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3010,7 +3133,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::DynamicClassTwo, Baml::Types::DynamicClassTwo].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3018,7 +3142,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynInputOutput,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::DynInputOutput])
     }
     def DynamicInputOutput(
@@ -3030,8 +3154,8 @@ module Baml
         
         raise ArgumentError.new("DynamicInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3045,7 +3169,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::DynInputOutput, Baml::Types::DynInputOutput].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3053,7 +3178,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T::Array[Baml::Types::DynInputOutput],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Array[Baml::Types::DynInputOutput]])
     }
     def DynamicListInputOutput(
@@ -3065,8 +3190,8 @@ module Baml
         
         raise ArgumentError.new("DynamicListInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3080,7 +3205,8 @@ module Baml
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::DynInputOutput], T::Array[Baml::Types::DynInputOutput]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3088,7 +3214,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def ExpectFailure(
@@ -3100,8 +3226,8 @@ module Baml
         
         raise ArgumentError.new("ExpectFailure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3115,7 +3241,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3123,7 +3250,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Array[String]])
     }
     def ExtractNames(
@@ -3135,8 +3262,8 @@ module Baml
         
         raise ArgumentError.new("ExtractNames may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3150,7 +3277,8 @@ module Baml
       )
       Baml::BamlStream[T::Array[T.nilable(String)], T::Array[String]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3158,7 +3286,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Array[Baml::Types::Person]])
     }
     def ExtractPeople(
@@ -3170,8 +3298,8 @@ module Baml
         
         raise ArgumentError.new("ExtractPeople may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3185,7 +3313,8 @@ module Baml
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::Person], T::Array[Baml::Types::Person]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3193,7 +3322,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::ReceiptInfo])
     }
     def ExtractReceiptInfo(
@@ -3205,8 +3334,8 @@ module Baml
         
         raise ArgumentError.new("ExtractReceiptInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3220,7 +3349,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::ReceiptInfo, Baml::Types::ReceiptInfo].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3228,7 +3358,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,img: T.nilable(Baml::Image),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::Resume])
     }
     def ExtractResume(
@@ -3240,8 +3370,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3255,7 +3385,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::Resume, Baml::Types::Resume].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3263,7 +3394,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::Resume])
     }
     def ExtractResume2(
@@ -3275,8 +3406,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3290,7 +3421,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::Resume, Baml::Types::Resume].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3298,7 +3430,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.nilable(Baml::Types::ClassOptionalOutput)])
     }
     def FnClassOptionalOutput(
@@ -3310,8 +3442,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3325,7 +3457,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::ClassOptionalOutput, T.nilable(Baml::Types::ClassOptionalOutput)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3333,7 +3466,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.nilable(Baml::Types::ClassOptionalOutput2)])
     }
     def FnClassOptionalOutput2(
@@ -3345,8 +3478,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3360,7 +3493,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::ClassOptionalOutput2, T.nilable(Baml::Types::ClassOptionalOutput2)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3368,7 +3502,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Array[T.any(Baml::Types::EnumOutput, String)]])
     }
     def FnEnumListOutput(
@@ -3380,8 +3514,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumListOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3395,7 +3529,8 @@ module Baml
       )
       Baml::BamlStream[T::Array[T.nilable(Baml::Types::EnumOutput)], T::Array[T.any(Baml::Types::EnumOutput, String)]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3403,7 +3538,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::EnumOutput, String)])
     }
     def FnEnumOutput(
@@ -3415,8 +3550,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3430,7 +3565,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(Baml::Types::EnumOutput), T.any(Baml::Types::EnumOutput, String)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3438,7 +3574,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: T.nilable(String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def FnNamedArgsSingleStringOptional(
@@ -3450,8 +3586,8 @@ module Baml
         
         raise ArgumentError.new("FnNamedArgsSingleStringOptional may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3465,7 +3601,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3473,7 +3610,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Boolean])
     }
     def FnOutputBool(
@@ -3485,8 +3622,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3500,7 +3637,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(T::Boolean), T::Boolean].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3508,7 +3646,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestOutputClass])
     }
     def FnOutputClass(
@@ -3520,8 +3658,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3535,7 +3673,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::TestOutputClass, Baml::Types::TestOutputClass].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3543,7 +3682,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Array[Baml::Types::TestOutputClass]])
     }
     def FnOutputClassList(
@@ -3555,8 +3694,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3570,7 +3709,8 @@ module Baml
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::TestOutputClass], T::Array[Baml::Types::TestOutputClass]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3578,7 +3718,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestClassNested])
     }
     def FnOutputClassNested(
@@ -3590,8 +3730,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassNested may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3605,7 +3745,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::TestClassNested, Baml::Types::TestClassNested].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3613,7 +3754,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestClassWithEnum])
     }
     def FnOutputClassWithEnum(
@@ -3625,8 +3766,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassWithEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3640,7 +3781,44 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::TestClassWithEnum, Baml::Types::TestClassWithEnum].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
+      )
+    end
+
+    sig {
+      params(
+        varargs: T.untyped,
+        input: String,
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
+      ).returns(Baml::BamlStream[Baml::Types::LinkPreview])
+    }
+    def FnOutputLinkPreview(
+        *varargs,
+        input:,
+        baml_options: {}
+    )
+      if varargs.any?
+        
+        raise ArgumentError.new("FnOutputLinkPreview may only be called with keyword arguments")
+      end
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
+      end
+
+      raw = @runtime.stream_function(
+        "FnOutputLinkPreview",
+        {
+          input: input,
+        },
+        @ctx_manager,
+        baml_options[:tb]&.instance_variable_get(:@registry),
+        baml_options[:client_registry],
+      )
+      Baml::BamlStream[Baml::PartialTypes::LinkPreview, Baml::Types::LinkPreview].new(
+        ffi_stream: raw,
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3648,7 +3826,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Array[String]])
     }
     def FnOutputStringList(
@@ -3660,8 +3838,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3675,7 +3853,8 @@ module Baml
       )
       Baml::BamlStream[T::Array[T.nilable(String)], T::Array[String]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3683,7 +3862,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::TestEnum, String)])
     }
     def FnTestAliasedEnumOutput(
@@ -3695,8 +3874,8 @@ module Baml
         
         raise ArgumentError.new("FnTestAliasedEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3710,7 +3889,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(Baml::Types::TestEnum), T.any(Baml::Types::TestEnum, String)].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3718,7 +3898,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestClassAlias])
     }
     def FnTestClassAlias(
@@ -3730,8 +3910,8 @@ module Baml
         
         raise ArgumentError.new("FnTestClassAlias may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3745,7 +3925,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::TestClassAlias, Baml::Types::TestClassAlias].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3753,7 +3934,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T.any(Baml::Types::NamedArgsSingleEnum, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def FnTestNamedArgsSingleEnum(
@@ -3765,8 +3946,8 @@ module Baml
         
         raise ArgumentError.new("FnTestNamedArgsSingleEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3780,7 +3961,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3788,7 +3970,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::RaysData])
     }
     def GetDataType(
@@ -3800,8 +3982,8 @@ module Baml
         
         raise ArgumentError.new("GetDataType may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3815,7 +3997,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::RaysData, Baml::Types::RaysData].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3823,7 +4006,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: Baml::Types::Email,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::OrderInfo])
     }
     def GetOrderInfo(
@@ -3835,8 +4018,8 @@ module Baml
         
         raise ArgumentError.new("GetOrderInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3850,7 +4033,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::OrderInfo, Baml::Types::OrderInfo].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3858,7 +4042,7 @@ module Baml
       params(
         varargs: T.untyped,
         query: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::SearchParams])
     }
     def GetQuery(
@@ -3870,8 +4054,8 @@ module Baml
         
         raise ArgumentError.new("GetQuery may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3885,7 +4069,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::SearchParams, Baml::Types::SearchParams].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3893,7 +4078,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::DynamicOutput])
     }
     def MyFunc(
@@ -3905,8 +4090,8 @@ module Baml
         
         raise ArgumentError.new("MyFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3920,7 +4105,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::DynamicOutput, Baml::Types::DynamicOutput].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3928,7 +4114,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Array[T.nilable(Baml::Types::OptionalTest_ReturnType)]])
     }
     def OptionalTest_Function(
@@ -3940,8 +4126,8 @@ module Baml
         
         raise ArgumentError.new("OptionalTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3955,7 +4141,8 @@ module Baml
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::OptionalTest_ReturnType], T::Array[T.nilable(Baml::Types::OptionalTest_ReturnType)]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3963,7 +4150,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestClaude(
@@ -3975,8 +4162,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaude may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -3990,7 +4177,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -3998,7 +4186,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestClaudeChat(
@@ -4010,8 +4198,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4025,7 +4213,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4033,7 +4222,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestClaudeChatNoSystem(
@@ -4045,8 +4234,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4060,7 +4249,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4068,7 +4258,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestOpenAI(
@@ -4080,8 +4270,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAI may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4095,7 +4285,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4103,7 +4294,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestOpenAIChat(
@@ -4115,8 +4306,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4130,7 +4321,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4138,7 +4330,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestOpenAIChatNoSystem(
@@ -4150,8 +4342,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4165,7 +4357,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4173,7 +4366,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestStreaming(
@@ -4185,8 +4378,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestStreaming may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4200,7 +4393,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4208,7 +4402,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::Schema])
     }
     def SchemaDescriptions(
@@ -4220,8 +4414,8 @@ module Baml
         
         raise ArgumentError.new("SchemaDescriptions may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4235,7 +4429,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::Schema, Baml::Types::Schema].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4243,7 +4438,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAnthropic(
@@ -4255,8 +4450,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4270,7 +4465,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4278,7 +4474,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAnthropicShorthand(
@@ -4290,8 +4486,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropicShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4305,7 +4501,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4313,7 +4510,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAws(
@@ -4325,8 +4522,8 @@ module Baml
         
         raise ArgumentError.new("TestAws may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4340,7 +4537,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4348,7 +4546,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAzure(
@@ -4360,8 +4558,8 @@ module Baml
         
         raise ArgumentError.new("TestAzure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4375,7 +4573,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4383,7 +4582,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestCaching(
@@ -4395,8 +4594,8 @@ module Baml
         
         raise ArgumentError.new("TestCaching may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4410,7 +4609,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4418,7 +4618,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFallbackClient(
@@ -4430,8 +4630,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackClient may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4445,7 +4645,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4453,7 +4654,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFallbackToShorthand(
@@ -4465,8 +4666,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackToShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4480,7 +4681,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4488,7 +4690,7 @@ module Baml
       params(
         varargs: T.untyped,
         myBool: T::Boolean,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleBool(
@@ -4500,8 +4702,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4515,7 +4717,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4523,7 +4726,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleClass(
@@ -4535,8 +4738,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4550,7 +4753,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4558,7 +4762,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[T.any(Baml::Types::NamedArgsSingleEnumList, String)],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleEnumList(
@@ -4570,8 +4774,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleEnumList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4585,7 +4789,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4593,7 +4798,7 @@ module Baml
       params(
         varargs: T.untyped,
         myFloat: Float,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleFloat(
@@ -4605,8 +4810,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleFloat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4620,7 +4825,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4628,7 +4834,7 @@ module Baml
       params(
         varargs: T.untyped,
         myInt: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleInt(
@@ -4640,8 +4846,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleInt may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4655,7 +4861,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4663,7 +4870,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, Baml::Types::StringToClassEntry],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Hash[String, Baml::Types::StringToClassEntry]])
     }
     def TestFnNamedArgsSingleMapStringToClass(
@@ -4675,8 +4882,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4690,7 +4897,8 @@ module Baml
       )
       Baml::BamlStream[T::Hash[String, Baml::PartialTypes::StringToClassEntry], T::Hash[String, Baml::Types::StringToClassEntry]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4698,7 +4906,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, T::Hash[String, String]],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Hash[String, T::Hash[String, String]]])
     }
     def TestFnNamedArgsSingleMapStringToMap(
@@ -4710,8 +4918,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToMap may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4725,7 +4933,8 @@ module Baml
       )
       Baml::BamlStream[T::Hash[String, T::Hash[String, T.nilable(String)]], T::Hash[String, T::Hash[String, String]]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4733,7 +4942,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[T::Hash[String, String]])
     }
     def TestFnNamedArgsSingleMapStringToString(
@@ -4745,8 +4954,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4760,7 +4969,8 @@ module Baml
       )
       Baml::BamlStream[T::Hash[String, T.nilable(String)], T::Hash[String, String]].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4768,7 +4978,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleString(
@@ -4780,8 +4990,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4795,7 +5005,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4803,7 +5014,7 @@ module Baml
       params(
         varargs: T.untyped,
         myStringArray: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleStringArray(
@@ -4815,8 +5026,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringArray may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4830,7 +5041,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4838,7 +5050,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleStringList(
@@ -4850,8 +5062,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4865,7 +5077,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4873,7 +5086,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestGemini(
@@ -4885,8 +5098,8 @@ module Baml
         
         raise ArgumentError.new("TestGemini may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4900,7 +5113,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4908,7 +5122,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestImageInput(
@@ -4920,8 +5134,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4935,7 +5149,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4943,7 +5158,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestImageInputAnthropic(
@@ -4955,8 +5170,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInputAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -4970,7 +5185,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -4978,7 +5194,7 @@ module Baml
       params(
         varargs: T.untyped,
         imgs: T::Array[Baml::Image],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestImageListInput(
@@ -4990,8 +5206,8 @@ module Baml
         
         raise ArgumentError.new("TestImageListInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5005,7 +5221,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5013,7 +5230,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,myArg2: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestMulticlassNamedArgs(
@@ -5025,8 +5242,8 @@ module Baml
         
         raise ArgumentError.new("TestMulticlassNamedArgs may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5040,7 +5257,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5048,7 +5266,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestOllama(
@@ -5060,8 +5278,8 @@ module Baml
         
         raise ArgumentError.new("TestOllama may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5075,7 +5293,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5083,7 +5302,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestOpenAILegacyProvider(
@@ -5095,8 +5314,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAILegacyProvider may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5110,7 +5329,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5118,7 +5338,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestOpenAIShorthand(
@@ -5130,8 +5350,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAIShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5145,7 +5365,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5153,7 +5374,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestRetryConstant(
@@ -5165,8 +5386,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryConstant may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5180,7 +5401,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5188,7 +5410,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestRetryExponential(
@@ -5200,8 +5422,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryExponential may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5215,7 +5437,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5223,7 +5446,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestVertex(
@@ -5235,8 +5458,8 @@ module Baml
         
         raise ArgumentError.new("TestVertex may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5250,7 +5473,8 @@ module Baml
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -5258,7 +5482,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T.any(String, T::Boolean),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::CancellationToken)]
       ).returns(Baml::BamlStream[Baml::Types::UnionTest_ReturnType])
     }
     def UnionTest_Function(
@@ -5270,8 +5494,8 @@ module Baml
         
         raise ArgumentError.new("UnionTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:client_registry, :tb, :cancellation_token]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb, :cancellation_token): #{baml_options.keys - [:client_registry, :tb, :cancellation_token]}")
       end
 
       raw = @runtime.stream_function(
@@ -5285,7 +5509,8 @@ module Baml
       )
       Baml::BamlStream[Baml::PartialTypes::UnionTest_ReturnType, Baml::Types::UnionTest_ReturnType].new(
         ffi_stream: raw,
-        ctx_manager: @ctx_manager
+        ctx_manager: @ctx_manager,
+        cancellation_token: baml_options[:cancellation_token]
       )
     end
 
@@ -39,6 +39,7 @@ module Baml
     class GroceryReceipt < T::Struct; end
     class InnerClass < T::Struct; end
     class InnerClass2 < T::Struct; end
+    class LinkPreview < T::Struct; end
     class NamedArgsSingleClass < T::Struct; end
     class Nested < T::Struct; end
     class Nested2 < T::Struct; end
@@ -350,6 +351,24 @@ module Baml
         @props = props
       end
     end
+    class LinkPreview < T::Struct
+      include Baml::Sorbet::Struct
+      const :url, T.nilable(String)
+      const :title, String
+      const :tags, T::Array[String]
+      const :description, T.nilable(String)
+
+      def initialize(props)
+        super(
+          url: props[:url],
+          title: props[:title],
+          tags: props[:tags],
+          description: props[:description],
+        )
+
+        @props = props
+      end
+    end
     class NamedArgsSingleClass < T::Struct
       include Baml::Sorbet::Struct
       const :key, T.nilable(String)
@@ -178,6 +178,22 @@ describe "ruby<->baml integration tests" do
     assert msgs.last == final, "Expected last stream message to match final response."
   end
 
+  it "streams fields marked with @stream only when finished or non-null" do
+    stream = b.stream.FnOutputLinkPreview(input: "the history of the bicycle")
+    msgs = []
+    stream.each do |msg|
+      msgs << msg
+    end
+    final = stream.get_final_response
+
+    assert msgs.size > 0, "Expected at least one streamed response but got none."
+    msgs.each do |msg|
+      refute_nil msg.title
+      refute_nil msg.tags
+      assert [nil, final.url].include?(msg.url), "Expected url to be streamed only once finished, but got #{msg.url}"
+    end
+  end
+
   it "tests dynamic" do
     t = Baml::TypeBuilder.new
     t.Person.add_property("last_name", t.string.list)
@@ -16,7 +16,9 @@ $ pnpm add @boundaryml/baml
 // @ts-nocheck
 // biome-ignore format: autogenerated code
 import { BamlRuntime, FunctionResult, BamlCtxManager, BamlStream, Image, ClientRegistry, BamlValidationError, createBamlValidationError } from "@boundaryml/baml"
-import {Blah, BookOrder, ClassOptionalOutput, ClassOptionalOutput2, ClassWithImage, CustomTaskResult, DummyOutput, DynInputOutput, DynamicClassOne, DynamicClassTwo, DynamicOutput, Education, Email, Event, FakeImage, FlightConfirmation, GroceryReceipt, InnerClass, InnerClass2, NamedArgsSingleClass, Nested, Nested2, OptionalTest_Prop1, OptionalTest_ReturnType, OrderInfo, Person, Quantity, RaysData, ReceiptInfo, ReceiptItem, Recipe, Resume, Schema, SearchParams, SomeClassNestedDynamic, StringToClassEntry, TestClassAlias, TestClassNested, TestClassWithEnum, TestOutputClass, UnionTest_ReturnType, WithReasoning, Category, Category2, Category3, Color, DataType, DynEnumOne, DynEnumTwo, EnumInClass, EnumOutput, Hobby, NamedArgsSingleEnum, NamedArgsSingleEnumList, OptionalTest_CategoryType, OrderStatus, Tag, TestEnum} from "./types"
+import {Blah, BookOrder, ClassOptionalOutput, ClassOptionalOutput2, ClassWithImage, CustomTaskResult, DummyOutput, DynInputOutput, DynamicClassOne, DynamicClassTwo, DynamicOutput, Education, Email, Event, FakeImage, FlightConfirmation, GroceryReceipt, InnerClass, InnerClass2, LinkPreview, NamedArgsSingleClass, Nested, Nested2, OptionalTest_Prop1, OptionalTest_ReturnType, OrderInfo, Person, Quantity, RaysData, ReceiptInfo, ReceiptItem, Recipe, Resume, Schema, SearchParams, SomeClassNestedDynamic, StringToClassEntry, TestClassAlias, TestClassNested, TestClassWithEnum, TestOutputClass, UnionTest_ReturnType, WithReasoning, Category, Category2, Category3, Color, DataType, DynEnumOne, DynEnumTwo, EnumInClass, EnumOutput, Hobby, NamedArgsSingleEnum, NamedArgsSingleEnumList, OptionalTest_CategoryType, OrderStatus, Tag, TestEnum} from "./types"
+import * as types from "./types"
+import { partial_types } from "./partial_types"
 import TypeBuilder from "./type_builder"
 import { DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_CTX, DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME } from "./globals"
 
@@ -44,20 +46,23 @@ export class BamlAsyncClient {
   
   async AaaSamOutputFormat(
       recipe: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Recipe> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "AaaSamOutputFormat",
         {
           "recipe": recipe
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Recipe
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -69,20 +74,23 @@ export class BamlAsyncClient {
   
   async AudioInput(
       aud: Audio,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "AudioInput",
         {
           "aud": aud
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -94,20 +102,23 @@ export class BamlAsyncClient {
   
   async ClassifyDynEnumTwo(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<(string | DynEnumTwo)> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ClassifyDynEnumTwo",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as (string | DynEnumTwo)
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -119,20 +130,23 @@ export class BamlAsyncClient {
   
   async ClassifyMessage(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Category> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ClassifyMessage",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Category
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -144,20 +158,23 @@ export class BamlAsyncClient {
   
   async ClassifyMessage2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Category> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ClassifyMessage2",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Category
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -169,20 +186,23 @@ export class BamlAsyncClient {
   
   async ClassifyMessage3(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Category> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ClassifyMessage3",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Category
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -194,20 +214,23 @@ export class BamlAsyncClient {
   
   async CustomTask(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<BookOrder | FlightConfirmation | GroceryReceipt> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "CustomTask",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as BookOrder | FlightConfirmation | GroceryReceipt
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -219,20 +242,23 @@ export class BamlAsyncClient {
   
   async DescribeImage(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DescribeImage",
         {
           "img": img
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -244,20 +270,23 @@ export class BamlAsyncClient {
   
   async DescribeImage2(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DescribeImage2",
         {
           "classWithImage": classWithImage,"img2": img2
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -269,20 +298,23 @@ export class BamlAsyncClient {
   
   async DescribeImage3(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DescribeImage3",
         {
           "classWithImage": classWithImage,"img2": img2
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -294,20 +326,23 @@ export class BamlAsyncClient {
   
   async DescribeImage4(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DescribeImage4",
         {
           "classWithImage": classWithImage,"img2": img2
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -319,20 +354,23 @@ export class BamlAsyncClient {
   
   async DummyOutputFunction(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<DummyOutput> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DummyOutputFunction",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as DummyOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -344,20 +382,23 @@ export class BamlAsyncClient {
   
   async DynamicFunc(
       input: DynamicClassOne,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<DynamicClassTwo> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DynamicFunc",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as DynamicClassTwo
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -369,20 +410,23 @@ export class BamlAsyncClient {
   
   async DynamicInputOutput(
       input: DynInputOutput,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<DynInputOutput> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DynamicInputOutput",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as DynInputOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -394,20 +438,23 @@ export class BamlAsyncClient {
   
   async DynamicListInputOutput(
       input: DynInputOutput[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<DynInputOutput[]> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "DynamicListInputOutput",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as DynInputOutput[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -419,20 +466,23 @@ export class BamlAsyncClient {
   
   async ExpectFailure(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ExpectFailure",
         {
           
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -444,20 +494,23 @@ export class BamlAsyncClient {
   
   async ExtractNames(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string[]> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ExtractNames",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -469,20 +522,23 @@ export class BamlAsyncClient {
   
   async ExtractPeople(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Person[]> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ExtractPeople",
         {
           "text": text
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Person[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -494,20 +550,23 @@ export class BamlAsyncClient {
   
   async ExtractReceiptInfo(
       email: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<ReceiptInfo> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ExtractReceiptInfo",
         {
           "email": email
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as ReceiptInfo
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -519,20 +578,23 @@ export class BamlAsyncClient {
   
   async ExtractResume(
       resume: string,img?: Image | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Resume> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ExtractResume",
         {
           "resume": resume,"img": img?? null
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Resume
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -544,20 +606,23 @@ export class BamlAsyncClient {
   
   async ExtractResume2(
       resume: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Resume> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "ExtractResume2",
         {
           "resume": resume
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Resume
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -569,20 +634,23 @@ export class BamlAsyncClient {
   
   async FnClassOptionalOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<ClassOptionalOutput | null> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnClassOptionalOutput",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as ClassOptionalOutput | null
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -594,20 +662,23 @@ export class BamlAsyncClient {
   
   async FnClassOptionalOutput2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<ClassOptionalOutput2 | null> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnClassOptionalOutput2",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as ClassOptionalOutput2 | null
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -619,20 +690,23 @@ export class BamlAsyncClient {
   
   async FnEnumListOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<EnumOutput[]> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnEnumListOutput",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as EnumOutput[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -644,20 +718,23 @@ export class BamlAsyncClient {
   
   async FnEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<EnumOutput> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnEnumOutput",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as EnumOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -669,20 +746,23 @@ export class BamlAsyncClient {
   
   async FnNamedArgsSingleStringOptional(
       myString?: string | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnNamedArgsSingleStringOptional",
         {
           "myString": myString?? null
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -694,20 +774,23 @@ export class BamlAsyncClient {
   
   async FnOutputBool(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<boolean> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnOutputBool",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as boolean
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -719,20 +802,23 @@ export class BamlAsyncClient {
   
   async FnOutputClass(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<TestOutputClass> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnOutputClass",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as TestOutputClass
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -744,20 +830,23 @@ export class BamlAsyncClient {
   
   async FnOutputClassList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<TestOutputClass[]> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnOutputClassList",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as TestOutputClass[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -769,20 +858,23 @@ export class BamlAsyncClient {
   
   async FnOutputClassNested(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<TestClassNested> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnOutputClassNested",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as TestClassNested
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -794,20 +886,51 @@ export class BamlAsyncClient {
   
   async FnOutputClassWithEnum(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<TestClassWithEnum> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnOutputClassWithEnum",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as TestClassWithEnum
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
+      const bamlError = createBamlValidationError(error);
+      if (bamlError instanceof BamlValidationError) {
+        throw bamlError;
+      } else {
+        throw error;
+      }
+    }
+  }
+  
+  async FnOutputLinkPreview(
+      input: string,
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): Promise<LinkPreview> {
+    try {
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
+        "FnOutputLinkPreview",
+        {
+          "input": input
+        },
+        ctx,
+        __baml_options__?.tb?.__tb(),
+        __baml_options__?.clientRegistry,
+      ))
+      return raw.parsed() as LinkPreview
+    } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -819,20 +942,23 @@ export class BamlAsyncClient {
   
   async FnOutputStringList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string[]> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnOutputStringList",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -844,20 +970,23 @@ export class BamlAsyncClient {
   
   async FnTestAliasedEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<TestEnum> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnTestAliasedEnumOutput",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as TestEnum
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -869,20 +998,23 @@ export class BamlAsyncClient {
   
   async FnTestClassAlias(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<TestClassAlias> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnTestClassAlias",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as TestClassAlias
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -894,20 +1026,23 @@ export class BamlAsyncClient {
   
   async FnTestNamedArgsSingleEnum(
       myArg: NamedArgsSingleEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "FnTestNamedArgsSingleEnum",
         {
           "myArg": myArg
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -919,20 +1054,23 @@ export class BamlAsyncClient {
   
   async GetDataType(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<RaysData> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "GetDataType",
         {
           "text": text
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as RaysData
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -944,20 +1082,23 @@ export class BamlAsyncClient {
   
   async GetOrderInfo(
       email: Email,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<OrderInfo> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "GetOrderInfo",
         {
           "email": email
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as OrderInfo
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -969,20 +1110,23 @@ export class BamlAsyncClient {
   
   async GetQuery(
       query: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<SearchParams> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "GetQuery",
         {
           "query": query
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as SearchParams
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -994,20 +1138,23 @@ export class BamlAsyncClient {
   
   async MyFunc(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<DynamicOutput> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "MyFunc",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as DynamicOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1019,20 +1166,23 @@ export class BamlAsyncClient {
   
   async OptionalTest_Function(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<(OptionalTest_ReturnType | null)[]> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "OptionalTest_Function",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as (OptionalTest_ReturnType | null)[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1044,20 +1194,23 @@ export class BamlAsyncClient {
   
   async PromptTestClaude(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "PromptTestClaude",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1069,20 +1222,23 @@ export class BamlAsyncClient {
   
   async PromptTestClaudeChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "PromptTestClaudeChat",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1094,20 +1250,23 @@ export class BamlAsyncClient {
   
   async PromptTestClaudeChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "PromptTestClaudeChatNoSystem",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1119,20 +1278,23 @@ export class BamlAsyncClient {
   
   async PromptTestOpenAI(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "PromptTestOpenAI",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1144,20 +1306,23 @@ export class BamlAsyncClient {
   
   async PromptTestOpenAIChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "PromptTestOpenAIChat",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1169,20 +1334,23 @@ export class BamlAsyncClient {
   
   async PromptTestOpenAIChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "PromptTestOpenAIChatNoSystem",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1194,20 +1362,23 @@ export class BamlAsyncClient {
   
   async PromptTestStreaming(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "PromptTestStreaming",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1219,20 +1390,23 @@ export class BamlAsyncClient {
   
   async SchemaDescriptions(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Schema> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "SchemaDescriptions",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Schema
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1244,20 +1418,23 @@ export class BamlAsyncClient {
   
   async TestAnthropic(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestAnthropic",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1269,20 +1446,23 @@ export class BamlAsyncClient {
   
   async TestAnthropicShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestAnthropicShorthand",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1294,20 +1474,23 @@ export class BamlAsyncClient {
   
   async TestAws(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestAws",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1319,20 +1502,23 @@ export class BamlAsyncClient {
   
   async TestAzure(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestAzure",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1344,20 +1530,23 @@ export class BamlAsyncClient {
   
   async TestCaching(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestCaching",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1369,20 +1558,23 @@ export class BamlAsyncClient {
   
   async TestFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFallbackClient",
         {
           
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1394,20 +1586,23 @@ export class BamlAsyncClient {
   
   async TestFallbackToShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFallbackToShorthand",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1419,20 +1614,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleBool(
       myBool: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleBool",
         {
           "myBool": myBool
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1444,20 +1642,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleClass(
       myArg: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleClass",
         {
           "myArg": myArg
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1469,20 +1670,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleEnumList(
       myArg: NamedArgsSingleEnumList[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleEnumList",
         {
           "myArg": myArg
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1494,20 +1698,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleFloat(
       myFloat: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleFloat",
         {
           "myFloat": myFloat
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1519,20 +1726,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleInt(
       myInt: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleInt",
         {
           "myInt": myInt
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1544,20 +1754,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleMapStringToClass(
       myMap: Record<string, StringToClassEntry>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Record<string, StringToClassEntry>> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleMapStringToClass",
         {
           "myMap": myMap
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Record<string, StringToClassEntry>
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1569,20 +1782,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleMapStringToMap(
       myMap: Record<string, Record<string, string>>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Record<string, Record<string, string>>> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleMapStringToMap",
         {
           "myMap": myMap
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Record<string, Record<string, string>>
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1594,20 +1810,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleMapStringToString(
       myMap: Record<string, string>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<Record<string, string>> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleMapStringToString",
         {
           "myMap": myMap
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as Record<string, string>
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1619,20 +1838,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleString(
       myString: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleString",
         {
           "myString": myString
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1644,20 +1866,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleStringArray(
       myStringArray: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleStringArray",
         {
           "myStringArray": myStringArray
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1669,20 +1894,23 @@ export class BamlAsyncClient {
   
   async TestFnNamedArgsSingleStringList(
       myArg: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestFnNamedArgsSingleStringList",
         {
           "myArg": myArg
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1694,20 +1922,23 @@ export class BamlAsyncClient {
   
   async TestGemini(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestGemini",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1719,20 +1950,23 @@ export class BamlAsyncClient {
   
   async TestImageInput(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestImageInput",
         {
           "img": img
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1744,20 +1978,23 @@ export class BamlAsyncClient {
   
   async TestImageInputAnthropic(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestImageInputAnthropic",
         {
           "img": img
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1769,20 +2006,23 @@ export class BamlAsyncClient {
   
   async TestImageListInput(
       imgs: Image[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestImageListInput",
         {
           "imgs": imgs
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1794,20 +2034,23 @@ export class BamlAsyncClient {
   
   async TestMulticlassNamedArgs(
       myArg: NamedArgsSingleClass,myArg2: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestMulticlassNamedArgs",
         {
           "myArg": myArg,"myArg2": myArg2
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1819,20 +2062,23 @@ export class BamlAsyncClient {
   
   async TestOllama(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestOllama",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1844,20 +2090,23 @@ export class BamlAsyncClient {
   
   async TestOpenAILegacyProvider(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestOpenAILegacyProvider",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1869,20 +2118,23 @@ export class BamlAsyncClient {
   
   async TestOpenAIShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestOpenAIShorthand",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1894,20 +2146,23 @@ export class BamlAsyncClient {
   
   async TestRetryConstant(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestRetryConstant",
         {
           
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1919,20 +2174,23 @@ export class BamlAsyncClient {
   
   async TestRetryExponential(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestRetryExponential",
         {
           
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1944,20 +2202,23 @@ export class BamlAsyncClient {
   
   async TestVertex(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<string> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "TestVertex",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -1969,20 +2230,23 @@ export class BamlAsyncClient {
   
   async UnionTest_Function(
       input: string | boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
   ): Promise<UnionTest_ReturnType> {
     try {
-      const raw = await this.runtime.callFunction(
+      const raw = await this.ctx_manager.callWithSignal(__baml_options__?.signal, (ctx) => this.runtime.callFunction(
         "UnionTest_Function",
         {
           "input": input
         },
-        this.ctx_manager.cloneContext(),
+        ctx,
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
-      )
+      ))
       return raw.parsed() as UnionTest_ReturnType
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
@@ -2000,8 +2264,8 @@ class BamlStreamClient {
   
   AaaSamOutputFormat(
       recipe: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Recipe>, Recipe> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.Recipe, Recipe> {
     try {
       const raw = this.runtime.streamFunction(
         "AaaSamOutputFormat",
@@ -2013,12 +2277,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Recipe>, Recipe>(
+      return new BamlStream<partial_types.Recipe, Recipe>(
         raw,
-        (a): a is RecursivePartialNull<Recipe> => a,
+        (a): a is partial_types.Recipe => a,
         (a): a is Recipe => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2033,8 +2297,8 @@ class BamlStreamClient {
   
   AudioInput(
       aud: Audio,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "AudioInput",
@@ -2046,12 +2310,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2066,8 +2330,8 @@ class BamlStreamClient {
   
   ClassifyDynEnumTwo(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<(string | DynEnumTwo)>, (string | DynEnumTwo)> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | types.DynEnumTwo | null), (string | DynEnumTwo)> {
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyDynEnumTwo",
@@ -2079,12 +2343,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<(string | DynEnumTwo)>, (string | DynEnumTwo)>(
+      return new BamlStream<(string | types.DynEnumTwo | null), (string | DynEnumTwo)>(
         raw,
-        (a): a is RecursivePartialNull<(string | DynEnumTwo)> => a,
+        (a): a is (string | types.DynEnumTwo | null) => a,
         (a): a is (string | DynEnumTwo) => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2099,8 +2363,8 @@ class BamlStreamClient {
   
   ClassifyMessage(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Category>, Category> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(types.Category | null), Category> {
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyMessage",
@@ -2112,12 +2376,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Category>, Category>(
+      return new BamlStream<(types.Category | null), Category>(
         raw,
-        (a): a is RecursivePartialNull<Category> => a,
+        (a): a is (types.Category | null) => a,
         (a): a is Category => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2132,8 +2396,8 @@ class BamlStreamClient {
   
   ClassifyMessage2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Category>, Category> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(types.Category | null), Category> {
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyMessage2",
@@ -2145,12 +2409,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Category>, Category>(
+      return new BamlStream<(types.Category | null), Category>(
         raw,
-        (a): a is RecursivePartialNull<Category> => a,
+        (a): a is (types.Category | null) => a,
         (a): a is Category => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2165,8 +2429,8 @@ class BamlStreamClient {
   
   ClassifyMessage3(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Category>, Category> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(types.Category | null), Category> {
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyMessage3",
@@ -2178,12 +2442,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Category>, Category>(
+      return new BamlStream<(types.Category | null), Category>(
         raw,
-        (a): a is RecursivePartialNull<Category> => a,
+        (a): a is (types.Category | null) => a,
         (a): a is Category => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2198,8 +2462,8 @@ class BamlStreamClient {
   
   CustomTask(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<BookOrder | FlightConfirmation | GroceryReceipt>, BookOrder | FlightConfirmation | GroceryReceipt> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(partial_types.BookOrder | partial_types.FlightConfirmation | partial_types.GroceryReceipt | null), BookOrder | FlightConfirmation | GroceryReceipt> {
     try {
       const raw = this.runtime.streamFunction(
         "CustomTask",
@@ -2211,12 +2475,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<BookOrder | FlightConfirmation | GroceryReceipt>, BookOrder | FlightConfirmation | GroceryReceipt>(
+      return new BamlStream<(partial_types.BookOrder | partial_types.FlightConfirmation | partial_types.GroceryReceipt | null), BookOrder | FlightConfirmation | GroceryReceipt>(
         raw,
-        (a): a is RecursivePartialNull<BookOrder | FlightConfirmation | GroceryReceipt> => a,
+        (a): a is (partial_types.BookOrder | partial_types.FlightConfirmation | partial_types.GroceryReceipt | null) => a,
         (a): a is BookOrder | FlightConfirmation | GroceryReceipt => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2231,8 +2495,8 @@ class BamlStreamClient {
   
   DescribeImage(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage",
@@ -2244,12 +2508,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2264,8 +2528,8 @@ class BamlStreamClient {
   
   DescribeImage2(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage2",
@@ -2277,12 +2541,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2297,8 +2561,8 @@ class BamlStreamClient {
   
   DescribeImage3(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage3",
@@ -2310,12 +2574,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2330,8 +2594,8 @@ class BamlStreamClient {
   
   DescribeImage4(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage4",
@@ -2343,12 +2607,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2363,8 +2627,8 @@ class BamlStreamClient {
   
   DummyOutputFunction(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DummyOutput>, DummyOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.DummyOutput, DummyOutput> {
     try {
       const raw = this.runtime.streamFunction(
         "DummyOutputFunction",
@@ -2376,12 +2640,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<DummyOutput>, DummyOutput>(
+      return new BamlStream<partial_types.DummyOutput, DummyOutput>(
         raw,
-        (a): a is RecursivePartialNull<DummyOutput> => a,
+        (a): a is partial_types.DummyOutput => a,
         (a): a is DummyOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2396,8 +2660,8 @@ class BamlStreamClient {
   
   DynamicFunc(
       input: DynamicClassOne,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynamicClassTwo>, DynamicClassTwo> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.DynamicClassTwo, DynamicClassTwo> {
     try {
       const raw = this.runtime.streamFunction(
         "DynamicFunc",
@@ -2409,12 +2673,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<DynamicClassTwo>, DynamicClassTwo>(
+      return new BamlStream<partial_types.DynamicClassTwo, DynamicClassTwo>(
         raw,
-        (a): a is RecursivePartialNull<DynamicClassTwo> => a,
+        (a): a is partial_types.DynamicClassTwo => a,
         (a): a is DynamicClassTwo => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2429,8 +2693,8 @@ class BamlStreamClient {
   
   DynamicInputOutput(
       input: DynInputOutput,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynInputOutput>, DynInputOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.DynInputOutput, DynInputOutput> {
     try {
       const raw = this.runtime.streamFunction(
         "DynamicInputOutput",
@@ -2442,12 +2706,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<DynInputOutput>, DynInputOutput>(
+      return new BamlStream<partial_types.DynInputOutput, DynInputOutput>(
         raw,
-        (a): a is RecursivePartialNull<DynInputOutput> => a,
+        (a): a is partial_types.DynInputOutput => a,
         (a): a is DynInputOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2462,8 +2726,8 @@ class BamlStreamClient {
   
   DynamicListInputOutput(
       input: DynInputOutput[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynInputOutput[]>, DynInputOutput[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.DynInputOutput[], DynInputOutput[]> {
     try {
       const raw = this.runtime.streamFunction(
         "DynamicListInputOutput",
@@ -2475,12 +2739,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<DynInputOutput[]>, DynInputOutput[]>(
+      return new BamlStream<partial_types.DynInputOutput[], DynInputOutput[]>(
         raw,
-        (a): a is RecursivePartialNull<DynInputOutput[]> => a,
+        (a): a is partial_types.DynInputOutput[] => a,
         (a): a is DynInputOutput[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2495,8 +2759,8 @@ class BamlStreamClient {
   
   ExpectFailure(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "ExpectFailure",
@@ -2508,12 +2772,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2528,8 +2792,8 @@ class BamlStreamClient {
   
   ExtractNames(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string[]>, string[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null)[], string[]> {
     try {
       const raw = this.runtime.streamFunction(
         "ExtractNames",
@@ -2541,12 +2805,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string[]>, string[]>(
+      return new BamlStream<(string | null)[], string[]>(
         raw,
-        (a): a is RecursivePartialNull<string[]> => a,
+        (a): a is (string | null)[] => a,
         (a): a is string[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2561,8 +2825,8 @@ class BamlStreamClient {
   
   ExtractPeople(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Person[]>, Person[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.Person[], Person[]> {
     try {
       const raw = this.runtime.streamFunction(
         "ExtractPeople",
@@ -2574,12 +2838,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Person[]>, Person[]>(
+      return new BamlStream<partial_types.Person[], Person[]>(
         raw,
-        (a): a is RecursivePartialNull<Person[]> => a,
+        (a): a is partial_types.Person[] => a,
         (a): a is Person[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2594,8 +2858,8 @@ class BamlStreamClient {
   
   ExtractReceiptInfo(
       email: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<ReceiptInfo>, ReceiptInfo> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.ReceiptInfo, ReceiptInfo> {
     try {
       const raw = this.runtime.streamFunction(
         "ExtractReceiptInfo",
@@ -2607,12 +2871,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<ReceiptInfo>, ReceiptInfo>(
+      return new BamlStream<partial_types.ReceiptInfo, ReceiptInfo>(
         raw,
-        (a): a is RecursivePartialNull<ReceiptInfo> => a,
+        (a): a is partial_types.ReceiptInfo => a,
         (a): a is ReceiptInfo => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2627,8 +2891,8 @@ class BamlStreamClient {
   
   ExtractResume(
       resume: string,img?: Image | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Resume>, Resume> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.Resume, Resume> {
     try {
       const raw = this.runtime.streamFunction(
         "ExtractResume",
@@ -2640,12 +2904,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Resume>, Resume>(
+      return new BamlStream<partial_types.Resume, Resume>(
         raw,
-        (a): a is RecursivePartialNull<Resume> => a,
+        (a): a is partial_types.Resume => a,
         (a): a is Resume => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2660,8 +2924,8 @@ class BamlStreamClient {
   
   ExtractResume2(
       resume: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Resume>, Resume> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.Resume, Resume> {
     try {
       const raw = this.runtime.streamFunction(
         "ExtractResume2",
@@ -2673,12 +2937,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Resume>, Resume>(
+      return new BamlStream<partial_types.Resume, Resume>(
         raw,
-        (a): a is RecursivePartialNull<Resume> => a,
+        (a): a is partial_types.Resume => a,
         (a): a is Resume => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2693,8 +2957,8 @@ class BamlStreamClient {
   
   FnClassOptionalOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<ClassOptionalOutput | null>, ClassOptionalOutput | null> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(partial_types.ClassOptionalOutput | null), ClassOptionalOutput | null> {
     try {
       const raw = this.runtime.streamFunction(
         "FnClassOptionalOutput",
@@ -2706,12 +2970,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<ClassOptionalOutput | null>, ClassOptionalOutput | null>(
+      return new BamlStream<(partial_types.ClassOptionalOutput | null), ClassOptionalOutput | null>(
         raw,
-        (a): a is RecursivePartialNull<ClassOptionalOutput | null> => a,
+        (a): a is (partial_types.ClassOptionalOutput | null) => a,
         (a): a is ClassOptionalOutput | null => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2726,8 +2990,8 @@ class BamlStreamClient {
   
   FnClassOptionalOutput2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<ClassOptionalOutput2 | null>, ClassOptionalOutput2 | null> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(partial_types.ClassOptionalOutput2 | null), ClassOptionalOutput2 | null> {
     try {
       const raw = this.runtime.streamFunction(
         "FnClassOptionalOutput2",
@@ -2739,12 +3003,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<ClassOptionalOutput2 | null>, ClassOptionalOutput2 | null>(
+      return new BamlStream<(partial_types.ClassOptionalOutput2 | null), ClassOptionalOutput2 | null>(
         raw,
-        (a): a is RecursivePartialNull<ClassOptionalOutput2 | null> => a,
+        (a): a is (partial_types.ClassOptionalOutput2 | null) => a,
         (a): a is ClassOptionalOutput2 | null => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2759,8 +3023,8 @@ class BamlStreamClient {
   
   FnEnumListOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<EnumOutput[]>, EnumOutput[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(types.EnumOutput | null)[], EnumOutput[]> {
     try {
       const raw = this.runtime.streamFunction(
         "FnEnumListOutput",
@@ -2772,12 +3036,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<EnumOutput[]>, EnumOutput[]>(
+      return new BamlStream<(types.EnumOutput | null)[], EnumOutput[]>(
         raw,
-        (a): a is RecursivePartialNull<EnumOutput[]> => a,
+        (a): a is (types.EnumOutput | null)[] => a,
         (a): a is EnumOutput[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2792,8 +3056,8 @@ class BamlStreamClient {
   
   FnEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<EnumOutput>, EnumOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(types.EnumOutput | null), EnumOutput> {
     try {
       const raw = this.runtime.streamFunction(
         "FnEnumOutput",
@@ -2805,12 +3069,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<EnumOutput>, EnumOutput>(
+      return new BamlStream<(types.EnumOutput | null), EnumOutput>(
         raw,
-        (a): a is RecursivePartialNull<EnumOutput> => a,
+        (a): a is (types.EnumOutput | null) => a,
         (a): a is EnumOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2825,8 +3089,8 @@ class BamlStreamClient {
   
   FnNamedArgsSingleStringOptional(
       myString?: string | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "FnNamedArgsSingleStringOptional",
@@ -2838,12 +3102,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2858,8 +3122,8 @@ class BamlStreamClient {
   
   FnOutputBool(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<boolean>, boolean> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(boolean | null), boolean> {
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputBool",
@@ -2871,12 +3135,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<boolean>, boolean>(
+      return new BamlStream<(boolean | null), boolean>(
         raw,
-        (a): a is RecursivePartialNull<boolean> => a,
+        (a): a is (boolean | null) => a,
         (a): a is boolean => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2891,8 +3155,8 @@ class BamlStreamClient {
   
   FnOutputClass(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestOutputClass>, TestOutputClass> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.TestOutputClass, TestOutputClass> {
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClass",
@@ -2904,12 +3168,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<TestOutputClass>, TestOutputClass>(
+      return new BamlStream<partial_types.TestOutputClass, TestOutputClass>(
         raw,
-        (a): a is RecursivePartialNull<TestOutputClass> => a,
+        (a): a is partial_types.TestOutputClass => a,
         (a): a is TestOutputClass => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2924,8 +3188,8 @@ class BamlStreamClient {
   
   FnOutputClassList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestOutputClass[]>, TestOutputClass[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.TestOutputClass[], TestOutputClass[]> {
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClassList",
@@ -2937,12 +3201,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<TestOutputClass[]>, TestOutputClass[]>(
+      return new BamlStream<partial_types.TestOutputClass[], TestOutputClass[]>(
         raw,
-        (a): a is RecursivePartialNull<TestOutputClass[]> => a,
+        (a): a is partial_types.TestOutputClass[] => a,
         (a): a is TestOutputClass[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2957,8 +3221,8 @@ class BamlStreamClient {
   
   FnOutputClassNested(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestClassNested>, TestClassNested> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.TestClassNested, TestClassNested> {
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClassNested",
@@ -2970,12 +3234,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<TestClassNested>, TestClassNested>(
+      return new BamlStream<partial_types.TestClassNested, TestClassNested>(
         raw,
-        (a): a is RecursivePartialNull<TestClassNested> => a,
+        (a): a is partial_types.TestClassNested => a,
         (a): a is TestClassNested => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -2990,8 +3254,8 @@ class BamlStreamClient {
   
   FnOutputClassWithEnum(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestClassWithEnum>, TestClassWithEnum> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.TestClassWithEnum, TestClassWithEnum> {
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClassWithEnum",
@@ -3003,12 +3267,45 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<TestClassWithEnum>, TestClassWithEnum>(
+      return new BamlStream<partial_types.TestClassWithEnum, TestClassWithEnum>(
         raw,
-        (a): a is RecursivePartialNull<TestClassWithEnum> => a,
+        (a): a is partial_types.TestClassWithEnum => a,
         (a): a is TestClassWithEnum => a,
         this.ctx_manager.cloneContext(),
+        __baml_options__?.signal,
+      )
+    } catch (error) {
+      if (error instanceof Error) {
+        const bamlError = createBamlValidationError(error);
+        if (bamlError instanceof BamlValidationError) {
+          throw bamlError;
+        }
+      }
+      throw error;
+    }
+  }
+  
+  FnOutputLinkPreview(
+      input: string,
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.LinkPreview, LinkPreview> {
+    try {
+      const raw = this.runtime.streamFunction(
+        "FnOutputLinkPreview",
+        {
+          "input": input
+        },
+        undefined,
+        this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
+        __baml_options__?.clientRegistry,
+      )
+      return new BamlStream<partial_types.LinkPreview, LinkPreview>(
+        raw,
+        (a): a is partial_types.LinkPreview => a,
+        (a): a is LinkPreview => a,
+        this.ctx_manager.cloneContext(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3023,8 +3320,8 @@ class BamlStreamClient {
   
   FnOutputStringList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string[]>, string[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null)[], string[]> {
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputStringList",
@@ -3036,12 +3333,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string[]>, string[]>(
+      return new BamlStream<(string | null)[], string[]>(
         raw,
-        (a): a is RecursivePartialNull<string[]> => a,
+        (a): a is (string | null)[] => a,
         (a): a is string[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3056,8 +3353,8 @@ class BamlStreamClient {
   
   FnTestAliasedEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestEnum>, TestEnum> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(types.TestEnum | null), TestEnum> {
     try {
       const raw = this.runtime.streamFunction(
         "FnTestAliasedEnumOutput",
@@ -3069,12 +3366,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<TestEnum>, TestEnum>(
+      return new BamlStream<(types.TestEnum | null), TestEnum>(
         raw,
-        (a): a is RecursivePartialNull<TestEnum> => a,
+        (a): a is (types.TestEnum | null) => a,
         (a): a is TestEnum => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3089,8 +3386,8 @@ class BamlStreamClient {
   
   FnTestClassAlias(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestClassAlias>, TestClassAlias> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.TestClassAlias, TestClassAlias> {
     try {
       const raw = this.runtime.streamFunction(
         "FnTestClassAlias",
@@ -3102,12 +3399,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<TestClassAlias>, TestClassAlias>(
+      return new BamlStream<partial_types.TestClassAlias, TestClassAlias>(
         raw,
-        (a): a is RecursivePartialNull<TestClassAlias> => a,
+        (a): a is partial_types.TestClassAlias => a,
         (a): a is TestClassAlias => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3122,8 +3419,8 @@ class BamlStreamClient {
   
   FnTestNamedArgsSingleEnum(
       myArg: NamedArgsSingleEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "FnTestNamedArgsSingleEnum",
@@ -3135,12 +3432,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3155,8 +3452,8 @@ class BamlStreamClient {
   
   GetDataType(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<RaysData>, RaysData> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.RaysData, RaysData> {
     try {
       const raw = this.runtime.streamFunction(
         "GetDataType",
@@ -3168,12 +3465,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<RaysData>, RaysData>(
+      return new BamlStream<partial_types.RaysData, RaysData>(
         raw,
-        (a): a is RecursivePartialNull<RaysData> => a,
+        (a): a is partial_types.RaysData => a,
         (a): a is RaysData => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3188,8 +3485,8 @@ class BamlStreamClient {
   
   GetOrderInfo(
       email: Email,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<OrderInfo>, OrderInfo> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.OrderInfo, OrderInfo> {
     try {
       const raw = this.runtime.streamFunction(
         "GetOrderInfo",
@@ -3201,12 +3498,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<OrderInfo>, OrderInfo>(
+      return new BamlStream<partial_types.OrderInfo, OrderInfo>(
         raw,
-        (a): a is RecursivePartialNull<OrderInfo> => a,
+        (a): a is partial_types.OrderInfo => a,
         (a): a is OrderInfo => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3221,8 +3518,8 @@ class BamlStreamClient {
   
   GetQuery(
       query: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<SearchParams>, SearchParams> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.SearchParams, SearchParams> {
     try {
       const raw = this.runtime.streamFunction(
         "GetQuery",
@@ -3234,12 +3531,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<SearchParams>, SearchParams>(
+      return new BamlStream<partial_types.SearchParams, SearchParams>(
         raw,
-        (a): a is RecursivePartialNull<SearchParams> => a,
+        (a): a is partial_types.SearchParams => a,
         (a): a is SearchParams => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3254,8 +3551,8 @@ class BamlStreamClient {
   
   MyFunc(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynamicOutput>, DynamicOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.DynamicOutput, DynamicOutput> {
     try {
       const raw = this.runtime.streamFunction(
         "MyFunc",
@@ -3267,12 +3564,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<DynamicOutput>, DynamicOutput>(
+      return new BamlStream<partial_types.DynamicOutput, DynamicOutput>(
         raw,
-        (a): a is RecursivePartialNull<DynamicOutput> => a,
+        (a): a is partial_types.DynamicOutput => a,
         (a): a is DynamicOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3287,8 +3584,8 @@ class BamlStreamClient {
   
   OptionalTest_Function(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<(OptionalTest_ReturnType | null)[]>, (OptionalTest_ReturnType | null)[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(partial_types.OptionalTest_ReturnType | null)[], (OptionalTest_ReturnType | null)[]> {
     try {
       const raw = this.runtime.streamFunction(
         "OptionalTest_Function",
@@ -3300,12 +3597,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<(OptionalTest_ReturnType | null)[]>, (OptionalTest_ReturnType | null)[]>(
+      return new BamlStream<(partial_types.OptionalTest_ReturnType | null)[], (OptionalTest_ReturnType | null)[]>(
         raw,
-        (a): a is RecursivePartialNull<(OptionalTest_ReturnType | null)[]> => a,
+        (a): a is (partial_types.OptionalTest_ReturnType | null)[] => a,
         (a): a is (OptionalTest_ReturnType | null)[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3320,8 +3617,8 @@ class BamlStreamClient {
   
   PromptTestClaude(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestClaude",
@@ -3333,12 +3630,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3353,8 +3650,8 @@ class BamlStreamClient {
   
   PromptTestClaudeChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestClaudeChat",
@@ -3366,12 +3663,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3386,8 +3683,8 @@ class BamlStreamClient {
   
   PromptTestClaudeChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestClaudeChatNoSystem",
@@ -3399,12 +3696,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3419,8 +3716,8 @@ class BamlStreamClient {
   
   PromptTestOpenAI(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestOpenAI",
@@ -3432,12 +3729,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3452,8 +3749,8 @@ class BamlStreamClient {
   
   PromptTestOpenAIChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestOpenAIChat",
@@ -3465,12 +3762,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3485,8 +3782,8 @@ class BamlStreamClient {
   
   PromptTestOpenAIChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestOpenAIChatNoSystem",
@@ -3498,12 +3795,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3518,8 +3815,8 @@ class BamlStreamClient {
   
   PromptTestStreaming(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestStreaming",
@@ -3531,12 +3828,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3551,8 +3848,8 @@ class BamlStreamClient {
   
   SchemaDescriptions(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Schema>, Schema> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.Schema, Schema> {
     try {
       const raw = this.runtime.streamFunction(
         "SchemaDescriptions",
@@ -3564,12 +3861,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Schema>, Schema>(
+      return new BamlStream<partial_types.Schema, Schema>(
         raw,
-        (a): a is RecursivePartialNull<Schema> => a,
+        (a): a is partial_types.Schema => a,
         (a): a is Schema => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3584,8 +3881,8 @@ class BamlStreamClient {
   
   TestAnthropic(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestAnthropic",
@@ -3597,12 +3894,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3617,8 +3914,8 @@ class BamlStreamClient {
   
   TestAnthropicShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestAnthropicShorthand",
@@ -3630,12 +3927,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3650,8 +3947,8 @@ class BamlStreamClient {
   
   TestAws(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestAws",
@@ -3663,12 +3960,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3683,8 +3980,8 @@ class BamlStreamClient {
   
   TestAzure(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestAzure",
@@ -3696,12 +3993,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3716,8 +4013,8 @@ class BamlStreamClient {
   
   TestCaching(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestCaching",
@@ -3729,12 +4026,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3749,8 +4046,8 @@ class BamlStreamClient {
   
   TestFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFallbackClient",
@@ -3762,12 +4059,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3782,8 +4079,8 @@ class BamlStreamClient {
   
   TestFallbackToShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFallbackToShorthand",
@@ -3795,12 +4092,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3815,8 +4112,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleBool(
       myBool: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleBool",
@@ -3828,12 +4125,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3848,8 +4145,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleClass(
       myArg: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleClass",
@@ -3861,12 +4158,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3881,8 +4178,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleEnumList(
       myArg: NamedArgsSingleEnumList[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleEnumList",
@@ -3894,12 +4191,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3914,8 +4211,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleFloat(
       myFloat: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleFloat",
@@ -3927,12 +4224,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3947,8 +4244,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleInt(
       myInt: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleInt",
@@ -3960,12 +4257,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -3980,8 +4277,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleMapStringToClass(
       myMap: Record<string, StringToClassEntry>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Record<string, StringToClassEntry>>, Record<string, StringToClassEntry>> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<Record<string, (partial_types.StringToClassEntry | null)>, Record<string, StringToClassEntry>> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleMapStringToClass",
@@ -3993,12 +4290,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Record<string, StringToClassEntry>>, Record<string, StringToClassEntry>>(
+      return new BamlStream<Record<string, (partial_types.StringToClassEntry | null)>, Record<string, StringToClassEntry>>(
         raw,
-        (a): a is RecursivePartialNull<Record<string, StringToClassEntry>> => a,
+        (a): a is Record<string, (partial_types.StringToClassEntry | null)> => a,
         (a): a is Record<string, StringToClassEntry> => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4013,8 +4310,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleMapStringToMap(
       myMap: Record<string, Record<string, string>>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Record<string, Record<string, string>>>, Record<string, Record<string, string>>> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<Record<string, Record<string, (string | null)>>, Record<string, Record<string, string>>> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleMapStringToMap",
@@ -4026,12 +4323,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Record<string, Record<string, string>>>, Record<string, Record<string, string>>>(
+      return new BamlStream<Record<string, Record<string, (string | null)>>, Record<string, Record<string, string>>>(
         raw,
-        (a): a is RecursivePartialNull<Record<string, Record<string, string>>> => a,
+        (a): a is Record<string, Record<string, (string | null)>> => a,
         (a): a is Record<string, Record<string, string>> => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4046,8 +4343,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleMapStringToString(
       myMap: Record<string, string>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Record<string, string>>, Record<string, string>> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<Record<string, (string | null)>, Record<string, string>> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleMapStringToString",
@@ -4059,12 +4356,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<Record<string, string>>, Record<string, string>>(
+      return new BamlStream<Record<string, (string | null)>, Record<string, string>>(
         raw,
-        (a): a is RecursivePartialNull<Record<string, string>> => a,
+        (a): a is Record<string, (string | null)> => a,
         (a): a is Record<string, string> => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4079,8 +4376,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleString(
       myString: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleString",
@@ -4092,12 +4389,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4112,8 +4409,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleStringArray(
       myStringArray: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleStringArray",
@@ -4125,12 +4422,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4145,8 +4442,8 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleStringList(
       myArg: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleStringList",
@@ -4158,12 +4455,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4178,8 +4475,8 @@ class BamlStreamClient {
   
   TestGemini(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestGemini",
@@ -4191,12 +4488,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4211,8 +4508,8 @@ class BamlStreamClient {
   
   TestImageInput(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestImageInput",
@@ -4224,12 +4521,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4244,8 +4541,8 @@ class BamlStreamClient {
   
   TestImageInputAnthropic(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestImageInputAnthropic",
@@ -4257,12 +4554,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4277,8 +4574,8 @@ class BamlStreamClient {
   
   TestImageListInput(
       imgs: Image[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestImageListInput",
@@ -4290,12 +4587,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4310,8 +4607,8 @@ class BamlStreamClient {
   
   TestMulticlassNamedArgs(
       myArg: NamedArgsSingleClass,myArg2: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestMulticlassNamedArgs",
@@ -4323,12 +4620,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4343,8 +4640,8 @@ class BamlStreamClient {
   
   TestOllama(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestOllama",
@@ -4356,12 +4653,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4376,8 +4673,8 @@ class BamlStreamClient {
   
   TestOpenAILegacyProvider(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestOpenAILegacyProvider",
@@ -4389,12 +4686,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4409,8 +4706,8 @@ class BamlStreamClient {
   
   TestOpenAIShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestOpenAIShorthand",
@@ -4422,12 +4719,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4442,8 +4739,8 @@ class BamlStreamClient {
   
   TestRetryConstant(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestRetryConstant",
@@ -4455,12 +4752,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4475,8 +4772,8 @@ class BamlStreamClient {
   
   TestRetryExponential(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestRetryExponential",
@@ -4488,12 +4785,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4508,8 +4805,8 @@ class BamlStreamClient {
   
   TestVertex(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
     try {
       const raw = this.runtime.streamFunction(
         "TestVertex",
@@ -4521,12 +4818,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -4541,8 +4838,8 @@ class BamlStreamClient {
   
   UnionTest_Function(
       input: string | boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<UnionTest_ReturnType>, UnionTest_ReturnType> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, signal?: AbortSignal }
+  ): BamlStream<partial_types.UnionTest_ReturnType, UnionTest_ReturnType> {
     try {
       const raw = this.runtime.streamFunction(
         "UnionTest_Function",
@@ -4554,12 +4851,12 @@ class BamlStreamClient {
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
       )
-      return new BamlStream<RecursivePartialNull<UnionTest_ReturnType>, UnionTest_ReturnType>(
+      return new BamlStream<partial_types.UnionTest_ReturnType, UnionTest_ReturnType>(
         raw,
-        (a): a is RecursivePartialNull<UnionTest_ReturnType> => a,
+        (a): a is partial_types.UnionTest_ReturnType => a,
         (a): a is UnionTest_ReturnType => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        __baml_options__?.signal,
       )
     } catch (error) {
       if (error instanceof Error) {
@@ -0,0 +1,284 @@
+/*************************************************************************************************
+
+Welcome to Baml! To use this generated code, please run one of the following:
+
+$ npm install @boundaryml/baml
+$ yarn add @boundaryml/baml
+$ pnpm add @boundaryml/baml
+
+*************************************************************************************************/
+
+// This file was generated by BAML: do not edit it. Instead, edit the BAML
+// files and re-generate this code.
+//
+/* eslint-disable */
+// tslint:disable
+// @ts-nocheck
+// biome-ignore format: autogenerated code
+import { Image } from "@boundaryml/baml"
+import * as types from "./types"
+
+///////////////////////////////////////////////////////////////////////////////
+//
+//  These types are used for streaming, for when an instance of a type
+//  is still being built up and any of its fields is not yet fully available.
+//
+///////////////////////////////////////////////////////////////////////////////
+
+export namespace partial_types {
+  export interface Blah {
+    prop4?: (string | null)
+    
+  }
+  export interface BookOrder {
+    orderId?: (string | null)
+    title?: (string | null)
+    quantity?: (number | null)
+    price?: (number | null)
+    
+  }
+  export interface ClassOptionalOutput {
+    prop1?: (string | null)
+    prop2?: (string | null)
+    
+  }
+  export interface ClassOptionalOutput2 {
+    prop1?: (string | null)
+    prop2?: (string | null)
+    prop3?: (partial_types.Blah | null)
+    
+  }
+  export interface ClassWithImage {
+    myImage?: (Image | null)
+    param2?: (string | null)
+    fake_image?: (partial_types.FakeImage | null)
+    
+  }
+  export interface CustomTaskResult {
+    bookOrder?: (partial_types.BookOrder | null)
+    flightConfirmation?: (partial_types.FlightConfirmation | null)
+    groceryReceipt?: (partial_types.GroceryReceipt | null)
+    
+  }
+  export interface DummyOutput {
+    nonce?: (string | null)
+    nonce2?: (string | null)
+    
+    [key: string]: any;
+  }
+  export interface DynInputOutput {
+    testKey?: (string | null)
+    
+    [key: string]: any;
+  }
+  export interface DynamicClassOne {
+    
+    [key: string]: any;
+  }
+  export interface DynamicClassTwo {
+    hi?: (string | null)
+    some_class?: (partial_types.SomeClassNestedDynamic | null)
+    status?: (string | types.DynEnumOne | null)
+    
+    [key: string]: any;
+  }
+  export interface DynamicOutput {
+    
+    [key: string]: any;
+  }
+  export interface Education {
+    institution?: (string | null)
+    location?: (string | null)
+    degree?: (string | null)
+    major?: (string | null)[]
+    graduation_date?: (string | null)
+    
+  }
+  export interface Email {
+    subject?: (string | null)
+    body?: (string | null)
+    from_address?: (string | null)
+    
+  }
+  export interface Event {
+    title?: (string | null)
+    date?: (string | null)
+    location?: (string | null)
+    description?: (string | null)
+    
+  }
+  export interface FakeImage {
+    url?: (string | null)
+    
+  }
+  export interface FlightConfirmation {
+    confirmationNumber?: (string | null)
+    flightNumber?: (string | null)
+    departureTime?: (string | null)
+    arrivalTime?: (string | null)
+    seatNumber?: (string | null)
+    
+  }
+  export interface GroceryReceipt {
+    receiptId?: (string | null)
+    storeName?: (string | null)
+    items?: ((string | null) | (number | null) | (number | null) | null)[]
+    totalAmount?: (number | null)
+    
+  }
+  export interface InnerClass {
+    prop1?: (string | null)
+    prop2?: (string | null)
+    inner?: (partial_types.InnerClass2 | null)
+    
+  }
+  export interface InnerClass2 {
+    prop2?: (number | null)
+    prop3?: (number | null)
+    
+  }
+  export interface LinkPreview {
+    url?: (string | null)
+    title: string
+    tags: string[]
+    description?: (string | null)
+    
+  }
+  export interface NamedArgsSingleClass {
+    key?: (string | null)
+    key_two?: (boolean | null)
+    key_three?: (number | null)
+    
+  }
+  export interface Nested {
+    prop3?: (string | null)
+    prop4?: (string | null)
+    prop20?: (partial_types.Nested2 | null)
+    
+  }
+  export interface Nested2 {
+    prop11?: (string | null)
+    prop12?: (string | null)
+    
+  }
+  export interface OptionalTest_Prop1 {
+    omega_a?: (string | null)
+    omega_b?: (number | null)
+    
+  }
+  export interface OptionalTest_ReturnType {
+    omega_1?: (partial_types.OptionalTest_Prop1 | null)
+    omega_2?: (string | null)
+    omega_3?: (types.OptionalTest_CategoryType | null)[]
+    
+  }
+  export interface OrderInfo {
+    order_status?: (types.OrderStatus | null)
+    tracking_number?: (string | null)
+    estimated_arrival_date?: (string | null)
+    
+  }
+  export interface Person {
+    name?: (string | null)
+    hair_color?: (string | types.Color | null)
+    
+    [key: string]: any;
+  }
+  export interface Quantity {
+    amount?: ((number | null) | (number | null) | null)
+    unit?: (string | null)
+    
+  }
+  export interface RaysData {
+    dataType?: (types.DataType | null)
+    value?: (partial_types.Resume | partial_types.Event | null)
+    
+  }
+  export interface ReceiptInfo {
+    items?: partial_types.ReceiptItem[]
+    total_cost?: (number | null)
+    
+  }
+  export interface ReceiptItem {
+    name?: (string | null)
+    description?: (string | null)
+    quantity?: (number | null)
+    price?: (number | null)
+    
+  }
+  export interface Recipe {
+    ingredients?: Record<string, (partial_types.Quantity | null)>
+    
+  }
+  export interface Resume {
+    name?: (string | null)
+    email?: (string | null)
+    phone?: (string | null)
+    experience?: partial_types.Education[]
+    education?: (string | null)[]
+    skills?: (string | null)[]
+    
+  }
+  export interface Schema {
+    prop1?: (string | null)
+    prop2?: (partial_types.Nested | (string | null) | null)
+    prop5?: (string | null)[]
+    prop6?: ((string | null) | partial_types.Nested[] | null)
+    nested_attrs?: ((string | null) | (null | null) | (null | null) | partial_types.Nested | null)[]
+    parens?: (string | null)
+    other_group?: ((string | null) | (number | null) | (string | null) | null)
+    
+  }
+  export interface SearchParams {
+    dateRange?: (number | null)
+    location?: (string | null)[]
+    jobTitle?: (partial_types.WithReasoning | null)
+    company?: (partial_types.WithReasoning | null)
+    description?: partial_types.WithReasoning[]
+    tags?: ((types.Tag | null) | (string | null) | null)[]
+    
+  }
+  export interface SomeClassNestedDynamic {
+    hi?: (string | null)
+    
+    [key: string]: any;
+  }
+  export interface StringToClassEntry {
+    word?: (string | null)
+    
+  }
+  export interface TestClassAlias {
+    key?: (string | null)
+    key2?: (string | null)
+    key3?: (string | null)
+    key4?: (string | null)
+    key5?: (string | null)
+    
+  }
+  export interface TestClassNested {
+    prop1?: (string | null)
+    prop2?: (partial_types.InnerClass | null)
+    
+  }
+  export interface TestClassWithEnum {
+    prop1?: (string | null)
+    prop2?: (types.EnumInClass | null)
+    
+  }
+  export interface TestOutputClass {
+    prop1?: (string | null)
+    prop2?: (number | null)
+    
+  }
+  export interface UnionTest_ReturnType {
+    prop1?: ((string | null) | (boolean | null) | null)
+    prop2?: ((number | null) | (boolean | null) | null)[]
+    prop3?: ((boolean | null)[] | (number | null)[] | null)
+    
+  }
+  export interface WithReasoning {
+    value?: (string | null)
+    reasoning?: (string | null)
+    
+  }
+}
\ No newline at end of file
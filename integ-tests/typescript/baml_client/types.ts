@@ -246,6 +246,14 @@ export interface InnerClass2 {
   
 }
 
+export interface LinkPreview {
+  url: string
+  title: string
+  tags: string[]
+  description?: string | null
+  
+}
+
 export interface NamedArgsSingleClass {
   key: string
   key_two: boolean
@@ -11,10 +11,9 @@ import {
   traceAsync,
   traceSync,
   setTags,
-  TestClassNested,
   onLogEvent,
+  partial_types,
 } from '../baml_client'
-import { RecursivePartialNull } from '../baml_client/async_client'
 import { b as b_sync } from '../baml_client/sync_client'
 import { config } from 'dotenv'
 import { BamlLogEvent, BamlRuntime } from '@boundaryml/baml/native'
@@ -513,7 +512,7 @@ describe('Integ tests', () => {
 
   it('should work with nested classes', async () => {
     let stream = b.stream.FnOutputClassNested('hi!')
-    let msgs: RecursivePartialNull<TestClassNested[]> = []
+    let msgs: partial_types.TestClassNested[] = []
     for await (const msg of stream) {
       console.log('msg', msg)
       msgs.push(msg)
@@ -524,6 +523,22 @@ describe('Integ tests', () => {
     expect(msgs.at(-1)).toEqual(final)
   })
 
+  it('should only stream finished or non-null fields marked with @stream', async () => {
+    let stream = b.stream.FnOutputLinkPreview('the history of the bicycle')
+    let msgs: partial_types.LinkPreview[] = []
+    for await (const msg of stream) {
+      msgs.push(msg)
+    }
+
+    const final = await stream.getFinalResponse()
+    expect(msgs.length).toBeGreaterThan(0)
+    for (const msg of msgs) {
+      expect(typeof msg.title).toEqual('string')
+      expect(Array.isArray(msg.tags)).toBe(true)
+      expect([null, undefined, final.url]).toContain(msg.url)
+    }
+  })
+
   it('should work with dynamic client', async () => {
     const clientRegistry = new ClientRegistry()
     clientRegistry.addLlmClient('myClient', 'openai', {
@@ -10,7 +10,13 @@ export const showTestsAtom = atom(false)
 export const showClientGraphAtom = atom(false)
 
 export type TestStatusType = 'queued' | 'running' | 'done' | 'error'
-export type DoneTestStatusType = 'passed' | 'llm_failed' | 'parse_failed' | 'error'
+export type DoneTestStatusType =
+  | 'passed'
+  | 'llm_failed'
+  | 'parse_failed'
+  | 'assertion_failed'
+  | 'needs_human_eval'
+  | 'error'
 export type TestState =
   | {
       status: 'queued'
@@ -44,6 +50,8 @@ export const statusCountAtom = atom({
     passed: 0,
     llm_failed: 0,
     parse_failed: 0,
+    assertion_failed: 0,
+    needs_human_eval: 0,
     error: 0,
   },
   error: 0,
@@ -92,6 +100,8 @@ export const useRunHooks = () => {
             passed: 0,
             llm_failed: 0,
             parse_failed: 0,
+            assertion_failed: 0,
+            needs_human_eval: 0,
             error: 0,
           },
           error: 0,
@@ -152,6 +162,10 @@ export const useRunHooks = () => {
                 response_status = 'llm_failed'
               } else if (status === 2) {
                 response_status = 'parse_failed'
+              } else if (status === 4) {
+                response_status = 'needs_human_eval'
+              } else if (status === 5) {
+                response_status = 'assertion_failed'
               } else {
                 response_status = 'error'
               }
@@ -52,6 +52,10 @@ const TestStatusMessage: React.FC<{ testStatus: DoneTestStatusType }> = ({ testS
       return <div className='text-vscode-testing-iconFailed'>LLM Failed</div>
     case 'parse_failed':
       return <div className='text-vscode-testing-iconFailed'>Parse Failed</div>
+    case 'assertion_failed':
+      return <div className='text-vscode-testing-iconFailed'>Assertion Failed</div>
+    case 'needs_human_eval':
+      return <div className='text-vscode-testing-iconQueued'>Checks Failed</div>
     case 'error':
       return <div className='text-vscode-testing-iconFailed'>Unable to run</div>
   }
@@ -93,8 +97,26 @@ const TestStatusIcon: React.FC<{
   )
 }
 
-type FilterValues = 'queued' | 'running' | 'error' | 'llm_failed' | 'parse_failed' | 'passed'
-const filterAtom = atom(new Set<FilterValues>(['running', 'error', 'llm_failed', 'parse_failed', 'passed']))
+type FilterValues =
+  | 'queued'
+  | 'running'
+  | 'error'
+  | 'llm_failed'
+  | 'parse_failed'
+  | 'assertion_failed'
+  | 'needs_human_eval'
+  | 'passed'
+const filterAtom = atom(
+  new Set<FilterValues>([
+    'running',
+    'error',
+    'llm_failed',
+    'parse_failed',
+    'assertion_failed',
+    'needs_human_eval',
+    'passed',
+  ]),
+)
 
 const checkFilter = (filter: Set<FilterValues>, status: TestStatusType, test_status?: DoneTestStatusType) => {
   if (filter.size === 0) {
@@ -234,6 +256,7 @@ const LLMTestResult: React.FC<{ test: WasmTestResponse; doneStatus: DoneTestStat
   const llm_response = test.llm_response()
   const llm_failure = test.llm_failure()
   const parsed = test.parsed_response()
+  const assertions = test.assertion_results()
 
   const latencyMs = llm_response?.latency_ms ?? llm_failure?.latency_ms
   const client = llm_response?.client_name() ?? llm_failure?.client_name()
@@ -267,6 +290,18 @@ const LLMTestResult: React.FC<{ test: WasmTestResponse; doneStatus: DoneTestStat
             {failure || '<no failure message>'}
           </div>
         )}
+      {assertions.length > 0 && (
+        <div className='flex flex-col text-xs'>
+          {assertions.map((a, i) => (
+            <div
+              key={i}
+              className={a.passed ? 'text-vscode-testing-iconPassed' : 'text-vscode-testing-iconFailed'}
+            >
+              {a.passed ? '✓' : '✗'} @@{a.level}({a.name}){a.error && `: ${a.error}`}
+            </div>
+          ))}
+        </div>
+      )}
       {(llm_response || llm_failure) && (
         <div className='w-full text-xs text-vscode-descriptionForeground'>
           <div>
@@ -510,6 +545,18 @@ const TestStatusBanner: React.FC = () => {
         count={statusCounts.done.parse_failed}
         onClick={() => toggleFilter('parse_failed')}
       />
+      <FilterButton
+        selected={filter.has('assertion_failed')}
+        name='Assertion Failed'
+        count={statusCounts.done.assertion_failed}
+        onClick={() => toggleFilter('assertion_failed')}
+      />
+      <FilterButton
+        selected={filter.has('needs_human_eval')}
+        name='Checks Failed'
+        count={statusCounts.done.needs_human_eval}
+        onClick={() => toggleFilter('needs_human_eval')}
+      />
       <FilterButton
         selected={filter.has('passed')}
         name='Passed'